@@ -0,0 +1,15 @@
+//! CPI interface for the access-mint program
+//!
+//! Depend on this crate to mint access tokens from another program without
+//! pulling in the program entrypoint.
+//!
+//! ```ignore
+//! use access_mint_cpi::{cpi, program::AccessMint};
+//!
+//! cpi::mint_access(CpiContext::new(
+//!     ctx.accounts.access_mint_program.to_account_info(),
+//!     cpi::accounts::MintAccess { /* ... */ },
+//! ))?;
+//! ```
+
+pub use access_mint::{accounts, cpi, errors::AccessMintError, instruction, program, receipt, state, ID};
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use common::ContentId;
+use crate::errors::*;
+
+/// Reject content ids that can't be the sha256 of any metadata blob
+pub fn validate_content_id(content_id: &[u8; 32]) -> Result<()> {
+    require!(
+        ContentId::from(*content_id).is_valid(),
+        AccessMintError::InvalidContentId
+    );
+    
+    Ok(())
+}
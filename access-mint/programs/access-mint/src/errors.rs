@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+/// Codes start at 8000 so access mint errors surfacing through payment escrow CPIs
+/// can be told apart from payment escrow's own (which start at 6000)
+#[error_code(offset = 8000)]
 pub enum AccessMintError {
     #[msg("Invalid creator")]
     InvalidCreator,
@@ -25,4 +27,82 @@ pub enum AccessMintError {
     
     #[msg("Numerical overflow")]
     NumericalOverflow,
+    
+    #[msg("Invalid rental duration")]
+    InvalidDuration,
+    
+    #[msg("Rental is still active")]
+    RentalStillActive,
+    
+    #[msg("Rental already reclaimed")]
+    AlreadyReclaimed,
+    
+    #[msg("Holder does not have active access")]
+    NoActiveAccess,
+    
+    #[msg("Invalid session duration")]
+    InvalidSessionDuration,
+    
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+    
+    #[msg("Max supply reached")]
+    MaxSupplyReached,
+    
+    #[msg("Max supply is below the number already minted")]
+    InvalidMaxSupply,
+    
+    #[msg("Invalid airdrop recipient token account")]
+    InvalidRecipient,
+    
+    #[msg("Invalid airdrop proof")]
+    InvalidAirdropProof,
+    
+    #[msg("All airdrop claims have been made")]
+    AirdropExhausted,
+    
+    #[msg("Access has expired")]
+    AccessExpired,
+    
+    #[msg("Invalid mint decimals")]
+    InvalidDecimals,
+    
+    #[msg("Units per purchase must be positive")]
+    InvalidUnitsPerPurchase,
+    
+    #[msg("Seat label too long")]
+    SeatTooLong,
+    
+    #[msg("Ticket has already been checked in")]
+    TicketAlreadyCheckedIn,
+    
+    #[msg("Ticket is not issued to this holder")]
+    InvalidTicketHolder,
+    
+    #[msg("A completed purchase of this content is required to mint access")]
+    PurchaseReceiptRequired,
+    
+    #[msg("Purchase receipt is not an unused completed escrow for this buyer and content")]
+    InvalidPurchaseReceipt,
+    
+    #[msg("Wallet already has access or has already had a grant for this content")]
+    PreviewUnavailable,
+    
+    #[msg("Access grant is not a preview")]
+    NotAPreview,
+    
+    #[msg("Collection name, symbol or URI too long")]
+    CollectionMetadataTooLong,
+    
+    #[msg("Access mint has already been verified into a collection")]
+    CollectionAlreadyVerified,
+    
+    #[msg("Collection accounts do not match the access mint's collection")]
+    InvalidCollection,
+    
+    #[msg("Rental and preview access can't be migrated")]
+    GrantNotMigratable,
+    
+    #[msg("Access can only be migrated to a different wallet")]
+    InvalidMigrationTarget,
 }
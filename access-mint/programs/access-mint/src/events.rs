@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+/// Emitted for each recipient of an access token airdrop
+#[event]
+pub struct AccessAirdropped {
+    /// Access mint state PDA
+    pub access_mint_state: Pubkey,
+    
+    /// Wallet receiving the access token
+    pub recipient: Pubkey,
+    
+    /// Recipient's token account credited
+    pub token_account: Pubkey,
+    
+    /// Timestamp of the airdrop
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder consumes one unit of an access token
+#[event]
+pub struct AccessRedeemed {
+    /// Redemption receipt PDA
+    pub redemption: Pubkey,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// The access token mint
+    pub mint: Pubkey,
+    
+    /// Wallet that redeemed the unit
+    pub holder: Pubkey,
+    
+    /// Timestamp of the redemption
+    pub timestamp: i64,
+}
+
+/// Emitted when a validator checks a ticket in
+#[event]
+pub struct TicketCheckedIn {
+    /// Ticket PDA
+    pub ticket: Pubkey,
+    
+    /// Creator-assigned ticket number
+    pub ticket_id: u64,
+    
+    /// Ticket holder
+    pub holder: Pubkey,
+    
+    /// Validator that checked the ticket in
+    pub validator: Pubkey,
+    
+    /// Timestamp of the check-in
+    pub timestamp: i64,
+}
+
+/// Emitted when a buyer's access is re-issued to a new wallet
+#[event]
+pub struct AccessMigrated {
+    /// Migration record PDA
+    pub migration: Pubkey,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// The access token mint
+    pub mint: Pubkey,
+    
+    /// Wallet the access was burned from
+    pub old_wallet: Pubkey,
+    
+    /// Wallet the access was minted to
+    pub new_wallet: Pubkey,
+    
+    /// Number of access units moved
+    pub amount: u64,
+    
+    /// Timestamp of the migration
+    pub timestamp: i64,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Authorize a validator key to check in tickets for this access mint
+pub fn add_ticket_validator(ctx: Context<AddTicketValidator>, validator: Pubkey) -> Result<()> {
+    let ticket_validator = &mut ctx.accounts.ticket_validator;
+    ticket_validator.access_mint_state = ctx.accounts.access_mint_state.key();
+    ticket_validator.validator = validator;
+    ticket_validator.bump = ctx.bumps.ticket_validator;
+    
+    msg!("Ticket validator added: {}", validator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(validator: Pubkey)]
+pub struct AddTicketValidator<'info> {
+    /// The creator who owns the content
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Ticket validator PDA
+    #[account(
+        init,
+        payer = creator,
+        space = TicketValidator::LEN,
+        seeds = [
+            TicketValidator::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            validator.as_ref(),
+        ],
+        bump
+    )]
+    pub ticket_validator: Account<'info, TicketValidator>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
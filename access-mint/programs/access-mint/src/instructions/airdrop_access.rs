@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Maximum recipients per airdrop call
+pub const MAX_AIRDROP_RECIPIENTS: usize = 20;
+
+/// Mint one access token to each recipient without payment (press copies, giveaways, migrations)
+/// Remaining accounts: each recipient's associated token account for the access mint (mut)
+pub fn airdrop_access<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AirdropAccess<'info>>,
+) -> Result<()> {
+    let recipient_count = ctx.remaining_accounts.len();
+    require!(
+        recipient_count > 0 && recipient_count <= MAX_AIRDROP_RECIPIENTS,
+        AccessMintError::InvalidBatchSize
+    );
+    
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    
+    // Airdrops count against the max supply like sales do
+    access_mint_state.record_mint(recipient_count as u64)?;
+    
+    let creator = access_mint_state.creator;
+    let content_id = access_mint_state.content_id;
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
+    
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        creator.as_ref(),
+        content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[access_mint_state.authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+    let timestamp = Clock::get()?.unix_timestamp;
+    
+    for recipient_account in ctx.remaining_accounts.iter() {
+        let token_account = Account::<TokenAccount>::try_from(recipient_account)?;
+        
+        // Only the recipient's canonical ATA, so wallets find the token
+        require!(
+            recipient_account.key()
+                == get_associated_token_address(&token_account.owner, &ctx.accounts.mint.key()),
+            AccessMintError::InvalidRecipient
+        );
+        
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: recipient_account.clone(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+        
+        emit!(AccessAirdropped {
+            access_mint_state: ctx.accounts.access_mint_state.key(),
+            recipient: token_account.owner,
+            token_account: recipient_account.key(),
+            timestamp,
+        });
+    }
+    
+    msg!("Access airdropped to {} recipients, total minted: {}", 
+        recipient_count, ctx.accounts.access_mint_state.total_minted);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AirdropAccess<'info> {
+    /// The creator who owns the content
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The mint account
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint authority PDA
+    /// CHECK: PDA validated by seeds with the stored bump
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    // Remaining accounts: recipient associated token accounts for the access mint
+}
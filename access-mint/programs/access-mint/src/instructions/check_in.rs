@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Mark a ticket as used, co-signed by the holder and a registered validator
+/// The holder keeps their access token; the ticket can only be checked in once
+pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    
+    require!(!ticket.checked_in, AccessMintError::TicketAlreadyCheckedIn);
+    
+    let clock = Clock::get()?;
+    ticket.checked_in = true;
+    ticket.checked_in_ts = clock.unix_timestamp;
+    ticket.checked_in_by = ctx.accounts.validator.key();
+    
+    emit!(TicketCheckedIn {
+        ticket: ticket.key(),
+        ticket_id: ticket.ticket_id,
+        holder: ticket.holder,
+        validator: ticket.checked_in_by,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Ticket {} checked in", ticket.ticket_id);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckIn<'info> {
+    /// The ticket holder
+    pub holder: Signer<'info>,
+    
+    /// Registered validator co-signing the check-in
+    pub validator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The access token mint
+    pub mint: Account<'info, Mint>,
+    
+    /// Holder's access token account (frozen accounts are rentals)
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        constraint = holder_token_account.amount > 0 && !holder_token_account.is_frozen()
+            @ AccessMintError::NoActiveAccess,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Validator registration PDA
+    #[account(
+        seeds = [
+            TicketValidator::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            validator.key().as_ref(),
+        ],
+        bump = ticket_validator.bump,
+    )]
+    pub ticket_validator: Account<'info, TicketValidator>,
+    
+    /// Ticket PDA
+    #[account(
+        mut,
+        seeds = [
+            Ticket::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            ticket.ticket_id.to_le_bytes().as_ref(),
+        ],
+        bump = ticket.bump,
+        has_one = holder @ AccessMintError::InvalidTicketHolder,
+    )]
+    pub ticket: Account<'info, Ticket>,
+}
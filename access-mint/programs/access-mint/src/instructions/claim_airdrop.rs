@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::associated_token::AssociatedToken;
+use common::merkle;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Claim an airdropped access token with a Merkle proof of eligibility
+/// The claim record's rent is reimbursed from the airdrop's deposit while it lasts
+pub fn claim_airdrop(ctx: Context<ClaimAirdrop>, proof: Vec<[u8; 32]>) -> Result<()> {
+    let recipient = ctx.accounts.recipient.key();
+    let airdrop = &mut ctx.accounts.airdrop;
+    
+    require!(
+        merkle::verify(&proof, &airdrop.merkle_root, merkle::leaf(&recipient.to_bytes())),
+        AccessMintError::InvalidAirdropProof
+    );
+    
+    airdrop.claimed = airdrop
+        .claimed
+        .checked_add(1)
+        .ok_or(AccessMintError::NumericalOverflow)?;
+    require!(
+        airdrop.max_claims == 0 || airdrop.claimed <= airdrop.max_claims,
+        AccessMintError::AirdropExhausted
+    );
+    
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    access_mint_state.record_mint(1)?;
+    
+    let creator = access_mint_state.creator;
+    let content_id = access_mint_state.content_id;
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
+    
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        creator.as_ref(),
+        content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[access_mint_state.authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+    
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    claim.airdrop = ctx.accounts.airdrop.key();
+    claim.recipient = recipient;
+    claim.claimed_ts = clock.unix_timestamp;
+    claim.bump = ctx.bumps.claim;
+    
+    // Reimburse the claim record's rent from whatever the deposit has left
+    let rent = Rent::get()?;
+    let available = ctx
+        .accounts
+        .airdrop
+        .get_lamports()
+        .saturating_sub(rent.minimum_balance(MerkleAirdrop::LEN));
+    let reimbursement = rent.minimum_balance(AirdropClaim::LEN).min(available);
+    if reimbursement > 0 {
+        ctx.accounts.airdrop.sub_lamports(reimbursement)?;
+        ctx.accounts.recipient.add_lamports(reimbursement)?;
+    }
+    
+    emit!(AccessAirdropped {
+        access_mint_state: ctx.accounts.access_mint_state.key(),
+        recipient,
+        token_account: ctx.accounts.recipient_token_account.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Airdrop claimed by {}, reimbursed: {}", recipient, reimbursement);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimAirdrop<'info> {
+    /// Wallet claiming its access token
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    
+    /// Merkle airdrop PDA
+    #[account(
+        mut,
+        seeds = [MerkleAirdrop::SEED_PREFIX, access_mint_state.key().as_ref()],
+        bump = airdrop.bump,
+        has_one = access_mint_state @ AccessMintError::InvalidMint,
+    )]
+    pub airdrop: Account<'info, MerkleAirdrop>,
+    
+    /// Claim record PDA; exists once the recipient has claimed
+    #[account(
+        init,
+        payer = recipient,
+        space = AirdropClaim::LEN,
+        seeds = [AirdropClaim::SEED_PREFIX, airdrop.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, AirdropClaim>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The mint account
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint authority PDA
+    /// CHECK: PDA validated by seeds with the stored bump
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Recipient's token account (ATA)
+    /// Will be created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
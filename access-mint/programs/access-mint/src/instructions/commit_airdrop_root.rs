@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Commit (or replace) the Merkle root of wallets that may claim an access token,
+/// depositing lamports that reimburse recipients for their claim records
+/// Wallets that already claimed stay claimed when the root is replaced
+pub fn commit_airdrop_root(
+    ctx: Context<CommitAirdropRoot>,
+    merkle_root: [u8; 32],
+    max_claims: u32,
+    rent_deposit: u64,
+) -> Result<()> {
+    let airdrop = &mut ctx.accounts.airdrop;
+    
+    // A cap below the claims already made would strand the airdrop
+    require!(
+        max_claims == 0 || max_claims >= airdrop.claimed,
+        AccessMintError::InvalidMaxSupply
+    );
+    
+    airdrop.access_mint_state = ctx.accounts.access_mint_state.key();
+    airdrop.merkle_root = merkle_root;
+    airdrop.max_claims = max_claims;
+    airdrop.updated_ts = Clock::get()?.unix_timestamp;
+    airdrop.bump = ctx.bumps.airdrop;
+    
+    if rent_deposit > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.airdrop.to_account_info(),
+                },
+            ),
+            rent_deposit,
+        )?;
+    }
+    
+    msg!("Airdrop root committed, max claims: {}, rent deposit: {}", max_claims, rent_deposit);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitAirdropRoot<'info> {
+    /// The creator who owns the content
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Merkle airdrop PDA
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = MerkleAirdrop::LEN,
+        seeds = [MerkleAirdrop::SEED_PREFIX, access_mint_state.key().as_ref()],
+        bump
+    )]
+    pub airdrop: Account<'info, MerkleAirdrop>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+
+/// Authorize a short-lived session key to act on the holder's access
+/// Re-authorizing an existing session key resets its expiry
+pub fn create_session_grant(
+    ctx: Context<CreateSessionGrant>,
+    session_key: Pubkey,
+    duration: i64,
+) -> Result<()> {
+    require!(
+        duration > 0 && duration <= SessionGrant::MAX_DURATION,
+        AccessMintError::InvalidSessionDuration
+    );
+    
+    let clock = Clock::get()?;
+    
+    // Renters hold a frozen token, so their grant decides whether they have access
+    AccessGrant::check_access(
+        &ctx.accounts.holder_token_account,
+        &ctx.accounts.access_grant,
+        clock.unix_timestamp,
+    )?;
+    
+    let expires_ts = clock
+        .unix_timestamp
+        .checked_add(duration)
+        .ok_or(AccessMintError::NumericalOverflow)?;
+    
+    let session = &mut ctx.accounts.session_grant;
+    session.holder = ctx.accounts.holder.key();
+    session.mint = ctx.accounts.mint.key();
+    session.session_key = session_key;
+    session.created_ts = clock.unix_timestamp;
+    session.expires_ts = expires_ts;
+    session.bump = ctx.bumps.session_grant;
+    
+    msg!("Session grant created, expires: {}", expires_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSessionGrant<'info> {
+    /// The access token holder authorizing the session
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The access token mint
+    pub mint: Account<'info, Mint>,
+    
+    /// Holder's access token account
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Holder's rental grant PDA, checked for expiry when it exists
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump
+    )]
+    pub access_grant: UncheckedAccount<'info>,
+    
+    /// Session grant PDA for this session key
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = SessionGrant::LEN,
+        seeds = [
+            SessionGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+            session_key.as_ref(),
+        ],
+        bump
+    )]
+    pub session_grant: Account<'info, SessionGrant>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
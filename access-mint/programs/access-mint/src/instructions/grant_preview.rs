@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::*;
+
+/// Mint a free, time-boxed preview access token to a wallet (creator only)
+/// The preview expires like a rental and is reclaimed with reclaim_expired; a full
+/// purchase that passes the preview grant to mint_access makes it permanent instead
+pub fn grant_preview(ctx: Context<GrantPreview>, duration: i64) -> Result<()> {
+    require!(duration > 0, AccessMintError::InvalidDuration);
+    
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    let grant = &mut ctx.accounts.access_grant;
+    let clock = Clock::get()?;
+    
+    // One preview per wallet, and never for a wallet that already holds or has rented access
+    require!(
+        grant.end_ts == 0 && ctx.accounts.holder_token_account.amount == 0,
+        AccessMintError::PreviewUnavailable
+    );
+    
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        access_mint_state.creator.as_ref(),
+        access_mint_state.content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[access_mint_state.authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+    
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    
+    // Previews count against the max supply; the upgrading purchase doesn't mint it again
+    access_mint_state.record_mint(1)?;
+    
+    grant.holder = ctx.accounts.holder.key();
+    grant.mint = ctx.accounts.mint.key();
+    grant.start_ts = clock.unix_timestamp;
+    grant.end_ts = clock
+        .unix_timestamp
+        .checked_add(duration)
+        .ok_or(AccessMintError::NumericalOverflow)?;
+    grant.reclaimed = false;
+    grant.preview = true;
+    grant.bump = ctx.bumps.access_grant;
+    
+    msg!("Preview access granted to: {} until: {}", grant.holder, grant.end_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GrantPreview<'info> {
+    /// The content creator granting the preview, paying for the accounts
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// The wallet receiving the preview
+    /// CHECK: Any wallet chosen by the creator
+    pub holder: UncheckedAccount<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::Unauthorized,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The mint account
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint (and freeze) authority PDA
+    /// CHECK: PDA validated by seeds
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Holder's token account (ATA)
+    /// Will be created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Access grant PDA recording the preview window
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = AccessGrant::LEN,
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
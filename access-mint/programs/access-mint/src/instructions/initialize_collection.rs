@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::*;
+use crate::metaplex::*;
+
+/// Create a creator's access collection: a Metaplex collection NFT held by the creator
+/// that the creator's access mints are verified into
+/// The collection PDA is the NFT's authority, so only this program can verify members
+pub fn initialize_collection(
+    ctx: Context<InitializeCollection>,
+    series: u64,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    require!(
+        name.len() <= MAX_NAME_LEN && symbol.len() <= MAX_SYMBOL_LEN && uri.len() <= MAX_URI_LEN,
+        AccessMintError::CollectionMetadataTooLong
+    );
+    
+    let creator = ctx.accounts.creator.key();
+    let series_bytes = series.to_le_bytes();
+    let collection_seeds = &[
+        AccessCollection::SEED_PREFIX,
+        creator.as_ref(),
+        series_bytes.as_ref(),
+        &[ctx.bumps.access_collection],
+    ];
+    let signer_seeds = &[&collection_seeds[..]];
+    
+    // The collection NFT is a one-of-one held by the creator
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.access_collection.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    
+    create_metadata_v3(
+        CreateMetadataAccounts {
+            metadata: ctx.accounts.collection_metadata.to_account_info(),
+            mint: ctx.accounts.collection_mint.to_account_info(),
+            mint_authority: ctx.accounts.access_collection.to_account_info(),
+            payer: ctx.accounts.creator.to_account_info(),
+            update_authority: ctx.accounts.access_collection.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        Some(0),
+        signer_seeds,
+    )?;
+    
+    create_master_edition_v3(
+        CreateMasterEditionAccounts {
+            edition: ctx.accounts.collection_master_edition.to_account_info(),
+            mint: ctx.accounts.collection_mint.to_account_info(),
+            update_authority: ctx.accounts.access_collection.to_account_info(),
+            mint_authority: ctx.accounts.access_collection.to_account_info(),
+            payer: ctx.accounts.creator.to_account_info(),
+            metadata: ctx.accounts.collection_metadata.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        Some(0),
+        signer_seeds,
+    )?;
+    
+    let access_collection = &mut ctx.accounts.access_collection;
+    access_collection.creator = creator;
+    access_collection.series = series;
+    access_collection.collection_mint = ctx.accounts.collection_mint.key();
+    access_collection.name = name;
+    access_collection.symbol = symbol;
+    access_collection.uri = uri;
+    access_collection.member_count = 0;
+    access_collection.bump = ctx.bumps.access_collection;
+    
+    msg!("Access collection {} created for creator: {}", access_collection.collection_mint, creator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(series: u64)]
+pub struct InitializeCollection<'info> {
+    /// The creator owning the collection
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Access collection PDA, authority of the collection NFT
+    #[account(
+        init,
+        payer = creator,
+        space = AccessCollection::LEN,
+        seeds = [
+            AccessCollection::SEED_PREFIX,
+            creator.key().as_ref(),
+            series.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub access_collection: Account<'info, AccessCollection>,
+    
+    /// The collection NFT mint (new keypair)
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 0,
+        mint::authority = access_collection,
+        mint::freeze_authority = access_collection,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    
+    /// Creator's token account holding the collection NFT
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = collection_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    /// Collection NFT metadata account
+    /// CHECK: Metaplex metadata PDA of the collection mint, created by Token Metadata
+    #[account(mut, address = metadata_address(&collection_mint.key()))]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// Collection NFT master edition account
+    /// CHECK: Metaplex master edition PDA of the collection mint, created by Token Metadata
+    #[account(mut, address = master_edition_address(&collection_mint.key()))]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    /// Metaplex Token Metadata program
+    /// CHECK: Address checked against the Token Metadata program id
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -1,13 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 use crate::state::*;
+use crate::content::*;
+use crate::errors::*;
 
 /// Initialize a new access token mint for a specific content
+/// Minting requires a purchase receipt (or the payment escrow's access minter) until the
+/// creator opts out with set_require_purchase_receipt
 pub fn initialize_mint(
     ctx: Context<InitializeMint>,
     content_id: [u8; 32],
     seed: u64,
+    tier: AccessTier,
+    decimals: u8,
+    units_per_purchase: u64,
 ) -> Result<()> {
+    validate_content_id(&content_id)?;
+    require!(
+        decimals <= AccessMintState::MAX_DECIMALS,
+        AccessMintError::InvalidDecimals
+    );
+    require!(units_per_purchase > 0, AccessMintError::InvalidUnitsPerPurchase);
+    
     let access_mint_state = &mut ctx.accounts.access_mint_state;
     let clock = Clock::get()?;
     
@@ -18,17 +32,23 @@ pub fn initialize_mint(
     access_mint_state.mint_authority = ctx.accounts.mint_authority.key();
     access_mint_state.seed = seed;
     access_mint_state.total_minted = 0;
+    access_mint_state.max_supply = 0;
+    access_mint_state.units_per_purchase = units_per_purchase;
+    access_mint_state.tier = tier;
     access_mint_state.created_ts = clock.unix_timestamp;
     access_mint_state.bump = ctx.bumps.access_mint_state;
+    access_mint_state.authority_bump = ctx.bumps.mint_authority;
+    access_mint_state.require_purchase_receipt = true;
+    access_mint_state.collection = None;
+    access_mint_state.collection_verified = false;
     
-    msg!("Access mint initialized for creator: {}, content_id: {:?}", 
-        ctx.accounts.creator.key(), content_id);
+    msg!("Access mint initialized for creator: {}", ctx.accounts.creator.key());
     
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(content_id: [u8; 32], seed: u64)]
+#[instruction(content_id: [u8; 32], seed: u64, tier: AccessTier, decimals: u8)]
 pub struct InitializeMint<'info> {
     /// The creator who owns the content
     #[account(mut)]
@@ -53,7 +73,7 @@ pub struct InitializeMint<'info> {
     #[account(
         init,
         payer = creator,
-        mint::decimals = 0,
+        mint::decimals = decimals,
         mint::authority = mint_authority,
         mint::freeze_authority = mint_authority,
     )]
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token::{initialize_mint2, InitializeMint2, Mint, Token};
+use crate::state::*;
+use crate::errors::*;
+use crate::content::*;
+
+/// Maximum mints per batch
+/// Every mint is a fresh keypair that signs, so transaction size is the binding limit
+pub const MAX_BATCH_MINTS: usize = 5;
+
+/// Remaining accounts consumed per entry: access mint state, mint, mint authority
+pub const ACCOUNTS_PER_BATCH_MINT: usize = 3;
+
+/// A content to create an access mint for in a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchMintEntry {
+    /// 32-byte unique identifier for the content
+    pub content_id: [u8; 32],
+    
+    /// Seed for PDA derivation
+    pub seed: u64,
+    
+    /// Access tier granted by the mint
+    pub tier: AccessTier,
+}
+
+/// Initialize access token mints for several contents in one transaction
+/// Remaining accounts: [access_mint_state (mut), mint (mut, signer), mint_authority] per entry
+pub fn initialize_mints_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeMintsBatch<'info>>,
+    entries: Vec<BatchMintEntry>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_BATCH_MINTS,
+        AccessMintError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * ACCOUNTS_PER_BATCH_MINT,
+        AccessMintError::InvalidBatchSize
+    );
+    
+    let creator = ctx.accounts.creator.key();
+    let rent = Rent::get()?;
+    let clock = Clock::get()?;
+    
+    for (entry, accounts) in entries
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_BATCH_MINT))
+    {
+        validate_content_id(&entry.content_id)?;
+        
+        let access_mint_state = &accounts[0];
+        let mint = &accounts[1];
+        let mint_authority = &accounts[2];
+        let seed_bytes = entry.seed.to_le_bytes();
+        
+        // Same PDAs initialize_mint derives
+        let (state_address, bump) = Pubkey::find_program_address(
+            &[
+                AccessMintState::SEED_PREFIX,
+                creator.as_ref(),
+                entry.content_id.as_ref(),
+                seed_bytes.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(access_mint_state.key(), state_address, AccessMintError::InvalidMint);
+        
+        let (authority_address, authority_bump) = Pubkey::find_program_address(
+            &[
+                AccessMintState::AUTHORITY_SEED_PREFIX,
+                creator.as_ref(),
+                entry.content_id.as_ref(),
+                seed_bytes.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            mint_authority.key(),
+            authority_address,
+            AccessMintError::InvalidMintAuthority
+        );
+        require!(mint.is_signer, AccessMintError::InvalidMint);
+        
+        // Create the access mint state, signed for by its PDA
+        create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: access_mint_state.clone(),
+                },
+                &[&[
+                    AccessMintState::SEED_PREFIX,
+                    creator.as_ref(),
+                    entry.content_id.as_ref(),
+                    seed_bytes.as_ref(),
+                    &[bump],
+                ]],
+            ),
+            rent.minimum_balance(AccessMintState::LEN),
+            AccessMintState::LEN as u64,
+            ctx.program_id,
+        )?;
+        
+        let state = AccessMintState {
+            creator,
+            content_id: entry.content_id,
+            mint: mint.key(),
+            mint_authority: authority_address,
+            seed: entry.seed,
+            total_minted: 0,
+            max_supply: 0,
+            units_per_purchase: 1,
+            tier: entry.tier,
+            created_ts: clock.unix_timestamp,
+            bump,
+            authority_bump,
+            require_purchase_receipt: true,
+            collection: None,
+            collection_verified: false,
+        };
+        state.try_serialize(&mut &mut access_mint_state.try_borrow_mut_data()?[..])?;
+        
+        // Create the SPL mint with the PDA as mint and freeze authority
+        create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: mint.clone(),
+                },
+            ),
+            rent.minimum_balance(Mint::LEN),
+            Mint::LEN as u64,
+            &Token::id(),
+        )?;
+        
+        initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InitializeMint2 { mint: mint.clone() },
+            ),
+            0,
+            &authority_address,
+            Some(&authority_address),
+        )?;
+    }
+    
+    msg!("{} access mints initialized for creator: {}", entries.len(), creator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeMintsBatch<'info> {
+    /// The creator who owns the contents
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: [access_mint_state, mint, mint_authority] per entry
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Assign a seat and tier to a ticket for one of the event's access token holders
+pub fn issue_ticket(
+    ctx: Context<IssueTicket>,
+    ticket_id: u64,
+    holder: Pubkey,
+    seat: String,
+    tier: u8,
+) -> Result<()> {
+    require!(seat.len() <= Ticket::MAX_SEAT_LEN, AccessMintError::SeatTooLong);
+    
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.access_mint_state = ctx.accounts.access_mint_state.key();
+    ticket.ticket_id = ticket_id;
+    ticket.holder = holder;
+    ticket.seat = seat;
+    ticket.tier = tier;
+    ticket.checked_in = false;
+    ticket.checked_in_ts = 0;
+    ticket.checked_in_by = Pubkey::default();
+    ticket.bump = ctx.bumps.ticket;
+    
+    msg!("Ticket {} issued to {}", ticket_id, holder);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(ticket_id: u64)]
+pub struct IssueTicket<'info> {
+    /// The creator who owns the content
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Ticket PDA
+    #[account(
+        init,
+        payer = creator,
+        space = Ticket::LEN,
+        seeds = [
+            Ticket::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            ticket_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
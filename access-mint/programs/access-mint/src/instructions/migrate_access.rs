@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Move a buyer's access from a compromised wallet to a new one
+/// The old wallet proves ownership by signing and the creator co-signs the re-issue
+pub fn migrate_access(ctx: Context<MigrateAccess>) -> Result<()> {
+    // Rentals and previews expire on their own grant, which is keyed by the old wallet
+    if !ctx.accounts.access_grant.data_is_empty() {
+        let data = ctx.accounts.access_grant.try_borrow_data()?;
+        let grant = AccessGrant::try_deserialize(&mut &data[..])?;
+        require!(
+            grant.end_ts == AccessGrant::PERMANENT_END_TS,
+            AccessMintError::GrantNotMigratable
+        );
+    }
+    
+    let amount = ctx.accounts.old_token_account.amount;
+    
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.old_token_account.to_account_info(),
+                authority: ctx.accounts.old_wallet.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let access_mint_state = &ctx.accounts.access_mint_state;
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        access_mint_state.creator.as_ref(),
+        access_mint_state.content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[access_mint_state.authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+    
+    // Re-issued units replace burned ones, so the minted total is left unchanged
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.new_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    let clock = Clock::get()?;
+    let migration = &mut ctx.accounts.migration;
+    migration.mint = ctx.accounts.mint.key();
+    migration.old_wallet = ctx.accounts.old_wallet.key();
+    migration.new_wallet = ctx.accounts.new_wallet.key();
+    migration.amount = amount;
+    migration.migrated_ts = clock.unix_timestamp;
+    migration.bump = ctx.bumps.migration;
+    
+    emit!(AccessMigrated {
+        migration: migration.key(),
+        content_id: access_mint_state.content_id,
+        mint: migration.mint,
+        old_wallet: migration.old_wallet,
+        new_wallet: migration.new_wallet,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Access migrated from {} to {}", migration.old_wallet, migration.new_wallet);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccess<'info> {
+    /// The buyer's old wallet, proving ownership by signing (also pays for the migration)
+    #[account(mut)]
+    pub old_wallet: Signer<'info>,
+    
+    /// The content creator co-signing the re-issue
+    pub creator: Signer<'info>,
+    
+    /// The buyer's new wallet receiving the access
+    /// CHECK: Any wallet chosen by the buyer
+    #[account(constraint = new_wallet.key() != old_wallet.key() @ AccessMintError::InvalidMigrationTarget)]
+    pub new_wallet: UncheckedAccount<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+        has_one = creator @ AccessMintError::Unauthorized,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The access token mint
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint authority PDA
+    /// CHECK: PDA validated by seeds with the stored bump
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Old wallet's access token account (frozen accounts are rentals)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = old_wallet,
+        constraint = old_token_account.amount > 0 && !old_token_account.is_frozen()
+            @ AccessMintError::NoActiveAccess,
+    )]
+    pub old_token_account: Account<'info, TokenAccount>,
+    
+    /// New wallet's access token account
+    /// Will be created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = old_wallet,
+        associated_token::mint = mint,
+        associated_token::authority = new_wallet,
+    )]
+    pub new_token_account: Account<'info, TokenAccount>,
+    
+    /// Old wallet's access grant, if any (time-boxed access can't be migrated)
+    /// CHECK: PDA validated by seeds, contents checked when the account exists
+    #[account(
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            old_wallet.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub access_grant: UncheckedAccount<'info>,
+    
+    /// Migration record PDA linking the new wallet to the original buyer
+    #[account(
+        init,
+        payer = old_wallet,
+        space = AccessMigration::LEN,
+        seeds = [
+            AccessMigration::SEED_PREFIX,
+            mint.key().as_ref(),
+            old_wallet.key().as_ref(),
+        ],
+        bump
+    )]
+    pub migration: Account<'info, AccessMigration>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
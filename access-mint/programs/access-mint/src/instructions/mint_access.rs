@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, ThawAccount};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::errors::*;
+use crate::receipt::{EscrowReceipt, PAYMENT_ESCROW_PROGRAM_ID};
+use crate::metaplex::*;
 
 /// Mint an access token to a buyer
 /// This is typically called via CPI from the payment escrow program
@@ -15,60 +17,156 @@ pub fn mint_access(ctx: Context<MintAccess>) -> Result<()> {
         AccessMintError::InvalidMint
     );
     
-    // Get PDA signer seeds
+    // Creators can require proof of a completed escrow purchase, so direct calls can't mint;
+    // the escrow program's other flows take their own payment and sign as the access minter
+    if access_mint_state.require_purchase_receipt && ctx.accounts.access_minter.is_none() {
+        let purchase_receipt = ctx
+            .accounts
+            .purchase_receipt
+            .as_ref()
+            .ok_or(AccessMintError::PurchaseReceiptRequired)?;
+        EscrowReceipt::load(purchase_receipt)?.check_purchase(
+            ctx.accounts.buyer.key,
+            &access_mint_state.creator,
+            &access_mint_state.content_id,
+        )?;
+    }
+    
+    // Get PDA signer seeds from the stored authority bump
     let creator = access_mint_state.creator;
     let content_id = access_mint_state.content_id;
-    let seed = access_mint_state.seed;
-    let seed_bytes = seed.to_le_bytes();
-    
-    // Derive the bump manually since we're using UncheckedAccount
-    let (expected_authority, authority_bump) = Pubkey::find_program_address(
-        &[
-            AccessMintState::AUTHORITY_SEED_PREFIX,
-            creator.as_ref(),
-            content_id.as_ref(),
-            seed_bytes.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    
-    // Verify the mint authority matches
-    require!(
-        ctx.accounts.mint_authority.key() == expected_authority,
-        AccessMintError::InvalidMintAuthority
-    );
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
     
     let authority_seeds = &[
         AccessMintState::AUTHORITY_SEED_PREFIX,
         creator.as_ref(),
         content_id.as_ref(),
         seed_bytes.as_ref(),
-        &[authority_bump],
+        &[access_mint_state.authority_bump],
     ];
     let signer_seeds = &[&authority_seeds[..]];
     
-    // Mint 1 access token to buyer (decimals = 0, so amount = 1)
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        1, // Mint 1 token (with 0 decimals)
-    )?;
+    // Mint the purchase's access units to the buyer
+    let mut units = access_mint_state.purchase_units();
     
-    // Update total minted count
-    access_mint_state.total_minted = access_mint_state
-        .total_minted
-        .checked_add(1)
-        .ok_or(AccessMintError::NumericalOverflow)?;
+    // A purchase upgrades the buyer's preview to permanent access: the previewed token
+    // is kept (thawed if it was already reclaimed) and only the remaining units are minted
+    if let Some(preview_grant) = ctx.accounts.preview_grant.as_mut() {
+        if preview_grant.reclaimed {
+            token::thaw_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                ThawAccount {
+                    account: ctx.accounts.buyer_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+        
+        let held = ctx.accounts.buyer_token_account.amount.min(1);
+        units = units.saturating_sub(held);
+        
+        preview_grant.end_ts = AccessGrant::PERMANENT_END_TS;
+        preview_grant.reclaimed = false;
+        preview_grant.preview = false;
+        
+        msg!("Preview upgraded to permanent access for: {}", preview_grant.holder);
+    }
+    
+    if units > 0 {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.buyer_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            units,
+        )?;
+        
+        // Update total minted count
+        access_mint_state.record_mint(units)?;
+    }
+    
+    // The first mint after a collection is assigned verifies the access mint into it
+    if let Some(access_collection) = ctx.accounts.access_collection.as_mut() {
+        require!(
+            access_mint_state.collection == Some(access_collection.key()),
+            AccessMintError::InvalidCollection
+        );
+        require!(
+            !access_mint_state.collection_verified,
+            AccessMintError::CollectionAlreadyVerified
+        );
+        
+        let access_metadata = ctx.accounts.access_metadata.as_ref().ok_or(AccessMintError::InvalidCollection)?;
+        let collection_mint = ctx.accounts.collection_mint.as_ref().ok_or(AccessMintError::InvalidCollection)?;
+        let collection_metadata = ctx.accounts.collection_metadata.as_ref().ok_or(AccessMintError::InvalidCollection)?;
+        let collection_master_edition = ctx.accounts.collection_master_edition.as_ref().ok_or(AccessMintError::InvalidCollection)?;
+        let token_metadata_program = ctx.accounts.token_metadata_program.as_ref().ok_or(AccessMintError::InvalidCollection)?;
+        
+        let collection_series = access_collection.series.to_le_bytes();
+        let collection_seeds = &[
+            AccessCollection::SEED_PREFIX,
+            access_collection.creator.as_ref(),
+            collection_series.as_ref(),
+            &[access_collection.bump],
+        ];
+        
+        // Access mints created before the collection have no metadata yet
+        if access_metadata.data_is_empty() {
+            create_metadata_v3(
+                CreateMetadataAccounts {
+                    metadata: access_metadata.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    update_authority: access_collection.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_metadata_program: token_metadata_program.to_account_info(),
+                },
+                DataV2 {
+                    name: access_collection.name.clone(),
+                    symbol: access_collection.symbol.clone(),
+                    uri: access_collection.uri.clone(),
+                    seller_fee_basis_points: 0,
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                },
+                None,
+                &[&authority_seeds[..], &collection_seeds[..]],
+            )?;
+        }
+        
+        set_and_verify_collection(
+            SetAndVerifyCollectionAccounts {
+                metadata: access_metadata.to_account_info(),
+                collection_authority: access_collection.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: access_collection.to_account_info(),
+                collection_mint: collection_mint.to_account_info(),
+                collection: collection_metadata.to_account_info(),
+                collection_master_edition: collection_master_edition.to_account_info(),
+                token_metadata_program: token_metadata_program.to_account_info(),
+            },
+            &[&collection_seeds[..]],
+        )?;
+        
+        access_mint_state.collection_verified = true;
+        access_collection.member_count = access_collection
+            .member_count
+            .checked_add(1)
+            .ok_or(AccessMintError::NumericalOverflow)?;
+        
+        msg!("Access mint verified into collection: {}", access_collection.collection_mint);
+    }
     
-    msg!("Access token minted to buyer: {}, total minted: {}", 
-        ctx.accounts.buyer.key(), access_mint_state.total_minted);
+    msg!("Access token minted, total minted: {}", access_mint_state.total_minted);
     
     Ok(())
 }
@@ -101,7 +199,16 @@ pub struct MintAccess<'info> {
     pub mint: Account<'info, Mint>,
     
     /// Mint authority PDA
-    /// CHECK: PDA validated manually in instruction
+    /// CHECK: PDA validated by seeds with the stored bump
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
     pub mint_authority: UncheckedAccount<'info>,
     
     /// Buyer's token account (ATA)
@@ -114,6 +221,86 @@ pub struct MintAccess<'info> {
     )]
     pub buyer_token_account: Account<'info, TokenAccount>,
     
+    /// The buyer's Completed payment escrow for this content
+    /// (required when the mint requires a purchase receipt)
+    /// CHECK: Owner, discriminator and contents validated when a receipt is required
+    pub purchase_receipt: Option<UncheckedAccount<'info>>,
+    
+    /// Payment escrow authority signing for the escrow program's flows that mint
+    /// without an escrow, which then need no purchase receipt
+    #[account(
+        seeds = [common::seeds::ACCESS_MINTER],
+        bump,
+        seeds::program = PAYMENT_ESCROW_PROGRAM_ID,
+    )]
+    pub access_minter: Option<Signer<'info>>,
+    
+    /// The buyer's preview grant, upgraded to permanent access by this purchase
+    #[account(
+        mut,
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump = preview_grant.bump,
+        constraint = preview_grant.preview @ AccessMintError::NotAPreview,
+    )]
+    pub preview_grant: Option<Account<'info, AccessGrant>>,
+    
+    /// The access collection assigned to this mint, verified into on first use
+    #[account(
+        mut,
+        seeds = [
+            AccessCollection::SEED_PREFIX,
+            access_collection.creator.as_ref(),
+            access_collection.series.to_le_bytes().as_ref(),
+        ],
+        bump = access_collection.bump,
+    )]
+    pub access_collection: Option<Account<'info, AccessCollection>>,
+    
+    /// Metadata account of the access mint
+    /// CHECK: Metaplex metadata PDA of the access mint, created by Token Metadata if empty
+    #[account(mut, address = metadata_address(&mint.key()))]
+    pub access_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// The collection NFT mint
+    /// CHECK: Checked against the access collection
+    #[account(
+        constraint = access_collection
+            .as_ref()
+            .is_some_and(|access_collection| access_collection.collection_mint == collection_mint.key())
+            @ AccessMintError::InvalidCollection,
+    )]
+    pub collection_mint: Option<UncheckedAccount<'info>>,
+    
+    /// Metadata account of the collection NFT
+    /// CHECK: Metaplex metadata PDA of the collection mint
+    #[account(
+        mut,
+        constraint = collection_mint
+            .as_ref()
+            .is_some_and(|collection_mint| collection_metadata.key() == metadata_address(&collection_mint.key()))
+            @ AccessMintError::InvalidCollection,
+    )]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// Master edition account of the collection NFT
+    /// CHECK: Metaplex master edition PDA of the collection mint
+    #[account(
+        constraint = collection_mint
+            .as_ref()
+            .is_some_and(|collection_mint| collection_master_edition.key() == master_edition_address(&collection_mint.key()))
+            @ AccessMintError::InvalidCollection,
+    )]
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+    
+    /// Metaplex Token Metadata program
+    /// CHECK: Address checked against the Token Metadata program id
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    
     /// Token program
     pub token_program: Program<'info, Token>,
     
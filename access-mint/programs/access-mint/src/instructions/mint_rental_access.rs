@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::*;
+use crate::receipt::PAYMENT_ESCROW_PROGRAM_ID;
+
+/// Mint a time-boxed rental access token to a buyer
+/// The holder's token account is frozen so the rental can't be transferred away and kept;
+/// the grant alone decides whether it gives access
+/// This is typically called via CPI from the payment escrow program
+pub fn mint_rental_access(ctx: Context<MintRentalAccess>, duration: i64) -> Result<()> {
+    require!(duration > 0, AccessMintError::InvalidDuration);
+    
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    let grant = &mut ctx.accounts.access_grant;
+    let clock = Clock::get()?;
+    
+    // Rentals aren't escrow purchases, so on gated mints only the escrow program's paid
+    // rental flows can mint them, signing as its access minter
+    if access_mint_state.require_purchase_receipt {
+        require!(
+            ctx.accounts.access_minter.is_some(),
+            AccessMintError::PurchaseReceiptRequired
+        );
+    }
+    
+    // A previous rental must have expired before renting again
+    require!(
+        grant.end_ts <= clock.unix_timestamp,
+        AccessMintError::RentalStillActive
+    );
+    
+    // Never attach a rental window to a permanently purchased token
+    if grant.end_ts == 0 {
+        require!(
+            ctx.accounts.holder_token_account.amount == 0,
+            AccessMintError::AlreadyMinted
+        );
+    }
+    
+    let creator = access_mint_state.creator;
+    let content_id = access_mint_state.content_id;
+    let seed_bytes = access_mint_state.seed.to_le_bytes();
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        creator.as_ref(),
+        content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[access_mint_state.authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+    
+    // Only mint when the holder doesn't already hold the token from a previous rental,
+    // which stays frozen in their account between rentals
+    if ctx.accounts.holder_token_account.amount == 0 {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+        
+        access_mint_state.record_mint(1)?;
+    }
+    
+    // Freeze the rental in place so it can't be transferred out
+    if !ctx.accounts.holder_token_account.is_frozen() {
+        token::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.holder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    
+    grant.holder = ctx.accounts.holder.key();
+    grant.mint = ctx.accounts.mint.key();
+    grant.start_ts = clock.unix_timestamp;
+    grant.end_ts = clock
+        .unix_timestamp
+        .checked_add(duration)
+        .ok_or(AccessMintError::NumericalOverflow)?;
+    grant.reclaimed = false;
+    grant.preview = false;
+    grant.bump = ctx.bumps.access_grant;
+    
+    msg!("Rental access granted to: {} until: {}", grant.holder, grant.end_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MintRentalAccess<'info> {
+    /// The wallet renting access
+    /// CHECK: Can be any account, validated by caller
+    pub holder: UncheckedAccount<'info>,
+    
+    /// The payer for account creation (usually the holder)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The mint account
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint (and freeze) authority PDA
+    /// CHECK: PDA validated by seeds
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Holder's token account (ATA)
+    /// Will be created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Access grant PDA recording the rental window
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AccessGrant::LEN,
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    
+    /// Payment escrow authority signing for its paid rental flows, required when the mint
+    /// requires a purchase receipt
+    #[account(
+        seeds = [common::seeds::ACCESS_MINTER],
+        bump,
+        seeds::program = PAYMENT_ESCROW_PROGRAM_ID,
+    )]
+    pub access_minter: Option<Signer<'info>>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -1,5 +1,45 @@
 pub mod initialize_mint;
 pub mod mint_access;
+pub mod mint_rental_access;
+pub mod reclaim_expired;
+pub mod create_session_grant;
+pub mod revoke_session_grant;
+pub mod initialize_mints_batch;
+pub mod set_max_supply;
+pub mod airdrop_access;
+pub mod commit_airdrop_root;
+pub mod claim_airdrop;
+pub mod verify_access;
+pub mod redeem_access;
+pub mod add_ticket_validator;
+pub mod remove_ticket_validator;
+pub mod issue_ticket;
+pub mod check_in;
+pub mod set_require_purchase_receipt;
+pub mod grant_preview;
+pub mod initialize_collection;
+pub mod set_access_collection;
+pub mod migrate_access;
 
 pub use initialize_mint::*;
 pub use mint_access::*;
+pub use mint_rental_access::*;
+pub use reclaim_expired::*;
+pub use create_session_grant::*;
+pub use revoke_session_grant::*;
+pub use initialize_mints_batch::*;
+pub use set_max_supply::*;
+pub use airdrop_access::*;
+pub use commit_airdrop_root::*;
+pub use claim_airdrop::*;
+pub use verify_access::*;
+pub use redeem_access::*;
+pub use add_ticket_validator::*;
+pub use remove_ticket_validator::*;
+pub use issue_ticket::*;
+pub use check_in::*;
+pub use set_require_purchase_receipt::*;
+pub use grant_preview::*;
+pub use initialize_collection::*;
+pub use set_access_collection::*;
+pub use migrate_access::*;
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+
+/// Reclaim an expired rental or preview, freezing its access token
+/// Rentals are already frozen when minted, so only previews are frozen here
+/// Permissionless - anyone can crank expired rentals
+pub fn reclaim_expired(ctx: Context<ReclaimExpired>) -> Result<()> {
+    let grant = &mut ctx.accounts.access_grant;
+    let clock = Clock::get()?;
+    
+    require!(!grant.reclaimed, AccessMintError::AlreadyReclaimed);
+    require!(
+        clock.unix_timestamp >= grant.end_ts,
+        AccessMintError::RentalStillActive
+    );
+    
+    let state = &ctx.accounts.access_mint_state;
+    let seed_bytes = state.seed.to_le_bytes();
+    let authority_seeds = &[
+        AccessMintState::AUTHORITY_SEED_PREFIX,
+        state.creator.as_ref(),
+        state.content_id.as_ref(),
+        seed_bytes.as_ref(),
+        &[state.authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+    
+    if !ctx.accounts.holder_token_account.is_frozen() {
+        token::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.holder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    
+    grant.reclaimed = true;
+    
+    msg!("Expired rental reclaimed for holder: {}", grant.holder);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimExpired<'info> {
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The access token mint
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint (and freeze) authority PDA
+    /// CHECK: PDA validated by seeds
+    #[account(
+        seeds = [
+            AccessMintState::AUTHORITY_SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Access grant PDA for the expired rental
+    #[account(
+        mut,
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            access_grant.holder.as_ref(),
+        ],
+        bump = access_grant.bump,
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    
+    /// Holder's access token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = access_grant.holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Consume one unit of the holder's access token, recording a redemption receipt
+pub fn redeem_access(ctx: Context<RedeemAccess>, nonce: u64) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+    
+    let clock = Clock::get()?;
+    let redemption = &mut ctx.accounts.redemption;
+    redemption.content_id = ctx.accounts.access_mint_state.content_id;
+    redemption.mint = ctx.accounts.mint.key();
+    redemption.holder = ctx.accounts.holder.key();
+    redemption.nonce = nonce;
+    redemption.redeemed_ts = clock.unix_timestamp;
+    redemption.bump = ctx.bumps.redemption;
+    
+    emit!(AccessRedeemed {
+        redemption: redemption.key(),
+        content_id: redemption.content_id,
+        mint: redemption.mint,
+        holder: redemption.holder,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Access redeemed by holder: {}", redemption.holder);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RedeemAccess<'info> {
+    /// The access token holder redeeming a unit
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The access token mint
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Holder's access token account (frozen accounts are rentals)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        constraint = holder_token_account.amount > 0 && !holder_token_account.is_frozen()
+            @ AccessMintError::NoActiveAccess,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Redemption receipt PDA
+    #[account(
+        init,
+        payer = holder,
+        space = AccessRedemption::LEN,
+        seeds = [
+            AccessRedemption::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub redemption: Account<'info, AccessRedemption>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
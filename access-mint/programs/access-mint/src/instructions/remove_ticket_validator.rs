@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Revoke a validator key, returning its rent to the creator
+pub fn remove_ticket_validator(ctx: Context<RemoveTicketValidator>) -> Result<()> {
+    msg!("Ticket validator removed: {}", ctx.accounts.ticket_validator.validator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveTicketValidator<'info> {
+    /// The creator who owns the content
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Ticket validator PDA
+    #[account(
+        mut,
+        seeds = [
+            TicketValidator::SEED_PREFIX,
+            access_mint_state.key().as_ref(),
+            ticket_validator.validator.as_ref(),
+        ],
+        bump = ticket_validator.bump,
+        close = creator,
+    )]
+    pub ticket_validator: Account<'info, TicketValidator>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Revoke a session key before it expires and reclaim its rent
+pub fn revoke_session_grant(_ctx: Context<RevokeSessionGrant>) -> Result<()> {
+    msg!("Session grant revoked");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeSessionGrant<'info> {
+    /// The access token holder revoking the session
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Session grant PDA being revoked
+    #[account(
+        mut,
+        seeds = [
+            SessionGrant::SEED_PREFIX,
+            session_grant.mint.as_ref(),
+            holder.key().as_ref(),
+            session_grant.session_key.as_ref(),
+        ],
+        bump = session_grant.bump,
+        has_one = holder @ AccessMintError::Unauthorized,
+        close = holder,
+    )]
+    pub session_grant: Account<'info, SessionGrant>,
+}
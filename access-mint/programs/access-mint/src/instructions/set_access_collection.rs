@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Assign the creator's access collection to an access mint
+/// The mint is verified into the collection the next time mint_access runs with the
+/// collection accounts; once verified the collection can no longer be changed
+pub fn set_access_collection(ctx: Context<SetAccessCollection>) -> Result<()> {
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    
+    // A verified collection is recorded on-chain in the Metaplex metadata
+    require!(
+        !access_mint_state.collection_verified,
+        AccessMintError::CollectionAlreadyVerified
+    );
+    
+    access_mint_state.collection = Some(ctx.accounts.access_collection.key());
+    
+    msg!("Access mint {} assigned to collection: {}", access_mint_state.mint, ctx.accounts.access_collection.collection_mint);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAccessCollection<'info> {
+    /// The creator who owns the content and the collection
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The creator's access collection
+    #[account(
+        seeds = [
+            AccessCollection::SEED_PREFIX,
+            access_collection.creator.as_ref(),
+            access_collection.series.to_le_bytes().as_ref(),
+        ],
+        bump = access_collection.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_collection: Account<'info, AccessCollection>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Cap the number of access tokens that can be minted (0 = unlimited)
+pub fn set_max_supply(ctx: Context<SetMaxSupply>, max_supply: u64) -> Result<()> {
+    let access_mint_state = &mut ctx.accounts.access_mint_state;
+    
+    // Tokens already minted can't be taken back
+    require!(
+        max_supply == 0 || max_supply >= access_mint_state.total_minted,
+        AccessMintError::InvalidMaxSupply
+    );
+    
+    access_mint_state.max_supply = max_supply;
+    
+    msg!("Max supply set to {}", max_supply);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxSupply<'info> {
+    /// The creator who owns the content
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Require mint_access to present a Completed payment escrow for this content (the default)
+/// Access can then only be minted by the payment escrow program: buy_and_mint presents
+/// the escrow, and its other flows (offers, campaigns, claims, rentals) sign as its
+/// access minter. Opting out lets anyone call mint_access and mint_rental_access directly
+pub fn set_require_purchase_receipt(ctx: Context<SetRequirePurchaseReceipt>, required: bool) -> Result<()> {
+    ctx.accounts.access_mint_state.require_purchase_receipt = required;
+    
+    msg!("Purchase receipt required: {}", required);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRequirePurchaseReceipt<'info> {
+    /// The creator who owns the content
+    pub creator: Signer<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        mut,
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = creator @ AccessMintError::InvalidCreator,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+}
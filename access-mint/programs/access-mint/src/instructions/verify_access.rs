@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+
+/// Assert that a wallet currently has access to the content, failing otherwise
+/// Meant for CPI from other programs gating features on content ownership: the holder
+/// must hold an unfrozen access token, or rented access that hasn't expired
+pub fn verify_access(ctx: Context<VerifyAccess>) -> Result<()> {
+    // Rented access carries a grant; an expired one no longer counts even before it is reclaimed
+    AccessGrant::check_access(
+        &ctx.accounts.holder_token_account,
+        &ctx.accounts.access_grant,
+        Clock::get()?.unix_timestamp,
+    )?;
+    
+    msg!("Access verified for holder: {}", ctx.accounts.holder.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyAccess<'info> {
+    /// The wallet whose access is checked
+    /// CHECK: Any wallet; access is read from its token account
+    pub holder: UncheckedAccount<'info>,
+    
+    /// Access mint state PDA
+    #[account(
+        seeds = [
+            AccessMintState::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+            access_mint_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = access_mint_state.bump,
+        has_one = mint @ AccessMintError::InvalidMint,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// The access token mint
+    pub mint: Account<'info, Mint>,
+    
+    /// Holder's access token account
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Holder's rental grant PDA, checked for expiry when it exists
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(
+        seeds = [
+            AccessGrant::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump
+    )]
+    pub access_grant: UncheckedAccount<'info>,
+}
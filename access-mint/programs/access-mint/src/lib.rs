@@ -6,6 +6,10 @@ declare_id!("FmqUGBhdGHK9iPWbweoBXFBU2BY9g6C5ncfQstbXpDf6");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod content;
+pub mod events;
+pub mod receipt;
+pub mod metaplex;
 
 use instructions::*;
 
@@ -18,12 +22,18 @@ pub mod access_mint {
     /// # Arguments
     /// * `content_id` - 32-byte unique identifier for the content
     /// * `seed` - Seed for PDA derivation (allows multiple mints per content)
+    /// * `tier` - Access tier granted by this mint
+    /// * `decimals` - Access token decimals (0 for whole tokens)
+    /// * `units_per_purchase` - Access tokens (in base units) minted per purchase
     pub fn initialize_mint(
         ctx: Context<InitializeMint>,
         content_id: [u8; 32],
         seed: u64,
+        tier: state::AccessTier,
+        decimals: u8,
+        units_per_purchase: u64,
     ) -> Result<()> {
-        instructions::initialize_mint::initialize_mint(ctx, content_id, seed)
+        instructions::initialize_mint::initialize_mint(ctx, content_id, seed, tier, decimals, units_per_purchase)
     }
 
     /// Mint an access token to a buyer
@@ -31,4 +41,179 @@ pub mod access_mint {
     pub fn mint_access(ctx: Context<MintAccess>) -> Result<()> {
         instructions::mint_access::mint_access(ctx)
     }
+
+    /// Mint a time-boxed rental access token to a buyer, frozen so it can't be transferred
+    /// Called via CPI from payment escrow program, which signs as the access minter for gated mints
+    /// 
+    /// # Arguments
+    /// * `duration` - Rental length in seconds
+    pub fn mint_rental_access(ctx: Context<MintRentalAccess>, duration: i64) -> Result<()> {
+        instructions::mint_rental_access::mint_rental_access(ctx, duration)
+    }
+
+    /// Reclaim an expired rental or preview access token (permissionless crank)
+    pub fn reclaim_expired(ctx: Context<ReclaimExpired>) -> Result<()> {
+        instructions::reclaim_expired::reclaim_expired(ctx)
+    }
+
+    /// Authorize a short-lived session key for a device, so gateways can verify
+    /// access without the holder's wallet signing every request
+    /// 
+    /// # Arguments
+    /// * `session_key` - Device or session public key
+    /// * `duration` - Session length in seconds (max 24 hours)
+    pub fn create_session_grant(
+        ctx: Context<CreateSessionGrant>,
+        session_key: Pubkey,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::create_session_grant::create_session_grant(ctx, session_key, duration)
+    }
+
+    /// Revoke a session key before it expires
+    pub fn revoke_session_grant(ctx: Context<RevokeSessionGrant>) -> Result<()> {
+        instructions::revoke_session_grant::revoke_session_grant(ctx)
+    }
+
+    /// Initialize access token mints for several contents in one transaction,
+    /// e.g. when migrating a back-catalog
+    /// 
+    /// # Arguments
+    /// * `entries` - Content id, seed and tier of each mint (max 5)
+    pub fn initialize_mints_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeMintsBatch<'info>>,
+        entries: Vec<BatchMintEntry>,
+    ) -> Result<()> {
+        instructions::initialize_mints_batch::initialize_mints_batch(ctx, entries)
+    }
+
+    /// Cap the number of access tokens that can be minted (creator only)
+    /// 
+    /// # Arguments
+    /// * `max_supply` - Maximum tokens ever minted (0 = unlimited)
+    pub fn set_max_supply(ctx: Context<SetMaxSupply>, max_supply: u64) -> Result<()> {
+        instructions::set_max_supply::set_max_supply(ctx, max_supply)
+    }
+
+    /// Mint access tokens to a list of wallets without payment (creator only)
+    /// Recipients are passed as remaining accounts (their ATAs for the access mint)
+    pub fn airdrop_access<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AirdropAccess<'info>>,
+    ) -> Result<()> {
+        instructions::airdrop_access::airdrop_access(ctx)
+    }
+
+    /// Commit the Merkle root of wallets that may claim an access token (creator only)
+    /// 
+    /// # Arguments
+    /// * `merkle_root` - Root of the tree of eligible wallets
+    /// * `max_claims` - Maximum number of claims (0 = every wallet in the tree)
+    /// * `rent_deposit` - Lamports reimbursing recipients for their claim records
+    pub fn commit_airdrop_root(
+        ctx: Context<CommitAirdropRoot>,
+        merkle_root: [u8; 32],
+        max_claims: u32,
+        rent_deposit: u64,
+    ) -> Result<()> {
+        instructions::commit_airdrop_root::commit_airdrop_root(ctx, merkle_root, max_claims, rent_deposit)
+    }
+
+    /// Claim an airdropped access token with a Merkle proof
+    /// 
+    /// # Arguments
+    /// * `proof` - Sibling hashes from the recipient's leaf to the root
+    pub fn claim_airdrop(ctx: Context<ClaimAirdrop>, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_airdrop::claim_airdrop(ctx, proof)
+    }
+
+    /// Assert that a wallet currently has access to the content (for CPI from other programs)
+    pub fn verify_access(ctx: Context<VerifyAccess>) -> Result<()> {
+        instructions::verify_access::verify_access(ctx)
+    }
+
+    /// Consume one unit of an access token (e.g. a ticket or single download)
+    /// 
+    /// # Arguments
+    /// * `nonce` - Holder-chosen nonce for the redemption receipt PDA
+    pub fn redeem_access(ctx: Context<RedeemAccess>, nonce: u64) -> Result<()> {
+        instructions::redeem_access::redeem_access(ctx, nonce)
+    }
+
+    /// Authorize a validator key to check in tickets (creator only)
+    /// 
+    /// # Arguments
+    /// * `validator` - Validator key allowed to co-sign check-ins
+    pub fn add_ticket_validator(ctx: Context<AddTicketValidator>, validator: Pubkey) -> Result<()> {
+        instructions::add_ticket_validator::add_ticket_validator(ctx, validator)
+    }
+
+    /// Revoke a ticket validator key (creator only)
+    pub fn remove_ticket_validator(ctx: Context<RemoveTicketValidator>) -> Result<()> {
+        instructions::remove_ticket_validator::remove_ticket_validator(ctx)
+    }
+
+    /// Issue a ticket with seat and tier metadata (creator only)
+    /// 
+    /// # Arguments
+    /// * `ticket_id` - Creator-assigned ticket number
+    /// * `holder` - Wallet the ticket is issued to
+    /// * `seat` - Seat label
+    /// * `tier` - Creator-defined ticket tier
+    pub fn issue_ticket(
+        ctx: Context<IssueTicket>,
+        ticket_id: u64,
+        holder: Pubkey,
+        seat: String,
+        tier: u8,
+    ) -> Result<()> {
+        instructions::issue_ticket::issue_ticket(ctx, ticket_id, holder, seat, tier)
+    }
+
+    /// Check a ticket in, co-signed by the holder and a registered validator
+    pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
+        instructions::check_in::check_in(ctx)
+    }
+
+    /// Require every mint_access call to present a Completed payment escrow for this content
+    /// 
+    /// # Arguments
+    /// * `required` - Whether a purchase receipt is required
+    pub fn set_require_purchase_receipt(ctx: Context<SetRequirePurchaseReceipt>, required: bool) -> Result<()> {
+        instructions::set_require_purchase_receipt::set_require_purchase_receipt(ctx, required)
+    }
+
+    /// Mint a free preview access token to a wallet that expires after `duration` seconds
+    /// 
+    /// # Arguments
+    /// * `duration` - Preview length in seconds
+    pub fn grant_preview(ctx: Context<GrantPreview>, duration: i64) -> Result<()> {
+        instructions::grant_preview::grant_preview(ctx, duration)
+    }
+
+    /// Create a Metaplex collection NFT that the creator's access mints are verified into
+    /// 
+    /// # Arguments
+    /// * `series` - Series number, allowing several collections per creator
+    /// * `name` - Collection name
+    /// * `symbol` - Collection symbol
+    /// * `uri` - Collection metadata URI
+    pub fn initialize_collection(
+        ctx: Context<InitializeCollection>,
+        series: u64,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::initialize_collection::initialize_collection(ctx, series, name, symbol, uri)
+    }
+
+    /// Assign an access collection to an access mint, verified on the next mint_access
+    pub fn set_access_collection(ctx: Context<SetAccessCollection>) -> Result<()> {
+        instructions::set_access_collection::set_access_collection(ctx)
+    }
+
+    /// Re-issue a buyer's access from an old wallet to a new one (old wallet and creator sign)
+    pub fn migrate_access(ctx: Context<MigrateAccess>) -> Result<()> {
+        instructions::migrate_access::migrate_access(ctx)
+    }
 }
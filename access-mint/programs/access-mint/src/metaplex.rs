@@ -0,0 +1,330 @@
+//! Metaplex Token Metadata instructions used for access collections
+//!
+//! Built by hand because the programs don't depend on the Metaplex crate; only the
+//! instructions and argument layouts needed to create a collection NFT and verify
+//! access mints into it are mirrored here.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// Metaplex Token Metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Token Metadata PDA seed prefix
+pub const METADATA_SEED: &[u8] = b"metadata";
+
+/// Master edition PDA seed suffix
+pub const EDITION_SEED: &[u8] = b"edition";
+
+/// Maximum lengths Token Metadata accepts for the name, symbol and URI
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+
+/// Instruction discriminators
+const CREATE_MASTER_EDITION_V3: u8 = 17;
+const SET_AND_VERIFY_COLLECTION: u8 = 25;
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// Metadata account of `mint`
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[METADATA_SEED, TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Master edition account of `mint`
+pub fn master_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[METADATA_SEED, TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref(), EDITION_SEED],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Token Metadata `Creator`
+#[derive(AnchorSerialize)]
+pub struct MetadataCreator {
+    /// Creator wallet
+    pub address: Pubkey,
+    
+    /// Whether the creator has signed
+    pub verified: bool,
+    
+    /// Royalty share in percent
+    pub share: u8,
+}
+
+/// Token Metadata `Collection`
+#[derive(AnchorSerialize)]
+pub struct MetadataCollection {
+    /// Whether the collection authority has verified the item
+    pub verified: bool,
+    
+    /// Collection NFT mint
+    pub key: Pubkey,
+}
+
+/// Token Metadata `Uses`
+#[derive(AnchorSerialize)]
+pub struct MetadataUses {
+    /// Use method (burn, multiple, single)
+    pub use_method: u8,
+    
+    /// Uses left
+    pub remaining: u64,
+    
+    /// Total uses
+    pub total: u64,
+}
+
+/// Token Metadata `DataV2`
+#[derive(AnchorSerialize)]
+pub struct DataV2 {
+    /// Asset name
+    pub name: String,
+    
+    /// Asset symbol
+    pub symbol: String,
+    
+    /// Off-chain JSON metadata URI
+    pub uri: String,
+    
+    /// Royalty in basis points
+    pub seller_fee_basis_points: u16,
+    
+    /// Royalty creators
+    pub creators: Option<Vec<MetadataCreator>>,
+    
+    /// Collection the asset belongs to
+    pub collection: Option<MetadataCollection>,
+    
+    /// Use tracking
+    pub uses: Option<MetadataUses>,
+}
+
+/// Token Metadata `CollectionDetails`
+#[derive(AnchorSerialize)]
+pub enum CollectionDetails {
+    V1 { size: u64 },
+}
+
+#[derive(AnchorSerialize)]
+struct CreateMetadataAccountArgsV3 {
+    data: DataV2,
+    is_mutable: bool,
+    collection_details: Option<CollectionDetails>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateMasterEditionArgs {
+    max_supply: Option<u64>,
+}
+
+/// Accounts for creating a metadata account
+pub struct CreateMetadataAccounts<'info> {
+    /// Metadata PDA to create
+    pub metadata: AccountInfo<'info>,
+    
+    /// Mint the metadata describes
+    pub mint: AccountInfo<'info>,
+    
+    /// Mint authority of the mint (signer)
+    pub mint_authority: AccountInfo<'info>,
+    
+    /// Rent payer (signer)
+    pub payer: AccountInfo<'info>,
+    
+    /// Metadata update authority (signer)
+    pub update_authority: AccountInfo<'info>,
+    
+    /// System program
+    pub system_program: AccountInfo<'info>,
+    
+    /// Token Metadata program
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Accounts for turning a collection mint into a master edition
+pub struct CreateMasterEditionAccounts<'info> {
+    /// Master edition PDA to create
+    pub edition: AccountInfo<'info>,
+    
+    /// One-of-one mint
+    pub mint: AccountInfo<'info>,
+    
+    /// Metadata update authority (signer)
+    pub update_authority: AccountInfo<'info>,
+    
+    /// Mint authority of the mint (signer)
+    pub mint_authority: AccountInfo<'info>,
+    
+    /// Rent payer (signer)
+    pub payer: AccountInfo<'info>,
+    
+    /// Metadata PDA of the mint
+    pub metadata: AccountInfo<'info>,
+    
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+    
+    /// System program
+    pub system_program: AccountInfo<'info>,
+    
+    /// Token Metadata program
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Accounts for setting and verifying an item's collection
+pub struct SetAndVerifyCollectionAccounts<'info> {
+    /// Metadata PDA of the item
+    pub metadata: AccountInfo<'info>,
+    
+    /// Collection update authority (signer)
+    pub collection_authority: AccountInfo<'info>,
+    
+    /// Payer (signer)
+    pub payer: AccountInfo<'info>,
+    
+    /// Update authority of the item
+    pub update_authority: AccountInfo<'info>,
+    
+    /// Collection NFT mint
+    pub collection_mint: AccountInfo<'info>,
+    
+    /// Metadata PDA of the collection
+    pub collection: AccountInfo<'info>,
+    
+    /// Master edition PDA of the collection
+    pub collection_master_edition: AccountInfo<'info>,
+    
+    /// Token Metadata program
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+fn instruction_data<T: AnchorSerialize>(discriminator: u8, args: &T) -> Result<Vec<u8>> {
+    let mut data = vec![discriminator];
+    args.serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Create the metadata account of a mint; `collection_size` makes it a sized collection
+pub fn create_metadata_v3(
+    accounts: CreateMetadataAccounts,
+    data: DataV2,
+    collection_size: Option<u64>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instruction = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.metadata.key(), false),
+            AccountMeta::new_readonly(accounts.mint.key(), false),
+            AccountMeta::new_readonly(accounts.mint_authority.key(), true),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.update_authority.key(), true),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data: instruction_data(
+            CREATE_METADATA_ACCOUNT_V3,
+            &CreateMetadataAccountArgsV3 {
+                data,
+                is_mutable: true,
+                collection_details: collection_size.map(|size| CollectionDetails::V1 { size }),
+            },
+        )?,
+    };
+    
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.metadata,
+            accounts.mint,
+            accounts.mint_authority,
+            accounts.payer,
+            accounts.update_authority,
+            accounts.system_program,
+            accounts.token_metadata_program,
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Create the master edition of a one-of-one mint, taking over its mint authority
+pub fn create_master_edition_v3(
+    accounts: CreateMasterEditionAccounts,
+    max_supply: Option<u64>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instruction = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.edition.key(), false),
+            AccountMeta::new(accounts.mint.key(), false),
+            AccountMeta::new_readonly(accounts.update_authority.key(), true),
+            AccountMeta::new_readonly(accounts.mint_authority.key(), true),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new(accounts.metadata.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data: instruction_data(CREATE_MASTER_EDITION_V3, &CreateMasterEditionArgs { max_supply })?,
+    };
+    
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.edition,
+            accounts.mint,
+            accounts.update_authority,
+            accounts.mint_authority,
+            accounts.payer,
+            accounts.metadata,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.token_metadata_program,
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Set an item's collection and verify it, signed by the collection's update authority
+pub fn set_and_verify_collection(
+    accounts: SetAndVerifyCollectionAccounts,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instruction = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.metadata.key(), false),
+            AccountMeta::new(accounts.collection_authority.key(), true),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.update_authority.key(), false),
+            AccountMeta::new_readonly(accounts.collection_mint.key(), false),
+            AccountMeta::new(accounts.collection.key(), false),
+            AccountMeta::new_readonly(accounts.collection_master_edition.key(), false),
+        ],
+        data: vec![SET_AND_VERIFY_COLLECTION],
+    };
+    
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.metadata,
+            accounts.collection_authority,
+            accounts.payer,
+            accounts.update_authority,
+            accounts.collection_mint,
+            accounts.collection,
+            accounts.collection_master_edition,
+            accounts.token_metadata_program,
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
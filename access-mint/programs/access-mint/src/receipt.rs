@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+/// Payment escrow program, owner of the escrows accepted as purchase receipts
+pub const PAYMENT_ESCROW_PROGRAM_ID: Pubkey = pubkey!("2T3AsDRbQdpLWaxEU5vbFXuzRHQnq7JT3wCQCmvdiKmJ");
+
+/// Anchor account discriminator of EscrowState (sha256("account:EscrowState")[..8])
+pub const ESCROW_STATE_DISCRIMINATOR: [u8; 8] = [19, 90, 148, 111, 55, 130, 229, 108];
+
+/// Leading fields of the payment escrow program's EscrowState, up to its status
+/// Mirrored here because access-mint can't depend on the payment escrow crate
+#[derive(AnchorDeserialize)]
+pub struct EscrowReceipt {
+    /// The buyer's public key
+    pub buyer: Pubkey,
+    
+    /// The creator's public key
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Price in lamports or SPL token amount
+    pub price: u64,
+    
+    /// Optional payment token mint
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount actually paid
+    pub payment_amount: u64,
+    
+    /// Access mint recorded once access has been minted for the escrow
+    pub access_mint_address: Option<Pubkey>,
+    
+    /// Listing the escrow was created against
+    pub listing: Option<Pubkey>,
+    
+    /// Listing price version at escrow creation
+    pub price_version: u32,
+    
+    /// Hash of the license terms agreed at purchase
+    pub license_terms_hash: [u8; 32],
+    
+    /// Buyer-supplied reference
+    pub memo: Option<String>,
+    
+    /// Timestamp when the escrow was created
+    pub created_ts: i64,
+    
+    /// Trade nonce
+    pub seed: u64,
+    
+    /// EscrowStatus discriminant (0 = Initialized, 1 = Completed, 2 = Cancelled)
+    pub status: u8,
+}
+
+impl EscrowReceipt {
+    /// EscrowStatus::Completed discriminant
+    pub const STATUS_COMPLETED: u8 = 1;
+    
+    /// Read a payment escrow account, checking its owner and account discriminator
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*account.owner, PAYMENT_ESCROW_PROGRAM_ID, AccessMintError::InvalidPurchaseReceipt);
+        
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == ESCROW_STATE_DISCRIMINATOR,
+            AccessMintError::InvalidPurchaseReceipt
+        );
+        
+        EscrowReceipt::deserialize(&mut &data[8..]).map_err(|_| AccessMintError::InvalidPurchaseReceipt.into())
+    }
+    
+    /// Check the escrow is a completed purchase of `content_id` from `creator` by `buyer`
+    /// that hasn't had access minted against it yet
+    /// The escrow program records the access mint right after minting, so each escrow
+    /// proves exactly one purchase
+    pub fn check_purchase(&self, buyer: &Pubkey, creator: &Pubkey, content_id: &[u8; 32]) -> Result<()> {
+        require!(
+            self.status == Self::STATUS_COMPLETED && self.access_mint_address.is_none(),
+            AccessMintError::InvalidPurchaseReceipt
+        );
+        require_keys_eq!(self.buyer, *buyer, AccessMintError::InvalidPurchaseReceipt);
+        require_keys_eq!(self.creator, *creator, AccessMintError::InvalidPurchaseReceipt);
+        require!(self.content_id == *content_id, AccessMintError::InvalidPurchaseReceipt);
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::metaplex::{MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN};
+
+/// Access Collection - a creator's Metaplex collection NFT that their access mints are
+/// verified into, so marketplaces and wallets group the access tokens together
+/// The PDA is the collection NFT's mint, freeze and update authority
+#[account]
+pub struct AccessCollection {
+    /// The creator owning the collection
+    pub creator: Pubkey,
+    
+    /// Series number, letting a creator keep separate collections per series
+    pub series: u64,
+    
+    /// The collection NFT mint
+    pub collection_mint: Pubkey,
+    
+    /// Name given to the collection and its access mints' metadata
+    pub name: String,
+    
+    /// Symbol given to the collection and its access mints' metadata
+    pub symbol: String,
+    
+    /// Metadata URI of the collection and its access mints
+    pub uri: String,
+    
+    /// Number of access mints verified into the collection
+    pub member_count: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AccessCollection {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + Pubkey (32) + String (4 + MAX_NAME_LEN)
+    /// + String (4 + MAX_SYMBOL_LEN) + String (4 + MAX_URI_LEN) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 4 + MAX_NAME_LEN + 4 + MAX_SYMBOL_LEN + 4 + MAX_URI_LEN + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ACCESS_COLLECTION;
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::errors::AccessMintError;
+
+/// Access Grant - time-boxed access (a rental or a creator's preview) attached to a holder's access token
+#[account]
+pub struct AccessGrant {
+    /// The wallet holding the rented access token
+    pub holder: Pubkey,
+    
+    /// The access token mint
+    pub mint: Pubkey,
+    
+    /// Timestamp when access started
+    pub start_ts: i64,
+    
+    /// Timestamp when access expires
+    pub end_ts: i64,
+    
+    /// Whether the expired grant has been reclaimed (its token frozen)
+    pub reclaimed: bool,
+    
+    /// Whether the grant is a free preview from the creator rather than a paid rental
+    pub preview: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AccessGrant {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + i64 (8) + i64 (8) + bool (1) + bool (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ACCESS_GRANT;
+    
+    /// Expiry of a preview that was upgraded to permanent access by a purchase
+    pub const PERMANENT_END_TS: i64 = i64::MAX;
+    
+    /// Check that a holder's access token gives them access at `now`
+    /// Rental tokens stay frozen so they can't be passed on, so time-boxed access is decided
+    /// by its grant; other tokens count while held and unfrozen
+    pub fn check_access(
+        holder_token_account: &TokenAccount,
+        access_grant: &AccountInfo,
+        now: i64,
+    ) -> Result<()> {
+        require!(holder_token_account.amount > 0, AccessMintError::NoActiveAccess);
+        
+        if access_grant.owner == &crate::ID && !access_grant.data_is_empty() {
+            let grant = AccessGrant::try_deserialize(&mut &access_grant.try_borrow_data()?[..])?;
+            require!(now < grant.end_ts, AccessMintError::AccessExpired);
+        } else {
+            require!(!holder_token_account.is_frozen(), AccessMintError::NoActiveAccess);
+        }
+        
+        Ok(())
+    }
+}
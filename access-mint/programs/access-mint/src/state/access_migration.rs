@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Access Migration - links access re-issued to a new wallet back to the wallet
+/// that originally bought it, so purchase receipts keyed by the old wallet stay traceable
+#[account]
+pub struct AccessMigration {
+    /// The access token mint
+    pub mint: Pubkey,
+    
+    /// Wallet the access was burned from (the original buyer)
+    pub old_wallet: Pubkey,
+    
+    /// Wallet the access was re-issued to
+    pub new_wallet: Pubkey,
+    
+    /// Number of access units moved
+    pub amount: u64,
+    
+    /// Timestamp of the migration
+    pub migrated_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AccessMigration {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ACCESS_MIGRATION;
+}
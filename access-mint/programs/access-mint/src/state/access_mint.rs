@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
+use crate::errors::AccessMintError;
 
 /// Access Mint State - stores metadata about the access token mint
 #[account]
+#[derive(InitSpace)]
 pub struct AccessMintState {
     /// The creator's public key
     pub creator: Pubkey,
@@ -21,22 +23,79 @@ pub struct AccessMintState {
     /// Total number of access tokens minted
     pub total_minted: u64,
     
+    /// Maximum number of access tokens that can be minted (0 = unlimited)
+    pub max_supply: u64,
+    
+    /// Access tokens (in base units) minted per purchase
+    pub units_per_purchase: u64,
+    
+    /// Access tier granted by this mint
+    pub tier: AccessTier,
+    
     /// Timestamp when created
     pub created_ts: i64,
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// Mint authority PDA bump seed
+    pub authority_bump: u8,
+    
+    /// Only mint against a Completed payment-escrow purchase of this content (on by default)
+    pub require_purchase_receipt: bool,
+    
+    /// Access collection the mint is grouped into, if any
+    pub collection: Option<Pubkey>,
+    
+    /// Whether the mint has been verified into its collection
+    pub collection_verified: bool,
 }
 
 impl AccessMintState {
     /// Size calculation for account allocation
-    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + Pubkey (32) 
-    /// + Pubkey (32) + u64 (8) + u64 (8) + i64 (8) + u8 (1)
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 1;
+    /// Discriminator (8) + fields (derived by InitSpace) + RESERVED_SPACE
+    pub const LEN: usize = 8 + Self::INIT_SPACE + Self::RESERVED_SPACE;
+    
+    /// Zeroed bytes allocated past the last field, so new fields can be appended
+    /// (deserializing as zero on existing mints) without reallocating every account
+    pub const RESERVED_SPACE: usize = 64;
     
     /// PDA seed prefix for access mint state
-    pub const SEED_PREFIX: &'static [u8] = b"access_mint_state";
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ACCESS_MINT_STATE;
     
     /// PDA seed prefix for mint authority
-    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"access_mint_authority";
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = common::seeds::ACCESS_MINT_AUTHORITY;
+    
+    /// Maximum access mint decimals
+    pub const MAX_DECIMALS: u8 = 9;
+    
+    /// Tokens minted per purchase (1 for mints created before units were configurable)
+    pub fn purchase_units(&self) -> u64 {
+        self.units_per_purchase.max(1)
+    }
+    
+    /// Count `amount` newly minted tokens, enforcing the max supply
+    pub fn record_mint(&mut self, amount: u64) -> Result<()> {
+        let total_minted = self
+            .total_minted
+            .checked_add(amount)
+            .ok_or(AccessMintError::NumericalOverflow)?;
+        require!(
+            self.max_supply == 0 || total_minted <= self.max_supply,
+            AccessMintError::MaxSupplyReached
+        );
+        
+        self.total_minted = total_minted;
+        
+        Ok(())
+    }
+}
+
+/// Access tier enum
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum AccessTier {
+    /// Standard access
+    Basic,
+    /// Premium access, reachable from Basic via upgrade
+    Premium,
 }
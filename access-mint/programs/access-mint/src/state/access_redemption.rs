@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Access Redemption - receipt for one consumed unit of an access token
+/// (e.g. an event entry or a single download)
+#[account]
+pub struct AccessRedemption {
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// The access token mint
+    pub mint: Pubkey,
+    
+    /// Wallet that redeemed the unit
+    pub holder: Pubkey,
+    
+    /// Holder-chosen nonce distinguishing this redemption
+    pub nonce: u64,
+    
+    /// Timestamp of the redemption
+    pub redeemed_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AccessRedemption {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + [u8; 32] (32) + Pubkey (32) + Pubkey (32) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ACCESS_REDEMPTION;
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// Merkle Airdrop - a committed list of wallets that may claim an access token
+/// Lamports above rent exemption reimburse recipients for their claim records
+#[account]
+pub struct MerkleAirdrop {
+    /// Access mint state the airdrop mints from
+    pub access_mint_state: Pubkey,
+    
+    /// Root of the Merkle tree of eligible wallets (see common::merkle)
+    pub merkle_root: [u8; 32],
+    
+    /// Maximum number of claims (0 = every wallet in the tree)
+    pub max_claims: u32,
+    
+    /// Number of claims so far
+    pub claimed: u32,
+    
+    /// Timestamp when the root was last committed
+    pub updated_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MerkleAirdrop {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + u32 (4) + u32 (4) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 4 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::MERKLE_AIRDROP;
+}
+
+/// Airdrop Claim - marks a recipient as having claimed, preventing double claims
+#[account]
+pub struct AirdropClaim {
+    /// The airdrop claimed from
+    pub airdrop: Pubkey,
+    
+    /// The wallet that claimed
+    pub recipient: Pubkey,
+    
+    /// Timestamp of the claim
+    pub claimed_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AirdropClaim {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::AIRDROP_CLAIM;
+}
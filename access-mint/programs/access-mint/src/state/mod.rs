@@ -1,3 +1,17 @@
 pub mod access_mint;
+pub mod access_grant;
+pub mod session_grant;
+pub mod merkle_airdrop;
+pub mod access_redemption;
+pub mod ticket;
+pub mod access_collection;
+pub mod access_migration;
 
 pub use access_mint::*;
+pub use access_grant::*;
+pub use session_grant::*;
+pub use merkle_airdrop::*;
+pub use access_redemption::*;
+pub use ticket::*;
+pub use access_collection::*;
+pub use access_migration::*;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Session Grant - a short-lived key authorized by an access token holder
+/// Lets content gateways verify a device session without the holder's wallet
+/// signing every request
+#[account]
+pub struct SessionGrant {
+    /// The wallet holding the access token
+    pub holder: Pubkey,
+    
+    /// The access token mint
+    pub mint: Pubkey,
+    
+    /// Device or session key acting on the holder's behalf
+    pub session_key: Pubkey,
+    
+    /// Timestamp when the session was authorized
+    pub created_ts: i64,
+    
+    /// Timestamp when the session expires
+    pub expires_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SessionGrant {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + i64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::SESSION_GRANT;
+    
+    /// Longest session a holder can authorize (24 hours)
+    pub const MAX_DURATION: i64 = 24 * 60 * 60;
+    
+    /// Whether the session is still valid at the given time
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_ts
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Ticket Validator - a key the creator trusts to check tickets in at the door
+#[account]
+pub struct TicketValidator {
+    /// Access mint state the validator checks tickets for
+    pub access_mint_state: Pubkey,
+    
+    /// Validator key
+    pub validator: Pubkey,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TicketValidator {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::TICKET_VALIDATOR;
+}
+
+/// Ticket - seat and tier of one event ticket, and whether it has been used
+#[account]
+pub struct Ticket {
+    /// Access mint state the ticket belongs to
+    pub access_mint_state: Pubkey,
+    
+    /// Creator-assigned ticket number
+    pub ticket_id: u64,
+    
+    /// Wallet the ticket is issued to
+    pub holder: Pubkey,
+    
+    /// Seat label (e.g. "B-12")
+    pub seat: String,
+    
+    /// Creator-defined ticket tier (e.g. 0 = general admission)
+    pub tier: u8,
+    
+    /// Whether the ticket has been checked in
+    pub checked_in: bool,
+    
+    /// Timestamp of the check-in (0 until checked in)
+    pub checked_in_ts: i64,
+    
+    /// Validator that checked the ticket in (default until checked in)
+    pub checked_in_by: Pubkey,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Ticket {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + Pubkey (32) + String (4 + MAX_SEAT_LEN)
+    /// + u8 (1) + bool (1) + i64 (8) + Pubkey (32) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 4 + Self::MAX_SEAT_LEN + 1 + 1 + 8 + 32 + 1;
+    
+    /// Maximum seat label length in bytes
+    pub const MAX_SEAT_LEN: usize = 32;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::TICKET;
+}
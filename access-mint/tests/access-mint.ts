@@ -12,8 +12,11 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
   getAccount,
+  createAssociatedTokenAccount,
+  transfer,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { createHash } from "crypto";
 
 describe("Access Mint Program", () => {
   const provider = anchor.AnchorProvider.env();
@@ -71,7 +74,7 @@ describe("Access Mint Program", () => {
       );
 
       const tx = await program.methods
-        .initializeMint(contentId, seed)
+        .initializeMint(contentId, seed, { basic: {} }, 0, new anchor.BN(1))
         .accountsPartial({
           creator: creator.publicKey,
           accessMintState: accessMintStatePda,
@@ -94,6 +97,20 @@ describe("Access Mint Program", () => {
       expect(accessMintState.mint.toString()).to.equal(mint.publicKey.toString());
       expect(accessMintState.mintAuthority.toString()).to.equal(mintAuthorityPda.toString());
       expect(accessMintState.totalMinted.toNumber()).to.equal(0);
+      expect(accessMintState.unitsPerPurchase.toNumber()).to.equal(1);
+      expect(accessMintState.requirePurchaseReceipt).to.be.true;
+
+      // Authority bump is stored so minting never re-derives it
+      const [, authorityBump] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      expect(accessMintState.authorityBump).to.equal(authorityBump);
 
       console.log("Mint:", mint.publicKey.toString());
       console.log("Mint Authority:", mintAuthorityPda.toString());
@@ -103,6 +120,82 @@ describe("Access Mint Program", () => {
       const mintInfo = await provider.connection.getAccountInfo(mint.publicKey);
       expect(mintInfo).to.not.be.null;
       console.log("SPL Mint account created");
+
+      // Until the creator opts out, rentals only come through the payment escrow program
+      const [accessGrantPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("access_grant"), mint.publicKey.toBuffer(), buyer.publicKey.toBuffer()],
+        program.programId
+      );
+      try {
+        await program.methods
+          .mintRentalAccess(new anchor.BN(60 * 60))
+          .accountsPartial({
+            holder: buyer.publicKey,
+            payer: buyer.publicKey,
+            accessMintState: accessMintStatePda,
+            mint: mint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            holderTokenAccount: await getAssociatedTokenAddress(mint.publicKey, buyer.publicKey),
+            accessGrant: accessGrantPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown PurchaseReceiptRequired error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("PurchaseReceiptRequired");
+      }
+    });
+
+    it("Should initialize access mints for a batch of contents", async () => {
+      const batchSeed = new anchor.BN(7);
+      const entries = [10, 20].map((offset) => ({
+        contentId: Array.from({ length: 32 }, (_, i) => i + offset),
+        seed: batchSeed,
+        tier: { basic: {} },
+      }));
+      const mints = entries.map(() => Keypair.generate());
+
+      const remainingAccounts = entries.flatMap((entry, i) => {
+        const seeds = [
+          creator.publicKey.toBuffer(),
+          Buffer.from(entry.contentId),
+          batchSeed.toArrayLike(Buffer, "le", 8),
+        ];
+        const [statePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("access_mint_state"), ...seeds],
+          program.programId
+        );
+        const [authorityPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("access_mint_authority"), ...seeds],
+          program.programId
+        );
+        return [
+          { pubkey: statePda, isSigner: false, isWritable: true },
+          { pubkey: mints[i].publicKey, isSigner: true, isWritable: true },
+          { pubkey: authorityPda, isSigner: false, isWritable: false },
+        ];
+      });
+
+      await program.methods
+        .initializeMintsBatch(entries)
+        .accountsPartial({
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers(mints)
+        .rpc();
+
+      for (let i = 0; i < entries.length; i++) {
+        const state = await program.account.accessMintState.fetch(remainingAccounts[i * 3].pubkey);
+        expect(state.mint.toString()).to.equal(mints[i].publicKey.toString());
+        expect(state.mintAuthority.toString()).to.equal(remainingAccounts[i * 3 + 2].pubkey.toString());
+        expect(state.totalMinted.toNumber()).to.equal(0);
+      }
     });
   });
 
@@ -138,7 +231,7 @@ describe("Access Mint Program", () => {
 
       // Initialize the mint first
       await program.methods
-        .initializeMint(contentId, seed2)
+        .initializeMint(contentId, seed2, { basic: {} }, 0, new anchor.BN(1))
         .accountsPartial({
           creator: creator.publicKey,
           accessMintState: accessMintStatePda,
@@ -151,6 +244,15 @@ describe("Access Mint Program", () => {
         .signers([mint2])
         .rpc();
 
+      // These tests mint directly, without the payment escrow program
+      await program.methods
+        .setRequirePurchaseReceipt(false)
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+        })
+        .rpc();
+
       // Get buyer's token account address
       buyerTokenAccount = await getAssociatedTokenAddress(
         mint2.publicKey,
@@ -213,6 +315,273 @@ describe("Access Mint Program", () => {
       console.log("Access verification successful");
       console.log("Buyer has", tokenAccountInfo.amount.toString(), "access token(s)");
     });
+
+    it("Should verify access on-chain for CPI callers", async () => {
+      const verify = (holder: PublicKey, holderTokenAccount: PublicKey) => {
+        const [accessGrant] = PublicKey.findProgramAddressSync(
+          [Buffer.from("access_grant"), mint.publicKey.toBuffer(), holder.toBuffer()],
+          program.programId
+        );
+        return program.methods
+          .verifyAccess()
+          .accountsPartial({
+            holder,
+            accessMintState: accessMintStatePda,
+            mint: mint.publicKey,
+            holderTokenAccount,
+            accessGrant,
+          })
+          .rpc();
+      };
+
+      await verify(buyer.publicKey, buyerTokenAccount);
+
+      // A wallet without an access token account is rejected
+      const stranger = Keypair.generate();
+      const strangerTokenAccount = await getAssociatedTokenAddress(
+        mint.publicKey,
+        stranger.publicKey
+      );
+      try {
+        await verify(stranger.publicKey, strangerTokenAccount);
+        expect.fail("Should have rejected a wallet without access");
+      } catch (error: any) {
+        expect(error.toString()).to.include("AccountNotInitialized");
+      }
+    });
+
+    it("Should airdrop access tokens up to the max supply", async () => {
+      const creatorTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        creator,
+        mint.publicKey,
+        creator.publicKey
+      );
+
+      await program.methods
+        .airdropAccess()
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: mint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: buyerTokenAccount, isSigner: false, isWritable: true },
+          { pubkey: creatorTokenAccount, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      const creatorAccount = await getAccount(provider.connection, creatorTokenAccount);
+      expect(creatorAccount.amount).to.equal(BigInt(1));
+
+      let accessMintState = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(accessMintState.totalMinted.toNumber()).to.equal(3);
+
+      await program.methods
+        .setMaxSupply(new anchor.BN(3))
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .airdropAccess()
+          .accountsPartial({
+            creator: creator.publicKey,
+            accessMintState: accessMintStatePda,
+            mint: mint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: creatorTokenAccount, isSigner: false, isWritable: true },
+          ])
+          .rpc();
+        expect.fail("Should have thrown MaxSupplyReached error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("MaxSupplyReached");
+      }
+
+      // Lift the cap for the remaining tests
+      await program.methods
+        .setMaxSupply(new anchor.BN(0))
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+        })
+        .rpc();
+
+      accessMintState = await program.account.accessMintState.fetch(accessMintStatePda);
+      expect(accessMintState.maxSupply.toNumber()).to.equal(0);
+    });
+
+    it("Should let an eligible wallet claim a Merkle airdrop once", async () => {
+      const sha256 = (...parts: Buffer[]) =>
+        createHash("sha256").update(Buffer.concat(parts)).digest();
+      const buyerLeaf = sha256(buyer.publicKey.toBuffer());
+      const creatorLeaf = sha256(creator.publicKey.toBuffer());
+      const merkleRoot = Buffer.compare(buyerLeaf, creatorLeaf) <= 0
+        ? sha256(buyerLeaf, creatorLeaf)
+        : sha256(creatorLeaf, buyerLeaf);
+
+      const [airdropPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("merkle_airdrop"), accessMintStatePda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .commitAirdropRoot(Array.from(merkleRoot), 2, new anchor.BN(LAMPORTS_PER_SOL / 100))
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          airdrop: airdropPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const claim = () =>
+        program.methods
+          .claimAirdrop([Array.from(creatorLeaf)])
+          .accountsPartial({
+            recipient: buyer.publicKey,
+            airdrop: airdropPda,
+            accessMintState: accessMintStatePda,
+            mint: mint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            recipientTokenAccount: buyerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+
+      const before = await getAccount(provider.connection, buyerTokenAccount);
+      await claim();
+      const after = await getAccount(provider.connection, buyerTokenAccount);
+      expect(after.amount - before.amount).to.equal(BigInt(1));
+
+      const airdrop = await program.account.merkleAirdrop.fetch(airdropPda);
+      expect(airdrop.claimed).to.equal(1);
+
+      try {
+        await claim();
+        expect.fail("Double claim should be rejected");
+      } catch (error: any) {
+        expect(error.toString()).to.include("already in use");
+      }
+    });
+
+    it("Should check a ticket in once with a registered validator", async () => {
+      const validator = Keypair.generate();
+      const ticketId = new anchor.BN(1);
+      const [ticketValidatorPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("ticket_validator"),
+          accessMintStatePda.toBuffer(),
+          validator.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const [ticketPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("ticket"),
+          accessMintStatePda.toBuffer(),
+          ticketId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .addTicketValidator(validator.publicKey)
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          ticketValidator: ticketValidatorPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .issueTicket(ticketId, buyer.publicKey, "B-12", 1)
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          ticket: ticketPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const checkIn = () =>
+        program.methods
+          .checkIn()
+          .accountsPartial({
+            holder: buyer.publicKey,
+            validator: validator.publicKey,
+            accessMintState: accessMintStatePda,
+            mint: mint.publicKey,
+            holderTokenAccount: buyerTokenAccount,
+            ticketValidator: ticketValidatorPda,
+            ticket: ticketPda,
+          })
+          .signers([buyer, validator])
+          .rpc();
+
+      const before = await getAccount(provider.connection, buyerTokenAccount);
+      await checkIn();
+      const after = await getAccount(provider.connection, buyerTokenAccount);
+      expect(after.amount).to.equal(before.amount);
+
+      const ticket = await program.account.ticket.fetch(ticketPda);
+      expect(ticket.checkedIn).to.be.true;
+      expect(ticket.seat).to.equal("B-12");
+      expect(ticket.checkedInBy.toString()).to.equal(validator.publicKey.toString());
+
+      try {
+        await checkIn();
+        expect.fail("Should have rejected a second check-in");
+      } catch (error: any) {
+        expect(error.toString()).to.include("TicketAlreadyCheckedIn");
+      }
+    });
+
+    it("Should redeem one access unit and record a receipt", async () => {
+      const nonce = new anchor.BN(1);
+      const [redemptionPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_redemption"),
+          mint.publicKey.toBuffer(),
+          buyer.publicKey.toBuffer(),
+          nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const before = await getAccount(provider.connection, buyerTokenAccount);
+      await program.methods
+        .redeemAccess(nonce)
+        .accountsPartial({
+          holder: buyer.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: mint.publicKey,
+          holderTokenAccount: buyerTokenAccount,
+          redemption: redemptionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+      const after = await getAccount(provider.connection, buyerTokenAccount);
+      expect(before.amount - after.amount).to.equal(BigInt(1));
+
+      const redemption = await program.account.accessRedemption.fetch(redemptionPda);
+      expect(redemption.holder.toString()).to.equal(buyer.publicKey.toString());
+      expect(redemption.contentId).to.deep.equal(contentId);
+    });
   });
 
   describe("Access Token Properties", () => {
@@ -258,4 +627,336 @@ describe("Access Mint Program", () => {
       console.log("Authority:", expectedAuthority.toString());
     });
   });
-});
\ No newline at end of file
+
+  describe("Rental Access", () => {
+    const seed3 = new anchor.BN(3);
+    const rentalMint = Keypair.generate();
+    let accessMintStatePda: PublicKey;
+    let mintAuthorityPda: PublicKey;
+    let accessGrantPda: PublicKey;
+    let renterTokenAccount: PublicKey;
+
+    before(async () => {
+      [accessMintStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_state"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed3.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_mint_authority"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed3.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [accessGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_grant"),
+          rentalMint.publicKey.toBuffer(),
+          buyer.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeMint(contentId, seed3, { basic: {} }, 0, new anchor.BN(1))
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: rentalMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([rentalMint])
+        .rpc();
+
+      // These tests mint directly, without the payment escrow program
+      await program.methods
+        .setRequirePurchaseReceipt(false)
+        .accountsPartial({
+          creator: creator.publicKey,
+          accessMintState: accessMintStatePda,
+        })
+        .rpc();
+
+      renterTokenAccount = await getAssociatedTokenAddress(
+        rentalMint.publicKey,
+        buyer.publicKey
+      );
+    });
+
+    it("Should mint rental access with an expiry", async () => {
+      const duration = new anchor.BN(48 * 60 * 60);
+
+      await program.methods
+        .mintRentalAccess(duration)
+        .accountsPartial({
+          holder: buyer.publicKey,
+          payer: buyer.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: rentalMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          holderTokenAccount: renterTokenAccount,
+          accessGrant: accessGrantPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const grant = await program.account.accessGrant.fetch(accessGrantPda);
+      expect(grant.endTs.sub(grant.startTs).toString()).to.equal(duration.toString());
+      expect(grant.reclaimed).to.be.false;
+
+      const tokenAccountInfo = await getAccount(provider.connection, renterTokenAccount);
+      expect(tokenAccountInfo.amount).to.equal(BigInt(1));
+
+      console.log("Rental expires at:", grant.endTs.toString());
+    });
+
+    it("Should not transfer rental access out of the renter's wallet", async () => {
+      const tokenAccountInfo = await getAccount(provider.connection, renterTokenAccount);
+      expect(tokenAccountInfo.isFrozen).to.be.true;
+
+      const recipient = Keypair.generate();
+      const recipientTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        buyer,
+        rentalMint.publicKey,
+        recipient.publicKey
+      );
+
+      try {
+        await transfer(
+          provider.connection,
+          buyer,
+          renterTokenAccount,
+          recipientTokenAccount,
+          buyer,
+          1
+        );
+        expect.fail("Should have thrown AccountFrozen error");
+      } catch (error: any) {
+        // spl-token's AccountFrozen
+        expect(error.toString()).to.include("0x11");
+      }
+
+      const recipientAccountInfo = await getAccount(provider.connection, recipientTokenAccount);
+      expect(recipientAccountInfo.amount).to.equal(BigInt(0));
+      console.log("Rental access cannot be transferred");
+    });
+
+    it("Should not reclaim an active rental", async () => {
+      try {
+        await program.methods
+          .reclaimExpired()
+          .accountsPartial({
+            accessMintState: accessMintStatePda,
+            mint: rentalMint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            accessGrant: accessGrantPda,
+            holderTokenAccount: renterTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have thrown RentalStillActive error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("RentalStillActive");
+        console.log("Active rental cannot be reclaimed");
+      }
+    });
+
+    it("Should not migrate rental access to a new wallet", async () => {
+      const newWallet = Keypair.generate();
+      const [migrationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_migration"),
+          rentalMint.publicKey.toBuffer(),
+          buyer.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .migrateAccess()
+          .accountsPartial({
+            oldWallet: buyer.publicKey,
+            creator: creator.publicKey,
+            newWallet: newWallet.publicKey,
+            accessMintState: accessMintStatePda,
+            mint: rentalMint.publicKey,
+            mintAuthority: mintAuthorityPda,
+            oldTokenAccount: renterTokenAccount,
+            newTokenAccount: await getAssociatedTokenAddress(
+              rentalMint.publicKey,
+              newWallet.publicKey
+            ),
+            accessGrant: accessGrantPda,
+            migration: migrationPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown NoActiveAccess error");
+      } catch (error: any) {
+        // The rental token is frozen, so it never reaches the grant check
+        expect(error.toString()).to.include("NoActiveAccess");
+      }
+    });
+
+    it("Should grant a preview that a purchase makes permanent", async () => {
+      const previewer = Keypair.generate();
+      const duration = new anchor.BN(24 * 60 * 60);
+
+      const [previewGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_grant"),
+          rentalMint.publicKey.toBuffer(),
+          previewer.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const previewerTokenAccount = await getAssociatedTokenAddress(
+        rentalMint.publicKey,
+        previewer.publicKey
+      );
+
+      await program.methods
+        .grantPreview(duration)
+        .accountsPartial({
+          creator: creator.publicKey,
+          holder: previewer.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: rentalMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          holderTokenAccount: previewerTokenAccount,
+          accessGrant: previewGrantPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      let grant = await program.account.accessGrant.fetch(previewGrantPda);
+      expect(grant.preview).to.be.true;
+      expect(grant.endTs.sub(grant.startTs).toString()).to.equal(duration.toString());
+
+      await program.methods
+        .mintAccess()
+        .accountsPartial({
+          buyer: previewer.publicKey,
+          payer: creator.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: rentalMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: previewerTokenAccount,
+          previewGrant: previewGrantPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      grant = await program.account.accessGrant.fetch(previewGrantPda);
+      expect(grant.preview).to.be.false;
+      expect(grant.endTs.toString()).to.equal("9223372036854775807");
+
+      // The previewed token is kept rather than minted again
+      const tokenAccountInfo = await getAccount(provider.connection, previewerTokenAccount);
+      expect(tokenAccountInfo.amount).to.equal(BigInt(1));
+      console.log("Preview upgraded to permanent access");
+    });
+
+    it("Should create a session grant for a device key", async () => {
+      const sessionKey = Keypair.generate().publicKey;
+      const duration = new anchor.BN(60 * 60);
+
+      const [sessionGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("session_grant"),
+          rentalMint.publicKey.toBuffer(),
+          buyer.publicKey.toBuffer(),
+          sessionKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createSessionGrant(sessionKey, duration)
+        .accountsPartial({
+          holder: buyer.publicKey,
+          accessMintState: accessMintStatePda,
+          mint: rentalMint.publicKey,
+          holderTokenAccount: renterTokenAccount,
+          sessionGrant: sessionGrantPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const session = await program.account.sessionGrant.fetch(sessionGrantPda);
+      expect(session.holder.toString()).to.equal(buyer.publicKey.toString());
+      expect(session.sessionKey.toString()).to.equal(sessionKey.toString());
+      expect(session.expiresTs.sub(session.createdTs).toString()).to.equal(duration.toString());
+
+      await program.methods
+        .revokeSessionGrant()
+        .accountsPartial({
+          holder: buyer.publicKey,
+          sessionGrant: sessionGrantPda,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const closed = await provider.connection.getAccountInfo(sessionGrantPda);
+      expect(closed).to.be.null;
+      console.log("Session grant created and revoked");
+    });
+
+    it("Should reject a session longer than the maximum", async () => {
+      const sessionKey = Keypair.generate().publicKey;
+
+      const [sessionGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("session_grant"),
+          rentalMint.publicKey.toBuffer(),
+          buyer.publicKey.toBuffer(),
+          sessionKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createSessionGrant(sessionKey, new anchor.BN(25 * 60 * 60))
+          .accountsPartial({
+            holder: buyer.publicKey,
+            accessMintState: accessMintStatePda,
+            mint: rentalMint.publicKey,
+            holderTokenAccount: renterTokenAccount,
+            sessionGrant: sessionGrantPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown InvalidSessionDuration error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidSessionDuration");
+      }
+    });
+  });
+});
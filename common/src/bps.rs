@@ -0,0 +1,74 @@
+//! Basis point math shared by fee and revenue split calculations
+
+/// Basis points representing 100%
+pub const MAX_BPS: u16 = 10000;
+
+/// Apply basis points to an amount, rounding down
+/// Multiplies in u128 so amounts up to u64::MAX don't overflow
+pub fn apply_bps(amount: u64, bps: u16) -> Option<u64> {
+    let result = (amount as u128)
+        .checked_mul(bps as u128)?
+        .checked_div(MAX_BPS as u128)?;
+    
+    u64::try_from(result).ok()
+}
+
+/// Sum basis points without wrapping, returning None on overflow
+pub fn checked_total_bps<I: IntoIterator<Item = u16>>(shares: I) -> Option<u32> {
+    shares
+        .into_iter()
+        .try_fold(0u32, |total, bps| total.checked_add(bps as u32))
+}
+
+/// Whether a total of basis points fits within 100%
+pub fn is_within_max(total_bps: u32) -> bool {
+    total_bps <= MAX_BPS as u32
+}
+
+/// Whether `amount` is within `bps` of `target`
+/// The tolerance is rounded down to a whole base unit, so 0 bps requires an exact match
+pub fn is_within_tolerance(amount: u64, target: u64, bps: u16) -> Option<bool> {
+    Some(amount.abs_diff(target) <= apply_bps(target, bps)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn apply_bps_rounds_down() {
+        assert_eq!(apply_bps(999, 1000), Some(99));
+        assert_eq!(apply_bps(10_000, MAX_BPS), Some(10_000));
+        assert_eq!(apply_bps(0, 500), Some(0));
+    }
+    
+    #[test]
+    fn apply_bps_handles_max_amount() {
+        assert_eq!(apply_bps(u64::MAX, MAX_BPS), Some(u64::MAX));
+        assert_eq!(apply_bps(u64::MAX, 5000), Some(u64::MAX / 2));
+    }
+    
+    #[test]
+    fn apply_bps_above_max_overflows_for_large_amounts() {
+        assert_eq!(apply_bps(u64::MAX, u16::MAX), None);
+    }
+    
+    #[test]
+    fn checked_total_bps_does_not_wrap() {
+        assert_eq!(checked_total_bps([u16::MAX; 10]), Some(u16::MAX as u32 * 10));
+        assert!(!is_within_max(checked_total_bps([u16::MAX; 10]).unwrap()));
+        assert!(is_within_max(checked_total_bps([5000, 5000]).unwrap()));
+    }
+    
+    #[test]
+    fn tolerance_accepts_either_side_of_target() {
+        assert_eq!(is_within_tolerance(1_000, 1_000, 0), Some(true));
+        assert_eq!(is_within_tolerance(999, 1_000, 0), Some(false));
+        assert_eq!(is_within_tolerance(990, 1_000, 100), Some(true));
+        assert_eq!(is_within_tolerance(1_010, 1_000, 100), Some(true));
+        assert_eq!(is_within_tolerance(989, 1_000, 100), Some(false));
+        
+        // 1% of 99 rounds down to 0, so only the exact amount is accepted
+        assert_eq!(is_within_tolerance(98, 99, 100), Some(false));
+    }
+}
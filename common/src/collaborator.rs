@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Collaborator with their revenue share
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct Collaborator {
+    /// Collaborator's public key
+    pub pubkey: Pubkey,
+    
+    /// Share in basis points (e.g., 500 = 5%)
+    pub share_bps: u16,
+    
+    /// Whether the collaborator has signed to accept their share
+    pub accepted: bool,
+}
+
+impl Collaborator {
+    /// Serialized size (derived by InitSpace)
+    pub const LEN: usize = Self::INIT_SPACE;
+    
+    /// Share paid out to the collaborator; pending invites receive nothing
+    pub fn active_share_bps(&self) -> u16 {
+        if self.accepted {
+            self.share_bps
+        } else {
+            0
+        }
+    }
+}
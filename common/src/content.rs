@@ -0,0 +1,59 @@
+//! Content identifiers
+//!
+//! A content id is the sha256 of the content's canonical metadata blob.
+
+/// Content id newtype over the raw 32 bytes stored on-chain
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ContentId(pub [u8; 32]);
+
+impl ContentId {
+    /// Derive the content id for a canonical metadata blob
+    pub fn from_metadata(metadata: &[u8]) -> Self {
+        Self(solana_sha256_hasher::hash(metadata).to_bytes())
+    }
+    
+    /// The all-zero id can never be a real hash, so it is rejected everywhere
+    pub fn is_valid(&self) -> bool {
+        self.0.iter().any(|byte| *byte != 0)
+    }
+    
+    /// Check that this id is the sha256 of the given metadata blob
+    pub fn matches(&self, metadata: &[u8]) -> bool {
+        Self::from_metadata(metadata) == *self
+    }
+    
+    /// Raw bytes, e.g. for PDA seeds
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for ContentId {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for ContentId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn zero_id_is_invalid() {
+        assert!(!ContentId([0; 32]).is_valid());
+        assert!(ContentId::from_metadata(b"").is_valid());
+    }
+    
+    #[test]
+    fn matches_only_its_preimage() {
+        let id = ContentId::from_metadata(b"{\"title\":\"preset pack\"}");
+        assert!(id.matches(b"{\"title\":\"preset pack\"}"));
+        assert!(!id.matches(b"{\"title\":\"other\"}"));
+    }
+}
@@ -0,0 +1,12 @@
+//! Types and helpers shared by the access-mint, distribution and payment-escrow programs
+
+pub mod bps;
+pub mod collaborator;
+pub mod content;
+pub mod merkle;
+pub mod payments;
+pub mod seeds;
+pub mod units;
+
+pub use collaborator::*;
+pub use content::*;
@@ -0,0 +1,39 @@
+//! Merkle allowlists
+//!
+//! Leaves are the sha256 of the allowlisted wallet's pubkey bytes. Each pair is
+//! hashed in sorted order, so proofs don't need to encode left/right positions.
+
+/// Leaf for an allowlisted wallet
+pub fn leaf(wallet: &[u8; 32]) -> [u8; 32] {
+    solana_sha256_hasher::hash(wallet).to_bytes()
+}
+
+/// Hash two nodes in sorted order
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    solana_sha256_hasher::hashv(&[first.as_ref(), second.as_ref()]).to_bytes()
+}
+
+/// Check that `leaf` is included in the tree with the given `root`
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn verifies_members_and_rejects_others() {
+        let wallets = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]];
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(leaf).collect();
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[3]);
+        let root = hash_pair(&left, &right);
+        
+        assert!(verify(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify(&[leaves[2], left], &root, leaves[3]));
+        assert!(!verify(&[leaves[1], right], &root, leaf(&[5; 32])));
+        assert!(!verify(&[], &root, leaves[0]));
+    }
+}
@@ -0,0 +1,141 @@
+//! SOL, SPL Token and Token-2022 payment transfers shared by the programs
+//!
+//! Vaults are program-owned PDAs: SOL sits in the vault's own lamports and SPL
+//! payments in a token account the vault is the authority of.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as Token2022Mint;
+use anchor_spl::token_interface::{self, Mint, TransferChecked};
+
+/// Whether `program_id` can move SPL payments (SPL Token or Token-2022)
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == anchor_spl::token::ID || *program_id == anchor_spl::token_2022::ID
+}
+
+/// Decimals of an SPL Token or Token-2022 payment mint, needed for `transfer_checked`
+pub fn mint_decimals(mint: &AccountInfo) -> Result<u8> {
+    Ok(Mint::try_deserialize(&mut &mint.try_borrow_data()?[..])?.decimals)
+}
+
+/// Transfer fee withheld when moving `amount` of the payment mint in the current epoch
+/// Zero for SPL Token mints and Token-2022 mints without the transfer-fee extension
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != anchor_spl::token_2022::ID {
+        return Ok(0);
+    }
+    
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+    
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or_else(|| ProgramError::ArithmeticOverflow.into()),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Move SPL payment tokens with `transfer_checked`, which Token-2022 requires for fee-bearing mints
+/// Returns the amount the destination actually receives after any transfer fee
+pub fn transfer_payment<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    require!(is_token_program(token_program.key), ErrorCode::InvalidProgramId);
+    
+    let decimals = mint_decimals(&mint)?;
+    let fee = transfer_fee(&mint, amount)?;
+    
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferChecked {
+                from,
+                mint,
+                to,
+                authority,
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )?;
+    
+    if fee > 0 {
+        msg!("Transfer fee of {} withheld by payment mint", fee);
+    }
+    
+    amount
+        .checked_sub(fee)
+        .ok_or_else(|| ProgramError::ArithmeticOverflow.into())
+}
+
+/// Token accounts an SPL payment moves between (omitted for SOL payments)
+pub struct SplPayment<'info> {
+    /// SPL Token or Token-2022 program owning the mint
+    pub token_program: AccountInfo<'info>,
+    
+    /// Payment token mint
+    pub mint: AccountInfo<'info>,
+    
+    /// Token account the payment leaves
+    pub from: AccountInfo<'info>,
+    
+    /// Token account the payment lands in
+    pub to: AccountInfo<'info>,
+}
+
+/// Pay `amount` from `payer` into `vault`
+/// SOL goes straight to the vault's lamports; SPL payments move from `spl.from` to
+/// `spl.to` with `payer` as the token authority
+/// Returns the amount the vault actually receives after any transfer fee
+pub fn pay_into_vault<'info>(
+    payer: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    spl: Option<SplPayment<'info>>,
+    amount: u64,
+) -> Result<u64> {
+    match spl {
+        None => {
+            system_program::transfer(
+                CpiContext::new(system_program, Transfer { from: payer, to: vault }),
+                amount,
+            )?;
+            
+            Ok(amount)
+        }
+        Some(spl) => transfer_payment(spl.token_program, spl.from, spl.mint, spl.to, payer, &[], amount),
+    }
+}
+
+/// Return `amount` held by the program-owned `vault` to `recipient`
+/// SOL moves directly between lamport balances; SPL payments move from `spl.from` to
+/// `spl.to`, signed by the vault PDA with `signer_seeds`
+/// Returns the amount the recipient actually receives after any transfer fee
+pub fn refund_from_vault<'info>(
+    vault: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    spl: Option<SplPayment<'info>>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    match spl {
+        None => {
+            vault.sub_lamports(amount)?;
+            recipient.add_lamports(amount)?;
+            
+            Ok(amount)
+        }
+        Some(spl) => transfer_payment(spl.token_program, spl.from, spl.mint, spl.to, vault, signer_seeds, amount),
+    }
+}
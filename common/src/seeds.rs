@@ -0,0 +1,180 @@
+//! PDA seed prefixes for every program
+//!
+//! Programs and clients derive addresses across program boundaries, so all
+//! prefixes live here to keep them from drifting apart.
+
+/// Access mint state PDA: [prefix, creator, content_id, seed]
+pub const ACCESS_MINT_STATE: &[u8] = b"access_mint_state";
+
+/// Access mint authority PDA: [prefix, creator, content_id, seed]
+pub const ACCESS_MINT_AUTHORITY: &[u8] = b"access_mint_authority";
+
+/// Rental access grant PDA: [prefix, mint, holder]
+pub const ACCESS_GRANT: &[u8] = b"access_grant";
+
+/// Device session grant PDA: [prefix, mint, holder, session_key]
+pub const SESSION_GRANT: &[u8] = b"session_grant";
+
+/// Creator access collection PDA (also the collection NFT's authority): [prefix, creator, series (u64 LE)]
+pub const ACCESS_COLLECTION: &[u8] = b"access_collection";
+
+/// Revenue split PDA: [prefix, creator, content_id, seed]
+pub const SPLIT: &[u8] = b"split";
+
+/// Distribution vault PDA: [prefix, split_state]
+pub const DISTRIBUTION_VAULT: &[u8] = b"vault";
+
+/// Distribution record PDA: [prefix, split_state, distribution index]
+pub const DISTRIBUTION_RECORD: &[u8] = b"distribution_record";
+
+/// Creator revenue bucket for a calendar month: [prefix, creator, payment mint, period (yyyymm)]
+pub const REVENUE_BUCKET: &[u8] = b"revenue_bucket";
+
+/// Escrow state PDA: [prefix, buyer, content_id, seed]
+pub const ESCROW: &[u8] = b"escrow";
+
+/// Buyer vault PDA shared by a buyer's escrows: [prefix, buyer, payment_mint]
+/// SOL vaults use the system program id as the payment mint
+pub const BUYER_VAULT: &[u8] = b"buyer_vault";
+
+/// Platform config PDA: [prefix]
+pub const PLATFORM_CONFIG: &[u8] = b"platform_config";
+
+/// Listing PDA: [prefix, creator, content_id]
+pub const LISTING: &[u8] = b"listing";
+
+/// Content metadata PDA: [prefix, listing]
+pub const CONTENT_METADATA: &[u8] = b"content_metadata";
+
+/// Crowdfunding campaign PDA: [prefix, creator, content_id, seed]
+pub const CAMPAIGN: &[u8] = b"campaign";
+
+/// Campaign vault PDA: [prefix, campaign]
+pub const CAMPAIGN_VAULT: &[u8] = b"campaign_vault";
+
+/// Contribution PDA: [prefix, campaign, contributor]
+pub const CONTRIBUTION: &[u8] = b"contribution";
+
+/// Creator badge PDA: [prefix, creator]
+pub const CREATOR_BADGE: &[u8] = b"creator_badge";
+
+/// Creator badge mint PDA (Token-2022, non-transferable): [prefix]
+pub const CREATOR_BADGE_MINT: &[u8] = b"creator_badge_mint";
+
+/// Collaborator share listing PDA: [prefix, split_state, seller]
+pub const SHARE_LISTING: &[u8] = b"share_listing";
+
+/// Upgrade governance (timelock and program upgrade authority) PDA: [prefix]
+pub const UPGRADE_GOVERNANCE: &[u8] = b"upgrade_governance";
+
+/// Per-wallet purchase counter PDA: [prefix, creator, content_id, buyer]
+pub const PURCHASE_COUNTER: &[u8] = b"purchase_counter";
+
+/// Content freeze (takedown) PDA: [prefix, content_id]
+pub const CONTENT_FREEZE: &[u8] = b"content_freeze";
+
+/// Buyer denylist entry PDA: [prefix, wallet]
+pub const DENYLIST: &[u8] = b"denylist";
+
+/// Marketplace platform PDA: [prefix, authority]
+pub const PLATFORM: &[u8] = b"platform";
+
+/// Merkle airdrop PDA: [prefix, access_mint_state]
+pub const MERKLE_AIRDROP: &[u8] = b"merkle_airdrop";
+
+/// Merkle airdrop claim record PDA: [prefix, airdrop, recipient]
+pub const AIRDROP_CLAIM: &[u8] = b"airdrop_claim";
+
+/// Buyer loyalty points PDA: [prefix, buyer]
+pub const BUYER_LOYALTY: &[u8] = b"buyer_loyalty";
+
+/// Creator bond (staked SOL) PDA: [prefix, creator]
+pub const CREATOR_BOND: &[u8] = b"creator_bond";
+
+/// Dispute recorded against a creator's bond for an escrow, one per escrow: [prefix, escrow]
+pub const DISPUTE_RECORD: &[u8] = b"dispute_record";
+
+/// Registered cross-chain payment emitter PDA: [prefix, emitter_chain (u16 LE)]
+pub const FOREIGN_EMITTER: &[u8] = b"foreign_emitter";
+
+/// Cross-chain payment redemption record PDA:
+/// [prefix, emitter_chain (u16 LE), emitter_address, sequence (u64 LE)]
+pub const CROSSCHAIN_REDEMPTION: &[u8] = b"crosschain_redemption";
+
+/// Fiat settlement record PDA: [prefix, external_payment_id]
+pub const FIAT_SETTLEMENT: &[u8] = b"fiat_settlement";
+
+/// Listing purchase attempt log PDA: [prefix, listing]
+pub const ATTEMPT_LOG: &[u8] = b"attempt_log";
+
+/// Compressed receipt tree PDA (also the tree authority): [prefix, merkle_tree]
+pub const RECEIPT_TREE: &[u8] = b"receipt_tree";
+
+/// Access redemption receipt PDA: [prefix, mint, holder, nonce (u64 LE)]
+pub const ACCESS_REDEMPTION: &[u8] = b"access_redemption";
+
+/// Ticket validator PDA: [prefix, access_mint_state, validator]
+pub const TICKET_VALIDATOR: &[u8] = b"ticket_validator";
+
+/// Event ticket PDA: [prefix, access_mint_state, ticket_id (u64 LE)]
+pub const TICKET: &[u8] = b"ticket";
+
+/// Access token resale listing PDA (also the escrowed token owner): [prefix, seller, access_mint]
+pub const RESALE_LISTING: &[u8] = b"resale_listing";
+
+/// Buyer offer (also holds the offered SOL): [prefix, buyer, content_id, seed (u64 LE)]
+pub const OFFER: &[u8] = b"offer";
+
+/// Milestone commission escrow (also holds the funds): [prefix, buyer, content_id, seed (u64 LE)]
+pub const MILESTONE_STATE: &[u8] = b"milestone_state";
+
+/// Platform-wide sale event PDA (also holds the subsidy budget): [prefix]
+pub const PLATFORM_SALE: &[u8] = b"platform_sale";
+
+/// Fee rebate mint authority PDA: [prefix]
+pub const REBATE_AUTHORITY: &[u8] = b"rebate_authority";
+
+/// Creator fee rebate opt-in PDA: [prefix, creator]
+pub const CREATOR_REBATE: &[u8] = b"creator_rebate";
+
+/// Priority access queue for a drop: [prefix, listing]
+pub const QUEUE_STATE: &[u8] = b"queue_state";
+
+/// Buyer's registered intent in a queue (holds the escrowed price): [prefix, queue_state, buyer]
+pub const QUEUE_ENTRY: &[u8] = b"queue_entry";
+
+/// Creator's mystery box of contents sold at one price: [prefix, creator, box_id]
+pub const MYSTERY_BOX: &[u8] = b"mystery_box";
+
+/// Paid access awaiting a later claim (holds the paid price): [prefix, buyer, listing]
+pub const PENDING_ACCESS: &[u8] = b"pending_access";
+
+/// Buyer's rating of a listing, one per verified purchaser: [prefix, listing, buyer]
+pub const RATING: &[u8] = b"rating";
+
+/// Platform refund insurance pool (holds the pooled lamports): [prefix]
+pub const INSURANCE_POOL: &[u8] = b"insurance_pool";
+
+/// Insurance payout made for an escrow, one per escrow: [prefix, escrow]
+pub const INSURANCE_CLAIM: &[u8] = b"insurance_claim";
+
+/// Recurring billing agreement (holds the buyer's pre-funded deposit): [prefix, buyer, listing]
+pub const BILLING_AGREEMENT: &[u8] = b"billing_agreement";
+
+/// Buyer's pre-funded deposit drawn on by session-key purchases: [prefix, buyer, payment_mint]
+pub const BUYER_DEPOSIT: &[u8] = b"buyer_deposit";
+
+/// Pay-per-view payment channel (holds the buyer's deposit): [prefix, buyer, listing]
+pub const PAYMENT_CHANNEL: &[u8] = b"payment_channel";
+
+/// Purchases attributed to a referrer: [prefix, referrer]
+pub const REFERRER_STATS: &[u8] = b"referrer_stats";
+
+/// Access wallet migration record PDA: [prefix, mint, old_wallet]
+pub const ACCESS_MIGRATION: &[u8] = b"access_migration";
+
+/// Payment escrow authority that waives the platform fee on distributions for fee-exempt wallets: [prefix]
+pub const FEE_WAIVER: &[u8] = b"fee_waiver";
+
+/// Payment escrow authority that mints access for the escrow program's own flows without a purchase receipt: [prefix]
+pub const ACCESS_MINTER: &[u8] = b"access_minter";
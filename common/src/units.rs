@@ -0,0 +1,50 @@
+//! Conversions between human-readable token amounts and mint base units
+
+/// Decimals of native SOL (1 SOL = 10^9 lamports)
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Convert a human-readable amount to base units of a mint with `decimals`
+/// The amount is a fixed-point integer with `amount_decimals` digits after the point
+/// (e.g. 1.5 USDC is `to_base_units(15, 1, 6)`); returns None on overflow or lost precision
+pub fn to_base_units(amount: u64, amount_decimals: u8, decimals: u8) -> Option<u64> {
+    if amount_decimals <= decimals {
+        let scale = 10u64.checked_pow((decimals - amount_decimals) as u32)?;
+        amount.checked_mul(scale)
+    } else {
+        let scale = 10u64.checked_pow((amount_decimals - decimals) as u32)?;
+        amount.is_multiple_of(scale).then_some(amount / scale)
+    }
+}
+
+/// Split an amount in base units into whole tokens and the remaining base units
+pub fn to_whole_units(amount: u64, decimals: u8) -> Option<(u64, u64)> {
+    let scale = 10u64.checked_pow(decimals as u32)?;
+    
+    Some((amount / scale, amount % scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn to_base_units_scales_by_mint_decimals() {
+        assert_eq!(to_base_units(15, 1, 6), Some(1_500_000));
+        assert_eq!(to_base_units(2, 0, SOL_DECIMALS), Some(2_000_000_000));
+        assert_eq!(to_base_units(1_500_000, 6, 6), Some(1_500_000));
+    }
+    
+    #[test]
+    fn to_base_units_rejects_lost_precision_and_overflow() {
+        assert_eq!(to_base_units(1_234_567, 7, 6), None);
+        assert_eq!(to_base_units(1_234_560, 7, 6), Some(123_456));
+        assert_eq!(to_base_units(u64::MAX, 0, 6), None);
+    }
+    
+    #[test]
+    fn to_whole_units_splits_fraction() {
+        assert_eq!(to_whole_units(1_500_000, 6), Some((1, 500_000)));
+        assert_eq!(to_whole_units(999, 0), Some((999, 0)));
+        assert_eq!(to_whole_units(1, 20), None);
+    }
+}
@@ -0,0 +1,19 @@
+//! CPI interface for the distribution program
+//!
+//! Depend on this crate to route payments through a revenue split from
+//! another program without pulling in the program entrypoint.
+//!
+//! ```ignore
+//! use distribution_cpi::{cpi, program::Distribution};
+//!
+//! cpi::distribute(
+//!     CpiContext::new(
+//!         ctx.accounts.distribution_program.to_account_info(),
+//!         cpi::accounts::Distribute { /* ... */ },
+//!     )
+//!     .with_remaining_accounts(collaborator_accounts),
+//!     amount,
+//! )?;
+//! ```
+
+pub use distribution::{accounts, cpi, errors::DistributionError, instruction, program, state, ID};
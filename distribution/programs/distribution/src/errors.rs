@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+/// Codes start at 7000 so distribution errors surfacing through payment escrow CPIs
+/// can be told apart from payment escrow's own (which start at 6000)
+#[error_code(offset = 7000)]
 pub enum DistributionError {
     #[msg("Invalid creator")]
     InvalidCreator,
@@ -37,4 +39,43 @@ pub enum DistributionError {
     
     #[msg("Distribution already completed")]
     AlreadyDistributed,
+    
+    #[msg("Memo exceeds maximum length")]
+    MemoTooLong,
+    
+    #[msg("Vault still holds undistributed funds")]
+    PendingDistribution,
+    
+    #[msg("Accrued payout is not due yet")]
+    PayoutNotDue,
+    
+    #[msg("Invalid payout schedule")]
+    InvalidPayoutSchedule,
+    
+    #[msg("Distribution record is still within its retention period")]
+    RetentionPeriodActive,
+    
+    #[msg("Collaboration already accepted")]
+    CollaborationAlreadyAccepted,
+    
+    #[msg("Collaboration has not been accepted")]
+    CollaborationNotAccepted,
+    
+    #[msg("Share transfer requires creator approval")]
+    ShareTransferApprovalRequired,
+    
+    #[msg("Invalid price")]
+    InvalidPrice,
+    
+    #[msg("Collaborator appears more than once")]
+    DuplicateCollaborator,
+    
+    #[msg("Accepted collaborator must sign to change or remove their share")]
+    AcceptedCollaboratorChanged,
+    
+    #[msg("Split has been paid in too many token mints")]
+    TooManyPaymentMints,
+    
+    #[msg("Share price exceeds the buyer's maximum")]
+    PriceExceedsMaximum,
 }
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a wallet tips a content's split
+#[event]
+pub struct TipReceived {
+    /// Split state PDA that received the tip
+    pub split_state: Pubkey,
+    
+    /// Wallet that sent the tip
+    pub tipper: Pubkey,
+    
+    /// Tip amount in lamports or SPL token amount
+    pub amount: u64,
+    
+    /// Payment token mint (System::id() for SOL)
+    pub payment_token_mint: Pubkey,
+    
+    /// Optional message from the tipper
+    pub memo: Option<String>,
+    
+    /// Timestamp of the tip
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator proposes a new payout address
+#[event]
+pub struct PayoutAddressProposed {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Current payout address
+    pub current: Pubkey,
+    
+    /// Proposed payout address
+    pub proposed: Pubkey,
+}
+
+/// Emitted when a proposed payout address is accepted
+#[event]
+pub struct PayoutAddressRotated {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Previous payout address
+    pub previous: Pubkey,
+    
+    /// New payout address
+    pub current: Pubkey,
+}
+
+/// Emitted when a collaborator accepts their share of a split
+#[event]
+pub struct CollaborationAccepted {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Collaborator accepting the share
+    pub collaborator: Pubkey,
+    
+    /// Share in basis points now active
+    pub share_bps: u16,
+}
+
+/// Emitted when a collaborator assigns part or all of their share to another wallet
+#[event]
+pub struct ShareTransferred {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Collaborator giving up the share
+    pub from: Pubkey,
+    
+    /// Wallet receiving the share
+    pub to: Pubkey,
+    
+    /// Share transferred in basis points
+    pub share_bps: u16,
+}
+
+/// Emitted when a collaborator lists part of their share for sale
+#[event]
+pub struct ShareListed {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Collaborator selling the share
+    pub seller: Pubkey,
+    
+    /// Share offered in basis points
+    pub share_bps: u16,
+    
+    /// Price in lamports
+    pub price: u64,
+}
+
+/// Emitted when a listed share is bought
+#[event]
+pub struct ShareSold {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Collaborator who sold the share
+    pub seller: Pubkey,
+    
+    /// New holder of the share
+    pub buyer: Pubkey,
+    
+    /// Share sold in basis points
+    pub share_bps: u16,
+    
+    /// Price paid in lamports
+    pub price: u64,
+}
+
+/// Emitted when SOL accrued under a payout schedule is paid out
+#[event]
+pub struct AccruedPayout {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Accrued lamports paid out
+    pub amount: u64,
+    
+    /// Timestamp of the payout
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator replaces a split's collaborator list
+#[event]
+pub struct SplitUpdated {
+    /// Split state PDA
+    pub split_state: Pubkey,
+    
+    /// Number of collaborators after the update
+    pub collaborator_count: u8,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Accept a pending collaborator invite on a split
+/// Until accepted, the collaborator's share is paid to the creator
+pub fn accept_collaboration(ctx: Context<AcceptCollaboration>) -> Result<()> {
+    let split_state_key = ctx.accounts.split_state.key();
+    let collaborator_key = ctx.accounts.collaborator.key();
+    
+    let collaborator = ctx
+        .accounts
+        .split_state
+        .collaborators
+        .iter_mut()
+        .find(|collaborator| collaborator.pubkey == collaborator_key)
+        .ok_or(DistributionError::InvalidCollaborator)?;
+    
+    require!(
+        !collaborator.accepted,
+        DistributionError::CollaborationAlreadyAccepted
+    );
+    
+    collaborator.accepted = true;
+    
+    emit!(CollaborationAccepted {
+        split_state: split_state_key,
+        collaborator: collaborator_key,
+        share_bps: collaborator.share_bps,
+    });
+    
+    msg!("Collaborator {} accepted {}bps", collaborator_key, collaborator.share_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptCollaboration<'info> {
+    /// Invited collaborator accepting their share
+    pub collaborator: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+}
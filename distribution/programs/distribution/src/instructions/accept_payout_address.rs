@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payment::*;
+
+/// Accept a proposed payout address
+/// Blocked while the split vault still holds undistributed funds: the vault's
+/// associated token account for every mint the split has been paid in must be empty
+pub fn accept_payout_address(ctx: Context<AcceptPayoutAddress>) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    
+    require!(
+        split_state.pending_payout_address == Some(ctx.accounts.new_payout_address.key()),
+        DistributionError::Unauthorized
+    );
+    
+    // Undistributed SOL sits directly in the vault
+    require!(
+        ctx.accounts.vault.lamports() == 0,
+        DistributionError::PendingDistribution
+    );
+    
+    // Undistributed SPL tokens sit in the vault's token account for each mint paid in
+    require!(
+        ctx.remaining_accounts.len() == split_state.payment_mints.len(),
+        DistributionError::InvalidVault
+    );
+    for (mint, account) in split_state.payment_mints.iter().zip(ctx.remaining_accounts.iter()) {
+        require!(is_token_program(account.owner), DistributionError::InvalidVault);
+        require!(
+            account.key() == get_associated_token_address_with_program_id(
+                ctx.accounts.vault.key,
+                mint,
+                account.owner,
+            ),
+            DistributionError::InvalidVault
+        );
+        let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        require!(
+            token_account.amount == 0,
+            DistributionError::PendingDistribution
+        );
+    }
+    
+    let previous = split_state.payout_address;
+    split_state.payout_address = ctx.accounts.new_payout_address.key();
+    split_state.pending_payout_address = None;
+    
+    emit!(PayoutAddressRotated {
+        split_state: split_state.key(),
+        previous,
+        current: split_state.payout_address,
+    });
+    
+    msg!("Payout address rotated: {} -> {}", previous, split_state.payout_address);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptPayoutAddress<'info> {
+    /// The proposed payout address accepting the rotation
+    pub new_payout_address: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Vault holding undistributed funds
+    #[account(
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    // Remaining accounts: the vault's associated token account for each of
+    // split_state.payment_mints, in order, which must be empty
+}
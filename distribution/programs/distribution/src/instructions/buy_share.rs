@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Buy a listed collaborator share
+/// Pays the seller and moves the share to the buyer in the same instruction;
+/// buying counts as accepting, so the buyer's share is active immediately
+/// `max_price` protects the buyer from the seller re-listing at a higher price
+pub fn buy_share(ctx: Context<BuyShare>, max_price: u64) -> Result<()> {
+    let listing = &ctx.accounts.share_listing;
+    let seller = listing.seller;
+    let buyer = ctx.accounts.buyer.key();
+    let share_bps = listing.share_bps;
+    let price = listing.price;
+    
+    // The buyer pays no more than the price they agreed to
+    require!(price <= max_price, DistributionError::PriceExceedsMaximum);
+    
+    // Accrued SOL is paid by the shares in place at payout time
+    require!(
+        ctx.accounts.split_state.accrued_amount == 0,
+        DistributionError::PendingDistribution
+    );
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+    
+    let split_state = &mut ctx.accounts.split_state;
+    split_state.transfer_share(&seller, &buyer, share_bps)?;
+    
+    if let Some(collaborator) = split_state
+        .collaborators
+        .iter_mut()
+        .find(|collaborator| collaborator.pubkey == buyer)
+    {
+        collaborator.accepted = true;
+    }
+    
+    emit!(ShareSold {
+        split_state: split_state.key(),
+        seller,
+        buyer,
+        share_bps,
+        price,
+    });
+    
+    msg!("Collaborator {} sold {}bps to {} for {} lamports", seller, share_bps, buyer, price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuyShare<'info> {
+    /// Buyer paying for the share (and any split growth)
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Seller receiving the payment and the listing rent
+    /// CHECK: Must match the listing's seller
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// Share listing PDA, closed on purchase
+    #[account(
+        mut,
+        close = seller,
+        seeds = [
+            ShareListing::SEED_PREFIX,
+            split_state.key().as_ref(),
+            seller.key().as_ref(),
+        ],
+        bump = share_listing.bump,
+        has_one = seller @ DistributionError::InvalidRecipient,
+        has_one = split_state @ DistributionError::InvalidCollaborator,
+    )]
+    pub share_listing: Account<'info, ShareListing>,
+    
+    /// Split state PDA, resized for the collaborator list after the sale
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        realloc = SplitState::space(split_state.collaborators_after_transfer(
+            seller.key,
+            buyer.key,
+            share_listing.share_bps,
+        )),
+        realloc::payer = buyer,
+        realloc::zero = false,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
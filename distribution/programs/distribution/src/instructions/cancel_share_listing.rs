@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Withdraw a share listing, refunding its rent to the seller
+pub fn cancel_share_listing(ctx: Context<CancelShareListing>) -> Result<()> {
+    msg!("Share listing cancelled by: {}", ctx.accounts.seller.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelShareListing<'info> {
+    /// Collaborator who created the listing
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    /// Share listing PDA
+    #[account(
+        mut,
+        close = seller,
+        seeds = [
+            ShareListing::SEED_PREFIX,
+            share_listing.split_state.as_ref(),
+            seller.key().as_ref(),
+        ],
+        bump = share_listing.bump,
+        has_one = seller @ DistributionError::Unauthorized,
+    )]
+    pub share_listing: Account<'info, ShareListing>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Close a distribution record once its retention period has elapsed
+/// Permissionless: rent is always returned to the account that paid for the record
+pub fn close_distribution_record(ctx: Context<CloseDistributionRecord>) -> Result<()> {
+    let record = &ctx.accounts.distribution_record;
+    
+    require!(
+        record.is_closable(Clock::get()?.unix_timestamp),
+        DistributionError::RetentionPeriodActive
+    );
+    
+    msg!("Distribution record {} closed for split: {}", record.index, record.split_state);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseDistributionRecord<'info> {
+    /// Distribution record PDA
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            DistributionRecord::SEED_PREFIX,
+            distribution_record.split_state.as_ref(),
+            distribution_record.index.to_le_bytes().as_ref(),
+        ],
+        bump = distribution_record.bump,
+        has_one = payer @ DistributionError::InvalidRecipient,
+    )]
+    pub distribution_record: Account<'info, DistributionRecord>,
+    
+    /// Account that paid the record's rent
+    /// CHECK: Must match the record's payer
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
@@ -1,30 +1,131 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer, System};
-use anchor_spl::token::{self, Transfer as SplTransfer};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{self, TokenAccount, TransferChecked};
 use crate::state::*;
 use crate::errors::*;
+use crate::payment::*;
+
+/// Payment escrow program, whose fee waiver PDA can waive the platform fee
+pub const PAYMENT_ESCROW_PROGRAM_ID: Pubkey = pubkey!("2T3AsDRbQdpLWaxEU5vbFXuzRHQnq7JT3wCQCmvdiKmJ");
 
 /// Distribute funds from vault to all recipients
 /// Called via CPI from payment escrow program
+/// SOL sales into a split with a payout schedule accrue in the vault until
+/// the next `payout_accrued` crank instead of being transferred per sale
+/// Every call is snapshotted into a new DistributionRecord for auditing
+/// Sales the payment escrow waived the platform fee on pay the creator that share instead
 pub fn distribute<'info>(
     ctx: Context<'_, '_, '_, 'info, Distribute<'info>>,
     amount: u64,
 ) -> Result<()> {
+    // Validate amounts
+    require!(amount > 0, DistributionError::InsufficientFunds);
+    
+    record_distribution(ctx.accounts, ctx.bumps.distribution_record, amount)?;
+    record_revenue(ctx.accounts, ctx.bumps.revenue_bucket, amount)?;
+    
+    let is_sol_payment = ctx.accounts.payment_token_mint.key() == System::id();
+    let vault_lamports = ctx.accounts.vault.lamports();
+    
+    // Tokens only pass through the vault's associated token account for the mint,
+    // which accept_payout_address checks for every mint recorded here
+    if !is_sol_payment {
+        let vault_ata = get_associated_token_address_with_program_id(
+            ctx.accounts.vault.key,
+            ctx.accounts.payment_token_mint.key,
+            ctx.accounts.token_program.key,
+        );
+        require!(
+            ctx.accounts.vault_token_account.key() == vault_ata,
+            DistributionError::InvalidVault
+        );
+        ctx.accounts.split_state.record_payment_mint(ctx.accounts.payment_token_mint.key())?;
+    }
+    
     let split_state = &mut ctx.accounts.split_state;
+    
+    // Accrued SOL is split at payout time, so fee-waived sales are paid out immediately
+    if is_sol_payment && split_state.is_accruing() && ctx.accounts.fee_waiver.is_none() {
+        split_state.accrue(amount, vault_lamports)?;
+        
+        msg!("Accrued {} lamports, total accrued: {}", amount, split_state.accrued_amount);
+        
+        return Ok(());
+    }
+    
+    // A payout schedule only batches SOL; keep its period anchored to the last batched payout
+    if !split_state.is_accruing() {
+        split_state.last_distributed_ts = Clock::get()?.unix_timestamp;
+    }
+    
+    pay_out(ctx, amount)
+}
+
+/// Snapshot a distribution of `amount` into the DistributionRecord and advance the
+/// split's record index; payouts are filled in by `pay_out`
+pub(crate) fn record_distribution(accounts: &mut Distribute, bump: u8, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
+    let split_state = &mut accounts.split_state;
+    let record = &mut accounts.distribution_record;
     
-    // Validate amounts
-    require!(amount > 0, DistributionError::InsufficientFunds);
+    record.split_state = split_state.key();
+    record.index = split_state.distribution_count;
+    record.payment_token_mint = accounts.payment_token_mint.key();
+    record.total_amount = amount;
+    record.payouts = Vec::new();
+    record.payer = accounts.payer.key();
+    record.slot = clock.slot;
+    record.timestamp = clock.unix_timestamp;
+    record.bump = bump;
+    
+    split_state.distribution_count = split_state
+        .distribution_count
+        .checked_add(1)
+        .ok_or(DistributionError::NumericalOverflow)?;
+    
+    Ok(())
+}
+
+/// Add a distribution of `amount` to the creator's revenue bucket for the current month
+/// Accrued SOL is counted when it's distributed into the vault, not when it's paid out,
+/// so `payout_accrued` passes 0 to only initialize the bucket
+pub(crate) fn record_revenue(accounts: &mut Distribute, bump: u8, amount: u64) -> Result<()> {
+    let split_state = &accounts.split_state;
+    let fee_waived = accounts.fee_waiver.is_some();
+    let platform_fee = split_state.platform_fee_for(amount, fee_waived)?;
+    let creator_amount = split_state.creator_share_for(amount, fee_waived)?;
+    let bucket = &mut accounts.revenue_bucket;
+    
+    bucket.creator = split_state.creator;
+    bucket.payment_token_mint = accounts.payment_token_mint.key();
+    bucket.period = RevenueBucket::period_of(Clock::get()?.unix_timestamp);
+    bucket.bump = bump;
+    
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    bucket.record(amount, platform_fee, creator_amount)
+}
+
+/// Transfer `amount` from the vault to the platform, collaborators and creator
+pub(crate) fn pay_out<'info>(
+    ctx: Context<'_, '_, '_, 'info, Distribute<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let split_state = &ctx.accounts.split_state;
     
     // Calculate distribution amounts
-    let platform_amount = split_state.calculate_platform_fee(amount)?;
-    let creator_amount = split_state.calculate_creator_share(amount)?;
+    let fee_waived = ctx.accounts.fee_waiver.is_some();
+    let platform_amount = split_state.platform_fee_for(amount, fee_waived)?;
+    let creator_amount = split_state.creator_share_for(amount, fee_waived)?;
     
     // Get vault bump for signing
     let split_state_key = split_state.key();
     let vault_bump = ctx.bumps.vault;
     let vault_seeds = &[
-        b"vault".as_ref(),
+        SplitState::VAULT_SEED_PREFIX,
         split_state_key.as_ref(),
         &[vault_bump],
     ];
@@ -33,6 +134,9 @@ pub fn distribute<'info>(
     // Determine if SOL or SPL payment
     let is_sol_payment = ctx.accounts.payment_token_mint.key() == System::id();
     
+    // Non-zero payouts, recorded in the distribution record
+    let mut payouts = Vec::with_capacity(split_state.collaborators.len() + 2);
+    
     if is_sol_payment {
         // Distribute SOL using system program transfers signed by vault PDA
         
@@ -49,12 +153,16 @@ pub fn distribute<'info>(
                 ),
                 platform_amount,
             )?;
+            payouts.push(Payout {
+                recipient: ctx.accounts.platform_treasury.key(),
+                amount: platform_amount,
+            });
             msg!("Distributed {} lamports to platform", platform_amount);
         }
         
         // Transfer to collaborators
         for (i, collaborator) in split_state.collaborators.iter().enumerate() {
-            let collab_amount = split_state.calculate_collaborator_share(amount, collaborator.share_bps)?;
+            let collab_amount = split_state.calculate_collaborator_share(amount, collaborator.active_share_bps())?;
             
             if collab_amount > 0 {
                 // Get collaborator account from remaining accounts
@@ -75,7 +183,11 @@ pub fn distribute<'info>(
                     ),
                     collab_amount,
                 )?;
-                msg!("Distributed {} lamports to collaborator {}", collab_amount, collaborator.pubkey);
+                payouts.push(Payout {
+                    recipient: collab_account.key(),
+                    amount: collab_amount,
+                });
+                msg!("Distributed {} lamports to collaborator {}", collab_amount, i);
             }
         }
         
@@ -92,6 +204,10 @@ pub fn distribute<'info>(
                 ),
                 creator_amount,
             )?;
+            payouts.push(Payout {
+                recipient: ctx.accounts.creator.key(),
+                amount: creator_amount,
+            });
             msg!("Distributed {} lamports to creator", creator_amount);
         }
     } else {
@@ -100,77 +216,114 @@ pub fn distribute<'info>(
             ctx.accounts.vault_token_account.key() != System::id(),
             DistributionError::InvalidVault
         );
+        require!(
+            is_token_program(ctx.accounts.token_program.key),
+            DistributionError::InvalidVault
+        );
+        
+        // Token-2022 mints with transfer fees require transfer_checked; each
+        // recipient's transfer fee is withheld from what they receive
+        let decimals = mint_decimals(&ctx.accounts.payment_token_mint)?;
         
         // Transfer to platform treasury
         if platform_amount > 0 {
-            token::transfer(
+            token_interface::transfer_checked(
                 CpiContext::new_with_signer(
                     ctx.accounts.token_program.to_account_info(),
-                    SplTransfer {
+                    TransferChecked {
                         from: ctx.accounts.vault_token_account.to_account_info(),
+                        mint: ctx.accounts.payment_token_mint.to_account_info(),
                         to: ctx.accounts.platform_treasury_token_account.to_account_info(),
                         authority: ctx.accounts.vault.to_account_info(),
                     },
                     signer_seeds,
                 ),
                 platform_amount,
+                decimals,
             )?;
+            payouts.push(Payout {
+                recipient: ctx.accounts.platform_treasury_token_account.key(),
+                amount: platform_amount,
+            });
             msg!("Distributed {} tokens to platform", platform_amount);
         }
         
         // Transfer to collaborators
         for (i, collaborator) in split_state.collaborators.iter().enumerate() {
-            let collab_amount = split_state.calculate_collaborator_share(amount, collaborator.share_bps)?;
+            let collab_amount = split_state.calculate_collaborator_share(amount, collaborator.active_share_bps())?;
             
             if collab_amount > 0 {
                 // Get collaborator token account from remaining accounts
                 let collab_token_account = &ctx.remaining_accounts[i];
                 
-                token::transfer(
+                token_interface::transfer_checked(
                     CpiContext::new_with_signer(
                         ctx.accounts.token_program.to_account_info(),
-                        SplTransfer {
+                        TransferChecked {
                             from: ctx.accounts.vault_token_account.to_account_info(),
+                            mint: ctx.accounts.payment_token_mint.to_account_info(),
                             to: collab_token_account.to_account_info(),
                             authority: ctx.accounts.vault.to_account_info(),
                         },
                         signer_seeds,
                     ),
                     collab_amount,
+                    decimals,
                 )?;
+                payouts.push(Payout {
+                    recipient: collab_token_account.key(),
+                    amount: collab_amount,
+                });
                 msg!("Distributed {} tokens to collaborator", collab_amount);
             }
         }
         
         // Transfer remaining to creator
         if creator_amount > 0 {
-            token::transfer(
+            let creator_token_account = TokenAccount::try_deserialize(
+                &mut &ctx.accounts.creator_token_account.try_borrow_data()?[..],
+            )?;
+            require!(
+                creator_token_account.owner == split_state.payout_address,
+                DistributionError::InvalidCreator
+            );
+            
+            token_interface::transfer_checked(
                 CpiContext::new_with_signer(
                     ctx.accounts.token_program.to_account_info(),
-                    SplTransfer {
+                    TransferChecked {
                         from: ctx.accounts.vault_token_account.to_account_info(),
+                        mint: ctx.accounts.payment_token_mint.to_account_info(),
                         to: ctx.accounts.creator_token_account.to_account_info(),
                         authority: ctx.accounts.vault.to_account_info(),
                     },
                     signer_seeds,
                 ),
                 creator_amount,
+                decimals,
             )?;
+            payouts.push(Payout {
+                recipient: ctx.accounts.creator_token_account.key(),
+                amount: creator_amount,
+            });
             msg!("Distributed {} tokens to creator", creator_amount);
         }
     }
     
-    // Update last distributed timestamp
-    split_state.last_distributed_ts = clock.unix_timestamp;
-    
     msg!("Distribution completed: platform={}, creator={}, collaborators={}", 
         platform_amount, creator_amount, split_state.collaborators.len());
     
+    ctx.accounts.distribution_record.payouts = payouts;
+    
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct Distribute<'info> {
+    /// Account paying rent for the distribution record
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     /// Split state PDA
     #[account(
         mut,
@@ -184,23 +337,58 @@ pub struct Distribute<'info> {
     )]
     pub split_state: Account<'info, SplitState>,
     
+    /// Audit record for this distribution, indexed by the split's distribution count
+    #[account(
+        init,
+        payer = payer,
+        space = DistributionRecord::space(split_state.collaborators.len()),
+        seeds = [
+            DistributionRecord::SEED_PREFIX,
+            split_state.key().as_ref(),
+            split_state.distribution_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub distribution_record: Account<'info, DistributionRecord>,
+    
+    /// Creator's revenue bucket for the current month in the payment mint (created on first use)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RevenueBucket::LEN,
+        seeds = [
+            RevenueBucket::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            payment_token_mint.key().as_ref(),
+            RevenueBucket::period_of(Clock::get()?.unix_timestamp).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub revenue_bucket: Box<Account<'info, RevenueBucket>>,
+    
     /// Vault holding the funds (SOL or SPL)
-    /// CHECK: Vault PDA validated by seeds
+    /// System-owned PDA; it signs SOL payouts and owns the SPL vault token accounts
     #[account(
         mut,
-        seeds = [b"vault", split_state.key().as_ref()],
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
         bump,
     )]
-    pub vault: UncheckedAccount<'info>,
+    pub vault: SystemAccount<'info>,
     
-    /// Creator receiving their share
-    /// CHECK: Creator validated from split_state
-    #[account(mut)]
+    /// Creator payout account receiving the creator share
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ DistributionError::InvalidCreator,
+    )]
     pub creator: UncheckedAccount<'info>,
     
     /// Platform treasury receiving platform fees
-    /// CHECK: Platform treasury validated from split_state
-    #[account(mut)]
+    /// CHECK: Must be the treasury recorded on split_state
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ DistributionError::InvalidRecipient,
+    )]
     pub platform_treasury: UncheckedAccount<'info>,
     
     /// Payment token mint (System::id() for SOL)
@@ -222,12 +410,21 @@ pub struct Distribute<'info> {
     #[account(mut)]
     pub platform_treasury_token_account: UncheckedAccount<'info>,
     
-    /// Token program for SPL payments
+    /// Token program for SPL payments (SPL Token or Token-2022)
     /// CHECK: Optional, validated when SPL payment is used
     pub token_program: UncheckedAccount<'info>,
     
     /// System program
     pub system_program: Program<'info, System>,
     
+    /// Payment escrow's fee waiver PDA, signed only for purchases by or sales from
+    /// fee-exempt wallets; when present the platform fee goes to the creator
+    #[account(
+        seeds = [common::seeds::FEE_WAIVER],
+        bump,
+        seeds::program = PAYMENT_ESCROW_PROGRAM_ID,
+    )]
+    pub fee_waiver: Option<Signer<'info>>,
+    
     // Remaining accounts: collaborator accounts (SOL) or token accounts (SPL)
 }
@@ -1,15 +1,19 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::content::*;
 
 /// Initialize a new split configuration for content
+/// Collaborators start as pending invites and must accept before their share activates
 pub fn initialize_split(
     ctx: Context<InitializeSplit>,
     content_id: [u8; 32],
     platform_fee_bps: u16,
-    collaborators: Vec<Collaborator>,
+    mut collaborators: Vec<Collaborator>,
     seed: u64,
 ) -> Result<()> {
+    validate_content_id(&content_id)?;
+    
     // Validate platform fee (max 10%)
     require!(
         platform_fee_bps <= 1000,
@@ -18,28 +22,40 @@ pub fn initialize_split(
     
     // Validate collaborators count (max 10)
     require!(
-        collaborators.len() <= 10,
+        collaborators.len() <= SplitState::MAX_COLLABORATORS,
         DistributionError::TooManyCollaborators
     );
     
+    // Shares only activate once each collaborator signs accept_collaboration
+    for collaborator in collaborators.iter_mut() {
+        collaborator.accepted = false;
+    }
+    
     let split_state = &mut ctx.accounts.split_state;
     let clock = Clock::get()?;
     
     // Initialize split state
     split_state.content_id = content_id;
     split_state.creator = ctx.accounts.creator.key();
+    split_state.payout_address = ctx.accounts.creator.key();
+    split_state.pending_payout_address = None;
     split_state.platform_fee_bps = platform_fee_bps;
     split_state.platform_treasury = ctx.accounts.platform_treasury.key();
     split_state.collaborators = collaborators;
     split_state.last_distributed_ts = clock.unix_timestamp;
+    split_state.payout_period = 0;
+    split_state.min_payout_lamports = 0;
+    split_state.accrued_amount = 0;
+    split_state.distribution_count = 0;
+    split_state.require_share_transfer_approval = false;
+    split_state.payment_mints = Vec::new();
     split_state.seed = seed;
     split_state.bump = ctx.bumps.split_state;
     
     // Validate total shares don't exceed 100%
     split_state.validate_shares()?;
     
-    msg!("Split initialized for creator: {}, content_id: {:?}", 
-        ctx.accounts.creator.key(), content_id);
+    msg!("Split initialized for creator: {}", ctx.accounts.creator.key());
     msg!("Platform fee: {}bps, Collaborators: {}", 
         platform_fee_bps, split_state.collaborators.len());
     
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// List part or all of a collaborator's share for sale at a fixed SOL price
+/// The creator must co-sign when the split requires share transfer approval
+pub fn list_share(ctx: Context<ListShare>, share_bps: u16, price: u64) -> Result<()> {
+    let split_state = &ctx.accounts.split_state;
+    let seller = ctx.accounts.seller.key();
+    
+    if split_state.require_share_transfer_approval {
+        require!(
+            ctx.accounts.creator.is_some(),
+            DistributionError::ShareTransferApprovalRequired
+        );
+    }
+    
+    require!(price > 0, DistributionError::InvalidPrice);
+    
+    let collaborator = split_state
+        .collaborators
+        .iter()
+        .find(|collaborator| collaborator.pubkey == seller)
+        .ok_or(DistributionError::InvalidCollaborator)?;
+    
+    // Only accepted shares can be sold, and never more than the seller holds
+    require!(collaborator.accepted, DistributionError::CollaborationNotAccepted);
+    require!(
+        share_bps > 0 && share_bps <= collaborator.share_bps,
+        DistributionError::InvalidShareDistribution
+    );
+    
+    let listing = &mut ctx.accounts.share_listing;
+    listing.split_state = split_state.key();
+    listing.seller = seller;
+    listing.share_bps = share_bps;
+    listing.price = price;
+    listing.listed_ts = Clock::get()?.unix_timestamp;
+    listing.bump = ctx.bumps.share_listing;
+    
+    emit!(ShareListed {
+        split_state: listing.split_state,
+        seller,
+        share_bps,
+        price,
+    });
+    
+    msg!("Collaborator {} listed {}bps for {} lamports", seller, share_bps, price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ListShare<'info> {
+    /// Collaborator listing their share
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    /// Creator approving the listing (required if the split demands approval)
+    #[account(address = split_state.creator @ DistributionError::Unauthorized)]
+    pub creator: Option<Signer<'info>>,
+    
+    /// Split state PDA
+    #[account(
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Share listing PDA
+    #[account(
+        init,
+        payer = seller,
+        space = ShareListing::LEN,
+        seeds = [ShareListing::SEED_PREFIX, split_state.key().as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub share_listing: Account<'info, ShareListing>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -1,5 +1,33 @@
 pub mod initialize_split;
 pub mod distribute;
+pub mod tip;
+pub mod propose_payout_address;
+pub mod accept_payout_address;
+pub mod set_payout_schedule;
+pub mod payout_accrued;
+pub mod close_distribution_record;
+pub mod accept_collaboration;
+pub mod transfer_share;
+pub mod set_share_transfer_approval;
+pub mod list_share;
+pub mod cancel_share_listing;
+pub mod buy_share;
+pub mod update_split;
+pub mod preview_distribution;
 
 pub use initialize_split::*;
 pub use distribute::*;
+pub use tip::*;
+pub use propose_payout_address::*;
+pub use accept_payout_address::*;
+pub use set_payout_schedule::*;
+pub use payout_accrued::*;
+pub use close_distribution_record::*;
+pub use accept_collaboration::*;
+pub use transfer_share::*;
+pub use set_share_transfer_approval::*;
+pub use list_share::*;
+pub use cancel_share_listing::*;
+pub use buy_share::*;
+pub use update_split::*;
+pub use preview_distribution::*;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use crate::instructions::distribute::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Pay out SOL accrued under a split's payout schedule
+/// Permissionless crank: callable by anyone once the period has elapsed and the
+/// accrued balance reaches the split's minimum payout
+pub fn payout_accrued<'info>(
+    ctx: Context<'_, '_, '_, 'info, PayoutAccrued<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let split_state = &mut ctx.accounts.distribute.split_state;
+    
+    require!(
+        split_state.is_payout_due(clock.unix_timestamp),
+        DistributionError::PayoutNotDue
+    );
+    
+    let amount = split_state.accrued_amount;
+    split_state.accrued_amount = 0;
+    split_state.last_distributed_ts = clock.unix_timestamp;
+    let split_state_key = split_state.key();
+    
+    record_distribution(
+        &mut ctx.accounts.distribute,
+        ctx.bumps.distribute.distribution_record,
+        amount,
+    )?;
+    record_revenue(
+        &mut ctx.accounts.distribute,
+        ctx.bumps.distribute.revenue_bucket,
+        0,
+    )?;
+    
+    emit!(AccruedPayout {
+        split_state: split_state_key,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    pay_out(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.distribute,
+            ctx.remaining_accounts,
+            ctx.bumps.distribute,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct PayoutAccrued<'info> {
+    /// Accounts used to distribute the accrued SOL through the split
+    #[account(
+        constraint = distribute.payment_token_mint.key() == System::id() @ DistributionError::InvalidVault,
+    )]
+    pub distribute: Distribute<'info>,
+    
+    // Remaining accounts: collaborator accounts
+}
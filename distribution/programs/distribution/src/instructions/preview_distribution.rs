@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Payouts returned by the preview_distribution instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DistributionPreview {
+    /// Amount being distributed
+    pub amount: u64,
+    
+    /// Amount the platform treasury will receive
+    pub platform_amount: u64,
+    
+    /// Amounts each collaborator will receive, in split order
+    /// (0 for collaborators who haven't accepted, whose share stays with the creator)
+    pub collaborator_payouts: Vec<Payout>,
+    
+    /// Amount the creator's payout address will receive, including rounding dust
+    pub creator_amount: u64,
+    
+    /// Whether SOL distributions accrue until the next scheduled payout
+    pub accrues: bool,
+}
+
+/// Preview the exact payouts of distributing `amount` through a split
+/// Read-only - intended to be simulated, result is returned via return data
+/// Uses the same split math as distribute, so floor rounding and dust match on-chain payouts
+pub fn preview_distribution(ctx: Context<PreviewDistribution>, amount: u64) -> Result<DistributionPreview> {
+    let split_state = &ctx.accounts.split_state;
+    
+    let collaborator_payouts = split_state
+        .collaborators
+        .iter()
+        .map(|collaborator| {
+            Ok(Payout {
+                recipient: collaborator.pubkey,
+                amount: split_state.calculate_collaborator_share(amount, collaborator.active_share_bps())?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    
+    Ok(DistributionPreview {
+        amount,
+        platform_amount: split_state.calculate_platform_fee(amount)?,
+        collaborator_payouts,
+        creator_amount: split_state.calculate_creator_share(amount)?,
+        accrues: split_state.is_accruing(),
+    })
+}
+
+#[derive(Accounts)]
+pub struct PreviewDistribution<'info> {
+    /// Split state PDA to preview
+    #[account(
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+    )]
+    pub split_state: Account<'info, SplitState>,
+}
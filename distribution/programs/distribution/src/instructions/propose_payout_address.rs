@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Propose a new payout address for the creator share
+/// The new address must accept before the rotation takes effect
+pub fn propose_payout_address(
+    ctx: Context<ProposePayoutAddress>,
+    new_payout_address: Pubkey,
+) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    
+    split_state.pending_payout_address = Some(new_payout_address);
+    
+    emit!(PayoutAddressProposed {
+        split_state: split_state.key(),
+        current: split_state.payout_address,
+        proposed: new_payout_address,
+    });
+    
+    msg!("Payout address rotation proposed: {} -> {}", 
+        split_state.payout_address, new_payout_address);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposePayoutAddress<'info> {
+    /// Creator who owns the split
+    pub creator: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        has_one = creator @ DistributionError::Unauthorized,
+    )]
+    pub split_state: Account<'info, SplitState>,
+}
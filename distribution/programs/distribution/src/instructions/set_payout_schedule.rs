@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Configure batched SOL payouts for a split
+/// While `payout_period` is non-zero, SOL sales accrue in the vault and are paid
+/// out by the `payout_accrued` crank at most once per period
+pub fn set_payout_schedule(
+    ctx: Context<SetPayoutSchedule>,
+    payout_period: i64,
+    min_payout_lamports: u64,
+) -> Result<()> {
+    require!(payout_period >= 0, DistributionError::InvalidPayoutSchedule);
+    
+    let split_state = &mut ctx.accounts.split_state;
+    
+    // Start the first period when accrual is switched on
+    if !split_state.is_accruing() && payout_period > 0 {
+        split_state.last_distributed_ts = Clock::get()?.unix_timestamp;
+    }
+    
+    split_state.payout_period = payout_period;
+    split_state.min_payout_lamports = min_payout_lamports;
+    
+    msg!("Payout schedule set: period={}s, min payout={} lamports", 
+        payout_period, min_payout_lamports);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutSchedule<'info> {
+    /// Creator who owns the split
+    pub creator: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        has_one = creator @ DistributionError::Unauthorized,
+    )]
+    pub split_state: Account<'info, SplitState>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Require (or stop requiring) creator approval for collaborator share transfers
+pub fn set_share_transfer_approval(
+    ctx: Context<SetShareTransferApproval>,
+    require_approval: bool,
+) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    
+    split_state.require_share_transfer_approval = require_approval;
+    
+    msg!("Share transfer approval required: {}", require_approval);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetShareTransferApproval<'info> {
+    /// Creator who owns the split
+    pub creator: Signer<'info>,
+    
+    /// Split state PDA
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        has_one = creator @ DistributionError::Unauthorized,
+    )]
+    pub split_state: Account<'info, SplitState>,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token_interface::{self, TransferChecked};
+use crate::instructions::distribute::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payment::*;
+
+/// Maximum tip memo length in bytes
+pub const MAX_TIP_MEMO_LEN: usize = 200;
+
+/// Send a tip to a content's split and distribute it immediately
+/// SOL tips accrue like sales when the split has a payout schedule
+pub fn tip<'info>(
+    ctx: Context<'_, '_, '_, 'info, Tip<'info>>,
+    amount: u64,
+    memo: Option<String>,
+) -> Result<()> {
+    require!(amount > 0, DistributionError::InsufficientFunds);
+    
+    if let Some(memo) = &memo {
+        require!(memo.len() <= MAX_TIP_MEMO_LEN, DistributionError::MemoTooLong);
+    }
+    
+    let accounts = &ctx.accounts.distribute;
+    let is_sol_payment = accounts.payment_token_mint.key() == System::id();
+    
+    // Move the tip into the split vault, distributing what arrives after any transfer fee
+    let distribution_amount = if is_sol_payment {
+        transfer(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tipper.to_account_info(),
+                    to: accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        amount
+    } else {
+        require!(
+            ctx.accounts.tipper_token_account.key() != System::id(),
+            DistributionError::InvalidVault
+        );
+        require!(
+            is_token_program(accounts.token_program.key),
+            DistributionError::InvalidVault
+        );
+        
+        token_interface::transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.tipper_token_account.to_account_info(),
+                    mint: accounts.payment_token_mint.to_account_info(),
+                    to: accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.tipper.to_account_info(),
+                },
+            ),
+            amount,
+            mint_decimals(&accounts.payment_token_mint)?,
+        )?;
+        
+        amount
+            .checked_sub(transfer_fee(&accounts.payment_token_mint, amount)?)
+            .ok_or(DistributionError::NumericalOverflow)?
+    };
+    
+    emit!(TipReceived {
+        split_state: accounts.split_state.key(),
+        tipper: ctx.accounts.tipper.key(),
+        amount,
+        payment_token_mint: accounts.payment_token_mint.key(),
+        memo,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Tip of {} received from: {}", amount, ctx.accounts.tipper.key());
+    
+    // Distribute the tip using the existing split configuration
+    distribute(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.distribute,
+            ctx.remaining_accounts,
+            ctx.bumps.distribute,
+        ),
+        distribution_amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Tip<'info> {
+    /// The wallet sending the tip
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+    
+    /// Tipper's SPL token account (for SPL tips)
+    /// CHECK: Optional account, validated when SPL tip is used
+    #[account(mut)]
+    pub tipper_token_account: UncheckedAccount<'info>,
+    
+    /// Accounts used to distribute the tip through the split
+    pub distribute: Distribute<'info>,
+    
+    // Remaining accounts: collaborator accounts (SOL) or token accounts (SPL)
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Assign part or all of a collaborator's share to another wallet
+/// The creator must co-sign when the split requires share transfer approval
+pub fn transfer_share(ctx: Context<TransferShare>, share_bps: u16) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    
+    if split_state.require_share_transfer_approval {
+        require!(
+            ctx.accounts.creator.is_some(),
+            DistributionError::ShareTransferApprovalRequired
+        );
+    }
+    
+    // Accrued SOL is paid by the shares in place at payout time
+    require!(
+        split_state.accrued_amount == 0,
+        DistributionError::PendingDistribution
+    );
+    
+    let from = ctx.accounts.collaborator.key();
+    let to = ctx.accounts.recipient.key();
+    split_state.transfer_share(&from, &to, share_bps)?;
+    
+    emit!(ShareTransferred {
+        split_state: split_state.key(),
+        from,
+        to,
+        share_bps,
+    });
+    
+    msg!("Collaborator {} transferred {}bps to {}", from, share_bps, to);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(share_bps: u16)]
+pub struct TransferShare<'info> {
+    /// Collaborator assigning their share (pays for any split growth)
+    #[account(mut)]
+    pub collaborator: Signer<'info>,
+    
+    /// Wallet receiving the share
+    /// CHECK: Any wallet; joins as a pending collaborator if new
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Creator approving the transfer (required if the split demands approval)
+    #[account(address = split_state.creator @ DistributionError::Unauthorized)]
+    pub creator: Option<Signer<'info>>,
+    
+    /// Split state PDA, resized for the collaborator list after the transfer
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        realloc = SplitState::space(split_state.collaborators_after_transfer(
+            collaborator.key,
+            recipient.key,
+            share_bps,
+        )),
+        realloc::payer = collaborator,
+        realloc::zero = false,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
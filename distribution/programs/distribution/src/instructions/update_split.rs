@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Replace the collaborator list of a split (creator only)
+/// The split account is reallocated to fit the new list: the creator pays for growth
+/// and receives the rent freed when collaborators are removed
+/// Accepted collaborators whose share is changed or removed must sign, passed as
+/// signer remaining accounts; pending collaborators can be added or removed freely
+pub fn update_split(ctx: Context<UpdateSplit>, collaborators: Vec<Collaborator>) -> Result<()> {
+    let split_state = &mut ctx.accounts.split_state;
+    
+    // Accrued SOL is paid by the shares in place at payout time
+    require!(
+        split_state.accrued_amount == 0,
+        DistributionError::PendingDistribution
+    );
+    
+    let consenting: Vec<Pubkey> = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key())
+        .collect();
+    split_state.set_collaborators(collaborators, &consenting)?;
+    
+    emit!(SplitUpdated {
+        split_state: split_state.key(),
+        collaborator_count: split_state.collaborators.len() as u8,
+    });
+    
+    msg!("Split updated, collaborators: {}", split_state.collaborators.len());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(collaborators: Vec<Collaborator>)]
+pub struct UpdateSplit<'info> {
+    /// Creator who owns the split
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Split state PDA, resized to fit the new collaborator list
+    #[account(
+        mut,
+        seeds = [
+            SplitState::SEED_PREFIX,
+            split_state.creator.as_ref(),
+            split_state.content_id.as_ref(),
+            split_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = split_state.bump,
+        has_one = creator @ DistributionError::Unauthorized,
+        realloc = SplitState::space(collaborators.len()),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -6,6 +6,9 @@ declare_id!("Czw384wkAHcNT7QpJC4y1DZ7LrKjyqsgTu8gHhsXtUpK");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod content;
+pub mod payment;
+pub mod events;
 
 use instructions::*;
 
@@ -18,7 +21,7 @@ pub mod distribution {
     /// # Arguments
     /// * `content_id` - 32-byte unique identifier for the content
     /// * `platform_fee_bps` - Platform fee in basis points (max 1000 = 10%)
-    /// * `collaborators` - List of collaborators and their share percentages
+    /// * `collaborators` - List of collaborators and their share percentages (pending until accepted)
     /// * `seed` - Seed for PDA derivation
     pub fn initialize_split(
         ctx: Context<InitializeSplit>,
@@ -47,4 +50,126 @@ pub mod distribution {
     ) -> Result<()> {
         instructions::distribute::distribute(ctx, amount)
     }
+
+    /// Tip a content's split, distributing the tip immediately by its bps configuration
+    ///
+    /// # Arguments
+    /// * `amount` - Tip amount in lamports (SOL) or token amount (SPL)
+    /// * `memo` - Optional message from the tipper (max 200 bytes)
+    pub fn tip<'info>(
+        ctx: Context<'_, '_, '_, 'info, Tip<'info>>,
+        amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::tip::tip(ctx, amount, memo)
+    }
+
+    /// Propose a new payout address for the creator share (creator only)
+    ///
+    /// # Arguments
+    /// * `new_payout_address` - Address that must accept before receiving payouts
+    pub fn propose_payout_address(
+        ctx: Context<ProposePayoutAddress>,
+        new_payout_address: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_payout_address::propose_payout_address(ctx, new_payout_address)
+    }
+
+    /// Accept a proposed payout address (signed by the new address)
+    /// Remaining accounts: vault SPL token accounts, which must be empty
+    pub fn accept_payout_address(ctx: Context<AcceptPayoutAddress>) -> Result<()> {
+        instructions::accept_payout_address::accept_payout_address(ctx)
+    }
+
+    /// Configure batched SOL payouts for a split (creator only)
+    ///
+    /// # Arguments
+    /// * `payout_period` - Seconds between batched payouts (0 = distribute on every sale)
+    /// * `min_payout_lamports` - Minimum accrued lamports before a payout is made
+    pub fn set_payout_schedule(
+        ctx: Context<SetPayoutSchedule>,
+        payout_period: i64,
+        min_payout_lamports: u64,
+    ) -> Result<()> {
+        instructions::set_payout_schedule::set_payout_schedule(ctx, payout_period, min_payout_lamports)
+    }
+
+    /// Pay out SOL accrued under the split's payout schedule (permissionless crank)
+    /// Remaining accounts: collaborator accounts
+    pub fn payout_accrued<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayoutAccrued<'info>>,
+    ) -> Result<()> {
+        instructions::payout_accrued::payout_accrued(ctx)
+    }
+
+    /// Close a distribution record after its retention period, refunding rent to its payer
+    pub fn close_distribution_record(ctx: Context<CloseDistributionRecord>) -> Result<()> {
+        instructions::close_distribution_record::close_distribution_record(ctx)
+    }
+
+    /// Accept a pending collaborator invite, activating the signer's share of the split
+    pub fn accept_collaboration(ctx: Context<AcceptCollaboration>) -> Result<()> {
+        instructions::accept_collaboration::accept_collaboration(ctx)
+    }
+
+    /// Assign part or all of the signer's collaborator share to another wallet
+    /// New recipients join as pending collaborators and must accept
+    ///
+    /// # Arguments
+    /// * `share_bps` - Basis points to transfer (all of the sender's share removes them)
+    pub fn transfer_share(ctx: Context<TransferShare>, share_bps: u16) -> Result<()> {
+        instructions::transfer_share::transfer_share(ctx, share_bps)
+    }
+
+    /// Require creator co-signature on collaborator share transfers (creator only)
+    ///
+    /// # Arguments
+    /// * `require_approval` - Whether transfer_share must be approved by the creator
+    pub fn set_share_transfer_approval(
+        ctx: Context<SetShareTransferApproval>,
+        require_approval: bool,
+    ) -> Result<()> {
+        instructions::set_share_transfer_approval::set_share_transfer_approval(ctx, require_approval)
+    }
+
+    /// List part or all of the signer's collaborator share for sale
+    ///
+    /// # Arguments
+    /// * `share_bps` - Basis points offered
+    /// * `price` - Price in lamports for the listed share
+    pub fn list_share(ctx: Context<ListShare>, share_bps: u16, price: u64) -> Result<()> {
+        instructions::list_share::list_share(ctx, share_bps, price)
+    }
+
+    /// Cancel a share listing (seller only)
+    pub fn cancel_share_listing(ctx: Context<CancelShareListing>) -> Result<()> {
+        instructions::cancel_share_listing::cancel_share_listing(ctx)
+    }
+
+    /// Buy a listed collaborator share, paying the seller and updating the split atomically
+    ///
+    /// # Arguments
+    /// * `max_price` - Most the buyer will pay in lamports; fails if the listing costs more
+    pub fn buy_share(ctx: Context<BuyShare>, max_price: u64) -> Result<()> {
+        instructions::buy_share::buy_share(ctx, max_price)
+    }
+
+    /// Replace the collaborator list, resizing the split to fit (creator only)
+    /// Growth is paid by the creator and rent freed by removing collaborators is returned
+    ///
+    /// # Arguments
+    /// * `collaborators` - New collaborator list; new or changed shares must be accepted again.
+    ///   Accepted collaborators being changed or removed sign as remaining accounts
+    pub fn update_split(ctx: Context<UpdateSplit>, collaborators: Vec<state::Collaborator>) -> Result<()> {
+        instructions::update_split::update_split(ctx, collaborators)
+    }
+
+    /// Preview the exact per-recipient payouts of distributing an amount through a split
+    /// Intended to be simulated - the preview is returned via return data
+    ///
+    /// # Arguments
+    /// * `amount` - Amount to preview distributing
+    pub fn preview_distribution(ctx: Context<PreviewDistribution>, amount: u64) -> Result<DistributionPreview> {
+        instructions::preview_distribution::preview_distribution(ctx, amount)
+    }
 }
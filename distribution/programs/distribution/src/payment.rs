@@ -0,0 +1,3 @@
+//! SPL payment helpers, shared with payment-escrow through the common crate
+
+pub use common::payments::{is_token_program, mint_decimals, transfer_fee};
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+/// Distribution Record - on-chain audit snapshot of a single distribution
+#[account]
+pub struct DistributionRecord {
+    /// Split state the distribution went through
+    pub split_state: Pubkey,
+    
+    /// Sequential index of this distribution for the split
+    pub index: u64,
+    
+    /// Payment token mint (System::id() for SOL)
+    pub payment_token_mint: Pubkey,
+    
+    /// Total amount distributed (or accrued) in this distribution
+    pub total_amount: u64,
+    
+    /// Amount paid to each recipient
+    /// Empty when SOL accrued in the vault for a later batched payout
+    pub payouts: Vec<Payout>,
+    
+    /// Account that paid rent for this record (refunded on close)
+    pub payer: Pubkey,
+    
+    /// Slot of the distribution
+    pub slot: u64,
+    
+    /// Timestamp of the distribution
+    pub timestamp: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Amount paid to one recipient in a distribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Payout {
+    /// Account that received the funds (wallet for SOL, token account for SPL)
+    pub recipient: Pubkey,
+    
+    /// Amount sent to the recipient
+    pub amount: u64,
+}
+
+impl Payout {
+    /// Size: Pubkey (32) + u64 (8)
+    pub const LEN: usize = 32 + 8;
+}
+
+impl DistributionRecord {
+    /// Base size without payouts
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + Pubkey (32) + u64 (8) + Vec length (4)
+    /// + Pubkey (32) + u64 (8) + i64 (8) + u8 (1)
+    pub const BASE_LEN: usize = 8 + 32 + 8 + 32 + 8 + 4 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::DISTRIBUTION_RECORD;
+    
+    /// Seconds a record must be kept before it can be closed (one year)
+    pub const RETENTION_PERIOD: i64 = 365 * 24 * 60 * 60;
+    
+    /// Calculate space needed for a distribution to a split with `num_collaborators`
+    /// (platform and creator are always recorded alongside the collaborators)
+    pub fn space(num_collaborators: usize) -> usize {
+        Self::BASE_LEN + Payout::LEN * (num_collaborators + 2)
+    }
+    
+    /// Whether the retention period has elapsed at `now`
+    pub fn is_closable(&self, now: i64) -> bool {
+        now >= self.timestamp.saturating_add(Self::RETENTION_PERIOD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn record_is_closable_only_after_retention_period() {
+        let record = DistributionRecord {
+            split_state: Pubkey::new_unique(),
+            index: 0,
+            payment_token_mint: Pubkey::default(),
+            total_amount: 1_000,
+            payouts: vec![],
+            payer: Pubkey::new_unique(),
+            slot: 1,
+            timestamp: 100,
+            bump: 0,
+        };
+        
+        assert!(!record.is_closable(100));
+        assert!(!record.is_closable(99 + DistributionRecord::RETENTION_PERIOD));
+        assert!(record.is_closable(100 + DistributionRecord::RETENTION_PERIOD));
+    }
+    
+    #[test]
+    fn space_fits_platform_creator_and_every_collaborator() {
+        let record = DistributionRecord {
+            split_state: Pubkey::new_unique(),
+            index: u64::MAX,
+            payment_token_mint: Pubkey::new_unique(),
+            total_amount: u64::MAX,
+            payouts: vec![
+                Payout {
+                    recipient: Pubkey::new_unique(),
+                    amount: u64::MAX,
+                };
+                3 + 2
+            ],
+            payer: Pubkey::new_unique(),
+            slot: u64::MAX,
+            timestamp: i64::MAX,
+            bump: u8::MAX,
+        };
+        
+        let mut data = Vec::new();
+        record.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), DistributionRecord::space(3));
+    }
+}
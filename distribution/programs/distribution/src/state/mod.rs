@@ -1,3 +1,9 @@
 pub mod split;
+pub mod distribution_record;
+pub mod share_listing;
+pub mod revenue_bucket;
 
 pub use split::*;
+pub use distribution_record::*;
+pub use share_listing::*;
+pub use revenue_bucket::*;
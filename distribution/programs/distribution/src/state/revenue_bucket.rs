@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::errors::DistributionError;
+
+/// Revenue Bucket - a creator's distributed revenue in one payment mint for one calendar month
+/// Written by every distribution, so statements and tax reports read one account per month
+/// instead of replaying distribution history
+#[account]
+pub struct RevenueBucket {
+    /// Creator whose splits the revenue went through
+    pub creator: Pubkey,
+    
+    /// Payment token mint (System::id() for SOL)
+    pub payment_token_mint: Pubkey,
+    
+    /// Calendar month (UTC) as yyyymm, e.g. 202610
+    pub period: u32,
+    
+    /// Total amount distributed through the creator's splits
+    pub gross_amount: u64,
+    
+    /// Platform fees taken
+    pub platform_fees: u64,
+    
+    /// Amount owed to collaborators
+    pub collaborator_amount: u64,
+    
+    /// Amount owed to the creator
+    pub creator_amount: u64,
+    
+    /// Number of distributions recorded
+    pub distribution_count: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RevenueBucket {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u32 (4) + u64 (8) + u64 (8)
+    /// + u64 (8) + u64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 8 + 8 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::REVENUE_BUCKET;
+    
+    /// Calendar month (UTC) of a unix timestamp as yyyymm
+    pub fn period_of(timestamp: i64) -> u32 {
+        // Civil-from-days conversion (proleptic Gregorian calendar, eras of 400 years)
+        let days = timestamp.div_euclid(86_400) + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        
+        (year * 100 + month) as u32
+    }
+    
+    /// Add a distribution of `gross_amount` and its shares to the bucket
+    pub fn record(&mut self, gross_amount: u64, platform_fee: u64, creator_amount: u64) -> Result<()> {
+        let collaborator_amount = gross_amount
+            .checked_sub(platform_fee)
+            .and_then(|amount| amount.checked_sub(creator_amount))
+            .ok_or(DistributionError::NumericalOverflow)?;
+        
+        self.gross_amount = self.gross_amount.checked_add(gross_amount).ok_or(DistributionError::NumericalOverflow)?;
+        self.platform_fees = self.platform_fees.checked_add(platform_fee).ok_or(DistributionError::NumericalOverflow)?;
+        self.collaborator_amount = self
+            .collaborator_amount
+            .checked_add(collaborator_amount)
+            .ok_or(DistributionError::NumericalOverflow)?;
+        self.creator_amount = self.creator_amount.checked_add(creator_amount).ok_or(DistributionError::NumericalOverflow)?;
+        self.distribution_count = self.distribution_count.checked_add(1).ok_or(DistributionError::NumericalOverflow)?;
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn period_is_the_utc_calendar_month() {
+        assert_eq!(RevenueBucket::period_of(0), 197001);
+        // 2024-02-29T23:59:59Z and the following second
+        assert_eq!(RevenueBucket::period_of(1_709_251_199), 202402);
+        assert_eq!(RevenueBucket::period_of(1_709_251_200), 202403);
+        // 2026-12-31T23:59:59Z and the following second
+        assert_eq!(RevenueBucket::period_of(1_798_761_599), 202612);
+        assert_eq!(RevenueBucket::period_of(1_798_761_600), 202701);
+    }
+    
+    #[test]
+    fn record_splits_gross_into_fees_collaborators_and_creator() {
+        let mut bucket = RevenueBucket {
+            creator: Pubkey::new_unique(),
+            payment_token_mint: Pubkey::default(),
+            period: 202610,
+            gross_amount: 0,
+            platform_fees: 0,
+            collaborator_amount: 0,
+            creator_amount: 0,
+            distribution_count: 0,
+            bump: 0,
+        };
+        
+        bucket.record(1_000, 50, 650).unwrap();
+        bucket.record(2_000, 100, 1_900).unwrap();
+        
+        assert_eq!(bucket.gross_amount, 3_000);
+        assert_eq!(bucket.platform_fees, 150);
+        assert_eq!(bucket.collaborator_amount, 300);
+        assert_eq!(bucket.creator_amount, 2_550);
+        assert_eq!(bucket.distribution_count, 2);
+        assert!(bucket.record(100, 60, 50).is_err());
+    }
+}
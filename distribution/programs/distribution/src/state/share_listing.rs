@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Share Listing - a collaborator's offer to sell part of their split share
+#[account]
+pub struct ShareListing {
+    /// Split state the share belongs to
+    pub split_state: Pubkey,
+    
+    /// Collaborator selling the share
+    pub seller: Pubkey,
+    
+    /// Share offered in basis points
+    pub share_bps: u16,
+    
+    /// Price in lamports for the whole listed share
+    pub price: u64,
+    
+    /// Timestamp the listing was created
+    pub listed_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ShareListing {
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u16 (2) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::SHARE_LISTING;
+}
@@ -1,7 +1,17 @@
 use anchor_lang::prelude::*;
+use common::bps;
+
+pub use common::Collaborator;
+
+/// Maximum number of collaborators on a split (sizes the collaborator list for InitSpace)
+const MAX_COLLABORATORS: usize = 10;
+
+/// Maximum number of SPL mints a split can be paid in
+const MAX_PAYMENT_MINTS: usize = 8;
 
 /// Split State - defines how revenue is distributed for a specific content
 #[account]
+#[derive(InitSpace)]
 pub struct SplitState {
     /// Content identifier (32 bytes)
     pub content_id: [u8; 32],
@@ -9,6 +19,12 @@ pub struct SplitState {
     /// Creator's public key
     pub creator: Pubkey,
     
+    /// Address receiving the creator's share (defaults to creator)
+    pub payout_address: Pubkey,
+    
+    /// Proposed new payout address awaiting acceptance
+    pub pending_payout_address: Option<Pubkey>,
+    
     /// Platform fee in basis points (e.g., 250 = 2.5%)
     pub platform_fee_bps: u16,
     
@@ -16,11 +32,31 @@ pub struct SplitState {
     pub platform_treasury: Pubkey,
     
     /// List of collaborators and their shares
+    #[max_len(MAX_COLLABORATORS)]
     pub collaborators: Vec<Collaborator>,
     
-    /// Timestamp of last distribution
+    /// Timestamp of last distribution (last batched payout while accruing)
     pub last_distributed_ts: i64,
     
+    /// Seconds between batched SOL payouts (0 = distribute on every sale)
+    pub payout_period: i64,
+    
+    /// Minimum accrued lamports before a batched payout is made
+    pub min_payout_lamports: u64,
+    
+    /// SOL accrued in the vault awaiting the next batched payout
+    pub accrued_amount: u64,
+    
+    /// Number of distributions recorded (index of the next DistributionRecord)
+    pub distribution_count: u64,
+    
+    /// Whether collaborator share transfers must be co-signed by the creator
+    pub require_share_transfer_approval: bool,
+    
+    /// SPL mints the split has been paid in, each held in the vault's associated token account
+    #[max_len(MAX_PAYMENT_MINTS)]
+    pub payment_mints: Vec<Pubkey>,
+    
     /// Seed for PDA derivation
     pub seed: u64,
     
@@ -30,41 +66,213 @@ pub struct SplitState {
 
 impl SplitState {
     /// Base size without collaborators
-    /// Discriminator (8) + [u8; 32] (32) + Pubkey (32) + u16 (2) 
-    /// + Pubkey (32) + Vec length (4) + i64 (8) + u64 (8) + u8 (1)
-    pub const BASE_LEN: usize = 8 + 32 + 32 + 2 + 32 + 4 + 8 + 8 + 1;
+    /// Discriminator (8) + fields at their largest (derived by InitSpace) less the
+    /// collaborator slots, which are allocated only as the list grows
+    pub const BASE_LEN: usize = 8 + Self::INIT_SPACE - Self::COLLABORATOR_LEN * Self::MAX_COLLABORATORS;
     
-    /// Size per collaborator: Pubkey (32) + u16 (2)
-    pub const COLLABORATOR_LEN: usize = 32 + 2;
+    /// Size per collaborator: Pubkey (32) + u16 (2) + bool (1)
+    pub const COLLABORATOR_LEN: usize = Collaborator::LEN;
     
     /// Calculate space needed for a given number of collaborators
     pub fn space(num_collaborators: usize) -> usize {
         Self::BASE_LEN + (Self::COLLABORATOR_LEN * num_collaborators)
     }
     
+    /// Maximum number of collaborators on a split
+    pub const MAX_COLLABORATORS: usize = MAX_COLLABORATORS;
+    
+    /// Maximum number of SPL mints a split can be paid in
+    pub const MAX_PAYMENT_MINTS: usize = MAX_PAYMENT_MINTS;
+    
     /// PDA seed prefix
-    pub const SEED_PREFIX: &'static [u8] = b"split";
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::SPLIT;
     
-    /// Validate that total basis points don't exceed 10000 (100%)
-    pub fn validate_shares(&self) -> Result<()> {
-        let total_collab_bps: u16 = self.collaborators
-            .iter()
-            .map(|c| c.share_bps)
-            .sum();
+    /// Vault PDA seed prefix
+    pub const VAULT_SEED_PREFIX: &'static [u8] = common::seeds::DISTRIBUTION_VAULT;
+    
+    /// Basis points representing 100%
+    pub const MAX_BPS: u16 = bps::MAX_BPS;
+    
+    /// Whether SOL sales accrue in the vault for batched payouts
+    pub fn is_accruing(&self) -> bool {
+        self.payout_period > 0
+    }
+    
+    /// Whether the accrued balance can be paid out at `now`
+    pub fn is_payout_due(&self, now: i64) -> bool {
+        if self.accrued_amount == 0 {
+            return false;
+        }
+        
+        // Accrual was switched off: flush whatever is left
+        if !self.is_accruing() {
+            return true;
+        }
         
-        let total_bps = self.platform_fee_bps
-            .checked_add(total_collab_bps)
+        let period_elapsed = now >= self.last_distributed_ts.saturating_add(self.payout_period);
+        period_elapsed && self.accrued_amount >= self.min_payout_lamports
+    }
+    
+    /// Add `amount` to the accrued balance, which must be held by the vault
+    /// `distribute` is permissionless, so an accrual not backed by lamports in the
+    /// vault is rejected rather than left to make every later payout fail
+    pub fn accrue(&mut self, amount: u64, vault_lamports: u64) -> Result<()> {
+        let accrued_amount = self
+            .accrued_amount
+            .checked_add(amount)
             .ok_or(DistributionError::NumericalOverflow)?;
+        require!(vault_lamports >= accrued_amount, DistributionError::InsufficientFunds);
+        
+        self.accrued_amount = accrued_amount;
+        
+        Ok(())
+    }
+    
+    /// Remember that the vault has been paid in `mint`, so a payout address rotation
+    /// can check every vault token account that may hold undistributed funds
+    pub fn record_payment_mint(&mut self, mint: Pubkey) -> Result<()> {
+        if self.payment_mints.contains(&mint) {
+            return Ok(());
+        }
+        
+        require!(
+            self.payment_mints.len() < Self::MAX_PAYMENT_MINTS,
+            DistributionError::TooManyPaymentMints
+        );
+        self.payment_mints.push(mint);
+        
+        Ok(())
+    }
+    
+    /// Number of collaborators once `from` transfers `share_bps` to `to`
+    /// Used to size the account before `transfer_share` applies the change
+    pub fn collaborators_after_transfer(&self, from: &Pubkey, to: &Pubkey, share_bps: u16) -> usize {
+        let len = self.collaborators.len();
+        let Some(sender) = self.collaborators.iter().find(|c| c.pubkey == *from) else {
+            return len;
+        };
+        let adds_recipient = !self.collaborators.iter().any(|c| c.pubkey == *to);
+        let removes_sender = sender.share_bps == share_bps;
+        
+        match (adds_recipient, removes_sender) {
+            (true, false) => len + 1,
+            (false, true) => len - 1,
+            _ => len,
+        }
+    }
+    
+    /// Move `share_bps` from collaborator `from` to `to`
+    /// A new recipient joins as a pending collaborator and must accept before being paid
+    pub fn transfer_share(&mut self, from: &Pubkey, to: &Pubkey, share_bps: u16) -> Result<()> {
+        require!(from != to, DistributionError::InvalidRecipient);
+        require!(share_bps > 0, DistributionError::InvalidShareDistribution);
+        
+        let sender_index = self
+            .collaborators
+            .iter()
+            .position(|c| c.pubkey == *from)
+            .ok_or(DistributionError::InvalidCollaborator)?;
+        let sender = &mut self.collaborators[sender_index];
+        
+        // Pending invites have nothing to assign yet
+        require!(sender.accepted, DistributionError::CollaborationNotAccepted);
+        require!(
+            share_bps <= sender.share_bps,
+            DistributionError::InvalidShareDistribution
+        );
+        
+        sender.share_bps -= share_bps;
+        
+        match self.collaborators.iter_mut().find(|c| c.pubkey == *to) {
+            Some(recipient) => {
+                recipient.share_bps = recipient
+                    .share_bps
+                    .checked_add(share_bps)
+                    .ok_or(DistributionError::NumericalOverflow)?;
+            }
+            None => self.collaborators.push(Collaborator {
+                pubkey: *to,
+                share_bps,
+                accepted: false,
+            }),
+        }
+        
+        // A fully transferred share leaves no entry behind
+        if self.collaborators[sender_index].share_bps == 0 {
+            self.collaborators.remove(sender_index);
+        }
+        
+        require!(
+            self.collaborators.len() <= Self::MAX_COLLABORATORS,
+            DistributionError::TooManyCollaborators
+        );
+        
+        Ok(())
+    }
+    
+    /// Replace the collaborator list with `collaborators`
+    /// Collaborators kept at the same share stay accepted; new wallets and changed
+    /// shares start pending and must be accepted again. Accepted collaborators can
+    /// only be changed or removed if they are in `consenting`
+    pub fn set_collaborators(&mut self, collaborators: Vec<Collaborator>, consenting: &[Pubkey]) -> Result<()> {
+        require!(
+            collaborators.len() <= Self::MAX_COLLABORATORS,
+            DistributionError::TooManyCollaborators
+        );
+        
+        // Each wallet holds a single entry
+        for (index, collaborator) in collaborators.iter().enumerate() {
+            require!(
+                !collaborators[..index].iter().any(|c| c.pubkey == collaborator.pubkey),
+                DistributionError::DuplicateCollaborator
+            );
+        }
+        
+        // An accepted share is only rewritten with its holder's signature
+        for existing in self.collaborators.iter().filter(|c| c.accepted) {
+            let unchanged = collaborators
+                .iter()
+                .any(|c| c.pubkey == existing.pubkey && c.share_bps == existing.share_bps);
+            require!(
+                unchanged || consenting.contains(&existing.pubkey),
+                DistributionError::AcceptedCollaboratorChanged
+            );
+        }
+        
+        let collaborators = collaborators
+            .into_iter()
+            .map(|collaborator| Collaborator {
+                accepted: self.collaborators.iter().any(|existing| {
+                    existing.pubkey == collaborator.pubkey
+                        && existing.share_bps == collaborator.share_bps
+                        && existing.accepted
+                }),
+                ..collaborator
+            })
+            .collect();
+        self.collaborators = collaborators;
+        
+        self.validate_shares()
+    }
+    
+    /// Validate that total basis points don't exceed 10000 (100%)
+    pub fn validate_shares(&self) -> Result<()> {
+        let total_bps = bps::checked_total_bps(
+            std::iter::once(self.platform_fee_bps)
+                .chain(self.collaborators.iter().map(|c| c.share_bps)),
+        )
+        .ok_or(DistributionError::NumericalOverflow)?;
         
         require!(
-            total_bps <= 10000,
+            bps::is_within_max(total_bps),
             DistributionError::InvalidShareDistribution
         );
         
         Ok(())
     }
     
-    /// Calculate creator's share after platform fee and collaborator shares
+    /// Calculate creator's share after platform fee and accepted collaborator shares
+    /// Shares of collaborators who have not accepted yet stay with the creator
     pub fn calculate_creator_share(&self, total_amount: u64) -> Result<u64> {
         let platform_amount = self.calculate_platform_fee(total_amount)?;
         
@@ -72,13 +280,9 @@ impl SplitState {
             .checked_sub(platform_amount)
             .ok_or(DistributionError::NumericalOverflow)?;
         
-        // Subtract collaborator shares
+        // Subtract accepted collaborator shares
         for collaborator in &self.collaborators {
-            let collab_amount = total_amount
-                .checked_mul(collaborator.share_bps as u64)
-                .ok_or(DistributionError::NumericalOverflow)?
-                .checked_div(10000)
-                .ok_or(DistributionError::NumericalOverflow)?;
+            let collab_amount = self.calculate_collaborator_share(total_amount, collaborator.active_share_bps())?;
             
             remaining = remaining
                 .checked_sub(collab_amount)
@@ -90,33 +294,378 @@ impl SplitState {
     
     /// Calculate platform fee amount
     pub fn calculate_platform_fee(&self, total_amount: u64) -> Result<u64> {
-        total_amount
-            .checked_mul(self.platform_fee_bps as u64)
-            .ok_or(DistributionError::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(DistributionError::NumericalOverflow)
-            .map_err(|_| DistributionError::NumericalOverflow.into())
+        Self::apply_bps(total_amount, self.platform_fee_bps)
+    }
+    
+    /// Platform fee on a distribution, or 0 when the payment escrow waived it
+    pub fn platform_fee_for(&self, total_amount: u64, fee_waived: bool) -> Result<u64> {
+        if fee_waived {
+            return Ok(0);
+        }
+        
+        self.calculate_platform_fee(total_amount)
+    }
+    
+    /// Creator share of a distribution; a waived platform fee stays with the creator
+    pub fn creator_share_for(&self, total_amount: u64, fee_waived: bool) -> Result<u64> {
+        let creator_amount = self.calculate_creator_share(total_amount)?;
+        
+        if !fee_waived {
+            return Ok(creator_amount);
+        }
+        
+        creator_amount
+            .checked_add(self.calculate_platform_fee(total_amount)?)
+            .ok_or_else(|| DistributionError::NumericalOverflow.into())
     }
     
     /// Calculate collaborator's share amount
     pub fn calculate_collaborator_share(&self, total_amount: u64, share_bps: u16) -> Result<u64> {
-        total_amount
-            .checked_mul(share_bps as u64)
-            .ok_or(DistributionError::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(DistributionError::NumericalOverflow)
-            .map_err(|_| DistributionError::NumericalOverflow.into())
+        Self::apply_bps(total_amount, share_bps)
+    }
+    
+    /// Apply basis points to an amount, rounding down
+    fn apply_bps(total_amount: u64, share_bps: u16) -> Result<u64> {
+        bps::apply_bps(total_amount, share_bps)
+            .ok_or_else(|| DistributionError::NumericalOverflow.into())
     }
-}
-
-/// Collaborator with their revenue share
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
-pub struct Collaborator {
-    /// Collaborator's public key
-    pub pubkey: Pubkey,
-    
-    /// Share in basis points (e.g., 500 = 5%)
-    pub share_bps: u16,
 }
 
 use crate::errors::DistributionError;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    
+    fn split(platform_fee_bps: u16, shares: &[u16]) -> SplitState {
+        SplitState {
+            content_id: [1; 32],
+            creator: Pubkey::new_unique(),
+            payout_address: Pubkey::new_unique(),
+            pending_payout_address: None,
+            platform_fee_bps,
+            platform_treasury: Pubkey::new_unique(),
+            collaborators: shares
+                .iter()
+                .map(|share_bps| Collaborator {
+                    pubkey: Pubkey::new_unique(),
+                    share_bps: *share_bps,
+                    accepted: true,
+                })
+                .collect(),
+            last_distributed_ts: 0,
+            payout_period: 0,
+            min_payout_lamports: 0,
+            accrued_amount: 0,
+            distribution_count: 0,
+            require_share_transfer_approval: false,
+            payment_mints: Vec::new(),
+            seed: 0,
+            bump: 0,
+        }
+    }
+    
+    /// Platform fee, collaborator shares and creator share for an amount
+    fn payouts(split: &SplitState, amount: u64) -> (u64, Vec<u64>, u64) {
+        let platform = split.calculate_platform_fee(amount).unwrap();
+        let collaborators = split
+            .collaborators
+            .iter()
+            .map(|c| split.calculate_collaborator_share(amount, c.active_share_bps()).unwrap())
+            .collect();
+        let creator = split.calculate_creator_share(amount).unwrap();
+        (platform, collaborators, creator)
+    }
+    
+    #[test]
+    fn full_allocation_leaves_only_rounding_dust_to_creator() {
+        let split = split(1000, &[4500, 4500]);
+        split.validate_shares().unwrap();
+        
+        let (platform, collaborators, creator) = payouts(&split, 10_000);
+        assert_eq!(platform, 1000);
+        assert_eq!(collaborators, vec![4500, 4500]);
+        assert_eq!(creator, 0);
+        
+        let (platform, collaborators, creator) = payouts(&split, 9_999);
+        assert_eq!(platform + collaborators.iter().sum::<u64>() + creator, 9_999);
+        assert_eq!(creator, 2);
+    }
+    
+    #[test]
+    fn max_amount_does_not_overflow() {
+        let split = split(1000, &[9000]);
+        let (platform, collaborators, creator) = payouts(&split, u64::MAX);
+        
+        assert_eq!(platform, u64::MAX / 10);
+        assert_eq!(platform as u128 + collaborators[0] as u128 + creator as u128, u64::MAX as u128);
+    }
+    
+    #[test]
+    fn waived_platform_fee_goes_to_creator() {
+        let split = split(1000, &[2000]);
+        
+        assert_eq!(split.platform_fee_for(10_000, true).unwrap(), 0);
+        assert_eq!(split.creator_share_for(10_000, true).unwrap(), 8000);
+        assert_eq!(split.platform_fee_for(10_000, false).unwrap(), 1000);
+        assert_eq!(split.creator_share_for(10_000, false).unwrap(), 7000);
+    }
+    
+    #[test]
+    fn oversized_collaborator_shares_are_rejected_not_wrapped() {
+        let split = split(0, &[u16::MAX; 10]);
+        assert!(split.validate_shares().is_err());
+    }
+    
+    #[test]
+    fn pending_collaborator_share_stays_with_creator() {
+        let mut split = split(1000, &[2000, 3000]);
+        split.collaborators[1].accepted = false;
+        
+        let (platform, collaborators, creator) = payouts(&split, 10_000);
+        assert_eq!(platform, 1000);
+        assert_eq!(collaborators, vec![2000, 0]);
+        assert_eq!(creator, 7000);
+        
+        split.collaborators[1].accepted = true;
+        assert_eq!(split.calculate_creator_share(10_000).unwrap(), 4000);
+    }
+    
+    #[test]
+    fn transfer_share_moves_bps_and_adds_pending_recipient() {
+        let mut split = split(0, &[3000, 2000]);
+        let sender = split.collaborators[0].pubkey;
+        let existing = split.collaborators[1].pubkey;
+        let new_wallet = Pubkey::new_unique();
+        
+        // Partial transfer to a new wallet adds a pending collaborator
+        assert_eq!(split.collaborators_after_transfer(&sender, &new_wallet, 1000), 3);
+        split.transfer_share(&sender, &new_wallet, 1000).unwrap();
+        assert_eq!(split.collaborators.len(), 3);
+        assert_eq!(split.collaborators[0].share_bps, 2000);
+        assert_eq!(split.collaborators[2].share_bps, 1000);
+        assert!(!split.collaborators[2].accepted);
+        
+        // Full transfer to an existing collaborator removes the sender
+        assert_eq!(split.collaborators_after_transfer(&sender, &existing, 2000), 2);
+        split.transfer_share(&sender, &existing, 2000).unwrap();
+        assert_eq!(split.collaborators.len(), 2);
+        assert_eq!(split.collaborators[0].pubkey, existing);
+        assert_eq!(split.collaborators[0].share_bps, 4000);
+        split.validate_shares().unwrap();
+    }
+    
+    #[test]
+    fn transfer_share_rejects_invalid_transfers() {
+        let mut split = split(0, &[3000]);
+        let sender = split.collaborators[0].pubkey;
+        let recipient = Pubkey::new_unique();
+        
+        assert!(split.transfer_share(&sender, &recipient, 0).is_err());
+        assert!(split.transfer_share(&sender, &recipient, 3001).is_err());
+        assert!(split.transfer_share(&sender, &sender, 1000).is_err());
+        assert!(split.transfer_share(&recipient, &sender, 1000).is_err());
+        
+        split.collaborators[0].accepted = false;
+        assert!(split.transfer_share(&sender, &recipient, 1000).is_err());
+    }
+    
+    #[test]
+    fn set_collaborators_keeps_only_unchanged_acceptances() {
+        let mut split = split(1000, &[3000, 2000]);
+        let kept = split.collaborators[0].pubkey;
+        let changed = split.collaborators[1].pubkey;
+        let added = Pubkey::new_unique();
+        
+        split
+            .set_collaborators(
+                vec![
+                    Collaborator { pubkey: kept, share_bps: 3000, accepted: false },
+                    Collaborator { pubkey: changed, share_bps: 1000, accepted: true },
+                    Collaborator { pubkey: added, share_bps: 1000, accepted: true },
+                ],
+                &[changed],
+            )
+            .unwrap();
+        assert_eq!(
+            split.collaborators.iter().map(|c| c.accepted).collect::<Vec<_>>(),
+            vec![true, false, false]
+        );
+        
+        // Shrinking drops removed collaborators entirely; pending ones need no consent
+        split
+            .set_collaborators(vec![Collaborator { pubkey: kept, share_bps: 3000, accepted: false }], &[])
+            .unwrap();
+        assert_eq!(split.collaborators.len(), 1);
+        assert!(split.collaborators[0].accepted);
+        
+        // Shares over 100% and oversized lists are rejected
+        assert!(split
+            .set_collaborators(vec![Collaborator { pubkey: kept, share_bps: 9001, accepted: false }], &[kept])
+            .is_err());
+        assert!(split
+            .set_collaborators(vec![Collaborator { pubkey: added, share_bps: 0, accepted: false }; 11], &[kept])
+            .is_err());
+    }
+    
+    #[test]
+    fn set_collaborators_needs_consent_to_change_accepted_shares() {
+        let mut split = split(0, &[3000]);
+        let accepted = split.collaborators[0].pubkey;
+        let added = Pubkey::new_unique();
+        
+        // Appending leaves the accepted share alone
+        split
+            .set_collaborators(
+                vec![
+                    Collaborator { pubkey: accepted, share_bps: 3000, accepted: true },
+                    Collaborator { pubkey: added, share_bps: 1000, accepted: false },
+                ],
+                &[],
+            )
+            .unwrap();
+        
+        // Removing or cutting the accepted share needs its holder's signature
+        let removed = vec![Collaborator { pubkey: added, share_bps: 1000, accepted: false }];
+        let cut = vec![Collaborator { pubkey: accepted, share_bps: 1, accepted: false }];
+        assert!(split.set_collaborators(removed.clone(), &[]).is_err());
+        assert!(split.set_collaborators(cut.clone(), &[added]).is_err());
+        split.set_collaborators(cut, &[accepted]).unwrap();
+        assert_eq!(split.collaborators[0].share_bps, 1);
+        assert!(!split.collaborators[0].accepted);
+        
+        // Once pending again, the entry can be removed without consent
+        split.set_collaborators(removed, &[]).unwrap();
+        assert_eq!(split.collaborators.len(), 1);
+    }
+    
+    #[test]
+    fn set_collaborators_rejects_duplicate_wallets() {
+        let mut split = split(0, &[]);
+        let wallet = Pubkey::new_unique();
+        
+        assert!(split
+            .set_collaborators(
+                vec![
+                    Collaborator { pubkey: wallet, share_bps: 1000, accepted: false },
+                    Collaborator { pubkey: wallet, share_bps: 1000, accepted: false },
+                ],
+                &[],
+            )
+            .is_err());
+        assert!(split.collaborators.is_empty());
+    }
+    
+    #[test]
+    fn payment_mints_are_recorded_once_up_to_the_limit() {
+        let mut split = split(0, &[]);
+        let mint = Pubkey::new_unique();
+        
+        split.record_payment_mint(mint).unwrap();
+        split.record_payment_mint(mint).unwrap();
+        assert_eq!(split.payment_mints, vec![mint]);
+        
+        for _ in 1..SplitState::MAX_PAYMENT_MINTS {
+            split.record_payment_mint(Pubkey::new_unique()).unwrap();
+        }
+        assert!(split.record_payment_mint(Pubkey::new_unique()).is_err());
+        split.record_payment_mint(mint).unwrap();
+        assert_eq!(split.payment_mints.len(), SplitState::MAX_PAYMENT_MINTS);
+    }
+    
+    #[test]
+    fn accrued_payout_waits_for_period_and_minimum() {
+        let mut split = split(0, &[]);
+        split.payout_period = 7 * 24 * 60 * 60;
+        split.min_payout_lamports = 1_000;
+        split.last_distributed_ts = 100;
+        
+        // Nothing accrued yet
+        assert!(!split.is_payout_due(i64::MAX));
+        
+        split.accrued_amount = 999;
+        assert!(!split.is_payout_due(100 + split.payout_period));
+        
+        split.accrued_amount = 1_000;
+        assert!(!split.is_payout_due(99 + split.payout_period));
+        assert!(split.is_payout_due(100 + split.payout_period));
+        
+        // Disabling accrual flushes any balance regardless of minimum
+        split.payout_period = 0;
+        split.accrued_amount = 1;
+        assert!(split.is_payout_due(0));
+    }
+    
+    #[test]
+    fn accrual_must_be_held_by_the_vault() {
+        let mut split = split(0, &[]);
+        split.payout_period = 7 * 24 * 60 * 60;
+        
+        split.accrue(1_000, 1_000).unwrap();
+        assert_eq!(split.accrued_amount, 1_000);
+        
+        // A caller claiming a distribution it never paid into the vault
+        assert!(split.accrue(500, 1_000).is_err());
+        assert_eq!(split.accrued_amount, 1_000);
+        
+        split.accrue(500, 1_500).unwrap();
+        assert_eq!(split.accrued_amount, 1_500);
+    }
+    
+    /// Strategy for platform fee and collaborator shares totalling at most 100%
+    fn valid_split() -> impl Strategy<Value = (u16, Vec<u16>)> {
+        (
+            0..=SplitState::MAX_BPS,
+            proptest::collection::vec(0..=SplitState::MAX_BPS, 0..=10),
+        )
+            .prop_map(|(platform_fee_bps, shares)| {
+                // Clamp each share to the remaining budget so totals of exactly 10000 are common
+                let mut budget = SplitState::MAX_BPS - platform_fee_bps;
+                let shares = shares
+                    .into_iter()
+                    .map(|share| {
+                        let share = share.min(budget);
+                        budget -= share;
+                        share
+                    })
+                    .collect();
+                (platform_fee_bps, shares)
+            })
+    }
+    
+    proptest! {
+        #[test]
+        fn payouts_sum_to_amount(
+            (platform_fee_bps, shares) in valid_split(),
+            amount in any::<u64>(),
+        ) {
+            let split = split(platform_fee_bps, &shares);
+            prop_assert!(split.validate_shares().is_ok());
+            
+            let (platform, collaborators, creator) = payouts(&split, amount);
+            
+            prop_assert!(platform <= amount);
+            prop_assert!(creator <= amount);
+            for collab_amount in &collaborators {
+                prop_assert!(*collab_amount <= amount);
+            }
+            
+            let total = platform as u128
+                + collaborators.iter().map(|a| *a as u128).sum::<u128>()
+                + creator as u128;
+            prop_assert_eq!(total, amount as u128);
+        }
+        
+        #[test]
+        fn validate_shares_matches_total_bps(
+            platform_fee_bps in any::<u16>(),
+            shares in proptest::collection::vec(any::<u16>(), 0..=10),
+        ) {
+            let total_bps = platform_fee_bps as u32 + shares.iter().map(|s| *s as u32).sum::<u32>();
+            let split = split(platform_fee_bps, &shares);
+            
+            prop_assert_eq!(split.validate_shares().is_ok(), total_bps <= SplitState::MAX_BPS as u32);
+        }
+    }
+}
@@ -4,7 +4,7 @@ import { headers } from "next/headers";
 import prisma from "@/lib/db";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { PAYMENT_ESCROW_PROGRAM_ID, ACCESS_MINT_PROGRAM_ID, DISTRIBUTION_PROGRAM_ID } from "@/lib/programs/constants";
-import { deriveEscrowState, deriveAccessMintState, deriveAccessMintAuthority, deriveSplitState, hexToContentId, deriveEscrowVault, deriveDistributionVault } from "@/lib/programs/pdas";
+import { deriveEscrowState, deriveAccessMintState, deriveAccessMintAuthority, deriveSplitState, hexToContentId, deriveBuyerVault, deriveDistributionVault } from "@/lib/programs/pdas";
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 /**
@@ -64,7 +64,7 @@ export async function POST(req: NextRequest) {
 
     // Derive PDAs
     const [escrowStatePda] = deriveEscrowState(buyerPublicKey, contentId, seed);
-    const [escrowVaultPda] = deriveEscrowVault(escrowStatePda);
+    const [buyerVaultPda] = deriveBuyerVault(buyerPublicKey);
     const [accessMintStatePda] = deriveAccessMintState(creatorPublicKey, contentId, seed);
     const [accessMintAuthorityPda] = deriveAccessMintAuthority(creatorPublicKey, contentId, seed);
     const [splitStatePda] = deriveSplitState(creatorPublicKey, contentId, seed);
@@ -91,7 +91,7 @@ export async function POST(req: NextRequest) {
           price: priceInLamports,
           // Escrow accounts
           escrowState: escrowStatePda.toString(),
-          vault: escrowVaultPda.toString(),
+          buyerVault: buyerVaultPda.toString(),
           // Access mint accounts
           accessMintProgram: ACCESS_MINT_PROGRAM_ID.toString(),
           accessMintState: accessMintStatePda.toString(),
@@ -14,8 +14,9 @@ import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
 import { PAYMENT_ESCROW_PROGRAM_ID, ACCESS_MINT_PROGRAM_ID, DISTRIBUTION_PROGRAM_ID } from "@/lib/programs/constants";
-import { deriveEscrowVault } from "@/lib/programs/pdas";
 import { usePaymentEscrowProgram } from "@/lib/programs/use-payment-escrow";
+import { useDistributionProgram } from "@/lib/programs/use-distribution";
+import { deriveDistributionRecord } from "@/lib/programs/pdas";
 import * as anchor from "@coral-xyz/anchor";
 
 interface Product {
@@ -43,6 +44,7 @@ export default function ProductDetailPage() {
   const { publicKey, connected, sendTransaction } = useWallet();
   const { connection } = useConnection();
   const { program: paymentEscrowProgram, provider: paymentEscrowProvider } = usePaymentEscrowProgram();
+  const { program: distributionProgram } = useDistributionProgram();
   const [product, setProduct] = useState<Product | null>(null);
   const [loading, setLoading] = useState(true);
   const [purchasing, setPurchasing] = useState(false);
@@ -81,7 +83,7 @@ export default function ProductDetailPage() {
       return;
     }
 
-    if (!paymentEscrowProgram || !paymentEscrowProvider) {
+    if (!paymentEscrowProgram || !paymentEscrowProvider || !distributionProgram) {
       alert("Payment program not available. Please try again later.");
       return;
     }
@@ -136,12 +138,15 @@ export default function ProductDetailPage() {
             Array.from(contentId),
             new anchor.BN(priceInLamports),
             null, // No SPL token payment, use SOL
-            new anchor.BN(buyParams.seed)
+            new anchor.BN(buyParams.seed),
+            buyParams.memo ?? null // Optional order reference for reconciliation
           )
           .accounts({
             buyer: publicKey,
+            payer: publicKey,
             creator: new PublicKey(buyParams.accounts.creator),
             escrowState: escrowState,
+            buyerVault: new PublicKey(buyParams.accounts.buyerVault),
             systemProgram: SystemProgram.programId,
           })
           .instruction();
@@ -163,19 +168,34 @@ export default function ProductDetailPage() {
       // Get accounts for SOL payment
       const creatorPublicKey = new PublicKey(buyParams.accounts.creator);
       const platformTreasury = new PublicKey(buyParams.accounts.platformTreasury);
-      const escrowVaultPda = new PublicKey(buyParams.accounts.vault);
+      const buyerVaultPda = new PublicKey(buyParams.accounts.buyerVault);
       const distributionVaultPda = new PublicKey(buyParams.accounts.distributionVault);
+      const splitStatePda = new PublicKey(buyParams.accounts.splitState);
+
+      // Each distribution writes an audit record at the split's next index
+      const splitStateAccount = await (distributionProgram.account as any).splitState.fetch(splitStatePda);
+      const [distributionRecordPda] = deriveDistributionRecord(
+        splitStatePda,
+        BigInt(splitStateAccount.distributionCount.toString())
+      );
 
       const buyAndMintIx = await paymentEscrowProgram.methods
-        .buyAndMint(new anchor.BN(buyParams.paymentAmount))
+        .buyAndMint(
+          new anchor.BN(buyParams.paymentAmount),
+          buyParams.licenseTermsHash ?? Array(32).fill(0), // Unlisted products carry no license terms
+          buyParams.allowlistProof ?? [], // Only needed during allowlisted sale phases
+          null, // No receipt NFT
+          false // Settle through the distribution vault
+        )
         .accounts({
           buyer: publicKey,
+          payer: publicKey,
           escrowState: escrowState,
-          vault: escrowVaultPda, // Escrow vault (derived from escrow_state) - required by constraint
+          buyerVault: buyerVaultPda, // Buyer vault shared by the buyer's SOL escrows
           // For SOL payments, these need to be the actual mutable accounts
           // The program will check if payment_token_mint is None to determine SOL vs SPL
           buyerTokenAccount: publicKey, // Buyer's wallet (mutable for SOL transfer)
-          vaultTokenAccount: escrowVaultPda, // Escrow vault PDA (mutable for SOL transfer)
+          vaultTokenAccount: buyerVaultPda, // Buyer vault PDA (mutable for SOL transfer)
           tokenProgram: SystemProgram.programId, // Not used for SOL, but required
           // Access mint accounts
           accessMintProgram: new PublicKey(buyParams.accounts.accessMintProgram),
@@ -187,7 +207,8 @@ export default function ProductDetailPage() {
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           // Distribution accounts
           distributionProgram: new PublicKey(buyParams.accounts.distributionProgram),
-          splitState: new PublicKey(buyParams.accounts.splitState),
+          splitState: splitStatePda,
+          distributionRecord: distributionRecordPda, // Audit record for this distribution
           distributionVault: distributionVaultPda, // Distribution vault (derived from split_state)
           distributionVaultTokenAccount: distributionVaultPda, // For SOL, same as distribution vault
           platformTreasury: platformTreasury,
@@ -108,7 +108,10 @@ export function CreateProductForm({ onSuccess }: CreateProductFormProps) {
           const accessMintTx = await accessMintProgram.methods
             .initializeMint(
               Array.from(contentIdBuffer), // Convert to array for Anchor
-              new anchor.BN(accessMint.seed)
+              new anchor.BN(accessMint.seed),
+              { basic: {} }, // Standard access tier
+              0, // Whole access tokens
+              new anchor.BN(1) // One access token per purchase
             )
             .accounts({
               creator: creatorPublicKey,
@@ -0,0 +1,30 @@
+import { Program, type Provider } from "@coral-xyz/anchor";
+import {
+  accessMintIdl,
+  distributionIdl,
+  paymentEscrowIdl,
+  type AccessMint,
+  type Distribution,
+  type PaymentEscrow,
+} from "./idl";
+
+/**
+ * Create a typed Access Mint program client
+ */
+export function createAccessMintProgram(provider: Provider): Program<AccessMint> {
+  return new Program(accessMintIdl as AccessMint, provider);
+}
+
+/**
+ * Create a typed Distribution program client
+ */
+export function createDistributionProgram(provider: Provider): Program<Distribution> {
+  return new Program(distributionIdl as Distribution, provider);
+}
+
+/**
+ * Create a typed Payment Escrow program client
+ */
+export function createPaymentEscrowProgram(provider: Provider): Program<PaymentEscrow> {
+  return new Program(paymentEscrowIdl as PaymentEscrow, provider);
+}
@@ -0,0 +1,15 @@
+/**
+ * IDLs and generated types for the on-chain programs.
+ *
+ * Both come from `anchor build` in each program workspace; the IDLs carry
+ * the instructions, accounts, events and error codes with their doc comments.
+ */
+import accessMintIdl from "../../../access-mint/target/idl/access_mint.json";
+import distributionIdl from "../../../distribution/target/idl/distribution.json";
+import paymentEscrowIdl from "../../../payment-escrow/target/idl/payment_escrow.json";
+
+export type { AccessMint } from "../../../access-mint/target/types/access_mint";
+export type { Distribution } from "../../../distribution/target/types/distribution";
+export type { PaymentEscrow } from "../../../payment-escrow/target/types/payment_escrow";
+
+export { accessMintIdl, distributionIdl, paymentEscrowIdl };
@@ -0,0 +1,4 @@
+export * from "./constants";
+export * from "./idl";
+export * from "./client";
+export * from "./pdas";
@@ -1,6 +1,37 @@
-import { PublicKey } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { ACCESS_MINT_PROGRAM_ID, PAYMENT_ESCROW_PROGRAM_ID, DISTRIBUTION_PROGRAM_ID } from "./constants";
 
+/**
+ * PDA seed prefixes - must match the SEED_PREFIX constants in the Rust programs
+ */
+export const SEEDS = {
+  accessMintState: "access_mint_state",
+  accessMintAuthority: "access_mint_authority",
+  accessGrant: "access_grant",
+  sessionGrant: "session_grant",
+  escrow: "escrow",
+  escrowVault: "vault",
+  buyerVault: "buyer_vault",
+  platformConfig: "platform_config",
+  listing: "listing",
+  campaign: "campaign",
+  campaignVault: "campaign_vault",
+  contribution: "contribution",
+  contentMetadata: "content_metadata",
+  split: "split",
+  distributionVault: "vault",
+  distributionRecord: "distribution_record",
+} as const;
+
+/**
+ * Encode a u64 PDA seed as little-endian bytes
+ */
+export function seedToBuffer(seed: number | bigint): Buffer {
+  const seedBuffer = Buffer.allocUnsafe(8);
+  seedBuffer.writeBigUInt64LE(BigInt(seed), 0);
+  return seedBuffer;
+}
+
 /**
  * Derive access mint state PDA
  */
@@ -10,15 +41,12 @@ export function deriveAccessMintState(
   seed: number | bigint,
   programId: PublicKey = ACCESS_MINT_PROGRAM_ID
 ): [PublicKey, number] {
-  const seedBuffer = Buffer.allocUnsafe(8);
-  seedBuffer.writeBigUInt64LE(BigInt(seed), 0);
-  
   return PublicKey.findProgramAddressSync(
     [
-      Buffer.from("access_mint_state"),
+      Buffer.from(SEEDS.accessMintState),
       creator.toBuffer(),
       Buffer.from(contentId),
-      seedBuffer,
+      seedToBuffer(seed),
     ],
     programId
   );
@@ -33,15 +61,12 @@ export function deriveAccessMintAuthority(
   seed: number | bigint,
   programId: PublicKey = ACCESS_MINT_PROGRAM_ID
 ): [PublicKey, number] {
-  const seedBuffer = Buffer.allocUnsafe(8);
-  seedBuffer.writeBigUInt64LE(BigInt(seed), 0);
-  
   return PublicKey.findProgramAddressSync(
     [
-      Buffer.from("access_mint_authority"),
+      Buffer.from(SEEDS.accessMintAuthority),
       creator.toBuffer(),
       Buffer.from(contentId),
-      seedBuffer,
+      seedToBuffer(seed),
     ],
     programId
   );
@@ -56,29 +81,45 @@ export function deriveEscrowState(
   seed: number | bigint,
   programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
 ): [PublicKey, number] {
-  const seedBuffer = Buffer.allocUnsafe(8);
-  seedBuffer.writeBigUInt64LE(BigInt(seed), 0);
-  
   return PublicKey.findProgramAddressSync(
     [
-      Buffer.from("escrow"),
+      Buffer.from(SEEDS.escrow),
       buyer.toBuffer(),
       Buffer.from(contentId),
-      seedBuffer,
+      seedToBuffer(seed),
     ],
     programId
   );
 }
 
 /**
- * Derive escrow vault PDA
+ * Derive legacy per-escrow vault PDA (escrows created before buyer vaults)
  */
 export function deriveEscrowVault(
   escrowState: PublicKey,
   programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), escrowState.toBuffer()],
+    [Buffer.from(SEEDS.escrowVault), escrowState.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive buyer vault PDA shared by a buyer's escrows in one payment mint
+ * SOL vaults use the system program id as the payment mint
+ */
+export function deriveBuyerVault(
+  buyer: PublicKey,
+  paymentTokenMint: PublicKey | null = null,
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SEEDS.buyerVault),
+      buyer.toBuffer(),
+      (paymentTokenMint ?? SystemProgram.programId).toBuffer(),
+    ],
     programId
   );
 }
@@ -92,15 +133,12 @@ export function deriveSplitState(
   seed: number | bigint,
   programId: PublicKey = DISTRIBUTION_PROGRAM_ID
 ): [PublicKey, number] {
-  const seedBuffer = Buffer.allocUnsafe(8);
-  seedBuffer.writeBigUInt64LE(BigInt(seed), 0);
-  
   return PublicKey.findProgramAddressSync(
     [
-      Buffer.from("split"), // Must match SplitState::SEED_PREFIX in Rust
+      Buffer.from(SEEDS.split),
       creator.toBuffer(),
       Buffer.from(contentId),
-      seedBuffer,
+      seedToBuffer(seed),
     ],
     programId
   );
@@ -115,7 +153,137 @@ export function deriveDistributionVault(
   programId: PublicKey = DISTRIBUTION_PROGRAM_ID
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), splitState.toBuffer()],
+    [Buffer.from(SEEDS.distributionVault), splitState.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive distribution record PDA written by a split's `index`-th distribution
+ * Seeds: [b"distribution_record", split_state.key().as_ref(), index (u64 LE)]
+ */
+export function deriveDistributionRecord(
+  splitState: PublicKey,
+  index: number | bigint,
+  programId: PublicKey = DISTRIBUTION_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.distributionRecord), splitState.toBuffer(), seedToBuffer(index)],
+    programId
+  );
+}
+
+/**
+ * Derive access grant PDA for a rental
+ */
+export function deriveAccessGrant(
+  mint: PublicKey,
+  holder: PublicKey,
+  programId: PublicKey = ACCESS_MINT_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.accessGrant), mint.toBuffer(), holder.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive session grant PDA authorizing a device key for a holder's access
+ */
+export function deriveSessionGrant(
+  mint: PublicKey,
+  holder: PublicKey,
+  sessionKey: PublicKey,
+  programId: PublicKey = ACCESS_MINT_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.sessionGrant), mint.toBuffer(), holder.toBuffer(), sessionKey.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive platform config PDA
+ */
+export function derivePlatformConfig(
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.platformConfig)],
+    programId
+  );
+}
+
+/**
+ * Derive listing PDA
+ */
+export function deriveListing(
+  creator: PublicKey,
+  contentId: Uint8Array | Buffer,
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.listing), creator.toBuffer(), Buffer.from(contentId)],
+    programId
+  );
+}
+
+/**
+ * Derive content metadata PDA for a listing
+ */
+export function deriveContentMetadata(
+  listing: PublicKey,
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.contentMetadata), listing.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive crowdfunding campaign PDA
+ */
+export function deriveCampaign(
+  creator: PublicKey,
+  contentId: Uint8Array | Buffer,
+  seed: number | bigint,
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SEEDS.campaign),
+      creator.toBuffer(),
+      Buffer.from(contentId),
+      seedToBuffer(seed),
+    ],
+    programId
+  );
+}
+
+/**
+ * Derive campaign vault PDA holding contributions
+ */
+export function deriveCampaignVault(
+  campaign: PublicKey,
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.campaignVault), campaign.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive contribution PDA for a contributor
+ */
+export function deriveContribution(
+  campaign: PublicKey,
+  contributor: PublicKey,
+  programId: PublicKey = PAYMENT_ESCROW_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.contribution), campaign.toBuffer(), contributor.toBuffer()],
     programId
   );
 }
@@ -4,7 +4,7 @@ import { AnchorProvider, Program, type Idl } from "@coral-xyz/anchor";
 import { useMemo } from "react";
 import { useConnection, useWallet, type AnchorWallet } from "@solana/wallet-adapter-react";
 import { ACCESS_MINT_PROGRAM_ID } from "./constants";
-import accessMintIdl from "../../../access-mint/target/idl/access_mint.json";
+import { accessMintIdl } from "./idl";
 
 /**
  * Hook to get Access Mint program instance
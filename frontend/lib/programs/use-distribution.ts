@@ -5,7 +5,7 @@ import { PublicKey } from "@solana/web3.js";
 import { useMemo } from "react";
 import { useConnection, useWallet, type AnchorWallet } from "@solana/wallet-adapter-react";
 import { DISTRIBUTION_PROGRAM_ID } from "./constants";
-import distributionIdl from "../../../distribution/target/idl/distribution.json";
+import { distributionIdl } from "./idl";
 
 /**
  * Hook to get Distribution program instance
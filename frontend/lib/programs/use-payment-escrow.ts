@@ -4,7 +4,7 @@ import { AnchorProvider, Program, type Idl } from "@coral-xyz/anchor";
 import { useMemo } from "react";
 import { useConnection, useWallet, type AnchorWallet } from "@solana/wallet-adapter-react";
 import { PAYMENT_ESCROW_PROGRAM_ID } from "./constants";
-import paymentEscrowIdl from "../../../payment-escrow/target/idl/payment_escrow.json";
+import { paymentEscrowIdl } from "./idl";
 
 /**
  * Hook to get Payment Escrow program instance
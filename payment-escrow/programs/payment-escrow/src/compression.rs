@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_sha256_hasher::hashv;
+use crate::state::EscrowState;
+
+/// SPL account compression program holding compressed purchase receipts
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// SPL noop program the compression program logs tree changes through
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Anchor discriminator of the compression program's `init_empty_merkle_tree`
+const INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+
+/// Anchor discriminator of the compression program's `append`
+const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+
+/// Accounts used to write to a receipt tree
+pub struct CompressionAccounts<'info> {
+    /// Concurrent Merkle tree account (owned by the compression program)
+    pub merkle_tree: AccountInfo<'info>,
+    
+    /// Tree authority PDA (signs with `authority_seeds`)
+    pub authority: AccountInfo<'info>,
+    
+    /// Noop program
+    pub noop_program: AccountInfo<'info>,
+    
+    /// Account compression program
+    pub compression_program: AccountInfo<'info>,
+}
+
+impl<'info> CompressionAccounts<'info> {
+    /// Invoke a compression program instruction on the tree, signed by the tree authority
+    fn invoke(&self, data: Vec<u8>, authority_seeds: &[&[u8]]) -> Result<()> {
+        let instruction = Instruction {
+            program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(self.merkle_tree.key(), false),
+                AccountMeta::new_readonly(self.authority.key(), true),
+                AccountMeta::new_readonly(self.noop_program.key(), false),
+            ],
+            data,
+        };
+        
+        invoke_signed(
+            &instruction,
+            &[
+                self.merkle_tree.clone(),
+                self.authority.clone(),
+                self.noop_program.clone(),
+                self.compression_program.clone(),
+            ],
+            &[authority_seeds],
+        )?;
+        
+        Ok(())
+    }
+    
+    /// Initialize an empty concurrent Merkle tree owned by the authority
+    pub fn init_empty_tree(
+        &self,
+        authority_seeds: &[&[u8]],
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        let mut data = INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&max_depth.to_le_bytes());
+        data.extend_from_slice(&max_buffer_size.to_le_bytes());
+        
+        self.invoke(data, authority_seeds)
+    }
+    
+    /// Append a leaf to the tree
+    pub fn append(&self, authority_seeds: &[&[u8]], leaf: [u8; 32]) -> Result<()> {
+        let mut data = APPEND_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&leaf);
+        
+        self.invoke(data, authority_seeds)
+    }
+}
+
+/// Leaf committing to a completed purchase
+/// sha256(escrow || buyer || creator || content_id || access_mint || payment_mint
+///        || payment_amount (u64 LE) || created_ts (i64 LE) || seed (u64 LE))
+/// SOL purchases use the default pubkey as the payment mint
+pub fn receipt_leaf(escrow_key: &Pubkey, escrow: &EscrowState) -> [u8; 32] {
+    hashv(&[
+        escrow_key.as_ref(),
+        escrow.buyer.as_ref(),
+        escrow.creator.as_ref(),
+        escrow.content_id.as_ref(),
+        escrow.access_mint_address.unwrap_or_default().as_ref(),
+        escrow.payment_token_mint.unwrap_or_default().as_ref(),
+        &escrow.payment_amount.to_le_bytes(),
+        &escrow.created_ts.to_le_bytes(),
+        &escrow.seed.to_le_bytes(),
+    ])
+    .to_bytes()
+}
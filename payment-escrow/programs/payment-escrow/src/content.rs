@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use common::ContentId;
+use crate::state::{ContentFreeze, Listing};
+use crate::errors::*;
+
+/// Reject content ids that can't be the sha256 of any metadata blob
+pub fn validate_content_id(content_id: &[u8; 32]) -> Result<()> {
+    require!(
+        ContentId::from(*content_id).is_valid(),
+        EscrowError::InvalidContentId
+    );
+    
+    Ok(())
+}
+
+/// Reject sales of content the platform has frozen
+/// `content_freeze` is the content's freeze PDA, which only exists once it was frozen
+pub fn check_not_frozen(content_freeze: &AccountInfo) -> Result<()> {
+    if content_freeze.data_is_empty() {
+        return Ok(());
+    }
+    
+    let freeze = ContentFreeze::try_deserialize(&mut &content_freeze.try_borrow_data()?[..])?;
+    require!(!freeze.frozen, EscrowError::ContentFrozen);
+    
+    Ok(())
+}
+
+/// Reject buyers on the platform denylist unless the listing opts out
+/// `denylist_entry` is the buyer's denylist PDA, which only exists while they are denied
+pub fn check_not_denylisted(denylist_entry: &AccountInfo, listing: Option<&Listing>) -> Result<()> {
+    if listing.is_some_and(|listing| listing.ignore_denylist) {
+        return Ok(());
+    }
+    
+    require!(
+        denylist_entry.data_is_empty(),
+        EscrowError::BuyerDenylisted
+    );
+    
+    Ok(())
+}
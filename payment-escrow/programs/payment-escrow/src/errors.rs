@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum EscrowError {
-    #[msg("Invalid price amount")]
+    #[msg("Invalid price amount - zero price requires a free listing")]
     InvalidPrice,
     
     #[msg("Invalid payment amount - does not match price")]
@@ -40,4 +40,370 @@ pub enum EscrowError {
     
     #[msg("Insufficient funds in vault")]
     InsufficientFunds,
+    
+    #[msg("Creator signature required to initialize escrow")]
+    CreatorSignatureRequired,
+    
+    #[msg("Escrow terms do not match the creator's listing")]
+    ListingMismatch,
+    
+    #[msg("Listing price changed since escrow was created")]
+    ListingPriceChanged,
+    
+    #[msg("Deadline must be in the future")]
+    InvalidDeadline,
+    
+    #[msg("Campaign is not active")]
+    CampaignNotActive,
+    
+    #[msg("Campaign deadline has passed")]
+    CampaignEnded,
+    
+    #[msg("Campaign is still active")]
+    CampaignStillActive,
+    
+    #[msg("Campaign has not succeeded")]
+    CampaignNotSucceeded,
+    
+    #[msg("Campaign has not failed")]
+    CampaignNotFailed,
+    
+    #[msg("Access already claimed")]
+    AccessAlreadyClaimed,
+    
+    #[msg("Invalid rental terms")]
+    InvalidRentalTerms,
+    
+    #[msg("Rentals are not enabled for this listing")]
+    RentalsDisabled,
+    
+    #[msg("Payment mint does not match")]
+    PaymentMintMismatch,
+    
+    #[msg("Invalid access tier")]
+    InvalidTier,
+    
+    #[msg("No premium tier available for this listing")]
+    UpgradeNotAvailable,
+    
+    #[msg("URI exceeds maximum length")]
+    UriTooLong,
+    
+    #[msg("License terms do not match the listing")]
+    LicenseTermsMismatch,
+    
+    #[msg("Payment mint has an extension that is not allowed")]
+    UnsupportedMintExtension,
+    
+    #[msg("Extension mint whitelist is full")]
+    WhitelistFull,
+    
+    #[msg("Sale is not active")]
+    SaleNotActive,
+    
+    #[msg("Sale end must be after sale start")]
+    InvalidSaleSchedule,
+    
+    #[msg("No sale phase is active")]
+    NoActivePhase,
+    
+    #[msg("Price does not match the active sale phase")]
+    PhasePriceMismatch,
+    
+    #[msg("Active sale phase is sold out")]
+    PhaseSoldOut,
+    
+    #[msg("Buyer is not on the sale phase allowlist")]
+    NotAllowlisted,
+    
+    #[msg("Invalid sale phases")]
+    InvalidSalePhases,
+    
+    #[msg("Buyer must hold the listing's gating token")]
+    GateTokenRequired,
+    
+    #[msg("Listing requires a verified creator badge")]
+    CreatorNotVerified,
+    
+    #[msg("Memo exceeds maximum length")]
+    MemoTooLong,
+    
+    #[msg("Receipt mint, token account and Token-2022 program are required for a receipt")]
+    ReceiptAccountsRequired,
+    
+    #[msg("Upgrade delay is shorter than the minimum")]
+    InvalidUpgradeDelay,
+    
+    #[msg("No upgrade has been proposed")]
+    NoUpgradeProposed,
+    
+    #[msg("Upgrade timelock has not elapsed")]
+    UpgradeTimelockActive,
+    
+    #[msg("Upgrade buffer does not match the proposal")]
+    UpgradeBufferMismatch,
+    
+    #[msg("Invalid purchase cooldown")]
+    InvalidPurchaseCooldown,
+    
+    #[msg("Purchase counter is required for throttled listings")]
+    PurchaseCounterRequired,
+    
+    #[msg("Wallet must wait before purchasing this content again")]
+    PurchaseCooldownActive,
+    
+    #[msg("Content is frozen by the platform")]
+    ContentFrozen,
+    
+    #[msg("Content is not frozen")]
+    ContentNotFrozen,
+    
+    #[msg("Freeze reason exceeds maximum length")]
+    FreezeReasonTooLong,
+    
+    #[msg("Buyer is on the platform denylist")]
+    BuyerDenylisted,
+    
+    #[msg("Price is below the platform minimum")]
+    PriceBelowFloor,
+    
+    #[msg("Too many per-mint minimum prices")]
+    MinPriceListFull,
+    
+    #[msg("Platform fee exceeds maximum")]
+    InvalidPlatformFee,
+    
+    #[msg("Platform does not match the listing or the content's split")]
+    PlatformMismatch,
+    
+    #[msg("Payment mint decimals do not match the price")]
+    PaymentDecimalsMismatch,
+    
+    #[msg("Direct split is not available for this split")]
+    DirectSplitUnavailable,
+    
+    #[msg("Payment recipient does not match the split")]
+    InvalidRecipient,
+    
+    #[msg("Invalid loyalty rate")]
+    InvalidLoyaltyRate,
+    
+    #[msg("Not enough loyalty points")]
+    InsufficientLoyaltyPoints,
+    
+    #[msg("Invalid bond amount")]
+    InvalidBondAmount,
+    
+    #[msg("Creator bond is insufficient")]
+    InsufficientBond,
+    
+    #[msg("Unstake cooldown has not elapsed")]
+    UnstakeCooldownActive,
+    
+    #[msg("Creator bond is locked by open disputes")]
+    BondLocked,
+    
+    #[msg("Creator bond cannot be slashed without enough disputes")]
+    BondNotSlashable,
+    
+    #[msg("Payout would leave the vault below its rent-exempt minimum")]
+    VaultBelowRentExempt,
+    
+    #[msg("Vault holds no surplus above its escrowed payments")]
+    NoSurplus,
+    
+    #[msg("Rent recipient does not match the escrow")]
+    InvalidRentRecipient,
+    
+    #[msg("VAA is not a valid posted VAA for this redemption")]
+    InvalidVaa,
+    
+    #[msg("VAA was not emitted by the registered emitter")]
+    InvalidEmitter,
+    
+    #[msg("Invalid cross-chain payment payload")]
+    InvalidCrosschainPayload,
+    
+    #[msg("Invalid receipt tree")]
+    InvalidReceiptTree,
+    
+    #[msg("Receipt tree is full")]
+    ReceiptTreeFull,
+    
+    #[msg("Resale royalty exceeds the maximum")]
+    InvalidResaleRoyalty,
+    
+    #[msg("Access token mint does not match the content")]
+    InvalidAccessMint,
+    
+    #[msg("Rented access cannot be resold")]
+    RentalNotResellable,
+    
+    #[msg("Commission needs between one and the maximum number of non-zero milestones")]
+    InvalidMilestones,
+    
+    #[msg("Milestone is not in the required status")]
+    InvalidMilestoneStatus,
+    
+    #[msg("All milestones have been released")]
+    CommissionComplete,
+    
+    #[msg("Invalid platform sale")]
+    InvalidPlatformSale,
+    
+    #[msg("Platform sale subsidy budget is exhausted")]
+    SaleSubsidyExhausted,
+    
+    #[msg("Invalid fee rebate configuration")]
+    InvalidRebateConfig,
+    
+    #[msg("Rebate mint, creator token account and rebate authority are required for an opted-in creator")]
+    RebateAccountsRequired,
+    
+    #[msg("SPL token account is required for SPL payments")]
+    TokenAccountRequired,
+    
+    #[msg("Token program is not SPL Token or Token-2022")]
+    InvalidTokenProgram,
+    
+    #[msg("Vault token account is not owned by the vault")]
+    VaultOwnerMismatch,
+    
+    #[msg("Vault token account does not hold the payment mint")]
+    VaultMintMismatch,
+    
+    #[msg("Payment amount must be greater than zero")]
+    ZeroPaymentAmount,
+    
+    #[msg("Strict-mode listings can't be bought alongside other programs' instructions")]
+    ForeignInstruction,
+    
+    #[msg("Instructions sysvar is required for strict-mode listings")]
+    InstructionsSysvarRequired,
+    
+    #[msg("Randomness account is not a Switchboard randomness account")]
+    InvalidRandomnessAccount,
+    
+    #[msg("Randomness was not committed in the previous slot")]
+    RandomnessExpired,
+    
+    #[msg("Randomness has not been revealed in this slot")]
+    RandomnessNotResolved,
+    
+    #[msg("Invalid queue configuration")]
+    InvalidQueueConfig,
+    
+    #[msg("Queue is not in the required status")]
+    InvalidQueueStatus,
+    
+    #[msg("Queue registration has closed")]
+    QueueRegistrationClosed,
+    
+    #[msg("Queue registration is still open")]
+    QueueRegistrationOpen,
+    
+    #[msg("Randomness account does not match the committed one")]
+    RandomnessAccountMismatch,
+    
+    #[msg("Invalid mystery box")]
+    InvalidMysteryBox,
+    
+    #[msg("Mystery box escrows can only be fulfilled through their randomness reveal")]
+    MysteryBoxEscrow,
+    
+    #[msg("Mystery box has not been revealed")]
+    MysteryBoxNotRevealed,
+    
+    #[msg("Pending access claim window has expired")]
+    PendingAccessExpired,
+    
+    #[msg("Pending access can still be claimed")]
+    PendingAccessNotExpired,
+    
+    #[msg("Contribution would raise more than the group buy price")]
+    GroupBuyOverfunded,
+    
+    #[msg("Contribution is below the campaign's access threshold")]
+    ContributionBelowAccessThreshold,
+    
+    #[msg("Access threshold exceeds the group buy price")]
+    InvalidAccessThreshold,
+    
+    #[msg("DAO purchase needs 1 to 10 member wallet and token account pairs")]
+    InvalidDaoMembers,
+    
+    #[msg("Rating must be between 1 and 5")]
+    InvalidRating,
+    
+    #[msg("Invalid insurance pool configuration")]
+    InvalidInsuranceConfig,
+    
+    #[msg("Insurance payout exceeds the pool's cap or the purchase price")]
+    InsurancePayoutTooLarge,
+    
+    #[msg("Escrow payment has already been distributed")]
+    EscrowAlreadyDistributed,
+    
+    #[msg("Invalid number of accounts in batch")]
+    InvalidBatchSize,
+    
+    #[msg("Payment tolerance exceeds the maximum")]
+    InvalidPaymentTolerance,
+    
+    #[msg("Escrow is not an expired unpaid checkout")]
+    EscrowNotExpired,
+    
+    #[msg("Escrow account does not match its seeds")]
+    InvalidEscrowAccount,
+    
+    #[msg("Billing payment is not due yet")]
+    BillingNotDue,
+    
+    #[msg("Billing deposit does not cover the period amount")]
+    InsufficientDeposit,
+    
+    #[msg("Voucher is not signed by the channel's buyer")]
+    InvalidVoucherSignature,
+    
+    #[msg("Voucher amount must exceed the settled amount and stay within the deposit")]
+    InvalidVoucherAmount,
+    
+    #[msg("Payment channel close has not been requested or its grace period has not passed")]
+    ChannelCloseNotReady,
+    
+    #[msg("Referrer must differ from the buyer and come with its referrer stats account")]
+    InvalidReferrer,
+    
+    #[msg("Cancellation fee exceeds the maximum")]
+    CancellationFeeTooHigh,
+    
+    #[msg("Cancellation fees are only supported on SOL listings")]
+    CancellationFeeRequiresSol,
+    
+    #[msg("Distribution accounts are required to pay the cancellation fee")]
+    CancellationFeeAccountsRequired,
+    
+    #[msg("Fee-exempt wallet list is full")]
+    FeeExemptListFull,
+    
+    #[msg("Fee waiver account is required for fee-exempt purchases")]
+    FeeWaiverRequired,
+    
+    #[msg("Account is not an escrow in the legacy layout")]
+    NotALegacyEscrow,
+    
+    #[msg("Rented or preview access cannot be upgraded")]
+    RentalNotUpgradable,
+    
+    #[msg("Queue was not drawn before its deadline")]
+    QueueDrawExpired,
+    
+    #[msg("Commission is neither overdue nor under an upheld dispute")]
+    CommissionNotRefundable,
+    
+    #[msg("Slash exceeds what the buyer paid")]
+    SlashExceedsPayment,
+    
+    #[msg("Cross-chain payment is below the listing price")]
+    CrosschainUnderpayment,
 }
@@ -0,0 +1,1025 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a buyer opens an escrow for a purchase
+#[event]
+pub struct EscrowInitialized {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow PDA
+    pub escrow: Pubkey,
+    
+    /// Buyer who opened the escrow
+    pub buyer: Pubkey,
+    
+    /// Creator of the content
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Agreed price
+    pub price: u64,
+    
+    /// Optional payment token mint (None = SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Buyer-supplied order reference, if any
+    pub memo: Option<String>,
+    
+    /// Timestamp of initialization
+    pub timestamp: i64,
+}
+
+/// Emitted when access is claimed for free (price = 0) content
+#[event]
+pub struct FreeClaim {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow PDA used for the claim
+    pub escrow: Pubkey,
+    
+    /// Wallet that claimed access
+    pub buyer: Pubkey,
+    
+    /// Creator of the content
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+    
+    /// Timestamp of the claim
+    pub timestamp: i64,
+}
+
+/// Emitted when a soulbound purchase receipt is minted to the buyer
+#[event]
+pub struct ReceiptMinted {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow the receipt was issued for
+    pub escrow: Pubkey,
+    
+    /// Wallet holding the receipt
+    pub buyer: Pubkey,
+    
+    /// Receipt NFT mint
+    pub receipt_mint: Pubkey,
+    
+    /// Amount paid
+    pub amount: u64,
+    
+    /// Receipt metadata URI
+    pub uri: String,
+    
+    /// Timestamp of the purchase
+    pub timestamp: i64,
+    
+    /// Region/market code of the listing sold (0 = untagged)
+    pub region: u16,
+}
+
+/// Emitted when a buyer rents time-boxed access
+#[event]
+pub struct AccessRented {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Listing the rental was purchased from
+    pub listing: Pubkey,
+    
+    /// Wallet renting access
+    pub buyer: Pubkey,
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+    
+    /// Rental price paid
+    pub price: u64,
+    
+    /// Timestamp when access expires
+    pub expires_ts: i64,
+    
+    /// Region/market code of the listing (0 = untagged)
+    pub region: u16,
+}
+
+/// Emitted when a holder upgrades basic access to premium
+#[event]
+pub struct AccessUpgraded {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Listing offering the premium tier
+    pub listing: Pubkey,
+    
+    /// Wallet that upgraded
+    pub buyer: Pubkey,
+    
+    /// Burned basic-tier access mint
+    pub basic_mint: Pubkey,
+    
+    /// Minted premium-tier access mint
+    pub premium_mint: Pubkey,
+    
+    /// Price difference paid
+    pub price_paid: u64,
+}
+
+/// Emitted when the platform admin verifies a creator
+#[event]
+pub struct CreatorBadgeIssued {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Verified creator
+    pub creator: Pubkey,
+    
+    /// Non-transferable badge mint
+    pub badge_mint: Pubkey,
+    
+    /// Timestamp of issuance
+    pub timestamp: i64,
+}
+
+/// Emitted when the platform admin revokes a creator's verification
+#[event]
+pub struct CreatorBadgeRevoked {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Creator whose badge was burned
+    pub creator: Pubkey,
+    
+    /// Timestamp of revocation
+    pub timestamp: i64,
+}
+
+/// Emitted when a program upgrade is proposed, starting the timelock
+#[event]
+pub struct UpgradeProposed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Buffer holding the proposed program binary
+    pub buffer: Pubkey,
+    
+    /// SHA-256 hash of the proposed program binary
+    pub buffer_hash: [u8; 32],
+    
+    /// Timestamp of the proposal
+    pub proposed_ts: i64,
+    
+    /// Earliest timestamp the upgrade can be executed
+    pub executable_ts: i64,
+}
+
+/// Emitted when a pending program upgrade is withdrawn
+#[event]
+pub struct UpgradeCancelled {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Buffer of the withdrawn proposal
+    pub buffer: Pubkey,
+}
+
+/// Emitted when a proposed program upgrade is executed
+#[event]
+pub struct UpgradeExecuted {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Buffer the program was upgraded from
+    pub buffer: Pubkey,
+    
+    /// SHA-256 hash of the deployed program binary
+    pub buffer_hash: [u8; 32],
+    
+    /// Timestamp of the upgrade
+    pub timestamp: i64,
+}
+
+/// Emitted when the platform halts sales of a content
+#[event]
+pub struct ContentFrozen {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// Reason given for the freeze
+    pub reason: String,
+    
+    /// Timestamp of the freeze
+    pub timestamp: i64,
+}
+
+/// Emitted when the platform resumes sales of a content
+#[event]
+pub struct ContentUnfrozen {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// Timestamp of the unfreeze
+    pub timestamp: i64,
+}
+
+/// Emitted when the platform denies a wallet from buying
+#[event]
+pub struct WalletDenylisted {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Denied wallet
+    pub wallet: Pubkey,
+    
+    /// Timestamp the wallet was added
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet is removed from the platform denylist
+#[event]
+pub struct WalletRemovedFromDenylist {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Wallet allowed to buy again
+    pub wallet: Pubkey,
+    
+    /// Timestamp the wallet was removed
+    pub timestamp: i64,
+}
+
+/// Emitted when a marketplace platform is registered or its terms change
+#[event]
+pub struct PlatformUpdated {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Platform PDA
+    pub platform: Pubkey,
+    
+    /// Treasury receiving the platform's fees
+    pub treasury: Pubkey,
+    
+    /// Platform fee in basis points
+    pub fee_bps: u16,
+}
+
+/// Emitted when a purchase credits loyalty points to the buyer
+#[event]
+pub struct LoyaltyPointsEarned {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Buyer earning the points
+    pub buyer: Pubkey,
+    
+    /// Points earned by the purchase
+    pub points: u64,
+    
+    /// Points available after the purchase
+    pub balance: u64,
+}
+
+/// Emitted when a buyer converts loyalty points into a discount
+#[event]
+pub struct LoyaltyPointsRedeemed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Buyer redeeming the points
+    pub buyer: Pubkey,
+    
+    /// Points redeemed
+    pub points: u64,
+    
+    /// Discount (lamports) available for upcoming purchases
+    pub discount_lamports: u64,
+}
+
+/// Emitted when a creator stakes SOL into their bond
+#[event]
+pub struct BondStaked {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Creator staking the bond
+    pub creator: Pubkey,
+    
+    /// Lamports staked
+    pub amount: u64,
+    
+    /// Total lamports staked after the deposit
+    pub staked: u64,
+}
+
+/// Emitted when a creator withdraws part of their bond
+#[event]
+pub struct BondUnstaked {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Creator withdrawing
+    pub creator: Pubkey,
+    
+    /// Lamports withdrawn
+    pub amount: u64,
+    
+    /// Lamports still staked
+    pub staked: u64,
+}
+
+/// Emitted when the platform slashes a creator's bond to compensate a buyer
+#[event]
+pub struct BondSlashed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Creator whose bond was slashed
+    pub creator: Pubkey,
+    
+    /// Buyer compensated
+    pub recipient: Pubkey,
+    
+    /// Lamports slashed
+    pub amount: u64,
+    
+    /// Lamports still staked
+    pub staked: u64,
+}
+
+/// Emitted when funds sent to a buyer vault outside the escrow flow are swept
+#[event]
+pub struct SurplusSwept {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Buyer vault swept
+    pub buyer_vault: Pubkey,
+    
+    /// Buyer or platform admin who swept the surplus back to the buyer
+    pub authority: Pubkey,
+    
+    /// Payment token mint (None = SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount swept
+    pub amount: u64,
+    
+    /// Amount still held for the buyer's escrows
+    pub held_amount: u64,
+}
+
+/// Emitted when a payment made on another chain is redeemed for access
+#[event]
+pub struct CrosschainPaymentRedeemed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Wormhole chain id the payment was made on
+    pub emitter_chain: u16,
+    
+    /// VAA sequence number
+    pub sequence: u64,
+    
+    /// Solana wallet that received access
+    pub recipient: Pubkey,
+    
+    /// Access mint state access was granted for
+    pub access_mint_state: Pubkey,
+    
+    /// Amount paid on the foreign chain
+    pub amount: u64,
+    
+    /// Timestamp of the redemption
+    pub timestamp: i64,
+}
+
+/// Emitted when the settlement authority grants access for an off-chain (fiat) payment
+#[event]
+pub struct FiatPurchaseSettled {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow recording the purchase
+    pub escrow: Pubkey,
+    
+    /// Buyer who received access
+    pub buyer: Pubkey,
+    
+    /// Creator of the content
+    pub creator: Pubkey,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// Access mint
+    pub access_mint: Pubkey,
+    
+    /// External payment id
+    pub external_payment_id: [u8; 32],
+    
+    /// Timestamp of the settlement
+    pub timestamp: i64,
+}
+
+/// Emitted when a completed escrow is compressed into a receipt tree
+/// Carries the leaf preimage so indexers can serve proofs of purchase
+#[event]
+pub struct ReceiptCompressed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow that was compressed (now closed)
+    pub escrow: Pubkey,
+    
+    /// Merkle tree holding the receipt
+    pub merkle_tree: Pubkey,
+    
+    /// Index of the receipt leaf
+    pub leaf_index: u64,
+    
+    /// Receipt leaf hash
+    pub leaf: [u8; 32],
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Creator
+    pub creator: Pubkey,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// Access mint granted by the purchase
+    pub access_mint: Option<Pubkey>,
+    
+    /// Payment token mint (None = SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount paid
+    pub payment_amount: u64,
+    
+    /// Timestamp the escrow was created
+    pub created_ts: i64,
+    
+    /// Escrow seed
+    pub seed: u64,
+}
+
+/// Emitted when a holder escrows access tokens for resale
+#[event]
+pub struct AccessListedForResale {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Resale listing PDA
+    pub resale_listing: Pubkey,
+    
+    /// Seller
+    pub seller: Pubkey,
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+    
+    /// Number of access tokens listed
+    pub amount: u64,
+    
+    /// Asking price in lamports
+    pub price: u64,
+}
+
+/// Emitted when escrowed access tokens are bought on resale
+#[event]
+pub struct AccessResold {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Resale listing PDA (now closed)
+    pub resale_listing: Pubkey,
+    
+    /// Seller
+    pub seller: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+    
+    /// Number of access tokens sold
+    pub amount: u64,
+    
+    /// Price paid in lamports
+    pub price: u64,
+    
+    /// Portion of the price paid to the content's split
+    pub royalty: u64,
+}
+
+/// Emitted when a buyer escrows an offer for a content
+#[event]
+pub struct OfferMade {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Offer PDA
+    pub offer: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Creator the offer is made to
+    pub creator: Pubkey,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// Offered amount in lamports
+    pub amount: u64,
+}
+
+/// Emitted when a creator accepts an offer
+#[event]
+pub struct OfferAccepted {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Offer PDA (now closed)
+    pub offer: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Creator
+    pub creator: Pubkey,
+    
+    /// Content identifier
+    pub content_id: [u8; 32],
+    
+    /// Amount distributed in lamports
+    pub amount: u64,
+    
+    /// Access mint the buyer received
+    pub access_mint: Pubkey,
+}
+
+/// Emitted when a buyer disputes a submitted milestone
+#[event]
+pub struct MilestoneDisputed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Milestone state PDA
+    pub milestone_state: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Creator
+    pub creator: Pubkey,
+    
+    /// Index of the disputed milestone
+    pub index: u8,
+}
+
+/// Emitted when an approved milestone's funds are released to the creator's split
+#[event]
+pub struct MilestoneReleased {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Milestone state PDA
+    pub milestone_state: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Creator
+    pub creator: Pubkey,
+    
+    /// Index of the released milestone
+    pub index: u8,
+    
+    /// Lamports released
+    pub amount: u64,
+    
+    /// Whether this was the final milestone (access was minted)
+    pub is_final: bool,
+}
+
+/// Emitted when a buyer takes back a commission's unreleased funds
+#[event]
+pub struct CommissionRefunded {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Milestone state PDA
+    pub milestone_state: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Creator
+    pub creator: Pubkey,
+    
+    /// Lamports of the unreleased milestones returned to the buyer
+    pub amount: u64,
+    
+    /// Whether the refund followed an admin upholding the buyer's dispute (else a missed deadline)
+    pub dispute_upheld: bool,
+}
+
+/// Emitted when a purchase is discounted by a platform-wide sale
+#[event]
+pub struct PlatformSaleDiscountApplied {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow the discount applied to
+    pub escrow: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Lamports taken off the price
+    pub discount: u64,
+    
+    /// Lamports of the discount paid to the creator from the subsidy budget
+    pub subsidy: u64,
+}
+
+/// Emitted when platform fee rebate tokens are minted to a creator
+#[event]
+pub struct FeeRebatePaid {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Creator receiving the rebate
+    pub creator: Pubkey,
+    
+    /// Platform token minted
+    pub rebate_mint: Pubkey,
+    
+    /// Platform fee the rebate was computed on
+    pub platform_fee: u64,
+    
+    /// Rebate tokens minted
+    pub rebate: u64,
+}
+
+/// Emitted when a buyer registers an intent in a priority access queue
+#[event]
+pub struct QueueIntentRegistered {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Queue registered in
+    pub queue: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Registration order within the queue
+    pub index: u32,
+    
+    /// Lamports escrowed
+    pub amount: u64,
+}
+
+/// Emitted when a queue's winners are drawn
+#[event]
+pub struct QueueDrawn {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Queue drawn
+    pub queue: Pubkey,
+    
+    /// Number of registered intents
+    pub entrants: u32,
+    
+    /// Number of winners
+    pub capacity: u32,
+    
+    /// Revealed randomness the winners were selected with
+    pub winning_seed: [u8; 32],
+}
+
+/// Emitted when an entrant claims their queue result
+#[event]
+pub struct QueueResultClaimed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Queue
+    pub queue: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Whether the buyer won (minted access) or lost (refunded)
+    pub won: bool,
+    
+    /// Lamports paid (winner) or refunded (loser)
+    pub amount: u64,
+}
+
+/// Emitted when a mystery box purchase reveals the content it contains
+#[event]
+pub struct MysteryBoxRevealed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow of the purchase
+    pub escrow: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Mystery box identifier
+    pub box_id: [u8; 32],
+    
+    /// Content selected by the revealed randomness
+    pub content_id: [u8; 32],
+}
+
+/// Emitted when access is paid for and awaits a later claim
+#[event]
+pub struct PendingAccessCreated {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Pending access PDA
+    pub pending_access: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Lamports paid
+    pub amount: u64,
+    
+    /// Timestamp the claim window closes
+    pub expiry_ts: i64,
+}
+
+/// Emitted when pending access is claimed and its token minted
+#[event]
+pub struct PendingAccessClaimed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Pending access PDA
+    pub pending_access: Pubkey,
+    
+    /// Buyer
+    pub buyer: Pubkey,
+    
+    /// Account that submitted and paid for the claim (the buyer or a relayer)
+    pub payer: Pubkey,
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+}
+
+/// Emitted when a DAO treasury buys access for its members
+#[event]
+pub struct DaoPurchaseCompleted {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Treasury that paid
+    pub treasury: Pubkey,
+    
+    /// Listing bought
+    pub listing: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Number of members minted access
+    pub member_count: u8,
+    
+    /// Total lamports paid
+    pub amount: u64,
+}
+
+/// Emitted when a verified purchaser rates a listing
+#[event]
+pub struct ContentRated {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Listing rated
+    pub listing: Pubkey,
+    
+    /// Buyer who rated
+    pub buyer: Pubkey,
+    
+    /// Rating from 1 to 5
+    pub rating: u8,
+    
+    /// Ratings recorded on the listing, including this one
+    pub rating_count: u32,
+    
+    /// Listing's average rating scaled by 100
+    pub average_rating_x100: u64,
+}
+
+/// Emitted when a slice of a platform fee is diverted into the insurance pool
+#[event]
+pub struct InsurancePremiumCollected {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow of the sale
+    pub escrow: Pubkey,
+    
+    /// Platform fee the premium was taken from
+    pub platform_fee: u64,
+    
+    /// Lamports added to the pool
+    pub premium: u64,
+}
+
+/// Emitted when the insurance pool refunds a buyer
+#[event]
+pub struct InsuranceClaimPaid {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow of the disputed purchase
+    pub escrow: Pubkey,
+    
+    /// Buyer refunded
+    pub buyer: Pubkey,
+    
+    /// Lamports paid out
+    pub amount: u64,
+}
+
+/// Emitted when the part of a payment above the amount due is returned to the buyer
+#[event]
+pub struct OverpaymentRefunded {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow of the purchase
+    pub escrow: Pubkey,
+    
+    /// Buyer refunded
+    pub buyer: Pubkey,
+    
+    /// Amount the buyer paid
+    pub payment_amount: u64,
+    
+    /// Excess returned to the buyer
+    pub refunded: u64,
+}
+
+/// Emitted when a buyer authorizes recurring billing for a listing
+#[event]
+pub struct BillingAgreementCreated {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Billing agreement PDA
+    pub billing_agreement: Pubkey,
+    
+    /// Buyer billed
+    pub buyer: Pubkey,
+    
+    /// Listing billed
+    pub listing: Pubkey,
+    
+    /// Lamports pulled each period
+    pub amount: u64,
+    
+    /// Billing period in seconds
+    pub period: i64,
+}
+
+/// Emitted when the platform pulls a period's payment and renews access
+#[event]
+pub struct BillingPaymentPulled {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Billing agreement PDA
+    pub billing_agreement: Pubkey,
+    
+    /// Buyer billed
+    pub buyer: Pubkey,
+    
+    /// Lamports pulled
+    pub amount: u64,
+    
+    /// When the renewed access expires (and the next payment is due)
+    pub next_pull_ts: i64,
+}
+
+/// Emitted when a buyer cancels recurring billing
+#[event]
+pub struct BillingAgreementCancelled {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Billing agreement PDA
+    pub billing_agreement: Pubkey,
+    
+    /// Buyer refunded
+    pub buyer: Pubkey,
+    
+    /// Unspent deposit returned to the buyer
+    pub refunded: u64,
+}
+
+/// Emitted when a buyer opens a pay-per-view payment channel
+#[event]
+pub struct PaymentChannelOpened {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Payment channel PDA
+    pub payment_channel: Pubkey,
+    
+    /// Buyer funding the channel
+    pub buyer: Pubkey,
+    
+    /// Listing metered
+    pub listing: Pubkey,
+    
+    /// Lamports deposited
+    pub deposit: u64,
+}
+
+/// Emitted when a creator settles a buyer's usage voucher
+#[event]
+pub struct PaymentChannelSettled {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Payment channel PDA
+    pub payment_channel: Pubkey,
+    
+    /// Cumulative usage the voucher covers
+    pub cumulative_amount: u64,
+    
+    /// Lamports distributed by this settlement
+    pub settled: u64,
+}
+
+/// Emitted when a buyer closes a payment channel
+#[event]
+pub struct PaymentChannelClosed {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Payment channel PDA
+    pub payment_channel: Pubkey,
+    
+    /// Buyer refunded
+    pub buyer: Pubkey,
+    
+    /// Unsettled deposit returned to the buyer
+    pub refunded: u64,
+}
+
+/// Emitted when buy_and_mint receives a purchase payment
+#[event]
+pub struct PaymentReceived {
+    /// Platform-wide sequence number, one higher than the previous event
+    pub event_sequence: u64,
+    
+    /// Escrow of the purchase
+    pub escrow: Pubkey,
+    
+    /// Buyer paying
+    pub buyer: Pubkey,
+    
+    /// Payment token mint (None = SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount paid
+    pub amount: u64,
+    
+    /// Referrer the purchase is attributed to, if any
+    pub referrer: Option<Pubkey>,
+    
+    /// Region/market code of the listing sold (0 = untagged)
+    pub region: u16,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Deny a wallet from buying on the platform
+pub fn add_to_denylist(ctx: Context<AddToDenylist>) -> Result<()> {
+    let entry = &mut ctx.accounts.denylist_entry;
+    let now = Clock::get()?.unix_timestamp;
+    
+    entry.wallet = ctx.accounts.wallet.key();
+    entry.added_ts = now;
+    entry.bump = ctx.bumps.denylist_entry;
+    
+    emit!(WalletDenylisted {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        wallet: entry.wallet,
+        timestamp: now,
+    });
+    
+    msg!("Wallet denylisted: {}", entry.wallet);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddToDenylist<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// The wallet being denied
+    /// CHECK: Any wallet can be denylisted
+    pub wallet: UncheckedAccount<'info>,
+    
+    /// Denylist entry PDA
+    #[account(
+        init,
+        payer = admin,
+        space = DenylistEntry::LEN,
+        seeds = [DenylistEntry::SEED_PREFIX, wallet.key().as_ref()],
+        bump
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Approve the submitted milestone, allowing its funds to be released (buyer only)
+pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+    let milestone_state = &mut ctx.accounts.milestone_state;
+    let index = milestone_state.current;
+    let milestone = milestone_state.current_milestone()?;
+    
+    require!(
+        milestone.status == MilestoneStatus::Submitted,
+        EscrowError::InvalidMilestoneStatus
+    );
+    
+    milestone.status = MilestoneStatus::Approved;
+    
+    msg!("Milestone {} approved", index);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    /// The buyer who commissioned the content
+    pub buyer: Signer<'info>,
+    
+    /// Milestone state PDA
+    #[account(
+        mut,
+        seeds = [
+            MilestoneState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            milestone_state.content_id.as_ref(),
+            milestone_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub milestone_state: Account<'info, MilestoneState>,
+}
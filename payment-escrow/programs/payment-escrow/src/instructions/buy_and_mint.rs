@@ -1,92 +1,500 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer, System};
-use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer};
-use anchor_spl::associated_token::AssociatedToken;
-use access_mint::{
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface;
+use access_mint_cpi::{
     program::AccessMint,
+    state::AccessMintState,
     cpi::accounts::MintAccess as AccessMintAccounts,
     cpi::mint_access,
 };
-use distribution::{
+use distribution_cpi::{
     program::Distribution,
+    state::SplitState,
     cpi::accounts::Distribute as DistributeAccounts,
     cpi::distribute,
 };
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+use crate::payment::*;
+use crate::receipt::*;
+use crate::introspection::*;
 
-/// Main atomic instruction - handles payment to escrow vault
+/// Main atomic instruction - handles payment to the buyer vault
 /// In a complete implementation, this would also CPI to Access Mint and Revenue Split programs
 pub fn buy_and_mint<'info>(
     ctx: Context<'_, '_, '_, 'info, BuyAndMint<'info>>,
     payment_amount: u64,
+    license_terms_hash: [u8; 32],
+    allowlist_proof: Vec<[u8; 32]>,
+    receipt_uri: Option<String>,
+    direct_split: bool,
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
-    let escrow = &mut ctx.accounts.escrow_state;
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
     
-    // Validate escrow status
-    require!(
-        escrow.status == EscrowStatus::Initialized,
-        EscrowError::InvalidEscrowStatus
-    );
+    // Purchases are authorized by the buyer, or by the session key of the buyer deposit
+    // paying for them; accounts created along the way are paid for by the payer
+    if !ctx.accounts.buyer.is_signer {
+        let (Some(buyer_deposit), Some(session_key)) = (
+            ctx.accounts.buyer_deposit.as_ref(),
+            ctx.accounts.session_key.as_ref(),
+        ) else {
+            return err!(EscrowError::Unauthorized);
+        };
+        require!(
+            buyer_deposit.is_session_key(session_key.key, Clock::get()?.unix_timestamp),
+            EscrowError::Unauthorized
+        );
+    }
+    let fee_payer = ctx.accounts.payer.to_account_info();
     
-    // Validate payment amount matches price
-    require!(
-        payment_amount == escrow.price,
-        EscrowError::InvalidPaymentAmount
-    );
+    let escrow = &mut ctx.accounts.escrow_state;
     
-    // Validate buyer
-    require!(
-        ctx.accounts.buyer.key() == escrow.buyer,
-        EscrowError::InvalidBuyer
-    );
+    // Mystery box purchases are paid at commit and fulfilled through their reveal
+    require!(escrow.randomness_account.is_none(), EscrowError::MysteryBoxEscrow);
     
-    // Transfer payment to vault
-    if escrow.payment_token_mint.is_none() {
-        // SOL payment
-        transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.buyer.to_account_info(),
-                    to: ctx.accounts.vault.to_account_info(),
-                },
-            ),
-            payment_amount,
-        )?;
-    } else {
-        // SPL token payment
-        // Validate that token accounts are provided
+    // Validate escrow status; it only moves once access is minted
+    let completed_status = escrow.status.transition(EscrowStatus::Completed)?;
+    
+    // Apply the listing's price change policy to escrows created before a price update
+    if let Some(listing_key) = escrow.listing {
+        let listing = ctx.accounts.listing.as_mut().ok_or(EscrowError::ListingMismatch)?;
+        require!(listing.key() == listing_key, EscrowError::ListingMismatch);
+        
+        // Timed drops can only be bought while the sale window is open
+        let now = Clock::get()?.unix_timestamp;
+        require!(listing.is_on_sale(now), EscrowError::SaleNotActive);
+        
+        // Phased listings sell through the phase active now
+        // With an attempt log, sold-out and allowlist rejections are logged instead of failing
+        if let Err(error) = listing.record_phase_purchase(now, escrow.price, &escrow.buyer, &allowlist_proof) {
+            let outcome = AttemptOutcome::from_error(&error);
+            match (ctx.accounts.attempt_log.as_deref_mut(), outcome) {
+                (Some(attempt_log), Some(outcome)) => {
+                    attempt_log.record(escrow.buyer, outcome, now)?;
+                    msg!("Purchase attempt rejected and logged: {}", error);
+                    
+                    return Ok(());
+                }
+                _ => return Err(error),
+            }
+        }
+        
+        // Gated listings are only sold to holders of the gating token
+        listing.check_gate(ctx.accounts.gate_token_account.as_deref())?;
+        
+        // Listings requiring a verified creator stop selling once the badge is revoked
+        listing.check_creator_badge(ctx.accounts.creator_badge.as_deref())?;
+        
+        // Platform listings must be paid out to the referenced platform
+        listing.check_platform(ctx.accounts.platform.as_ref(), &ctx.accounts.split_state)?;
+        
+        // Strict-mode listings can't be bought alongside other programs' instructions
+        if listing.strict_mode {
+            let instructions_sysvar = ctx
+                .accounts
+                .instructions_sysvar
+                .as_ref()
+                .ok_or(EscrowError::InstructionsSysvarRequired)?;
+            check_no_foreign_instructions(instructions_sysvar)?;
+        }
+        
+        // Throttled listings limit how often one wallet can buy this content
+        if let Some(purchase_counter) = ctx.accounts.purchase_counter.as_mut() {
+            purchase_counter.buyer = escrow.buyer;
+            purchase_counter.content_id = escrow.content_id;
+            purchase_counter.bump = ctx.bumps.purchase_counter.unwrap_or_default();
+        }
+        listing.check_purchase_cooldown(ctx.accounts.purchase_counter.as_deref_mut(), now)?;
+        
+        // The buyer must accept the license terms currently set by the creator
         require!(
-            ctx.accounts.buyer_token_account.key() != System::id(),
-            EscrowError::InvalidVault
+            listing.license_terms_hash == license_terms_hash,
+            EscrowError::LicenseTermsMismatch
         );
+        
+        if listing.price_version != escrow.price_version {
+            require!(
+                listing.price_change_policy == PriceChangePolicy::Grandfather,
+                EscrowError::ListingPriceChanged
+            );
+            msg!("Honoring grandfathered price {} (listing price version {})", 
+                escrow.price, listing.price_version);
+        }
+        
+        if let Some(attempt_log) = ctx.accounts.attempt_log.as_deref_mut() {
+            attempt_log.record(escrow.buyer, AttemptOutcome::Success, now)?;
+        }
+    }
+    
+    // Denylisted wallets can't buy, unless the escrow's own listing ignores the denylist;
+    // any other listing passed in can't waive it
+    let escrow_listing = ctx
+        .accounts
+        .listing
+        .as_ref()
+        .filter(|listing| escrow.listing == Some(listing.key()));
+    check_not_denylisted(&ctx.accounts.denylist_entry, escrow_listing.map(|listing| &**listing))?;
+    
+    // Purchases through a listing carry its region tag into events and receipts
+    let region = match (escrow.listing, ctx.accounts.listing.as_deref()) {
+        (Some(_), Some(listing)) => listing.region,
+        _ => 0,
+    };
+    
+    // Redeemed loyalty points discount SOL purchases
+    if let Some(buyer_loyalty) = ctx.accounts.buyer_loyalty.as_mut() {
+        buyer_loyalty.buyer = escrow.buyer;
+        buyer_loyalty.bump = ctx.bumps.buyer_loyalty.unwrap_or_default();
+    }
+    let discount = match ctx.accounts.buyer_loyalty.as_mut() {
+        Some(buyer_loyalty) if escrow.payment_token_mint.is_none() => {
+            buyer_loyalty.take_discount(escrow.price)
+        }
+        _ => 0,
+    };
+    
+    let loyalty_price = escrow
+        .price
+        .checked_sub(discount)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    // Opted-in listings sell at the platform-wide sale discount while a sale is active
+    let sale_discount = match (ctx.accounts.platform_sale.as_ref(), ctx.accounts.listing.as_deref()) {
+        (Some(platform_sale), Some(listing))
+            if escrow.listing.is_some()
+                && listing.platform_sale_opt_in
+                && escrow.payment_token_mint.is_none() =>
+        {
+            platform_sale.discount(loyalty_price, Clock::get()?.unix_timestamp)?
+        }
+        _ => 0,
+    };
+    
+    // A subsidized sale pays the discount to the creator out of its own budget
+    let subsidy = match ctx.accounts.platform_sale.as_ref() {
+        Some(platform_sale) if platform_sale.subsidized => sale_discount,
+        _ => 0,
+    };
+    
+    // Validate payment amount matches price, less any loyalty and sale discounts,
+    // within the platform's payment tolerance
+    let discounted_price = loyalty_price
+        .checked_sub(sale_discount)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    let offered_amount = payment_amount;
+    let payment_amount = ctx
+        .accounts
+        .platform_config
+        .settle_payment(offered_amount, discounted_price)?;
+    
+    // Free listings (price = 0) and fully discounted purchases skip payment and distribution
+    let is_free_claim = payment_amount == 0 && subsidy == 0;
+    
+    // Purchases by or sales from fee-exempt wallets pay the platform fee to the creator
+    let fee_waived = ctx.accounts.platform_config.is_fee_exempt(&escrow.buyer)
+        || ctx.accounts.platform_config.is_fee_exempt(&escrow.creator);
+    
+    // Transfer payment to vault, tracking what arrives after any Token-2022 transfer fee
+    let received_amount = if is_free_claim {
+        msg!("Free claim - no payment required");
+        0
+    } else if direct_split {
+        // Subsidies are paid through the vault, so subsidized sales can't bypass it,
+        // and deposits only pay into the vault
         require!(
-            ctx.accounts.vault_token_account.key() != System::id(),
-            EscrowError::InvalidVault
+            subsidy == 0 && ctx.accounts.buyer_deposit.is_none(),
+            EscrowError::DirectSplitUnavailable
         );
+        
+        // The escrow program pays the platform share itself here, so a slice of it can go
+        // to the insurance pool (SOL only, as the pool holds lamports)
+        let platform_fee = ctx.accounts.split_state.platform_fee_for(payment_amount, fee_waived)?;
+        let insurance_premium = match ctx.accounts.insurance_pool.as_ref() {
+            Some(insurance_pool) if escrow.payment_token_mint.is_none() => insurance_pool.premium(platform_fee)?,
+            _ => 0,
+        };
+        
+        // Nothing is held: every share goes from the buyer straight to its recipient
+        escrow.mark_distributed(Clock::get()?.unix_timestamp)?;
+        pay_direct_split(
+            DirectSplitAccounts {
+                split_state: &ctx.accounts.split_state,
+                is_sol_payment: escrow.payment_token_mint.is_none(),
+                buyer: ctx.accounts.buyer.to_account_info(),
+                buyer_token_account: ctx.accounts.buyer_token_account.to_account_info(),
+                payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+                creator: ctx.accounts.creator.to_account_info(),
+                creator_token_account: ctx.accounts.creator_token_account.to_account_info(),
+                platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                platform_treasury_token_account: ctx.accounts.platform_treasury_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                insurance_pool: ctx.accounts.insurance_pool.as_ref().map(|pool| pool.to_account_info()),
+                insurance_premium,
+                fee_waived,
+            },
+            ctx.remaining_accounts,
+            payment_amount,
+        )?;
+        
+        if insurance_premium > 0 {
+            let insurance_pool = ctx.accounts.insurance_pool.as_mut().ok_or(EscrowError::InvalidInsuranceConfig)?;
+            insurance_pool.total_contributed = insurance_pool
+                .total_contributed
+                .checked_add(insurance_premium)
+                .ok_or(EscrowError::NumericalOverflow)?;
+            
+            emit!(InsurancePremiumCollected {
+                event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+                escrow: escrow.key(),
+                platform_fee,
+                premium: insurance_premium,
+            });
+        }
+        0
+    } else if let Some(buyer_deposit) = ctx.accounts.buyer_deposit.as_mut() {
+        // Draw the exact amount due from the buyer's deposit
         require!(
-            ctx.accounts.token_program.key() == anchor_spl::token::ID,
-            EscrowError::InvalidVault
+            buyer_deposit.payment_token_mint == escrow.payment_token_mint,
+            EscrowError::PaymentMintMismatch
         );
+        buyer_deposit.debit(payment_amount)?;
         
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                SplTransfer {
-                    from: ctx.accounts.buyer_token_account.to_account_info(),
-                    to: ctx.accounts.vault_token_account.to_account_info(),
-                    authority: ctx.accounts.buyer.to_account_info(),
-                },
-            ),
+        let spl = if escrow.payment_token_mint.is_none() {
+            None
+        } else {
+            require!(
+                ctx.accounts.vault_token_account.key() != System::id(),
+                EscrowError::TokenAccountRequired
+            );
+            let deposit_token_account = ctx
+                .accounts
+                .deposit_token_account
+                .as_ref()
+                .ok_or(EscrowError::TokenAccountRequired)?;
+            validate_vault_token_account(
+                deposit_token_account,
+                &buyer_deposit.key(),
+                &escrow.payment_token_mint.unwrap_or_default(),
+            )?;
+            validate_vault_token_account(
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.buyer_vault.key(),
+                &escrow.payment_token_mint.unwrap_or_default(),
+            )?;
+            validate_payment_units(
+                &ctx.accounts.payment_token_mint,
+                escrow.payment_decimals,
+                &[deposit_token_account, &ctx.accounts.vault_token_account],
+            )?;
+            
+            Some(SplPayment {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.payment_token_mint.to_account_info(),
+                from: deposit_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+            })
+        };
+        
+        let buyer_key = escrow.buyer;
+        let seeds = &[
+            BuyerDeposit::SEED_PREFIX,
+            buyer_key.as_ref(),
+            BuyerVault::mint_seed(&escrow.payment_token_mint).as_ref(),
+            &[buyer_deposit.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        refund_from_vault(
+            buyer_deposit.to_account_info(),
+            ctx.accounts.buyer_vault.to_account_info(),
+            spl,
+            signer_seeds,
             payment_amount,
+        )?
+    } else {
+        let spl = if escrow.payment_token_mint.is_none() {
+            None
+        } else {
+            // Validate that token accounts are provided
+            require!(
+                ctx.accounts.buyer_token_account.key() != System::id(),
+                EscrowError::TokenAccountRequired
+            );
+            require!(
+                ctx.accounts.vault_token_account.key() != System::id(),
+                EscrowError::TokenAccountRequired
+            );
+            validate_vault_token_account(
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.buyer_vault.key(),
+                &escrow.payment_token_mint.unwrap_or_default(),
+            )?;
+            validate_payment_units(
+                &ctx.accounts.payment_token_mint,
+                escrow.payment_decimals,
+                &[
+                    &ctx.accounts.buyer_token_account,
+                    &ctx.accounts.vault_token_account,
+                ],
+            )?;
+            
+            Some(SplPayment {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.payment_token_mint.to_account_info(),
+                from: ctx.accounts.buyer_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+            })
+        };
+        
+        // With overpayment refunds on, the whole payment is taken and the excess returned
+        let overpayment = if ctx.accounts.platform_config.refund_overpayment {
+            offered_amount
+                .checked_sub(payment_amount)
+                .ok_or(EscrowError::NumericalOverflow)?
+        } else {
+            0
+        };
+        
+        let received_amount = pay_into_vault(
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            spl,
+            payment_amount
+                .checked_add(overpayment)
+                .ok_or(EscrowError::NumericalOverflow)?,
         )?;
+        
+        if overpayment > 0 {
+            let spl = if escrow.payment_token_mint.is_none() {
+                None
+            } else {
+                Some(SplPayment {
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.payment_token_mint.to_account_info(),
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.buyer_token_account.to_account_info(),
+                })
+            };
+            
+            let buyer_key = escrow.buyer;
+            let seeds = &[
+                BuyerVault::SEED_PREFIX,
+                buyer_key.as_ref(),
+                BuyerVault::mint_seed(&escrow.payment_token_mint).as_ref(),
+                &[escrow.vault_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+            
+            refund_from_vault(
+                ctx.accounts.buyer_vault.to_account_info(),
+                ctx.accounts.buyer.to_account_info(),
+                spl,
+                signer_seeds,
+                overpayment,
+            )?;
+            
+            emit!(OverpaymentRefunded {
+                event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+                escrow: escrow.key(),
+                buyer: escrow.buyer,
+                payment_amount: offered_amount,
+                refunded: overpayment,
+            });
+            
+            received_amount
+                .checked_sub(overpayment)
+                .ok_or(EscrowError::NumericalOverflow)?
+        } else {
+            received_amount
+        }
+    };
+    
+    // Top the vault up with the sale subsidy so the creator is paid the undiscounted price
+    let received_amount = if subsidy > 0 {
+        let platform_sale = ctx.accounts.platform_sale.as_mut().ok_or(EscrowError::InvalidPlatformSale)?;
+        require!(
+            platform_sale.subsidy_budget(&platform_sale.to_account_info())? >= subsidy,
+            EscrowError::SaleSubsidyExhausted
+        );
+        platform_sale.sub_lamports(subsidy)?;
+        ctx.accounts.buyer_vault.add_lamports(subsidy)?;
+        platform_sale.subsidy_paid = platform_sale
+            .subsidy_paid
+            .checked_add(subsidy)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        
+        received_amount
+            .checked_add(subsidy)
+            .ok_or(EscrowError::NumericalOverflow)?
+    } else {
+        received_amount
+    };
+    
+    if sale_discount > 0 {
+        emit!(PlatformSaleDiscountApplied {
+            event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+            escrow: escrow.key(),
+            buyer: escrow.buyer,
+            discount: sale_discount,
+            subsidy,
+        });
     }
     
-    // Update escrow state
+    // Update escrow state and the shared vault balance
     escrow.payment_amount = payment_amount;
+    ctx.accounts.buyer_vault.deposit(received_amount)?;
+    
+    // Referred purchases are attributed to the referrer even when no referral fee is paid
+    if let Some(referrer) = referrer {
+        require!(referrer != escrow.buyer, EscrowError::InvalidReferrer);
+        
+        let referrer_stats = ctx.accounts.referrer_stats.as_mut().ok_or(EscrowError::InvalidReferrer)?;
+        referrer_stats.referrer = referrer;
+        referrer_stats.bump = ctx.bumps.referrer_stats.unwrap_or_default();
+        let sol_amount = if escrow.payment_token_mint.is_none() { payment_amount } else { 0 };
+        referrer_stats.record(sol_amount, Clock::get()?.unix_timestamp)?;
+    }
     
-    msg!("Payment of {} received from buyer: {}", payment_amount, ctx.accounts.buyer.key());
+    emit!(PaymentReceived {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        payment_token_mint: escrow.payment_token_mint,
+        amount: payment_amount,
+        referrer,
+        region,
+    });
+    
+    msg!("Payment of {} received", payment_amount);
+    
+    // SOL purchases earn loyalty points on what was actually paid
+    if let Some(buyer_loyalty) = ctx.accounts.buyer_loyalty.as_mut() {
+        if escrow.payment_token_mint.is_none() && payment_amount > 0 {
+            let points = buyer_loyalty.credit(payment_amount, ctx.accounts.platform_config.loyalty_rate_bps)?;
+            
+            emit!(LoyaltyPointsEarned {
+                event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+                buyer: buyer_loyalty.buyer,
+                points,
+                balance: buyer_loyalty.points,
+            });
+        }
+    }
+    
+    // Persist the completed status before minting so the escrow serves as the purchase
+    // receipt access mints can require; the access mint is recorded right after
+    escrow.status = completed_status;
+    escrow.exit(&crate::ID)?;
     
     // CPI to Access Mint program to mint access token to buyer
     mint_access(
@@ -94,11 +502,20 @@ pub fn buy_and_mint<'info>(
             ctx.accounts.access_mint_program.to_account_info(),
             AccessMintAccounts {
                 buyer: ctx.accounts.buyer.to_account_info(),
-                payer: ctx.accounts.buyer.to_account_info(),
+                payer: fee_payer.clone(),
                 access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
                 mint: ctx.accounts.access_mint.to_account_info(),
                 mint_authority: ctx.accounts.mint_authority.to_account_info(),
                 buyer_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+                purchase_receipt: Some(escrow.to_account_info()),
+                access_minter: None,
+                preview_grant: ctx.accounts.preview_grant.as_ref().map(|grant| grant.to_account_info()),
+                access_collection: None,
+                access_metadata: None,
+                collection_mint: None,
+                collection_metadata: None,
+                collection_master_edition: None,
+                token_metadata_program: None,
                 token_program: ctx.accounts.access_token_program.to_account_info(),
                 associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
                 system_program: ctx.accounts.system_program.to_account_info(),
@@ -106,73 +523,154 @@ pub fn buy_and_mint<'info>(
         ),
     )?;
     
-    // Store the access mint address in escrow
+    // Store the access mint address and agreed license terms in escrow
     escrow.access_mint_address = Some(ctx.accounts.access_mint.key());
-    escrow.status = EscrowStatus::Completed;
-    
-    msg!("Access token minted to buyer: {}", ctx.accounts.buyer.key());
+    escrow.license_terms_hash = license_terms_hash;
     
-    // Transfer funds from escrow vault to distribution vault before distributing
-    if escrow.payment_token_mint.is_none() {
-        // SOL payment: Transfer from escrow vault to distribution vault
-        let escrow_key = escrow.key();
-        let vault_bump = ctx.bumps.vault;
-        let vault_seeds = &[
-            b"vault".as_ref(),
-            escrow_key.as_ref(),
-            &[vault_bump],
+    // Optionally mint a soulbound receipt NFT recording the purchase for accounting
+    if let Some(receipt_uri) = receipt_uri {
+        let (Some(receipt_mint), Some(receipt_token_account), Some(receipt_token_program)) = (
+            ctx.accounts.receipt_mint.as_ref(),
+            ctx.accounts.receipt_token_account.as_ref(),
+            ctx.accounts.receipt_token_program.as_ref(),
+        ) else {
+            return err!(EscrowError::ReceiptAccountsRequired);
+        };
+        
+        let timestamp = Clock::get()?.unix_timestamp;
+        let payment_mint = escrow.payment_token_mint.unwrap_or(System::id());
+        let fields = vec![
+            ("escrow".to_string(), escrow.key().to_string()),
+            ("content_id".to_string(), to_hex(&escrow.content_id)),
+            ("amount".to_string(), payment_amount.to_string()),
+            ("payment_mint".to_string(), payment_mint.to_string()),
+            ("timestamp".to_string(), timestamp.to_string()),
+            ("region".to_string(), region.to_string()),
         ];
-        let signer_seeds = &[&vault_seeds[..]];
         
-        // Use system program transfer to properly handle account creation and rent
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: ctx.accounts.distribution_vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            payment_amount,
+        let seed_bytes = escrow.seed.to_le_bytes();
+        let escrow_seeds: &[&[u8]] = &[
+            EscrowState::SEED_PREFIX,
+            escrow.buyer.as_ref(),
+            escrow.content_id.as_ref(),
+            seed_bytes.as_ref(),
+            &[escrow.bump],
+        ];
+        
+        mint_receipt(
+            &ReceiptAccounts {
+                mint: receipt_mint.to_account_info(),
+                token_account: receipt_token_account.to_account_info(),
+                owner: ctx.accounts.buyer.to_account_info(),
+                authority: escrow.to_account_info(),
+                token_program: receipt_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            escrow_seeds,
+            receipt_uri.clone(),
+            fields,
         )?;
         
-        msg!("Transferred {} lamports from escrow vault to distribution vault", payment_amount);
+        emit!(ReceiptMinted {
+            event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+            escrow: escrow.key(),
+            buyer: escrow.buyer,
+            receipt_mint: receipt_mint.key(),
+            amount: payment_amount,
+            uri: receipt_uri,
+            timestamp,
+            region,
+        });
+    }
+    
+    if direct_split && !is_free_claim {
+        pay_fee_rebate(ctx.accounts, ctx.bumps.rebate_authority, payment_amount, fee_waived)?;
+        
+        msg!("Buy and mint completed with direct split");
+        
+        return Ok(());
+    }
+    
+    if is_free_claim {
+        emit!(FreeClaim {
+            event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+            escrow: escrow.key(),
+            buyer: escrow.buyer,
+            creator: escrow.creator,
+            content_id: escrow.content_id,
+            access_mint: ctx.accounts.access_mint.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Free claim completed successfully");
+        
+        return Ok(());
+    }
+    
+    // Transfer funds from buyer vault to distribution vault before distributing
+    let distribution_amount = if escrow.payment_token_mint.is_none() {
+        // SOL payment: the buyer vault is program-owned, so lamports move directly
+        BuyerVault::check_sol_payout(&ctx.accounts.buyer_vault.to_account_info(), received_amount)?;
+        ctx.accounts.buyer_vault.sub_lamports(received_amount)?;
+        ctx.accounts.distribution_vault.add_lamports(received_amount)?;
+        
+        msg!("Transferred {} lamports from buyer vault to distribution vault", received_amount);
+        received_amount
     } else {
-        // SPL token payment: Transfer from escrow vault token account to distribution vault token account
-        let escrow_key = escrow.key();
-        let vault_bump = ctx.bumps.vault;
+        // SPL token payment: Transfer from buyer vault token account to distribution vault token account
+        let buyer_key = escrow.buyer;
         let vault_seeds = &[
-            b"vault".as_ref(),
-            escrow_key.as_ref(),
-            &[vault_bump],
+            BuyerVault::SEED_PREFIX,
+            buyer_key.as_ref(),
+            BuyerVault::mint_seed(&escrow.payment_token_mint).as_ref(),
+            &[escrow.vault_bump],
         ];
         let signer_seeds = &[&vault_seeds[..]];
         
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                SplTransfer {
-                    from: ctx.accounts.vault_token_account.to_account_info(),
-                    to: ctx.accounts.distribution_vault_token_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            payment_amount,
+        let distribution_amount = transfer_payment(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.payment_token_mint.to_account_info(),
+            ctx.accounts.distribution_vault_token_account.to_account_info(),
+            ctx.accounts.buyer_vault.to_account_info(),
+            signer_seeds,
+            received_amount,
         )?;
         
-        msg!("Transferred {} tokens from escrow vault to distribution vault", payment_amount);
-    }
+        msg!("Transferred {} tokens from buyer vault to distribution vault", received_amount);
+        distribution_amount
+    };
+    
+    ctx.accounts.buyer_vault.release(received_amount)?;
+    escrow.mark_distributed(Clock::get()?.unix_timestamp)?;
+    
+    // The fee waiver PDA signs the distribution to waive the platform fee
+    let fee_waiver = if fee_waived {
+        Some(
+            ctx.accounts
+                .fee_waiver
+                .as_ref()
+                .ok_or(EscrowError::FeeWaiverRequired)?
+                .to_account_info(),
+        )
+    } else {
+        None
+    };
+    let fee_waiver_bump = [ctx.bumps.fee_waiver.unwrap_or_default()];
+    let fee_waiver_seeds: &[&[u8]] = &[PlatformConfig::FEE_WAIVER_SEED, &fee_waiver_bump];
     
     // CPI to Distribution program to distribute funds from distribution vault
     let remaining_accounts = ctx.remaining_accounts.to_vec();
     
     distribute(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.distribution_program.to_account_info(),
             DistributeAccounts {
+                payer: fee_payer,
                 split_state: ctx.accounts.split_state.to_account_info(),
+                distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
                 vault: ctx.accounts.distribution_vault.to_account_info(),
                 creator: ctx.accounts.creator.to_account_info(),
                 platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
@@ -182,24 +680,258 @@ pub fn buy_and_mint<'info>(
                 platform_treasury_token_account: ctx.accounts.platform_treasury_token_account.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
                 system_program: ctx.accounts.system_program.to_account_info(),
+                fee_waiver,
             },
+            &[fee_waiver_seeds],
         )
         .with_remaining_accounts(remaining_accounts),
-        payment_amount,
+        distribution_amount,
     )?;
     
-    msg!("Funds distributed to creator, platform, and collaborators");
+    pay_fee_rebate(ctx.accounts, ctx.bumps.rebate_authority, distribution_amount, fee_waived)?;
     
     msg!("Buy and mint completed successfully");
     
     Ok(())
 }
 
+/// Mint the platform fee rebate on a distributed `amount` to an opted-in creator
+/// The creator rebate PDA is always passed, so an opted-in creator can't be skipped;
+/// creators who haven't opted in have no account at that address
+fn pay_fee_rebate(
+    accounts: &mut BuyAndMint<'_>,
+    rebate_authority_bump: Option<u8>,
+    amount: u64,
+    fee_waived: bool,
+) -> Result<()> {
+    if accounts.creator_rebate.data_is_empty() {
+        return Ok(());
+    }
+    let mut creator_rebate =
+        CreatorRebate::try_deserialize(&mut &accounts.creator_rebate.try_borrow_data()?[..])?;
+    
+    let platform_fee = accounts.split_state.platform_fee_for(amount, fee_waived)?;
+    let rebate = accounts.platform_config.rebate_amount(platform_fee)?;
+    if rebate == 0 {
+        return Ok(());
+    }
+    
+    let (rebate_mint, rebate_token_account, rebate_authority) = match (
+        accounts.rebate_mint.as_ref(),
+        accounts.creator_rebate_token_account.as_ref(),
+        accounts.rebate_authority.as_ref(),
+    ) {
+        (Some(mint), Some(token_account), Some(authority)) => (mint, token_account, authority),
+        _ => return err!(EscrowError::RebateAccountsRequired),
+    };
+    
+    let authority_seeds: &[&[u8]] = &[
+        CreatorRebate::AUTHORITY_SEED_PREFIX,
+        &[rebate_authority_bump.unwrap_or_default()],
+    ];
+    
+    token::mint_to(
+        CpiContext::new_with_signer(
+            accounts.access_token_program.to_account_info(),
+            MintTo {
+                mint: rebate_mint.to_account_info(),
+                to: rebate_token_account.to_account_info(),
+                authority: rebate_authority.to_account_info(),
+            },
+            &[authority_seeds],
+        ),
+        rebate,
+    )?;
+    
+    creator_rebate.total_rebated = creator_rebate
+        .total_rebated
+        .checked_add(rebate)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    emit!(FeeRebatePaid {
+        event_sequence: accounts.platform_config.next_event_sequence()?,
+        creator: creator_rebate.creator,
+        rebate_mint: rebate_mint.key(),
+        platform_fee,
+        rebate,
+    });
+    creator_rebate.try_serialize(&mut &mut accounts.creator_rebate.try_borrow_mut_data()?[..])?;
+    
+    msg!("Minted {} rebate tokens on a platform fee of {}", rebate, platform_fee);
+    
+    Ok(())
+}
+
+/// Accounts moving a direct-split payment
+struct DirectSplitAccounts<'a, 'info> {
+    /// Split configuration the shares are computed from
+    split_state: &'a SplitState,
+    
+    /// Whether the escrow is paid in SOL
+    is_sol_payment: bool,
+    
+    /// Buyer paying every share
+    buyer: AccountInfo<'info>,
+    
+    /// Buyer's SPL token account (SPL payments)
+    buyer_token_account: AccountInfo<'info>,
+    
+    /// Payment token mint (SPL payments)
+    payment_token_mint: AccountInfo<'info>,
+    
+    /// Creator payout account (SOL payments)
+    creator: AccountInfo<'info>,
+    
+    /// Creator payout token account (SPL payments)
+    creator_token_account: AccountInfo<'info>,
+    
+    /// Platform treasury (SOL payments)
+    platform_treasury: AccountInfo<'info>,
+    
+    /// Platform treasury token account (SPL payments)
+    platform_treasury_token_account: AccountInfo<'info>,
+    
+    /// Token program (SPL payments)
+    token_program: AccountInfo<'info>,
+    
+    /// System program (SOL payments)
+    system_program: AccountInfo<'info>,
+    
+    /// Insurance pool receiving `insurance_premium` (SOL payments)
+    insurance_pool: Option<AccountInfo<'info>>,
+    
+    /// Slice of the platform share paid to the insurance pool instead of the treasury
+    insurance_premium: u64,
+    
+    /// Whether the platform fee is waived and paid to the creator instead
+    fee_waived: bool,
+}
+
+/// Pay each share of `amount` from the buyer straight to the split's recipients,
+/// trading the vault's batching and audit record for fewer transfers and instant settlement
+fn pay_direct_split<'info>(
+    accounts: DirectSplitAccounts<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let split_state = accounts.split_state;
+    let is_sol_payment = accounts.is_sol_payment;
+    
+    // Splits on a payout schedule batch SOL sales in the distribution vault
+    require!(
+        !(is_sol_payment && split_state.is_accruing()),
+        EscrowError::DirectSplitUnavailable
+    );
+    
+    // (owner of the share, account receiving it, amount)
+    let mut shares = Vec::with_capacity(split_state.collaborators.len() + 2);
+    shares.push((
+        split_state.platform_treasury,
+        if is_sol_payment {
+            accounts.platform_treasury.clone()
+        } else {
+            accounts.platform_treasury_token_account.clone()
+        },
+        split_state
+            .platform_fee_for(amount, accounts.fee_waived)?
+            .checked_sub(accounts.insurance_premium)
+            .ok_or(EscrowError::NumericalOverflow)?,
+    ));
+    for (i, collaborator) in split_state.collaborators.iter().enumerate() {
+        let collaborator_account = remaining_accounts
+            .get(i)
+            .ok_or(EscrowError::InvalidRecipient)?;
+        shares.push((
+            collaborator.pubkey,
+            collaborator_account.clone(),
+            split_state.calculate_collaborator_share(amount, collaborator.active_share_bps())?,
+        ));
+    }
+    shares.push((
+        split_state.payout_address,
+        if is_sol_payment {
+            accounts.creator.clone()
+        } else {
+            accounts.creator_token_account.clone()
+        },
+        split_state.creator_share_for(amount, accounts.fee_waived)?,
+    ));
+    
+    for (owner, recipient, share) in shares {
+        if share == 0 {
+            continue;
+        }
+        
+        if is_sol_payment {
+            require_keys_eq!(recipient.key(), owner, EscrowError::InvalidRecipient);
+            
+            transfer(
+                CpiContext::new(
+                    accounts.system_program.clone(),
+                    Transfer {
+                        from: accounts.buyer.clone(),
+                        to: recipient,
+                    },
+                ),
+                share,
+            )?;
+        } else {
+            let token_account = token_interface::TokenAccount::try_deserialize(
+                &mut &recipient.try_borrow_data()?[..],
+            )?;
+            require_keys_eq!(token_account.owner, owner, EscrowError::InvalidRecipient);
+            
+            transfer_payment(
+                accounts.token_program.clone(),
+                accounts.buyer_token_account.clone(),
+                accounts.payment_token_mint.clone(),
+                recipient,
+                accounts.buyer.clone(),
+                &[],
+                share,
+            )?;
+        }
+    }
+    
+    if accounts.insurance_premium > 0 {
+        let insurance_pool = accounts.insurance_pool.ok_or(EscrowError::InvalidInsuranceConfig)?;
+        transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                Transfer {
+                    from: accounts.buyer.clone(),
+                    to: insurance_pool,
+                },
+            ),
+            accounts.insurance_premium,
+        )?;
+    }
+    
+    msg!("Paid {} directly to {} recipients", amount, split_state.collaborators.len() + 2);
+    
+    Ok(())
+}
+
 #[derive(Accounts)]
+#[instruction(
+    payment_amount: u64,
+    license_terms_hash: [u8; 32],
+    allowlist_proof: Vec<[u8; 32]>,
+    receipt_uri: Option<String>,
+    direct_split: bool,
+    referrer: Option<Pubkey>,
+)]
 pub struct BuyAndMint<'info> {
     /// The buyer making the payment
+    /// Signs, unless the purchase is paid from the buyer's deposit and authorized by its session key
+    /// CHECK: Must match the escrow's buyer; signature checked in the handler
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Pays for accounts created by the purchase: the buyer, or the session key on a
+    /// deposit purchase where the buyer doesn't sign
     #[account(mut)]
-    pub buyer: Signer<'info>,
+    pub payer: Signer<'info>,
     
     /// Escrow state PDA
     #[account(
@@ -211,29 +943,158 @@ pub struct BuyAndMint<'info> {
             escrow_state.seed.to_le_bytes().as_ref(),
         ],
         bump = escrow_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
     )]
     pub escrow_state: Account<'info, EscrowState>,
     
-    /// Vault PDA to hold SOL payments
-    /// CHECK: Vault is a PDA derived from escrow state
+    /// Buyer's deposit for the escrow's payment mint (optional, pays for the purchase when present)
+    #[account(
+        mut,
+        seeds = [
+            BuyerDeposit::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            BuyerVault::mint_seed(&escrow_state.payment_token_mint).as_ref(),
+        ],
+        bump = buyer_deposit.bump,
+    )]
+    pub buyer_deposit: Option<Box<Account<'info, BuyerDeposit>>>,
+    
+    /// Deposit's SPL token account (for SPL purchases paid from the deposit)
+    /// CHECK: Validated when an SPL purchase is paid from the deposit
+    #[account(mut)]
+    pub deposit_token_account: Option<UncheckedAccount<'info>>,
+    
+    /// Session key authorizing a deposit purchase in place of the buyer's signature
+    pub session_key: Option<Signer<'info>>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, escrow_state.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's denylist entry; purchases are rejected while it exists
+    /// CHECK: Validated by seeds, only checked for existence
+    #[account(seeds = [DenylistEntry::SEED_PREFIX, buyer.key().as_ref()], bump)]
+    pub denylist_entry: UncheckedAccount<'info>,
+    
+    /// Listing the escrow was created against (required when escrow.listing is set)
+    #[account(mut)]
+    pub listing: Option<Account<'info, Listing>>,
+    
+    /// Buyer's token account for the listing's gating mint (required when the listing is gated)
+    #[account(token::authority = buyer)]
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Creator's badge record (required when the listing requires a verified creator)
+    #[account(
+        seeds = [CreatorBadge::SEED_PREFIX, escrow_state.creator.as_ref()],
+        bump = creator_badge.bump,
+    )]
+    pub creator_badge: Option<Account<'info, CreatorBadge>>,
+    
+    /// Marketplace platform the listing is sold through (required when listing.platform is set)
+    #[account(
+        seeds = [Platform::SEED_PREFIX, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Option<Account<'info, Platform>>,
+    
+    /// Buyer's purchase counter for this content (required when the listing is throttled)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PurchaseCounter::LEN,
+        seeds = [
+            PurchaseCounter::SEED_PREFIX,
+            escrow_state.creator.as_ref(),
+            escrow_state.content_id.as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub purchase_counter: Option<Account<'info, PurchaseCounter>>,
+    
+    /// Listing's purchase attempt log (optional, records successes and logged rejections)
+    #[account(
+        mut,
+        seeds = [AttemptLog::SEED_PREFIX, attempt_log.listing.as_ref()],
+        bump = attempt_log.bump,
+        constraint = escrow_state.listing == Some(attempt_log.listing) @ EscrowError::ListingMismatch,
+    )]
+    pub attempt_log: Option<Box<Account<'info, AttemptLog>>>,
+    
+    /// Platform-wide sale (optional, discounts opted-in listings while active)
     #[account(
         mut,
-        seeds = [b"vault", escrow_state.key().as_ref()],
+        seeds = [PlatformSale::SEED_PREFIX],
+        bump = platform_sale.bump,
+    )]
+    pub platform_sale: Option<Account<'info, PlatformSale>>,
+    
+    /// Refund insurance pool; direct-split SOL sales divert a slice of the platform fee into it
+    #[account(
+        mut,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+    
+    /// Instructions sysvar (required for strict-mode listings)
+    /// CHECK: Address must be the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
+    /// Platform config PDA (loyalty rate)
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Buyer's loyalty points (optional; earns points and applies redeemed discounts)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BuyerLoyalty::LEN,
+        seeds = [BuyerLoyalty::SEED_PREFIX, buyer.key().as_ref()],
         bump,
     )]
-    pub vault: UncheckedAccount<'info>,
+    pub buyer_loyalty: Option<Account<'info, BuyerLoyalty>>,
+    
+    /// Referrer's attribution stats (required when a referrer is passed)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReferrerStats::LEN,
+        seeds = [ReferrerStats::SEED_PREFIX, referrer.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Option<Account<'info, ReferrerStats>>,
+    
+    /// Buyer vault holding payments for the buyer's escrows in this payment mint
+    #[account(
+        mut,
+        seeds = [
+            BuyerVault::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            BuyerVault::mint_seed(&escrow_state.payment_token_mint).as_ref(),
+        ],
+        bump = escrow_state.vault_bump,
+    )]
+    pub buyer_vault: Account<'info, BuyerVault>,
     
     /// Buyer's SPL token account (for SPL payments)
     /// CHECK: Optional account, validated when SPL payment is used
     #[account(mut)]
     pub buyer_token_account: UncheckedAccount<'info>,
     
-    /// Vault's SPL token account (for SPL payments)
+    /// Buyer vault's SPL token account (for SPL payments)
     /// CHECK: Optional account, validated when SPL payment is used
     #[account(mut)]
     pub vault_token_account: UncheckedAccount<'info>,
     
-    /// Token program (for SPL payments)
+    /// Token program (SPL Token or Token-2022, for SPL payments)
     /// CHECK: Optional account, validated when SPL payment is used
     pub token_program: UncheckedAccount<'info>,
     
@@ -242,14 +1103,18 @@ pub struct BuyAndMint<'info> {
     /// Access mint program
     pub access_mint_program: Program<'info, AccessMint>,
     
-    /// Access mint state PDA
-    /// CHECK: Validated by access mint program via CPI
-    #[account(mut)]
-    pub access_mint_state: UncheckedAccount<'info>,
+    /// Access mint state for the escrowed content
+    #[account(
+        mut,
+        constraint = access_mint_state.creator == escrow_state.creator @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == escrow_state.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
     
     /// Access token mint
+    /// CHECK: Validated by access mint program via CPI
     #[account(mut)]
-    pub access_mint: Account<'info, Mint>,
+    pub access_mint: UncheckedAccount<'info>,
     
     /// Mint authority for access tokens
     /// CHECK: Validated by access mint program via CPI
@@ -260,6 +1125,11 @@ pub struct BuyAndMint<'info> {
     #[account(mut)]
     pub buyer_access_token_account: UncheckedAccount<'info>,
     
+    /// Buyer's preview grant, upgraded to permanent access by this purchase (optional)
+    /// CHECK: Validated by access mint program via CPI
+    #[account(mut)]
+    pub preview_grant: Option<UncheckedAccount<'info>>,
+    
     /// Token program for access mint
     pub access_token_program: Program<'info, Token>,
     
@@ -271,16 +1141,33 @@ pub struct BuyAndMint<'info> {
     /// Distribution program
     pub distribution_program: Program<'info, Distribution>,
     
-    /// Split state PDA (revenue split configuration)
-    /// CHECK: Validated by distribution program via CPI
+    /// Split state for the escrowed content
+    #[account(
+        mut,
+        constraint = split_state.creator == escrow_state.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == escrow_state.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
     #[account(mut)]
-    pub split_state: UncheckedAccount<'info>,
+    pub distribution_record: UncheckedAccount<'info>,
     
-    /// Distribution vault PDA (derived from split_state)
-    /// CHECK: Vault is a PDA derived from split_state in the distribution program
-    /// Validated by distribution program via CPI
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
     #[account(mut)]
-    pub distribution_vault: UncheckedAccount<'info>,
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// System-owned, so SOL moved into it can only leave through the distribution program
+    #[account(
+        mut,
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+        seeds::program = distribution_program.key(),
+    )]
+    pub distribution_vault: SystemAccount<'info>,
     
     /// Distribution vault's SPL token account (for SPL payments)
     /// CHECK: Optional account, validated when SPL payment is used
@@ -298,7 +1185,10 @@ pub struct BuyAndMint<'info> {
     pub platform_treasury: UncheckedAccount<'info>,
     
     /// Payment token mint (System::id() for SOL, token mint for SPL)
-    /// CHECK: Used to determine payment type in distribution
+    /// CHECK: Used to determine payment type in distribution, must match the escrow terms
+    #[account(
+        constraint = payment_token_mint.key() == escrow_state.payment_token_mint.unwrap_or(System::id()) @ EscrowError::PaymentMintMismatch,
+    )]
     pub payment_token_mint: UncheckedAccount<'info>,
     
     /// Creator's token account (for SPL payments)
@@ -311,6 +1201,63 @@ pub struct BuyAndMint<'info> {
     #[account(mut)]
     pub platform_treasury_token_account: UncheckedAccount<'info>,
     
+    // ============ Receipt Accounts ============
+    
+    /// Fresh keypair for the receipt mint (required when a receipt is requested)
+    #[account(mut)]
+    pub receipt_mint: Option<Signer<'info>>,
+    
+    /// Buyer's receipt token account, created for the receipt mint
+    /// CHECK: Must be the buyer's Token-2022 associated token account for the receipt mint
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            buyer.key,
+            &receipt_mint.as_ref().map_or(Pubkey::default(), |mint| mint.key()),
+            &anchor_spl::token_2022::ID,
+        ) @ EscrowError::ReceiptAccountsRequired,
+    )]
+    pub receipt_token_account: Option<UncheckedAccount<'info>>,
+    
+    /// Token-2022 program for the receipt mint
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+    
+    // ============ Fee Rebate Accounts ============
+    
+    /// Creator's fee rebate opt-in PDA, required so an opted-in creator's rebate can't be skipped
+    /// CHECK: Validated by seeds; rebates are paid only when the creator has opted in (account exists)
+    #[account(
+        mut,
+        seeds = [CreatorRebate::SEED_PREFIX, escrow_state.creator.as_ref()],
+        bump,
+    )]
+    pub creator_rebate: UncheckedAccount<'info>,
+    
+    /// Platform token minted as the rebate
+    #[account(
+        mut,
+        address = platform_config.rebate_mint @ EscrowError::RebateAccountsRequired,
+    )]
+    pub rebate_mint: Option<Account<'info, Mint>>,
+    
+    /// Creator's token account receiving the rebate
+    #[account(
+        mut,
+        token::mint = platform_config.rebate_mint,
+        token::authority = escrow_state.creator,
+    )]
+    pub creator_rebate_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Rebate mint authority PDA
+    /// CHECK: Validated by seeds, signs the rebate mint
+    #[account(seeds = [CreatorRebate::AUTHORITY_SEED_PREFIX], bump)]
+    pub rebate_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Fee waiver PDA (required when the buyer or creator is fee exempt)
+    /// CHECK: Validated by seeds, signs the distribution to waive the platform fee
+    #[account(seeds = [PlatformConfig::FEE_WAIVER_SEED], bump)]
+    pub fee_waiver: Option<UncheckedAccount<'info>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
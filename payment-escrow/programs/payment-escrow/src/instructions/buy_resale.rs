@@ -0,0 +1,249 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer as SplTransfer};
+use anchor_spl::associated_token::AssociatedToken;
+use common::bps::apply_bps;
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Buy escrowed access tokens from a resale listing
+/// The royalty snapshotted at listing time is paid into the content's split and distributed; the rest goes
+/// to the seller. Escrowed tokens move to the buyer and the listing closes to the seller
+pub fn buy_resale<'info>(ctx: Context<'_, '_, '_, 'info, BuyResale<'info>>) -> Result<()> {
+    let price = ctx.accounts.resale_listing.price;
+    let royalty = apply_bps(price, ctx.accounts.resale_listing.royalty_bps)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    let seller_proceeds = price
+        .checked_sub(royalty)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    if royalty > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.distribution_vault.to_account_info(),
+                },
+            ),
+            royalty,
+        )?;
+        
+        // CPI to Distribution program to split the royalty; resales are SOL-only, so the
+        // SPL token accounts are never read and the vault and payees stand in for them
+        let remaining_accounts = ctx.remaining_accounts.to_vec();
+        
+        distribute(
+            CpiContext::new(
+                ctx.accounts.distribution_program.to_account_info(),
+                DistributeAccounts {
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    split_state: ctx.accounts.split_state.to_account_info(),
+                    distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                    revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                    vault: ctx.accounts.distribution_vault.to_account_info(),
+                    creator: ctx.accounts.creator.to_account_info(),
+                    platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                    payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                    vault_token_account: ctx.accounts.distribution_vault.to_account_info(),
+                    creator_token_account: ctx.accounts.creator.to_account_info(),
+                    platform_treasury_token_account: ctx.accounts.platform_treasury.to_account_info(),
+                    token_program: ctx.accounts.system_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    fee_waiver: None,
+                },
+            )
+            .with_remaining_accounts(remaining_accounts),
+            royalty,
+        )?;
+    }
+    
+    if seller_proceeds > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            seller_proceeds,
+        )?;
+    }
+    
+    // Release the escrowed tokens to the buyer and close the escrow account to the seller
+    let resale_listing = &ctx.accounts.resale_listing;
+    let seller_key = resale_listing.seller;
+    let access_mint_key = resale_listing.access_mint;
+    let resale_seeds = &[
+        ResaleListing::SEED_PREFIX,
+        seller_key.as_ref(),
+        access_mint_key.as_ref(),
+        &[resale_listing.bump],
+    ];
+    let signer_seeds = &[&resale_seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SplTransfer {
+                from: ctx.accounts.resale_token_account.to_account_info(),
+                to: ctx.accounts.buyer_access_token_account.to_account_info(),
+                authority: ctx.accounts.resale_listing.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        resale_listing.amount,
+    )?;
+    
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.resale_token_account.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: ctx.accounts.resale_listing.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    emit!(AccessResold {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        resale_listing: resale_listing.key(),
+        seller: seller_key,
+        buyer: ctx.accounts.buyer.key(),
+        access_mint: access_mint_key,
+        amount: resale_listing.amount,
+        price,
+        royalty,
+    });
+    
+    msg!("Access resold to: {}, price: {}, royalty: {}", ctx.accounts.buyer.key(), price, royalty);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuyResale<'info> {
+    /// The buyer
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Seller receiving the proceeds and the listing's rent
+    /// CHECK: Must match the resale listing's seller
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// Resale listing PDA
+    #[account(
+        mut,
+        seeds = [
+            ResaleListing::SEED_PREFIX,
+            resale_listing.seller.as_ref(),
+            resale_listing.access_mint.as_ref(),
+        ],
+        bump = resale_listing.bump,
+        has_one = seller @ EscrowError::Unauthorized,
+        has_one = listing @ EscrowError::InvalidContentId,
+        has_one = access_mint @ EscrowError::InvalidAccessMint,
+        close = seller,
+    )]
+    pub resale_listing: Account<'info, ResaleListing>,
+    
+    /// Primary listing of the content (identifies the split)
+    pub listing: Box<Account<'info, Listing>>,
+    
+    /// Access token mint
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Escrow token account owned by the resale listing
+    #[account(
+        mut,
+        associated_token::mint = access_mint,
+        associated_token::authority = resale_listing,
+    )]
+    pub resale_token_account: Account<'info, TokenAccount>,
+    
+    /// Buyer's access token account (created if needed)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = access_mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_access_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program for access tokens
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the listed content
+    #[account(
+        mut,
+        constraint = split_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for the royalty (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// System-owned, so SOL moved into it can only leave through the distribution program
+    #[account(
+        mut,
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+        seeds::program = distribution_program.key(),
+    )]
+    pub distribution_vault: SystemAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts receiving their royalty share
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Cancel recurring billing at any time
+/// Closing the agreement returns the unspent deposit and its rent to the buyer; access
+/// already paid for runs until the end of its period
+pub fn cancel_billing_agreement(ctx: Context<CancelBillingAgreement>) -> Result<()> {
+    let billing_agreement = &ctx.accounts.billing_agreement;
+    
+    emit!(BillingAgreementCancelled {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        billing_agreement: billing_agreement.key(),
+        buyer: billing_agreement.buyer,
+        refunded: billing_agreement.deposit,
+    });
+    
+    msg!("Billing agreement cancelled by: {}, refunded: {}", billing_agreement.buyer, billing_agreement.deposit);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelBillingAgreement<'info> {
+    /// The buyer who owns the agreement
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Billing agreement PDA
+    #[account(
+        mut,
+        seeds = [BillingAgreement::SEED_PREFIX, buyer.key().as_ref(), billing_agreement.listing.as_ref()],
+        bump = billing_agreement.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        close = buyer,
+    )]
+    pub billing_agreement: Account<'info, BillingAgreement>,
+}
@@ -1,83 +1,81 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::System;
-use anchor_spl::token::{self, Transfer as SplTransfer};
 use crate::state::*;
 use crate::errors::*;
+use crate::payment::*;
 
 /// Cancel an escrow and refund the buyer if payment was made
 pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
     let escrow = &mut ctx.accounts.escrow_state;
     
-    // Validate escrow can be cancelled
-    require!(
-        escrow.status != EscrowStatus::Completed,
-        EscrowError::EscrowAlreadyCompleted
-    );
+    // Mystery box purchases are bound to their randomness; cancelling once the reveal is
+    // known would let buyers reroll
+    require!(escrow.randomness_account.is_none(), EscrowError::MysteryBoxEscrow);
     
-    require!(
-        escrow.status != EscrowStatus::Cancelled,
-        EscrowError::EscrowAlreadyCancelled
-    );
+    // Only escrows that haven't completed or been cancelled can be cancelled
+    escrow.transition_to(EscrowStatus::Cancelled)?;
     
-    // Validate buyer is the one cancelling
-    require!(
-        ctx.accounts.buyer.key() == escrow.buyer,
-        EscrowError::InvalidBuyer
-    );
-    
-    // Refund if payment was made
+    // Refund this escrow's share of the buyer vault if payment was made
     if escrow.payment_amount > 0 {
-        let escrow_key = escrow.key();
-        let bump = ctx.bumps.vault;
-        let seeds = &[
-            b"vault".as_ref(),
-            escrow_key.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-        
-        if escrow.payment_token_mint.is_none() {
-            // Refund SOL
-            **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= escrow.payment_amount;
-            **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += escrow.payment_amount;
-            
-            msg!("Refunded {} lamports to buyer", escrow.payment_amount);
+        let spl = if escrow.payment_token_mint.is_none() {
+            // The vault's rent reserve stays behind for the buyer's other escrows
+            BuyerVault::check_sol_payout(&ctx.accounts.buyer_vault.to_account_info(), escrow.payment_amount)?;
+            None
         } else {
-            // Refund SPL tokens
             // Validate that token accounts are provided
             require!(
                 ctx.accounts.buyer_token_account.key() != System::id(),
-                EscrowError::InvalidVault
+                EscrowError::TokenAccountRequired
             );
             require!(
                 ctx.accounts.vault_token_account.key() != System::id(),
-                EscrowError::InvalidVault
+                EscrowError::TokenAccountRequired
             );
             require!(
-                ctx.accounts.token_program.key() == anchor_spl::token::ID,
-                EscrowError::InvalidVault
+                is_token_program(ctx.accounts.token_program.key),
+                EscrowError::InvalidTokenProgram
             );
-            
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    SplTransfer {
-                        from: ctx.accounts.vault_token_account.to_account_info(),
-                        to: ctx.accounts.buyer_token_account.to_account_info(),
-                        authority: ctx.accounts.vault.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                escrow.payment_amount,
+            require_keys_eq!(
+                ctx.accounts.payment_token_mint.key(),
+                escrow.payment_token_mint.unwrap_or_default(),
+                EscrowError::PaymentMintMismatch
+            );
+            validate_vault_token_account(
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.buyer_vault.key(),
+                &escrow.payment_token_mint.unwrap_or_default(),
             )?;
             
-            msg!("Refunded {} tokens to buyer", escrow.payment_amount);
-        }
+            Some(SplPayment {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.payment_token_mint.to_account_info(),
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+            })
+        };
+        
+        let buyer_key = escrow.buyer;
+        let seeds = &[
+            BuyerVault::SEED_PREFIX,
+            buyer_key.as_ref(),
+            BuyerVault::mint_seed(&escrow.payment_token_mint).as_ref(),
+            &[escrow.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        refund_from_vault(
+            ctx.accounts.buyer_vault.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            spl,
+            signer_seeds,
+            escrow.payment_amount,
+        )?;
+        
+        msg!("Refunded {} to buyer", escrow.payment_amount);
+        
+        ctx.accounts.buyer_vault.release(escrow.payment_amount)?;
     }
     
-    // Update escrow status
-    escrow.status = EscrowStatus::Cancelled;
-    
     msg!("Escrow cancelled for buyer: {}", ctx.accounts.buyer.key());
     
     Ok(())
@@ -99,30 +97,44 @@ pub struct CancelEscrow<'info> {
             escrow_state.seed.to_le_bytes().as_ref(),
         ],
         bump = escrow_state.bump,
-        close = buyer,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        has_one = rent_recipient @ EscrowError::InvalidRentRecipient,
+        close = rent_recipient,
     )]
     pub escrow_state: Account<'info, EscrowState>,
     
-    /// Vault PDA holding funds
-    /// CHECK: Vault is a PDA derived from escrow state
+    /// Account that paid the escrow's rent, refunded when it closes
+    /// CHECK: Must match the rent recipient recorded on the escrow
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+    
+    /// Buyer vault holding payments for the buyer's escrows in this payment mint
     #[account(
         mut,
-        seeds = [b"vault", escrow_state.key().as_ref()],
-        bump,
+        seeds = [
+            BuyerVault::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            BuyerVault::mint_seed(&escrow_state.payment_token_mint).as_ref(),
+        ],
+        bump = escrow_state.vault_bump,
     )]
-    pub vault: UncheckedAccount<'info>,
+    pub buyer_vault: Account<'info, BuyerVault>,
     
     /// Buyer's SPL token account (for SPL refunds)
     /// CHECK: Optional account, validated when SPL refund is needed
     #[account(mut)]
     pub buyer_token_account: UncheckedAccount<'info>,
     
-    /// Vault's SPL token account (for SPL refunds)
+    /// Buyer vault's SPL token account (for SPL refunds)
     /// CHECK: Optional account, validated when SPL refund is needed
     #[account(mut)]
     pub vault_token_account: UncheckedAccount<'info>,
     
-    /// Token program (for SPL refunds)
+    /// Payment token mint (for SPL refunds, System Program for SOL)
+    /// CHECK: Optional account, validated against the escrow when SPL refund is needed
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Token program (SPL Token or Token-2022, for SPL refunds)
     /// CHECK: Optional account, validated when SPL refund is needed
     pub token_program: UncheckedAccount<'info>,
     
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Maximum escrows closed per batch
+pub const MAX_CANCEL_BATCH: usize = 16;
+
+/// Remaining accounts per escrow: escrow state, rent recipient
+pub const ACCOUNTS_PER_CANCELLED_ESCROW: usize = 2;
+
+/// Close abandoned checkouts: Initialized escrows that were never paid and have passed
+/// the checkout expiry
+/// Permissionless so creators or a crank can keep the account set tidy; each escrow's
+/// rent goes back to whoever paid it
+/// Remaining accounts: for each escrow, the escrow state (mut) then its rent recipient (mut)
+pub fn cancel_expired_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelExpiredBatch<'info>>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !remaining.is_empty()
+            && remaining.len().is_multiple_of(ACCOUNTS_PER_CANCELLED_ESCROW)
+            && remaining.len() / ACCOUNTS_PER_CANCELLED_ESCROW <= MAX_CANCEL_BATCH,
+        EscrowError::InvalidBatchSize
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    
+    for accounts in remaining.chunks(ACCOUNTS_PER_CANCELLED_ESCROW) {
+        let escrow = Account::<EscrowState>::try_from(&accounts[0])?;
+        let rent_recipient = &accounts[1];
+        
+        // Same PDA the escrow was created at
+        let seed_bytes = escrow.seed.to_le_bytes();
+        let escrow_address = Pubkey::create_program_address(
+            &[
+                EscrowState::SEED_PREFIX,
+                escrow.buyer.as_ref(),
+                escrow.content_id.as_ref(),
+                seed_bytes.as_ref(),
+                &[escrow.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| EscrowError::InvalidEscrowAccount)?;
+        require_keys_eq!(escrow.key(), escrow_address, EscrowError::InvalidEscrowAccount);
+        require_keys_eq!(
+            rent_recipient.key(),
+            escrow.rent_recipient,
+            EscrowError::InvalidRentRecipient
+        );
+        
+        // Paid escrows hold funds and are only cancelled by their buyer with a refund
+        require!(escrow.is_abandoned(now), EscrowError::EscrowNotExpired);
+        
+        escrow.close(rent_recipient.clone())?;
+    }
+    
+    msg!(
+        "Closed {} expired escrows",
+        remaining.len() / ACCOUNTS_PER_CANCELLED_ESCROW
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelExpiredBatch<'info> {
+    /// Creator or crank submitting the batch
+    pub authority: Signer<'info>,
+}
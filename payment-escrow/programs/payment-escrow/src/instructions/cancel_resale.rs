@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer as SplTransfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Withdraw a resale listing, returning the escrowed tokens and rent to the seller
+pub fn cancel_resale(ctx: Context<CancelResale>) -> Result<()> {
+    let resale_listing = &ctx.accounts.resale_listing;
+    let seller_key = resale_listing.seller;
+    let access_mint_key = resale_listing.access_mint;
+    let resale_seeds = &[
+        ResaleListing::SEED_PREFIX,
+        seller_key.as_ref(),
+        access_mint_key.as_ref(),
+        &[resale_listing.bump],
+    ];
+    let signer_seeds = &[&resale_seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SplTransfer {
+                from: ctx.accounts.resale_token_account.to_account_info(),
+                to: ctx.accounts.seller_access_token_account.to_account_info(),
+                authority: ctx.accounts.resale_listing.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        resale_listing.amount,
+    )?;
+    
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.resale_token_account.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: ctx.accounts.resale_listing.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    msg!("Resale listing cancelled by: {}", seller_key);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelResale<'info> {
+    /// Holder who listed the tokens
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    /// Resale listing PDA
+    #[account(
+        mut,
+        seeds = [
+            ResaleListing::SEED_PREFIX,
+            seller.key().as_ref(),
+            access_mint.key().as_ref(),
+        ],
+        bump = resale_listing.bump,
+        has_one = seller @ EscrowError::Unauthorized,
+        close = seller,
+    )]
+    pub resale_listing: Account<'info, ResaleListing>,
+    
+    /// Access token mint
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Escrow token account owned by the resale listing
+    #[account(
+        mut,
+        associated_token::mint = access_mint,
+        associated_token::authority = resale_listing,
+    )]
+    pub resale_token_account: Account<'info, TokenAccount>,
+    
+    /// Seller's access token account
+    #[account(
+        mut,
+        associated_token::mint = access_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_access_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program for access tokens
+    pub token_program: Program<'info, Token>,
+}
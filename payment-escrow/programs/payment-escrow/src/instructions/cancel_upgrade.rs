@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Withdraw the pending upgrade proposal
+pub fn cancel_upgrade(ctx: Context<CancelUpgrade>) -> Result<()> {
+    let governance = &mut ctx.accounts.upgrade_governance;
+    
+    let buffer = governance
+        .proposed_buffer
+        .ok_or(EscrowError::NoUpgradeProposed)?;
+    governance.clear_proposal();
+    
+    emit!(UpgradeCancelled {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        buffer,
+    });
+    
+    msg!("Upgrade from buffer {} cancelled", buffer);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelUpgrade<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Upgrade governance PDA
+    #[account(
+        mut,
+        seeds = [UpgradeGovernance::SEED_PREFIX],
+        bump = upgrade_governance.bump,
+    )]
+    pub upgrade_governance: Account<'info, UpgradeGovernance>,
+}
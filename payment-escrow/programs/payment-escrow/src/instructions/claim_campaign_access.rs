@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use crate::state::*;
+use crate::errors::*;
+
+/// Mint an access token to a contributor of a successful campaign
+pub fn claim_campaign_access(ctx: Context<ClaimCampaignAccess>) -> Result<()> {
+    require!(
+        ctx.accounts.campaign.status == CampaignStatus::Succeeded,
+        EscrowError::CampaignNotSucceeded
+    );
+    require!(
+        !ctx.accounts.contribution.access_claimed,
+        EscrowError::AccessAlreadyClaimed
+    );
+    require!(
+        ctx.accounts.campaign.earns_access(ctx.accounts.contribution.amount),
+        EscrowError::ContributionBelowAccessThreshold
+    );
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept this flow
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program to mint access token to contributor
+    mint_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            AccessMintAccounts {
+                buyer: ctx.accounts.contributor.to_account_info(),
+                payer: ctx.accounts.contributor.to_account_info(),
+                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                mint: ctx.accounts.access_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                buyer_token_account: ctx.accounts.contributor_access_token_account.to_account_info(),
+                purchase_receipt: None,
+                access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                preview_grant: None,
+                access_collection: None,
+                access_metadata: None,
+                collection_mint: None,
+                collection_metadata: None,
+                collection_master_edition: None,
+                token_metadata_program: None,
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[access_minter_seeds],
+        ),
+    )?;
+    
+    ctx.accounts.contribution.access_claimed = true;
+    
+    msg!("Campaign access claimed by contributor: {}", ctx.accounts.contributor.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCampaignAccess<'info> {
+    /// The contributor claiming access
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    /// Campaign state PDA
+    #[account(
+        seeds = [
+            CampaignState::SEED_PREFIX,
+            campaign.creator.as_ref(),
+            campaign.content_id.as_ref(),
+            campaign.seed.to_le_bytes().as_ref(),
+        ],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, CampaignState>,
+    
+    /// Contribution PDA for this contributor
+    #[account(
+        mut,
+        seeds = [
+            Contribution::SEED_PREFIX,
+            campaign.key().as_ref(),
+            contributor.key().as_ref(),
+        ],
+        bump = contribution.bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the campaign's content
+    #[account(
+        mut,
+        constraint = access_mint_state.creator == campaign.creator @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == campaign.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Contributor's access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub contributor_access_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Refund a contributor of a failed campaign
+pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+    require!(
+        ctx.accounts.campaign.status == CampaignStatus::Failed,
+        EscrowError::CampaignNotFailed
+    );
+    
+    let amount = ctx.accounts.contribution.amount;
+    
+    let campaign_key = ctx.accounts.campaign.key();
+    let vault_bump = ctx.bumps.vault;
+    let vault_seeds = &[
+        CampaignState::VAULT_SEED_PREFIX,
+        campaign_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.contributor.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    msg!("Refunded {} lamports to contributor: {}", amount, ctx.accounts.contributor.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    /// The contributor claiming a refund
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    /// Campaign state PDA
+    #[account(
+        seeds = [
+            CampaignState::SEED_PREFIX,
+            campaign.creator.as_ref(),
+            campaign.content_id.as_ref(),
+            campaign.seed.to_le_bytes().as_ref(),
+        ],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, CampaignState>,
+    
+    /// Contribution PDA, closed to the contributor after refund
+    #[account(
+        mut,
+        seeds = [
+            Contribution::SEED_PREFIX,
+            campaign.key().as_ref(),
+            contributor.key().as_ref(),
+        ],
+        bump = contribution.bump,
+        close = contributor,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// Campaign vault PDA holding contributions
+    #[account(
+        mut,
+        seeds = [CampaignState::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Claim a queue entry's result once winners are drawn, or a refund once the draw is abandoned
+/// Winners' escrowed price is distributed and access minted to them; losers are refunded.
+/// Either way the entry closes to the buyer
+pub fn claim_result<'info>(ctx: Context<'_, '_, '_, 'info, ClaimResult<'info>>) -> Result<()> {
+    let queue_state = &ctx.accounts.queue_state;
+    
+    // A queue left undrawn past its deadline refunds everyone
+    let abandoned = queue_state.is_abandoned(Clock::get()?.unix_timestamp);
+    require!(
+        queue_state.status == QueueStatus::Drawn || abandoned,
+        EscrowError::InvalidQueueStatus
+    );
+    
+    let won = !abandoned && queue_state.is_winner(ctx.accounts.queue_entry.index);
+    let amount = queue_state.price;
+    
+    if won {
+        // Frozen (taken down) content can't be sold; winners claim once it's unfrozen
+        check_not_frozen(&ctx.accounts.content_freeze)?;
+        
+        // The entry account is program-owned, so lamports move directly
+        ctx.accounts.queue_entry.sub_lamports(amount)?;
+        ctx.accounts.distribution_vault.add_lamports(amount)?;
+        
+        // CPI to Distribution program to split the price; queues are SOL-only, so the
+        // SPL token accounts are never read and the vault and payees stand in for them
+        let remaining_accounts = ctx.remaining_accounts.to_vec();
+        
+        distribute(
+            CpiContext::new(
+                ctx.accounts.distribution_program.to_account_info(),
+                DistributeAccounts {
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    split_state: ctx.accounts.split_state.to_account_info(),
+                    distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                    revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                    vault: ctx.accounts.distribution_vault.to_account_info(),
+                    creator: ctx.accounts.creator_payout.to_account_info(),
+                    platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                    payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                    vault_token_account: ctx.accounts.distribution_vault.to_account_info(),
+                    creator_token_account: ctx.accounts.creator_payout.to_account_info(),
+                    platform_treasury_token_account: ctx.accounts.platform_treasury.to_account_info(),
+                    token_program: ctx.accounts.system_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    fee_waiver: None,
+                },
+            )
+            .with_remaining_accounts(remaining_accounts),
+            amount,
+        )?;
+        
+        // Sign as the access minter so mints requiring a purchase receipt accept this flow
+        let access_minter_bump = [ctx.bumps.access_minter];
+        let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+        
+        // CPI to Access Mint program to mint access token to the winner
+        mint_access(
+            CpiContext::new_with_signer(
+                ctx.accounts.access_mint_program.to_account_info(),
+                AccessMintAccounts {
+                    buyer: ctx.accounts.buyer.to_account_info(),
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                    mint: ctx.accounts.access_mint.to_account_info(),
+                    mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                    buyer_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+                    purchase_receipt: None,
+                    access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                    preview_grant: None,
+                    access_collection: None,
+                    access_metadata: None,
+                    collection_mint: None,
+                    collection_metadata: None,
+                    collection_master_edition: None,
+                    token_metadata_program: None,
+                    token_program: ctx.accounts.access_token_program.to_account_info(),
+                    associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[access_minter_seeds],
+            ),
+        )?;
+    }
+    
+    emit!(QueueResultClaimed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        queue: queue_state.key(),
+        buyer: ctx.accounts.buyer.key(),
+        won,
+        amount,
+    });
+    
+    msg!("Queue result claimed by: {}, won: {}", ctx.accounts.buyer.key(), won);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimResult<'info> {
+    /// The registered buyer (receives access or the refund, and the entry's rent)
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Queue state PDA
+    #[account(
+        seeds = [QueueState::SEED_PREFIX, queue_state.listing.as_ref()],
+        bump = queue_state.bump,
+    )]
+    pub queue_state: Box<Account<'info, QueueState>>,
+    
+    /// Queue entry PDA
+    #[account(
+        mut,
+        seeds = [QueueEntry::SEED_PREFIX, queue_state.key().as_ref(), buyer.key().as_ref()],
+        bump = queue_entry.bump,
+        close = buyer,
+    )]
+    pub queue_entry: Account<'info, QueueEntry>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, queue_state.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the queued content
+    #[account(
+        mut,
+        constraint = access_mint_state.creator == queue_state.creator @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == queue_state.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the queued content
+    #[account(
+        mut,
+        constraint = split_state.creator == queue_state.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == queue_state.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// System-owned, so SOL moved into it can only leave through the distribution program
+    #[account(
+        mut,
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+        seeds::program = distribution_program.key(),
+    )]
+    pub distribution_vault: SystemAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator_payout: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts receiving their share (winners only)
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Close a payment channel once its close grace period has passed
+/// Closing the account returns the unsettled deposit and its rent to the buyer
+pub fn close_payment_channel(ctx: Context<ClosePaymentChannel>) -> Result<()> {
+    let payment_channel = &ctx.accounts.payment_channel;
+    
+    require!(
+        payment_channel.is_close_ready(Clock::get()?.unix_timestamp),
+        EscrowError::ChannelCloseNotReady
+    );
+    
+    emit!(PaymentChannelClosed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        payment_channel: payment_channel.key(),
+        buyer: payment_channel.buyer,
+        refunded: payment_channel.unsettled(),
+    });
+    
+    msg!("Payment channel closed by: {}, refunded: {}", payment_channel.buyer, payment_channel.unsettled());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClosePaymentChannel<'info> {
+    /// The buyer who owns the channel
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Payment channel PDA
+    #[account(
+        mut,
+        seeds = [PaymentChannel::SEED_PREFIX, buyer.key().as_ref(), payment_channel.listing.as_ref()],
+        bump = payment_channel.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        close = buyer,
+    )]
+    pub payment_channel: Account<'info, PaymentChannel>,
+}
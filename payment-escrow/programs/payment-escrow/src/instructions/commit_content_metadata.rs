@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use common::ContentId;
+
+/// Commit the URI of a listing's canonical metadata blob so buyers can verify the content id
+pub fn commit_content_metadata(
+    ctx: Context<CommitContentMetadata>,
+    uri: String,
+    preimage: Option<Vec<u8>>,
+) -> Result<()> {
+    require!(
+        uri.len() <= ContentMetadata::MAX_URI_LEN,
+        EscrowError::UriTooLong
+    );
+    
+    // Optionally prove the blob hashes to the content id on-chain
+    let verified = match &preimage {
+        Some(preimage) => {
+            require!(
+                ContentId::from(ctx.accounts.listing.content_id).matches(preimage),
+                EscrowError::InvalidContentId
+            );
+            true
+        }
+        None => false,
+    };
+    
+    let metadata = &mut ctx.accounts.content_metadata;
+    
+    metadata.listing = ctx.accounts.listing.key();
+    metadata.uri = uri;
+    metadata.verified = verified;
+    metadata.bump = ctx.bumps.content_metadata;
+    
+    msg!("Content metadata committed: {}, verified: {}", metadata.uri, verified);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitContentMetadata<'info> {
+    /// The creator who owns the listing
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Content metadata PDA
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = ContentMetadata::LEN,
+        seeds = [ContentMetadata::SEED_PREFIX, listing.key().as_ref()],
+        bump
+    )]
+    pub content_metadata: Account<'info, ContentMetadata>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
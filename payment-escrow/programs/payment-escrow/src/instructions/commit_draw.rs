@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::randomness::Randomness;
+
+/// Close registration and bind the draw to a freshly committed Switchboard randomness account
+/// Queues with no more entrants than capacity are drawn immediately, since everyone wins.
+/// Only the creator can commit, and only before the draw deadline
+pub fn commit_draw(ctx: Context<CommitDraw>) -> Result<()> {
+    let clock = Clock::get()?;
+    let queue_state = &mut ctx.accounts.queue_state;
+    
+    require!(
+        queue_state.status == QueueStatus::Registering,
+        EscrowError::InvalidQueueStatus
+    );
+    require!(
+        clock.unix_timestamp >= queue_state.register_end_ts,
+        EscrowError::QueueRegistrationOpen
+    );
+    
+    // Past the deadline the entrants are refunded instead
+    require!(
+        !queue_state.is_abandoned(clock.unix_timestamp),
+        EscrowError::QueueDrawExpired
+    );
+    
+    if queue_state.entrants <= queue_state.capacity {
+        queue_state.status = QueueStatus::Drawn;
+        
+        emit!(QueueDrawn {
+            event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+            queue: queue_state.key(),
+            entrants: queue_state.entrants,
+            capacity: queue_state.capacity,
+            winning_seed: queue_state.winning_seed,
+        });
+        
+        msg!("Queue undersubscribed, all {} entrants win", queue_state.entrants);
+        
+        return Ok(());
+    }
+    
+    // The randomness must be committed in the previous slot so its value was unknown
+    // to everyone, including the caller, when the draw was bound to it
+    let randomness = Randomness::load(&ctx.accounts.randomness_account)?;
+    randomness.check_fresh_commit(clock.slot)?;
+    
+    queue_state.randomness_account = ctx.accounts.randomness_account.key();
+    queue_state.commit_slot = randomness.seed_slot;
+    queue_state.status = QueueStatus::Committed;
+    
+    msg!("Queue draw committed to randomness at slot: {}", randomness.seed_slot);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitDraw<'info> {
+    /// The queue's creator, who commits the draw once registration has closed
+    pub creator: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Queue state PDA
+    #[account(
+        mut,
+        seeds = [QueueState::SEED_PREFIX, queue_state.listing.as_ref()],
+        bump = queue_state.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub queue_state: Account<'info, QueueState>,
+    
+    /// Switchboard randomness account
+    /// CHECK: Owner and layout are validated when read
+    pub randomness_account: UncheckedAccount<'info>,
+}
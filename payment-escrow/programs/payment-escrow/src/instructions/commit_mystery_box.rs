@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::randomness::Randomness;
+use common::units::SOL_DECIMALS;
+
+/// Buy a mystery box: pay its price into the buyer vault and bind the purchase escrow
+/// to a freshly committed Switchboard randomness account
+/// The escrow can't be cancelled afterwards; it's fulfilled once the randomness is revealed
+pub fn commit_mystery_box(ctx: Context<CommitMysteryBox>, seed: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // The randomness must be committed in the previous slot so its value was unknown
+    // to everyone, including the buyer, when the purchase was bound to it
+    let randomness = Randomness::load(&ctx.accounts.randomness_account)?;
+    randomness.check_fresh_commit(clock.slot)?;
+    
+    let price = ctx.accounts.mystery_box.price;
+    
+    // Pay the price into the buyer vault, topping up its rent reserve first
+    let shortfall = BuyerVault::rent_shortfall(&ctx.accounts.buyer_vault.to_account_info())?;
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.buyer_vault.to_account_info(),
+            },
+        ),
+        price.checked_add(shortfall).ok_or(EscrowError::NumericalOverflow)?,
+    )?;
+    
+    let buyer_vault = &mut ctx.accounts.buyer_vault;
+    buyer_vault.buyer = ctx.accounts.buyer.key();
+    buyer_vault.payment_token_mint = None;
+    buyer_vault.bump = ctx.bumps.buyer_vault;
+    buyer_vault.deposit(price)?;
+    
+    let mystery_box = &ctx.accounts.mystery_box;
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    escrow.buyer = ctx.accounts.buyer.key();
+    escrow.creator = mystery_box.creator;
+    escrow.content_id = mystery_box.box_id;
+    escrow.price = price;
+    escrow.payment_token_mint = None;
+    escrow.payment_amount = price;
+    escrow.access_mint_address = None;
+    escrow.listing = None;
+    escrow.price_version = 0;
+    escrow.license_terms_hash = [0; 32];
+    escrow.memo = None;
+    escrow.created_ts = clock.unix_timestamp;
+    escrow.seed = seed;
+    escrow.status = EscrowStatus::Initialized;
+    escrow.bump = ctx.bumps.escrow_state;
+    escrow.vault_bump = ctx.bumps.buyer_vault;
+    escrow.payment_decimals = SOL_DECIMALS;
+    escrow.rent_recipient = ctx.accounts.buyer.key();
+    escrow.payment_source = PaymentSource::Onchain;
+    escrow.randomness_account = Some(ctx.accounts.randomness_account.key());
+    escrow.randomness_commit_slot = randomness.seed_slot;
+    escrow.revealed_content_id = None;
+    escrow.distributed_ts = 0;
+    
+    emit!(EscrowInitialized {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        creator: escrow.creator,
+        content_id: escrow.content_id,
+        price,
+        payment_token_mint: None,
+        memo: None,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Mystery box purchased, randomness committed at slot: {}", randomness.seed_slot);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CommitMysteryBox<'info> {
+    /// The buyer purchasing the box
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Mystery box PDA
+    #[account(
+        seeds = [MysteryBox::SEED_PREFIX, mystery_box.creator.as_ref(), mystery_box.box_id.as_ref()],
+        bump = mystery_box.bump,
+    )]
+    pub mystery_box: Account<'info, MysteryBox>,
+    
+    /// Escrow state PDA account (keyed by the box id)
+    #[account(
+        init,
+        payer = buyer,
+        space = EscrowState::LEN,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            mystery_box.box_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Buyer's SOL vault (created on first use)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerVault::LEN,
+        seeds = [
+            BuyerVault::SEED_PREFIX,
+            buyer.key().as_ref(),
+            BuyerVault::SOL_MINT.as_ref(),
+        ],
+        bump
+    )]
+    pub buyer_vault: Account<'info, BuyerVault>,
+    
+    /// Switchboard randomness account
+    /// CHECK: Owner and layout are validated when read
+    pub randomness_account: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::compression::*;
+
+/// Move a completed purchase into a receipt tree and close its escrow account
+/// The leaf (see `receipt_leaf`) and the emitted event keep a verifiable proof of purchase
+pub fn compress_receipt(ctx: Context<CompressReceipt>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let escrow = &ctx.accounts.escrow_state;
+    
+    // The buyer or the platform admin can compress a purchase record
+    require!(
+        authority == escrow.buyer || authority == ctx.accounts.platform_config.admin,
+        EscrowError::Unauthorized
+    );
+    
+    // Only finished purchases are historical records
+    require!(
+        escrow.status == EscrowStatus::Completed,
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    let escrow_key = escrow.key();
+    let leaf = receipt_leaf(&escrow_key, escrow);
+    
+    let receipt_tree = &mut ctx.accounts.receipt_tree;
+    let leaf_index = receipt_tree.next_leaf_index()?;
+    let merkle_tree_key = receipt_tree.merkle_tree;
+    let authority_seeds: &[&[u8]] = &[
+        ReceiptTree::SEED_PREFIX,
+        merkle_tree_key.as_ref(),
+        &[receipt_tree.bump],
+    ];
+    
+    CompressionAccounts {
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        authority: ctx.accounts.receipt_tree.to_account_info(),
+        noop_program: ctx.accounts.noop_program.to_account_info(),
+        compression_program: ctx.accounts.compression_program.to_account_info(),
+    }
+    .append(authority_seeds, leaf)?;
+    
+    emit!(ReceiptCompressed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: escrow_key,
+        merkle_tree: merkle_tree_key,
+        leaf_index,
+        leaf,
+        buyer: escrow.buyer,
+        creator: escrow.creator,
+        content_id: escrow.content_id,
+        access_mint: escrow.access_mint_address,
+        payment_token_mint: escrow.payment_token_mint,
+        payment_amount: escrow.payment_amount,
+        created_ts: escrow.created_ts,
+        seed: escrow.seed,
+    });
+    
+    msg!("Compressed escrow {} as receipt leaf {}", escrow_key, leaf_index);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompressReceipt<'info> {
+    /// The buyer or the platform admin
+    pub authority: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Completed escrow, closed once compressed
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        has_one = rent_recipient @ EscrowError::InvalidRentRecipient,
+        close = rent_recipient,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Account that paid the escrow's rent, refunded when it closes
+    /// CHECK: Must match the rent recipient recorded on the escrow
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+    
+    /// Receipt tree PDA, the tree authority
+    #[account(
+        mut,
+        seeds = [ReceiptTree::SEED_PREFIX, receipt_tree.merkle_tree.as_ref()],
+        bump = receipt_tree.bump,
+    )]
+    pub receipt_tree: Account<'info, ReceiptTree>,
+    
+    /// Concurrent Merkle tree the receipt is appended to
+    /// CHECK: Must be the receipt tree's Merkle tree
+    #[account(
+        mut,
+        address = receipt_tree.merkle_tree @ EscrowError::InvalidReceiptTree,
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// Noop program
+    /// CHECK: Address checked
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+    
+    /// Account compression program
+    /// CHECK: Address checked
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use common::bps::MAX_BPS;
+use crate::state::*;
+use crate::errors::*;
+
+/// Configure the refund insurance pool (admin only)
+/// Setting a zero contribution stops diverting platform fees; pooled funds stay in the account
+pub fn configure_insurance_pool(
+    ctx: Context<ConfigureInsurancePool>,
+    contribution_bps: u16,
+    max_payout: u64,
+) -> Result<()> {
+    require!(contribution_bps <= MAX_BPS, EscrowError::InvalidInsuranceConfig);
+    
+    let insurance_pool = &mut ctx.accounts.insurance_pool;
+    
+    insurance_pool.contribution_bps = contribution_bps;
+    insurance_pool.max_payout = max_payout;
+    insurance_pool.bump = ctx.bumps.insurance_pool;
+    
+    msg!("Insurance pool configured: {} bps of platform fees, max payout: {}", contribution_bps, max_payout);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureInsurancePool<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Insurance pool PDA
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = InsurancePool::LEN,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use common::bps::MAX_BPS;
+use crate::state::*;
+use crate::errors::*;
+
+/// Configure the platform-wide sale window (admin only)
+/// Setting a zero discount ends the sale; the subsidy budget stays in the account
+pub fn configure_platform_sale(
+    ctx: Context<ConfigurePlatformSale>,
+    discount_bps: u16,
+    start_ts: i64,
+    end_ts: i64,
+    subsidized: bool,
+) -> Result<()> {
+    require!(discount_bps <= MAX_BPS, EscrowError::InvalidPlatformSale);
+    require!(end_ts > start_ts, EscrowError::InvalidSaleSchedule);
+    
+    let platform_sale = &mut ctx.accounts.platform_sale;
+    
+    platform_sale.discount_bps = discount_bps;
+    platform_sale.start_ts = start_ts;
+    platform_sale.end_ts = end_ts;
+    platform_sale.subsidized = subsidized;
+    platform_sale.bump = ctx.bumps.platform_sale;
+    
+    msg!(
+        "Platform sale configured: {} bps from {} to {}, subsidized: {}",
+        discount_bps,
+        start_ts,
+        end_ts,
+        subsidized
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePlatformSale<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Platform sale PDA
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PlatformSale::LEN,
+        seeds = [PlatformSale::SEED_PREFIX],
+        bump
+    )]
+    pub platform_sale: Account<'info, PlatformSale>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Contribute SOL to an active crowdfunding campaign
+pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::ZeroPaymentAmount);
+    
+    let campaign = &mut ctx.accounts.campaign;
+    let clock = Clock::get()?;
+    
+    require!(
+        campaign.status == CampaignStatus::Active,
+        EscrowError::CampaignNotActive
+    );
+    require!(
+        clock.unix_timestamp < campaign.deadline,
+        EscrowError::CampaignEnded
+    );
+    
+    // Group buys raise exactly the listing price
+    if campaign.is_group_buy() {
+        let total = campaign
+            .total_raised
+            .checked_add(amount)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        require!(total <= campaign.goal, EscrowError::GroupBuyOverfunded);
+    }
+    
+    // Transfer contribution to the campaign vault
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.contributor.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let contribution = &mut ctx.accounts.contribution;
+    
+    // First contribution from this wallet
+    if contribution.amount == 0 {
+        contribution.campaign = campaign.key();
+        contribution.contributor = ctx.accounts.contributor.key();
+        contribution.access_claimed = false;
+        contribution.bump = ctx.bumps.contribution;
+        
+        campaign.contributor_count = campaign
+            .contributor_count
+            .checked_add(1)
+            .ok_or(EscrowError::NumericalOverflow)?;
+    }
+    
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    campaign.total_raised = campaign
+        .total_raised
+        .checked_add(amount)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    msg!("Contribution of {} from: {}, total raised: {}/{}", 
+        amount, contribution.contributor, campaign.total_raised, campaign.goal);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Contribute<'info> {
+    /// The wallet contributing to the campaign
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    /// Campaign state PDA
+    #[account(
+        mut,
+        seeds = [
+            CampaignState::SEED_PREFIX,
+            campaign.creator.as_ref(),
+            campaign.content_id.as_ref(),
+            campaign.seed.to_le_bytes().as_ref(),
+        ],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, CampaignState>,
+    
+    /// Contribution PDA for this contributor
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = Contribution::LEN,
+        seeds = [
+            Contribution::SEED_PREFIX,
+            campaign.key().as_ref(),
+            contributor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// Campaign vault PDA holding contributions
+    #[account(
+        mut,
+        seeds = [CampaignState::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
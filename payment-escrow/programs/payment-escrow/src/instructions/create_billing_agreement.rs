@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Authorize the platform to bill a listing's rental price every rental period
+/// The price and period are fixed at today's listing terms; `deposit` pre-funds the
+/// agreement and the first payment can be pulled immediately
+pub fn create_billing_agreement(ctx: Context<CreateBillingAgreement>, deposit: u64) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    // Frozen (taken down) content can't be sold, nor sold to denylisted wallets
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    check_not_denylisted(&ctx.accounts.denylist_entry, Some(listing))?;
+    
+    // The agreement holds its deposit in lamports, so only priced SOL rentals qualify
+    require!(listing.rental_duration > 0, EscrowError::RentalsDisabled);
+    require!(
+        listing.payment_token_mint.is_none() && listing.rental_price > 0,
+        EscrowError::InvalidPrice
+    );
+    
+    if deposit > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.billing_agreement.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+    
+    let billing_agreement = &mut ctx.accounts.billing_agreement;
+    
+    billing_agreement.buyer = ctx.accounts.buyer.key();
+    billing_agreement.listing = listing.key();
+    billing_agreement.creator = listing.creator;
+    billing_agreement.content_id = listing.content_id;
+    billing_agreement.amount = listing.rental_price;
+    billing_agreement.period = listing.rental_duration;
+    billing_agreement.next_pull_ts = clock.unix_timestamp;
+    billing_agreement.deposit = deposit;
+    billing_agreement.pull_count = 0;
+    billing_agreement.bump = ctx.bumps.billing_agreement;
+    
+    emit!(BillingAgreementCreated {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        billing_agreement: billing_agreement.key(),
+        buyer: billing_agreement.buyer,
+        listing: billing_agreement.listing,
+        amount: billing_agreement.amount,
+        period: billing_agreement.period,
+    });
+    
+    msg!("Billing agreement created for: {}, amount: {} every {}s", billing_agreement.buyer, billing_agreement.amount, billing_agreement.period);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateBillingAgreement<'info> {
+    /// The buyer authorizing the billing
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Listing PDA
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Billing agreement PDA (holds the deposit)
+    #[account(
+        init,
+        payer = buyer,
+        space = BillingAgreement::LEN,
+        seeds = [BillingAgreement::SEED_PREFIX, buyer.key().as_ref(), listing.key().as_ref()],
+        bump
+    )]
+    pub billing_agreement: Account<'info, BillingAgreement>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, listing.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's denylist entry; purchases are rejected while it exists
+    /// CHECK: Validated by seeds, only checked for existence
+    #[account(seeds = [DenylistEntry::SEED_PREFIX, buyer.key().as_ref()], bump)]
+    pub denylist_entry: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::*;
+use crate::errors::*;
+use crate::content::*;
+use crate::payment::*;
+use common::units::SOL_DECIMALS;
+
+/// Create a creator-signed listing offering a content for sale
+pub fn create_listing(
+    ctx: Context<CreateListing>,
+    content_id: [u8; 32],
+    price: u64,
+    payment_token_mint: Option<Pubkey>,
+    price_change_policy: PriceChangePolicy,
+    license_terms_hash: [u8; 32],
+) -> Result<()> {
+    validate_content_id(&content_id)?;
+    
+    // Dust-priced listings cost more in rent than they earn
+    ctx.accounts.platform_config.check_price_floor(price, &payment_token_mint)?;
+    
+    // Creators must back their listings with a bond when the platform requires one
+    let min_creator_bond = ctx.accounts.platform_config.min_creator_bond;
+    if min_creator_bond > 0 {
+        let creator_bond = ctx
+            .accounts
+            .creator_bond
+            .as_ref()
+            .ok_or(EscrowError::InsufficientBond)?;
+        require!(creator_bond.staked >= min_creator_bond, EscrowError::InsufficientBond);
+    }
+    
+    // SPL listings must pass the payment mint so its extensions can be checked
+    if payment_token_mint.is_some() {
+        let payment_mint = ctx
+            .accounts
+            .payment_mint
+            .as_ref()
+            .ok_or(EscrowError::PaymentMintMismatch)?;
+        validate_payment_mint(&payment_mint.to_account_info(), &ctx.accounts.platform_config)?;
+    }
+    
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    listing.creator = ctx.accounts.creator.key();
+    listing.content_id = content_id;
+    listing.price = price;
+    listing.payment_token_mint = payment_token_mint;
+    listing.price_version = 0;
+    listing.price_change_policy = price_change_policy;
+    listing.rental_price = 0;
+    listing.rental_duration = 0;
+    listing.premium_price = 0;
+    listing.license_terms_hash = license_terms_hash;
+    listing.sale_start_ts = 0;
+    listing.sale_end_ts = 0;
+    listing.phases = Vec::new();
+    listing.gate_mint = None;
+    listing.require_creator_badge = false;
+    listing.purchase_cooldown = 0;
+    listing.ignore_denylist = false;
+    listing.platform = None;
+    listing.payment_decimals = ctx
+        .accounts
+        .payment_mint
+        .as_ref()
+        .map_or(SOL_DECIMALS, |payment_mint| payment_mint.decimals);
+    listing.resale_royalty_bps = 0;
+    listing.platform_sale_opt_in = false;
+    listing.strict_mode = false;
+    listing.region = 0;
+    listing.cancellation_fee_bps = 0;
+    listing.rating_count = 0;
+    listing.rating_sum = 0;
+    listing.created_ts = clock.unix_timestamp;
+    listing.bump = ctx.bumps.listing;
+    
+    msg!("Listing created by creator: {}, price: {}", listing.creator, price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: [u8; 32], price: u64, payment_token_mint: Option<Pubkey>)]
+pub struct CreateListing<'info> {
+    /// The creator offering the content
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        init,
+        payer = creator,
+        space = Listing::LEN,
+        seeds = [
+            Listing::SEED_PREFIX,
+            creator.key().as_ref(),
+            content_id.as_ref(),
+        ],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Payment token mint (required for SPL listings)
+    #[account(
+        constraint = Some(payment_mint.key()) == payment_token_mint @ EscrowError::PaymentMintMismatch,
+    )]
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// Creator's bond (required when the platform sets a minimum bond)
+    #[account(
+        seeds = [CreatorBond::SEED_PREFIX, creator.key().as_ref()],
+        bump = creator_bond.bump,
+    )]
+    pub creator_bond: Option<Account<'info, CreatorBond>>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::content::*;
+
+/// Create a mystery box selling one of several contents at a single price
+/// The content each buyer receives is drawn with Switchboard randomness
+pub fn create_mystery_box(
+    ctx: Context<CreateMysteryBox>,
+    box_id: [u8; 32],
+    price: u64,
+    items: Vec<[u8; 32]>,
+) -> Result<()> {
+    validate_content_id(&box_id)?;
+    require!(price > 0, EscrowError::InvalidPrice);
+    require!(
+        !items.is_empty() && items.len() <= MysteryBox::MAX_ITEMS,
+        EscrowError::InvalidMysteryBox
+    );
+    for item in &items {
+        validate_content_id(item)?;
+    }
+    
+    let mystery_box = &mut ctx.accounts.mystery_box;
+    
+    mystery_box.creator = ctx.accounts.creator.key();
+    mystery_box.box_id = box_id;
+    mystery_box.price = price;
+    mystery_box.items = items;
+    mystery_box.bump = ctx.bumps.mystery_box;
+    
+    msg!("Mystery box created with {} items, price: {}", mystery_box.items.len(), price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(box_id: [u8; 32])]
+pub struct CreateMysteryBox<'info> {
+    /// The creator of the box's contents
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Mystery box PDA
+    #[account(
+        init,
+        payer = creator,
+        space = MysteryBox::LEN,
+        seeds = [MysteryBox::SEED_PREFIX, creator.key().as_ref(), box_id.as_ref()],
+        bump
+    )]
+    pub mystery_box: Account<'info, MysteryBox>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Buy a listing's access for DAO members, paid from the DAO treasury
+/// The treasury is usually a governance PDA signing through CPI (e.g. a Realms native
+/// treasury executing a proposal). It pays the listing price once per member, and
+/// an access token is minted to every member wallet listed in the remaining accounts
+pub fn dao_purchase<'info>(
+    ctx: Context<'_, '_, '_, 'info, DaoPurchase<'info>>,
+    member_count: u8,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    // Treasury purchases are paid in lamports
+    require!(
+        listing.payment_token_mint.is_none() && listing.price > 0,
+        EscrowError::InvalidPrice
+    );
+    require!(
+        listing.is_on_sale(Clock::get()?.unix_timestamp),
+        EscrowError::SaleNotActive
+    );
+    
+    // Gated and phased listings are checked per buyer, so they can't be bought in bulk
+    require!(listing.gate_mint.is_none(), EscrowError::GateTokenRequired);
+    require!(listing.phases.is_empty(), EscrowError::NoActivePhase);
+    
+    // Members are the trailing (wallet, token account) pairs; collaborators come first
+    let member_accounts = member_count as usize * 2;
+    require!(
+        member_count > 0
+            && member_count as usize <= MAX_DAO_MEMBERS
+            && ctx.remaining_accounts.len() >= member_accounts,
+        EscrowError::InvalidDaoMembers
+    );
+    let (collaborators, members) = ctx
+        .remaining_accounts
+        .split_at(ctx.remaining_accounts.len() - member_accounts);
+    
+    let amount = listing
+        .price
+        .checked_mul(member_count as u64)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    // Pay the distribution vault from the treasury (a signer through the caller's CPI)
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.distribution_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // CPI to Distribution program to split the payment; treasury purchases are SOL-only, so
+    // the SPL token accounts are never read and the vault and payees stand in for them
+    distribute(
+        CpiContext::new(
+            ctx.accounts.distribution_program.to_account_info(),
+            DistributeAccounts {
+                payer: ctx.accounts.treasury.to_account_info(),
+                split_state: ctx.accounts.split_state.to_account_info(),
+                distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                vault: ctx.accounts.distribution_vault.to_account_info(),
+                creator: ctx.accounts.creator_payout.to_account_info(),
+                platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                vault_token_account: ctx.accounts.distribution_vault.to_account_info(),
+                creator_token_account: ctx.accounts.creator_payout.to_account_info(),
+                platform_treasury_token_account: ctx.accounts.platform_treasury.to_account_info(),
+                token_program: ctx.accounts.system_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                fee_waiver: None,
+            },
+        )
+        .with_remaining_accounts(collaborators.to_vec()),
+        amount,
+    )?;
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept this flow
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program once per member
+    for member in members.chunks(2) {
+        mint_access(
+            CpiContext::new_with_signer(
+                ctx.accounts.access_mint_program.to_account_info(),
+                AccessMintAccounts {
+                    buyer: member[0].clone(),
+                    payer: ctx.accounts.treasury.to_account_info(),
+                    access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                    mint: ctx.accounts.access_mint.to_account_info(),
+                    mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                    buyer_token_account: member[1].clone(),
+                    purchase_receipt: None,
+                    access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                    preview_grant: None,
+                    access_collection: None,
+                    access_metadata: None,
+                    collection_mint: None,
+                    collection_metadata: None,
+                    collection_master_edition: None,
+                    token_metadata_program: None,
+                    token_program: ctx.accounts.access_token_program.to_account_info(),
+                    associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[access_minter_seeds],
+            ),
+        )?;
+    }
+    
+    emit!(DaoPurchaseCompleted {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        treasury: ctx.accounts.treasury.key(),
+        listing: listing.key(),
+        content_id: listing.content_id,
+        member_count,
+        amount,
+    });
+    
+    msg!("DAO purchase by treasury: {}, members: {}, amount: {}", 
+        ctx.accounts.treasury.key(), member_count, amount);
+    
+    Ok(())
+}
+
+/// Maximum members minted access in one DAO purchase (bounded by transaction size)
+pub const MAX_DAO_MEMBERS: usize = 10;
+
+#[derive(Accounts)]
+pub struct DaoPurchase<'info> {
+    /// DAO treasury paying for the purchase (a PDA signing through CPI)
+    #[account(mut)]
+    pub treasury: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Listing being bought
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, listing.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the listed content
+    #[account(
+        mut,
+        constraint = access_mint_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the listed content
+    #[account(
+        mut,
+        constraint = split_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// System-owned, so SOL moved into it can only leave through the distribution program
+    #[account(
+        mut,
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+        seeds::program = distribution_program.key(),
+    )]
+    pub distribution_vault: SystemAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator_payout: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts receiving their share, followed by
+    // `member_count` (member wallet, member access token account) pairs
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Reject the submitted milestone, sending it back to the creator (buyer only)
+pub fn dispute_milestone(ctx: Context<DisputeMilestone>) -> Result<()> {
+    let milestone_state = &mut ctx.accounts.milestone_state;
+    let index = milestone_state.current;
+    let milestone = milestone_state.current_milestone()?;
+    
+    require!(
+        milestone.status == MilestoneStatus::Submitted,
+        EscrowError::InvalidMilestoneStatus
+    );
+    
+    milestone.status = MilestoneStatus::Disputed;
+    
+    emit!(MilestoneDisputed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        milestone_state: milestone_state.key(),
+        buyer: milestone_state.buyer,
+        creator: milestone_state.creator,
+        index,
+    });
+    
+    msg!("Milestone {} disputed", index);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisputeMilestone<'info> {
+    /// The buyer who commissioned the content
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Milestone state PDA
+    #[account(
+        mut,
+        seeds = [
+            MilestoneState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            milestone_state.content_id.as_ref(),
+            milestone_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub milestone_state: Account<'info, MilestoneState>,
+}
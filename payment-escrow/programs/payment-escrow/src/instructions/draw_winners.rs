@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::randomness::Randomness;
+
+/// Draw a queue's winners from the revealed value of its committed randomness account
+pub fn draw_winners(ctx: Context<DrawWinners>) -> Result<()> {
+    let clock = Clock::get()?;
+    let queue_state = &mut ctx.accounts.queue_state;
+    
+    require!(
+        queue_state.status == QueueStatus::Committed,
+        EscrowError::InvalidQueueStatus
+    );
+    
+    // Past the deadline the entrants are refunded instead
+    require!(
+        !queue_state.is_abandoned(clock.unix_timestamp),
+        EscrowError::QueueDrawExpired
+    );
+    
+    let randomness = Randomness::load(&ctx.accounts.randomness_account)?;
+    
+    // A re-committed account would carry a different value than the one bound at commit
+    require!(
+        randomness.seed_slot == queue_state.commit_slot,
+        EscrowError::RandomnessExpired
+    );
+    
+    queue_state.winning_seed = randomness.revealed_value(clock.slot)?;
+    queue_state.status = QueueStatus::Drawn;
+    
+    emit!(QueueDrawn {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        queue: queue_state.key(),
+        entrants: queue_state.entrants,
+        capacity: queue_state.capacity,
+        winning_seed: queue_state.winning_seed,
+    });
+    
+    msg!("Queue drawn, {} winners of {} entrants", queue_state.capacity, queue_state.entrants);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DrawWinners<'info> {
+    /// Anyone can reveal the draw
+    pub authority: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Queue state PDA
+    #[account(
+        mut,
+        seeds = [QueueState::SEED_PREFIX, queue_state.listing.as_ref()],
+        bump = queue_state.bump,
+    )]
+    pub queue_state: Account<'info, QueueState>,
+    
+    /// Switchboard randomness account committed for the draw
+    /// CHECK: Must match the committed account; owner and layout are validated when read
+    #[account(address = queue_state.randomness_account @ EscrowError::RandomnessAccountMismatch)]
+    pub randomness_account: UncheckedAccount<'info>,
+}
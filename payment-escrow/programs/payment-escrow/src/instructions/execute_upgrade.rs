@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_sha256_hasher::hash;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Upgrade the program from the proposed buffer once the timelock has elapsed
+/// The buffer contents must still match the announced hash, and both the buffer
+/// and the program must have the governance PDA as their upgrade authority
+pub fn execute_upgrade(ctx: Context<ExecuteUpgrade>) -> Result<()> {
+    let governance = &ctx.accounts.upgrade_governance;
+    let buffer = ctx.accounts.buffer.key();
+    
+    require!(
+        governance.proposed_buffer.is_some(),
+        EscrowError::NoUpgradeProposed
+    );
+    require!(
+        governance.is_executable(Clock::get()?.unix_timestamp),
+        EscrowError::UpgradeTimelockActive
+    );
+    require!(
+        governance.proposed_buffer == Some(buffer),
+        EscrowError::UpgradeBufferMismatch
+    );
+    
+    // Guard against the buffer being rewritten after the proposal was announced
+    let buffer_hash = {
+        let data = ctx.accounts.buffer.try_borrow_data()?;
+        let metadata_len = UpgradeableLoaderState::size_of_buffer_metadata();
+        require!(data.len() > metadata_len, EscrowError::UpgradeBufferMismatch);
+        hash(&data[metadata_len..]).to_bytes()
+    };
+    require!(
+        buffer_hash == governance.proposed_hash,
+        EscrowError::UpgradeBufferMismatch
+    );
+    
+    let governance_seeds = &[UpgradeGovernance::SEED_PREFIX, &[governance.bump]];
+    
+    invoke_signed(
+        &bpf_loader_upgradeable::upgrade(
+            ctx.accounts.program.key,
+            &buffer,
+            &governance.key(),
+            ctx.accounts.admin.key,
+        ),
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+            ctx.accounts.buffer.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            governance.to_account_info(),
+        ],
+        &[governance_seeds],
+    )?;
+    
+    ctx.accounts.upgrade_governance.clear_proposal();
+    
+    emit!(UpgradeExecuted {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        buffer,
+        buffer_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Program upgraded from buffer: {}", buffer);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteUpgrade<'info> {
+    /// The platform admin (receives the buffer's lamports)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Upgrade governance PDA, the program's upgrade authority
+    #[account(
+        mut,
+        seeds = [UpgradeGovernance::SEED_PREFIX],
+        bump = upgrade_governance.bump,
+    )]
+    pub upgrade_governance: Account<'info, UpgradeGovernance>,
+    
+    /// This program's account
+    /// CHECK: Must be this program
+    #[account(mut, address = crate::ID)]
+    pub program: UncheckedAccount<'info>,
+    
+    /// This program's program data account
+    /// CHECK: Program data PDA validated by seeds
+    #[account(
+        mut,
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: UncheckedAccount<'info>,
+    
+    /// Buffer holding the new program binary
+    /// CHECK: Must match the proposed buffer and hash
+    #[account(mut, owner = bpf_loader_upgradeable::ID)]
+    pub buffer: UncheckedAccount<'info>,
+    
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// Clock sysvar
+    pub clock: Sysvar<'info, Clock>,
+    
+    /// BPF upgradeable loader
+    /// CHECK: Address validated
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+}
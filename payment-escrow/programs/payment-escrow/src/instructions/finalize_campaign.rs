@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+
+/// Finalize a campaign: distribute funds if the goal was reached, otherwise open refunds
+/// Permissionless - anyone can crank finalization
+pub fn finalize_campaign<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeCampaign<'info>>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let clock = Clock::get()?;
+    
+    require!(
+        campaign.status == CampaignStatus::Active,
+        EscrowError::CampaignNotActive
+    );
+    
+    if campaign.total_raised < campaign.goal {
+        // Goal missed - only fail once the deadline has passed
+        require!(
+            clock.unix_timestamp >= campaign.deadline,
+            EscrowError::CampaignStillActive
+        );
+        
+        campaign.status = CampaignStatus::Failed;
+        
+        msg!("Campaign failed: raised {}/{}, refunds open", campaign.total_raised, campaign.goal);
+        
+        return Ok(());
+    }
+    
+    campaign.status = CampaignStatus::Succeeded;
+    let total_raised = campaign.total_raised;
+    
+    // Move raised funds from the campaign vault to the distribution vault
+    let campaign_key = campaign.key();
+    let vault_bump = ctx.bumps.vault;
+    let vault_seeds = &[
+        CampaignState::VAULT_SEED_PREFIX,
+        campaign_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.distribution_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        total_raised,
+    )?;
+    
+    // CPI to Distribution program to split the raised funds
+    let remaining_accounts = ctx.remaining_accounts.to_vec();
+    
+    distribute(
+        CpiContext::new(
+            ctx.accounts.distribution_program.to_account_info(),
+            DistributeAccounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                split_state: ctx.accounts.split_state.to_account_info(),
+                distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                vault: ctx.accounts.distribution_vault.to_account_info(),
+                creator: ctx.accounts.creator.to_account_info(),
+                platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                vault_token_account: ctx.accounts.distribution_vault.to_account_info(),
+                creator_token_account: ctx.accounts.creator.to_account_info(),
+                platform_treasury_token_account: ctx.accounts.platform_treasury.to_account_info(),
+                token_program: ctx.accounts.system_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                fee_waiver: None,
+            },
+        )
+        .with_remaining_accounts(remaining_accounts),
+        total_raised,
+    )?;
+    
+    msg!("Campaign succeeded: {} lamports distributed, {} contributors can claim access", 
+        total_raised, ctx.accounts.campaign.contributor_count);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeCampaign<'info> {
+    /// Wallet cranking finalization (pays rent for the distribution record)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Campaign state PDA
+    #[account(
+        mut,
+        seeds = [
+            CampaignState::SEED_PREFIX,
+            campaign.creator.as_ref(),
+            campaign.content_id.as_ref(),
+            campaign.seed.to_le_bytes().as_ref(),
+        ],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, CampaignState>,
+    
+    /// Campaign vault PDA holding contributions
+    #[account(
+        mut,
+        seeds = [CampaignState::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the campaign's content
+    #[account(
+        mut,
+        constraint = split_state.creator == campaign.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == campaign.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Account<'info, SplitState>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// CHECK: Validated by distribution program via CPI
+    #[account(mut)]
+    pub distribution_vault: UncheckedAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Halt new escrows and purchases of a content (DMCA/abuse handling)
+/// Holders keep their access tokens
+pub fn freeze_content(
+    ctx: Context<FreezeContent>,
+    content_id: [u8; 32],
+    reason: String,
+) -> Result<()> {
+    require!(
+        reason.len() <= ContentFreeze::MAX_REASON_LEN,
+        EscrowError::FreezeReasonTooLong
+    );
+    
+    let freeze = &mut ctx.accounts.content_freeze;
+    let now = Clock::get()?.unix_timestamp;
+    
+    freeze.content_id = content_id;
+    freeze.frozen = true;
+    freeze.reason = reason.clone();
+    freeze.updated_ts = now;
+    freeze.bump = ctx.bumps.content_freeze;
+    
+    emit!(ContentFrozen {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        content_id,
+        reason,
+        timestamp: now,
+    });
+    
+    msg!("Content frozen by admin: {}", ctx.accounts.admin.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: [u8; 32])]
+pub struct FreezeContent<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Content freeze PDA (kept after unfreezing as a takedown record)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ContentFreeze::LEN,
+        seeds = [ContentFreeze::SEED_PREFIX, content_id.as_ref()],
+        bump,
+    )]
+    pub content_freeze: Account<'info, ContentFreeze>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
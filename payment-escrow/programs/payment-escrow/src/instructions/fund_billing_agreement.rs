@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Top up a billing agreement's deposit so future periods can be pulled
+pub fn fund_billing_agreement(ctx: Context<FundBillingAgreement>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::InvalidPrice);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.billing_agreement.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let billing_agreement = &mut ctx.accounts.billing_agreement;
+    billing_agreement.fund(amount)?;
+    
+    msg!("Billing deposit funded: {}, total: {}", amount, billing_agreement.deposit);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundBillingAgreement<'info> {
+    /// The buyer who owns the agreement
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Billing agreement PDA
+    #[account(
+        mut,
+        seeds = [BillingAgreement::SEED_PREFIX, buyer.key().as_ref(), billing_agreement.listing.as_ref()],
+        bump = billing_agreement.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub billing_agreement: Account<'info, BillingAgreement>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
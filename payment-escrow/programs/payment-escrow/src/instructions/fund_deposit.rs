@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use crate::state::*;
+use crate::errors::*;
+use crate::payment::*;
+
+/// Add funds to the buyer's deposit for a payment mint, creating it on first use
+/// SPL deposits are paid into a token account owned by the deposit PDA
+pub fn fund_deposit(ctx: Context<FundDeposit>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::InvalidPrice);
+    
+    let payment_token_mint = ctx.accounts.payment_token_mint.key();
+    let is_sol_deposit = payment_token_mint == System::id();
+    
+    let spl = if is_sol_deposit {
+        None
+    } else {
+        validate_vault_token_account(
+            &ctx.accounts.deposit_token_account,
+            &ctx.accounts.buyer_deposit.key(),
+            &payment_token_mint,
+        )?;
+        
+        Some(SplPayment {
+            token_program: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.payment_token_mint.to_account_info(),
+            from: ctx.accounts.buyer_token_account.to_account_info(),
+            to: ctx.accounts.deposit_token_account.to_account_info(),
+        })
+    };
+    
+    // Credit what arrives after any Token-2022 transfer fee
+    let received_amount = pay_into_vault(
+        ctx.accounts.buyer.to_account_info(),
+        ctx.accounts.buyer_deposit.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        spl,
+        amount,
+    )?;
+    
+    let buyer_deposit = &mut ctx.accounts.buyer_deposit;
+    buyer_deposit.buyer = ctx.accounts.buyer.key();
+    buyer_deposit.payment_token_mint = if is_sol_deposit { None } else { Some(payment_token_mint) };
+    buyer_deposit.bump = ctx.bumps.buyer_deposit;
+    buyer_deposit.credit(received_amount)?;
+    
+    msg!("Deposit funded: {}, balance: {}", received_amount, buyer_deposit.balance);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundDeposit<'info> {
+    /// The buyer funding the deposit
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Buyer deposit PDA for the payment mint
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerDeposit::LEN,
+        seeds = [BuyerDeposit::SEED_PREFIX, buyer.key().as_ref(), payment_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub buyer_deposit: Account<'info, BuyerDeposit>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Used as a seed; validated by the token program for SPL deposits
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Buyer's SPL token account (for SPL deposits)
+    /// CHECK: Optional account, validated when an SPL deposit is made
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Deposit's SPL token account, owned by the deposit PDA (for SPL deposits)
+    /// CHECK: Optional account, validated when an SPL deposit is made
+    #[account(mut)]
+    pub deposit_token_account: UncheckedAccount<'info>,
+    
+    /// Token program (SPL Token or Token-2022, for SPL deposits)
+    /// CHECK: Optional account, validated when an SPL deposit is made
+    pub token_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Top up the refund insurance pool (admin only)
+pub fn fund_insurance_pool(ctx: Context<FundInsurancePool>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.insurance_pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    msg!("Insurance pool funded with {} lamports", amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundInsurancePool<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Insurance pool PDA
+    #[account(
+        mut,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
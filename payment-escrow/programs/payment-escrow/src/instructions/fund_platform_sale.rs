@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+/// Add lamports to the platform sale's subsidy budget (admin only)
+pub fn fund_platform_sale(ctx: Context<FundPlatformSale>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.platform_sale.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    msg!("Platform sale subsidy budget funded with {} lamports", amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundPlatformSale<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Platform sale PDA
+    #[account(
+        mut,
+        seeds = [PlatformSale::SEED_PREFIX],
+        bump = platform_sale.bump,
+    )]
+    pub platform_sale: Account<'info, PlatformSale>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
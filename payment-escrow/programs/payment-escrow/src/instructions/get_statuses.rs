@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Maximum escrows per status query
+pub const MAX_STATUS_QUERY_ESCROWS: usize = 32;
+
+/// Status byte for an account that isn't a live escrow (closed, or not an escrow at all)
+pub const ESCROW_STATUS_UNKNOWN: u8 = u8::MAX;
+
+/// Status of each escrow passed in remaining accounts, one byte per account in order
+/// Bytes are EscrowStatus discriminants (0 = Initialized, 1 = Completed, 2 = Cancelled)
+/// or ESCROW_STATUS_UNKNOWN
+/// Read-only - intended to be simulated, result is returned via return data
+pub fn get_statuses(ctx: Context<GetStatuses>) -> Result<Vec<u8>> {
+    let escrow_count = ctx.remaining_accounts.len();
+    require!(
+        escrow_count > 0 && escrow_count <= MAX_STATUS_QUERY_ESCROWS,
+        EscrowError::InvalidBatchSize
+    );
+    
+    ctx.remaining_accounts
+        .iter()
+        .map(|account| {
+            // Anything this program doesn't own can't be an escrow
+            if *account.owner != crate::ID {
+                return Ok(ESCROW_STATUS_UNKNOWN);
+            }
+            
+            let data = account.try_borrow_data()?;
+            Ok(match EscrowState::try_deserialize(&mut &data[..]) {
+                Ok(escrow) => escrow.status as u8,
+                Err(_) => ESCROW_STATUS_UNKNOWN,
+            })
+        })
+        .collect()
+}
+
+#[derive(Accounts)]
+pub struct GetStatuses {}
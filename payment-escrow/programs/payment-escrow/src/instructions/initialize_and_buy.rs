@@ -0,0 +1,506 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program::System;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+use crate::payment::*;
+use crate::introspection::*;
+
+/// Create an escrow, take payment, mint access and distribute in one instruction
+/// The escrow completes immediately, so payment goes straight to the distribution
+/// vault instead of through the buyer vault
+pub fn initialize_and_buy<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeAndBuy<'info>>,
+    content_id: [u8; 32],
+    price: u64,
+    payment_token_mint: Option<Pubkey>,
+    seed: u64,
+    license_terms_hash: [u8; 32],
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    validate_content_id(&content_id)?;
+    
+    // Frozen (taken down) content can't be sold, nor sold to denylisted wallets
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    check_not_denylisted(
+        &ctx.accounts.denylist_entry,
+        ctx.accounts.listing.as_deref().map(|account| &**account),
+    )?;
+    
+    // Zero price is only valid for free content offered through a creator listing
+    require!(
+        price > 0 || ctx.accounts.listing.is_some(),
+        EscrowError::InvalidPrice
+    );
+    ctx.accounts.platform_config.check_price_floor(price, &payment_token_mint)?;
+    
+    let clock = Clock::get()?;
+    
+    // Timed drops can only be bought while the sale window is open,
+    // phased listings sell through the phase active now, gated listings
+    // only to holders of the gating token and verified-creator listings
+    // only while the creator holds a badge
+    if let Some(listing) = ctx.accounts.listing.as_mut() {
+        require!(
+            listing.is_on_sale(clock.unix_timestamp),
+            EscrowError::SaleNotActive
+        );
+        listing.record_phase_purchase(
+            clock.unix_timestamp,
+            price,
+            ctx.accounts.buyer.key,
+            &allowlist_proof,
+        )?;
+        listing.check_gate(ctx.accounts.gate_token_account.as_deref().map(|account| &**account))?;
+        listing.check_creator_badge(ctx.accounts.creator_badge.as_deref().map(|account| &**account))?;
+        listing.check_platform(ctx.accounts.platform.as_deref(), &ctx.accounts.split_state)?;
+        
+        // Strict-mode listings can't be bought alongside other programs' instructions
+        if listing.strict_mode {
+            let instructions_sysvar = ctx
+                .accounts
+                .instructions_sysvar
+                .as_ref()
+                .ok_or(EscrowError::InstructionsSysvarRequired)?;
+            check_no_foreign_instructions(instructions_sysvar)?;
+        }
+        
+        // Throttled listings limit how often one wallet can buy this content
+        if let Some(purchase_counter) = ctx.accounts.purchase_counter.as_mut() {
+            purchase_counter.buyer = ctx.accounts.buyer.key();
+            purchase_counter.content_id = content_id;
+            purchase_counter.bump = ctx.bumps.purchase_counter.unwrap_or_default();
+        }
+        listing.check_purchase_cooldown(
+            ctx.accounts.purchase_counter.as_deref_mut().map(|account| &mut **account),
+            clock.unix_timestamp,
+        )?;
+    }
+    
+    // Listing terms are enforced by account constraints; unlisted sales may need the creator's signature
+    if ctx.accounts.listing.is_none() && ctx.accounts.platform_config.require_creator_cosign {
+        require!(
+            ctx.accounts.creator.is_signer,
+            EscrowError::CreatorSignatureRequired
+        );
+    }
+    
+    if payment_token_mint.is_some() {
+        validate_payment_mint(&ctx.accounts.payment_token_mint, &ctx.accounts.platform_config)?;
+    }
+    
+    // Prices are in the payment mint's base units; the listing must agree on the decimals
+    let decimals = payment_decimals(&ctx.accounts.payment_token_mint)?;
+    if let Some(listing) = &ctx.accounts.listing {
+        require!(
+            listing.payment_decimals == decimals,
+            EscrowError::PaymentDecimalsMismatch
+        );
+    }
+    
+    let is_free_claim = price == 0;
+    
+    // Pay straight into the distribution vault, distributing what arrives after any transfer fee
+    let distribution_amount = if is_free_claim {
+        msg!("Free claim - no payment required");
+        0
+    } else {
+        let spl = if payment_token_mint.is_none() {
+            None
+        } else {
+            require!(
+                ctx.accounts.buyer_token_account.key() != System::id(),
+                EscrowError::TokenAccountRequired
+            );
+            validate_payment_units(
+                &ctx.accounts.payment_token_mint,
+                decimals,
+                &[
+                    &ctx.accounts.buyer_token_account,
+                    &ctx.accounts.distribution_vault_token_account,
+                ],
+            )?;
+            
+            Some(SplPayment {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.payment_token_mint.to_account_info(),
+                from: ctx.accounts.buyer_token_account.to_account_info(),
+                to: ctx.accounts.distribution_vault_token_account.to_account_info(),
+            })
+        };
+        
+        pay_into_vault(
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.distribution_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            spl,
+            price,
+        )?
+    };
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept this flow
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program to mint access token to buyer
+    mint_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            AccessMintAccounts {
+                buyer: ctx.accounts.buyer.to_account_info(),
+                payer: ctx.accounts.buyer.to_account_info(),
+                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                mint: ctx.accounts.access_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                buyer_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+                purchase_receipt: None,
+                access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                preview_grant: None,
+                access_collection: None,
+                access_metadata: None,
+                collection_mint: None,
+                collection_metadata: None,
+                collection_master_edition: None,
+                token_metadata_program: None,
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[access_minter_seeds],
+        ),
+    )?;
+    
+    // CPI to Distribution program to split the payment
+    if !is_free_claim {
+        let remaining_accounts = ctx.remaining_accounts.to_vec();
+        
+        distribute(
+            CpiContext::new(
+                ctx.accounts.distribution_program.to_account_info(),
+                DistributeAccounts {
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    split_state: ctx.accounts.split_state.to_account_info(),
+                    distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                    revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                    vault: ctx.accounts.distribution_vault.to_account_info(),
+                    creator: ctx.accounts.creator.to_account_info(),
+                    platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                    payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+                    vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
+                    creator_token_account: ctx.accounts.creator_token_account.to_account_info(),
+                    platform_treasury_token_account: ctx.accounts.platform_treasury_token_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    fee_waiver: None,
+                },
+            )
+            .with_remaining_accounts(remaining_accounts),
+            distribution_amount,
+        )?;
+    }
+    
+    // SOL purchases earn loyalty points
+    if let Some(buyer_loyalty) = ctx.accounts.buyer_loyalty.as_mut() {
+        buyer_loyalty.buyer = ctx.accounts.buyer.key();
+        buyer_loyalty.bump = ctx.bumps.buyer_loyalty.unwrap_or_default();
+        
+        if payment_token_mint.is_none() && price > 0 {
+            let points = buyer_loyalty.credit(price, ctx.accounts.platform_config.loyalty_rate_bps)?;
+            
+            emit!(LoyaltyPointsEarned {
+                event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+                buyer: buyer_loyalty.buyer,
+                points,
+                balance: buyer_loyalty.points,
+            });
+        }
+    }
+    
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    // Record the completed purchase
+    escrow.buyer = ctx.accounts.buyer.key();
+    escrow.creator = ctx.accounts.creator.key();
+    escrow.content_id = content_id;
+    escrow.price = price;
+    escrow.payment_token_mint = payment_token_mint;
+    escrow.payment_amount = price;
+    escrow.access_mint_address = Some(ctx.accounts.access_mint.key());
+    escrow.listing = ctx.accounts.listing.as_ref().map(|listing| listing.key());
+    escrow.price_version = ctx.accounts.listing.as_ref().map_or(0, |listing| listing.price_version);
+    escrow.license_terms_hash = license_terms_hash;
+    escrow.memo = None;
+    escrow.created_ts = clock.unix_timestamp;
+    escrow.seed = seed;
+    // Created and completed in one step
+    escrow.status = EscrowStatus::Initialized;
+    escrow.transition_to(EscrowStatus::Completed)?;
+    escrow.bump = ctx.bumps.escrow_state;
+    // Payment never passes through the buyer vault
+    escrow.vault_bump = 0;
+    escrow.payment_decimals = decimals;
+    escrow.rent_recipient = ctx.accounts.buyer.key();
+    escrow.payment_source = PaymentSource::Onchain;
+    escrow.randomness_account = None;
+    escrow.randomness_commit_slot = 0;
+    escrow.revealed_content_id = None;
+    // The payment was distributed above unless access was free
+    escrow.distributed_ts = if is_free_claim { 0 } else { clock.unix_timestamp };
+    
+    if is_free_claim {
+        emit!(FreeClaim {
+            event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+            escrow: escrow.key(),
+            buyer: escrow.buyer,
+            creator: escrow.creator,
+            content_id,
+            access_mint: ctx.accounts.access_mint.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    msg!("Initialize and buy completed, price: {}", price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(
+    content_id: [u8; 32],
+    price: u64,
+    payment_mint: Option<Pubkey>,
+    seed: u64,
+    license_terms_hash: [u8; 32],
+)]
+pub struct InitializeAndBuy<'info> {
+    /// The buyer making the purchase
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// The creator who will receive payment
+    /// Must sign when the platform requires creator co-signing and no listing is provided
+    /// CHECK: Validated by distribution program via CPI
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's denylist entry; purchases are rejected while it exists
+    /// CHECK: Validated by seeds, only checked for existence
+    #[account(seeds = [DenylistEntry::SEED_PREFIX, buyer.key().as_ref()], bump)]
+    pub denylist_entry: UncheckedAccount<'info>,
+    
+    /// Optional creator-signed listing for this content
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            creator.key().as_ref(),
+            content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::ListingMismatch,
+        constraint = listing.offers_price(price) @ EscrowError::ListingMismatch,
+        constraint = listing.payment_token_mint == payment_mint @ EscrowError::PaymentMintMismatch,
+        constraint = listing.license_terms_hash == license_terms_hash @ EscrowError::LicenseTermsMismatch,
+    )]
+    pub listing: Option<Box<Account<'info, Listing>>>,
+    
+    /// Buyer's token account for the listing's gating mint (required when the listing is gated)
+    #[account(token::authority = buyer)]
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Creator's badge record (required when the listing requires a verified creator)
+    #[account(
+        seeds = [CreatorBadge::SEED_PREFIX, creator.key().as_ref()],
+        bump = creator_badge.bump,
+    )]
+    pub creator_badge: Option<Box<Account<'info, CreatorBadge>>>,
+    
+    /// Marketplace platform the listing is sold through (required when listing.platform is set)
+    #[account(
+        seeds = [Platform::SEED_PREFIX, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+    
+    /// Buyer's purchase counter for this content (required when the listing is throttled)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PurchaseCounter::LEN,
+        seeds = [
+            PurchaseCounter::SEED_PREFIX,
+            creator.key().as_ref(),
+            content_id.as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub purchase_counter: Option<Box<Account<'info, PurchaseCounter>>>,
+    
+    /// Buyer's loyalty points (optional; earns points on SOL purchases)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerLoyalty::LEN,
+        seeds = [BuyerLoyalty::SEED_PREFIX, buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_loyalty: Option<Box<Account<'info, BuyerLoyalty>>>,
+    
+    /// Instructions sysvar (required for strict-mode listings)
+    /// CHECK: Address must be the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
+    /// Escrow state PDA account recording the purchase
+    #[account(
+        init,
+        payer = buyer,
+        space = EscrowState::LEN,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub escrow_state: Box<Account<'info, EscrowState>>,
+    
+    /// Buyer's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Token program (SPL Token or Token-2022, for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    pub token_program: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the purchased content
+    #[account(
+        mut,
+        constraint = access_mint_state.creator == creator.key() @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == content_id @ EscrowError::InvalidContentId,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    /// CHECK: Validated by access mint program via CPI
+    #[account(mut)]
+    pub access_mint: UncheckedAccount<'info>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the purchased content
+    #[account(
+        mut,
+        constraint = split_state.creator == creator.key() @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// CHECK: Vault is a PDA derived from split_state in the distribution program
+    /// Validated by distribution program via CPI
+    #[account(mut)]
+    pub distribution_vault: UncheckedAccount<'info>,
+    
+    /// Distribution vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub distribution_vault_token_account: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Validated by distribution program via CPI
+    #[account(mut)]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Used to determine payment type in distribution, must match the escrow terms
+    #[account(
+        constraint = payment_token_mint.key() == payment_mint.unwrap_or(System::id()) @ EscrowError::PaymentMintMismatch,
+    )]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Creator's token account (for SPL payments)
+    /// CHECK: Optional, validated by distribution program when SPL payment is used
+    #[account(mut)]
+    pub creator_token_account: UncheckedAccount<'info>,
+    
+    /// Platform treasury token account (for SPL payments)
+    /// CHECK: Optional, validated by distribution program when SPL payment is used
+    #[account(mut)]
+    pub platform_treasury_token_account: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts (SOL) or token accounts (SPL)
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Create the attempt log for a listing, opting it into purchase attempt analytics
+pub fn initialize_attempt_log(ctx: Context<InitializeAttemptLog>) -> Result<()> {
+    let attempt_log = &mut ctx.accounts.attempt_log;
+    attempt_log.listing = ctx.accounts.listing.key();
+    attempt_log.head = 0;
+    attempt_log.total_attempts = 0;
+    attempt_log.total_successes = 0;
+    attempt_log.entries = Vec::new();
+    attempt_log.bump = ctx.bumps.attempt_log;
+    
+    msg!("Attempt log created for listing: {}", attempt_log.listing);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeAttemptLog<'info> {
+    /// The creator who owns the listing
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Attempt log PDA for the listing
+    #[account(
+        init,
+        payer = creator,
+        space = AttemptLog::LEN,
+        seeds = [AttemptLog::SEED_PREFIX, listing.key().as_ref()],
+        bump,
+    )]
+    pub attempt_log: Account<'info, AttemptLog>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
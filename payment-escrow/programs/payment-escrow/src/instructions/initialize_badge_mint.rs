@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::{
+    initialize_mint2,
+    spl_token_2022::{extension::ExtensionType, state::Mint as Token2022Mint},
+    InitializeMint2, Token2022,
+};
+use anchor_spl::token_2022_extensions::{
+    non_transferable_mint_initialize, permanent_delegate_initialize,
+    NonTransferableMintInitialize, PermanentDelegateInitialize,
+};
+use crate::state::*;
+use crate::errors::*;
+
+/// Create the non-transferable creator badge mint
+/// The platform config is mint authority and permanent delegate, so badges
+/// cannot be moved by creators and can be burned when revoked
+pub fn initialize_badge_mint(ctx: Context<InitializeBadgeMint>) -> Result<()> {
+    let mint_info = ctx.accounts.badge_mint.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let platform_config = ctx.accounts.platform_config.key();
+    
+    let space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+    ])?;
+    
+    let mint_seeds: &[&[u8]] = &[
+        CreatorBadge::MINT_SEED_PREFIX,
+        &[ctx.bumps.badge_mint],
+    ];
+    
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.admin.to_account_info(),
+                to: mint_info.clone(),
+            },
+            &[mint_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
+    
+    // Extensions must be initialized before the mint itself
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint_info.clone(),
+        },
+    ))?;
+    
+    permanent_delegate_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            PermanentDelegateInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint_info.clone(),
+            },
+        ),
+        &platform_config,
+    )?;
+    
+    initialize_mint2(
+        CpiContext::new(token_program, InitializeMint2 { mint: mint_info }),
+        0,
+        &platform_config,
+        None,
+    )?;
+    
+    msg!("Creator badge mint initialized: {}", ctx.accounts.badge_mint.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeBadgeMint<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA (badge mint authority and permanent delegate)
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Creator badge mint PDA, created by this instruction
+    /// CHECK: Allocated and initialized as a Token-2022 mint here
+    #[account(
+        mut,
+        seeds = [CreatorBadge::MINT_SEED_PREFIX],
+        bump,
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+    
+    /// Token-2022 program
+    pub token_program: Program<'info, Token2022>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
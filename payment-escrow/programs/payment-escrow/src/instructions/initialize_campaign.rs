@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::content::*;
+
+/// Initialize a crowdfunding campaign for a content
+pub fn initialize_campaign(
+    ctx: Context<InitializeCampaign>,
+    content_id: [u8; 32],
+    goal: u64,
+    deadline: i64,
+    seed: u64,
+) -> Result<()> {
+    validate_content_id(&content_id)?;
+    
+    require!(goal > 0, EscrowError::InvalidPrice);
+    
+    let clock = Clock::get()?;
+    require!(deadline > clock.unix_timestamp, EscrowError::InvalidDeadline);
+    
+    let campaign = &mut ctx.accounts.campaign;
+    
+    campaign.creator = ctx.accounts.creator.key();
+    campaign.content_id = content_id;
+    campaign.goal = goal;
+    campaign.deadline = deadline;
+    campaign.total_raised = 0;
+    campaign.contributor_count = 0;
+    campaign.status = CampaignStatus::Active;
+    campaign.seed = seed;
+    campaign.bump = ctx.bumps.campaign;
+    campaign.listing = None;
+    campaign.access_threshold = 0;
+    
+    // Keep the vault rent-exempt so a contribution or refund smaller than the
+    // rent minimum can't leave it below the threshold
+    let shortfall = CampaignState::vault_rent_shortfall(&ctx.accounts.vault.to_account_info())?;
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+        msg!("Funded campaign vault rent reserve with {} lamports", shortfall);
+    }
+    
+    msg!("Campaign initialized by creator: {}, goal: {}, deadline: {}", 
+        campaign.creator, goal, deadline);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: [u8; 32], goal: u64, deadline: i64, seed: u64)]
+pub struct InitializeCampaign<'info> {
+    /// The creator launching the campaign
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Campaign state PDA
+    #[account(
+        init,
+        payer = creator,
+        space = CampaignState::LEN,
+        seeds = [
+            CampaignState::SEED_PREFIX,
+            creator.key().as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub campaign: Account<'info, CampaignState>,
+    
+    /// Campaign vault PDA that will hold contributions
+    #[account(
+        mut,
+        seeds = [CampaignState::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
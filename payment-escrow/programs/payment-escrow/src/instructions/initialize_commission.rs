@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::content::*;
+
+/// Fund a commissioned content with the total of its milestones
+/// Each milestone is paid out to the creator once the buyer approves it, and must be delivered
+/// within `MilestoneState::MILESTONE_DEADLINE` of the previous one
+pub fn initialize_commission(
+    ctx: Context<InitializeCommission>,
+    creator: Pubkey,
+    content_id: [u8; 32],
+    seed: u64,
+    milestone_amounts: Vec<u64>,
+) -> Result<()> {
+    validate_content_id(&content_id)?;
+    
+    // At least one milestone, none of them empty
+    require!(
+        !milestone_amounts.is_empty()
+            && milestone_amounts.len() <= MilestoneState::MAX_MILESTONES
+            && milestone_amounts.iter().all(|amount| *amount > 0),
+        EscrowError::InvalidMilestones
+    );
+    
+    let total = milestone_amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    // Escrow the full commission in the milestone state account
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.milestone_state.to_account_info(),
+            },
+        ),
+        total,
+    )?;
+    
+    let milestone_state = &mut ctx.accounts.milestone_state;
+    let clock = Clock::get()?;
+    
+    milestone_state.buyer = ctx.accounts.buyer.key();
+    milestone_state.creator = creator;
+    milestone_state.content_id = content_id;
+    milestone_state.seed = seed;
+    milestone_state.milestones = milestone_amounts
+        .into_iter()
+        .map(|amount| Milestone { amount, status: MilestoneStatus::Pending })
+        .collect();
+    milestone_state.current = 0;
+    milestone_state.released_amount = 0;
+    milestone_state.created_ts = clock.unix_timestamp;
+    milestone_state.milestone_started_ts = clock.unix_timestamp;
+    milestone_state.bump = ctx.bumps.milestone_state;
+    
+    msg!(
+        "Commission funded by: {}, milestones: {}, total: {}",
+        milestone_state.buyer,
+        milestone_state.milestones.len(),
+        total
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey, content_id: [u8; 32], seed: u64)]
+pub struct InitializeCommission<'info> {
+    /// The buyer commissioning the content
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Milestone state PDA
+    #[account(
+        init,
+        payer = buyer,
+        space = MilestoneState::LEN,
+        seeds = [
+            MilestoneState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub milestone_state: Account<'info, MilestoneState>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
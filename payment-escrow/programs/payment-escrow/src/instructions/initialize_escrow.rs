@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+use crate::payment::*;
+use common::units::SOL_DECIMALS;
 
 /// Initialize a new escrow account for a purchase
 pub fn initialize_escrow(
@@ -9,8 +16,59 @@ pub fn initialize_escrow(
     price: u64,
     payment_token_mint: Option<Pubkey>,
     seed: u64,
+    memo: Option<String>,
 ) -> Result<()> {
-    require!(price > 0, EscrowError::InvalidPrice);
+    validate_content_id(&content_id)?;
+    
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    if let Some(memo) = &memo {
+        require!(memo.len() <= EscrowState::MAX_MEMO_LEN, EscrowError::MemoTooLong);
+    }
+    
+    // Zero price is only valid for free content offered through a creator listing
+    require!(
+        price > 0 || ctx.accounts.listing.is_some(),
+        EscrowError::InvalidPrice
+    );
+    ctx.accounts.platform_config.check_price_floor(price, &payment_token_mint)?;
+    
+    // Listing terms are enforced by account constraints; unlisted sales may need the creator's signature
+    if ctx.accounts.listing.is_none() && ctx.accounts.platform_config.require_creator_cosign {
+        require!(
+            ctx.accounts.creator.is_signer,
+            EscrowError::CreatorSignatureRequired
+        );
+    }
+    
+    // SPL escrows must pass the payment mint so its extensions can be checked
+    if payment_token_mint.is_some() {
+        let payment_mint = ctx
+            .accounts
+            .payment_mint
+            .as_ref()
+            .ok_or(EscrowError::PaymentMintMismatch)?;
+        validate_payment_mint(&payment_mint.to_account_info(), &ctx.accounts.platform_config)?;
+    }
+    
+    // Create the vault's token account up front so the purchase doesn't need a setup transaction
+    if let Some(vault_token_account) = &ctx.accounts.vault_token_account {
+        create_vault_token_account(ctx.accounts, vault_token_account)?;
+    }
+    
+    // Prices are in the payment mint's base units; the listing must agree on the decimals
+    let payment_decimals = ctx
+        .accounts
+        .payment_mint
+        .as_ref()
+        .map_or(SOL_DECIMALS, |payment_mint| payment_mint.decimals);
+    if let Some(listing) = &ctx.accounts.listing {
+        require!(
+            listing.payment_decimals == payment_decimals,
+            EscrowError::PaymentDecimalsMismatch
+        );
+    }
     
     let escrow = &mut ctx.accounts.escrow_state;
     let clock = Clock::get()?;
@@ -23,17 +81,103 @@ pub fn initialize_escrow(
     escrow.payment_token_mint = payment_token_mint;
     escrow.payment_amount = 0;
     escrow.access_mint_address = None;
+    escrow.listing = ctx.accounts.listing.as_ref().map(|listing| listing.key());
+    escrow.price_version = ctx.accounts.listing.as_ref().map_or(0, |listing| listing.price_version);
+    escrow.license_terms_hash = [0; 32];
+    escrow.memo = memo.clone();
     escrow.created_ts = clock.unix_timestamp;
     escrow.seed = seed;
     escrow.status = EscrowStatus::Initialized;
     escrow.bump = ctx.bumps.escrow_state;
+    escrow.vault_bump = ctx.bumps.buyer_vault;
+    escrow.payment_decimals = payment_decimals;
+    escrow.rent_recipient = ctx.accounts.payer.key();
+    escrow.payment_source = PaymentSource::Onchain;
+    escrow.randomness_account = None;
+    escrow.randomness_commit_slot = 0;
+    escrow.revealed_content_id = None;
+    escrow.distributed_ts = 0;
+    
+    // Payments for every escrow of this buyer and mint go through one shared vault
+    let buyer_vault = &mut ctx.accounts.buyer_vault;
+    buyer_vault.buyer = ctx.accounts.buyer.key();
+    buyer_vault.payment_token_mint = payment_token_mint;
+    buyer_vault.bump = ctx.bumps.buyer_vault;
+    
+    // SOL payments sit in the vault's own lamports, so keep it rent-exempt
+    // before any deposit lands (vaults created by older versions may be short)
+    if payment_token_mint.is_none() {
+        let shortfall = BuyerVault::rent_shortfall(&ctx.accounts.buyer_vault.to_account_info())?;
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.buyer.to_account_info(),
+                        to: ctx.accounts.buyer_vault.to_account_info(),
+                    },
+                ),
+                shortfall,
+            )?;
+            
+            msg!("Funded buyer vault rent reserve with {} lamports", shortfall);
+        }
+    }
     
-    msg!("Escrow initialized for buyer: {}, creator: {}, content_id: {:?}, price: {}", 
-        escrow.buyer, escrow.creator, content_id, price);
+    emit!(EscrowInitialized {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        creator: escrow.creator,
+        content_id,
+        price,
+        payment_token_mint,
+        memo,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Escrow initialized, price: {}", price);
     
     Ok(())
 }
 
+/// Create the buyer vault's associated token account for the payment mint (if missing),
+/// paid for by `payer`, and check it belongs to the vault
+fn create_vault_token_account<'info>(
+    accounts: &InitializeEscrow<'info>,
+    vault_token_account: &UncheckedAccount<'info>,
+) -> Result<()> {
+    let (payment_mint, token_program, associated_token_program) = match (
+        accounts.payment_mint.as_ref(),
+        accounts.token_program.as_ref(),
+        accounts.associated_token_program.as_ref(),
+    ) {
+        (Some(mint), Some(token_program), Some(associated_token_program)) => {
+            (mint, token_program, associated_token_program)
+        }
+        _ => return err!(EscrowError::TokenAccountRequired),
+    };
+    
+    // Idempotent create also checks the address is the vault's ATA for this mint and token program
+    associated_token::create_idempotent(CpiContext::new(
+        associated_token_program.to_account_info(),
+        Create {
+            payer: accounts.payer.to_account_info(),
+            associated_token: vault_token_account.to_account_info(),
+            authority: accounts.buyer_vault.to_account_info(),
+            mint: payment_mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+    
+    validate_vault_token_account(
+        &vault_token_account.to_account_info(),
+        &accounts.buyer_vault.key(),
+        &payment_mint.key(),
+    )
+}
+
 #[derive(Accounts)]
 #[instruction(content_id: [u8; 32], price: u64, payment_token_mint: Option<Pubkey>, seed: u64)]
 pub struct InitializeEscrow<'info> {
@@ -41,14 +185,47 @@ pub struct InitializeEscrow<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
     
+    /// Pays the escrow's rent and receives it back when the escrow closes
+    /// (the buyer, or a relayer sponsoring the transaction)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     /// The creator who will receive payment
+    /// Must sign when the platform requires creator co-signing and no listing is provided
     /// CHECK: Creator account doesn't need to be validated beyond being a valid pubkey
     pub creator: UncheckedAccount<'info>,
     
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Optional creator-signed listing for this content
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            creator.key().as_ref(),
+            content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::ListingMismatch,
+        constraint = listing.offers_price(price) @ EscrowError::ListingMismatch,
+        constraint = listing.payment_token_mint == payment_token_mint @ EscrowError::PaymentMintMismatch,
+    )]
+    pub listing: Option<Account<'info, Listing>>,
+    
     /// Escrow state PDA account
     #[account(
         init,
-        payer = buyer,
+        payer = payer,
         space = EscrowState::LEN,
         seeds = [
             EscrowState::SEED_PREFIX,
@@ -60,6 +237,38 @@ pub struct InitializeEscrow<'info> {
     )]
     pub escrow_state: Account<'info, EscrowState>,
     
+    /// Buyer vault shared by the buyer's escrows in this payment mint (created on first use)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerVault::LEN,
+        seeds = [
+            BuyerVault::SEED_PREFIX,
+            buyer.key().as_ref(),
+            BuyerVault::mint_seed(&payment_token_mint).as_ref(),
+        ],
+        bump
+    )]
+    pub buyer_vault: Account<'info, BuyerVault>,
+    
+    /// Payment token mint (required for SPL escrows)
+    #[account(
+        constraint = Some(payment_mint.key()) == payment_token_mint @ EscrowError::PaymentMintMismatch,
+    )]
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// Buyer vault's token account for the payment mint, created here if missing
+    /// (omit when it already exists or for SOL escrows)
+    /// CHECK: Must be the buyer vault's ATA, validated by the associated token program
+    #[account(mut)]
+    pub vault_token_account: Option<UncheckedAccount<'info>>,
+    
+    /// Token program owning the payment mint (required with the vault token account)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Associated token program (required with the vault token account)
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
 }
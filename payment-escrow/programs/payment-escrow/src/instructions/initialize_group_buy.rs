@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::content::*;
+
+/// Open a group buy: a campaign raising a listing's price from several buyers
+/// Once fully funded it's finalized like any campaign; contributors whose total reaches
+/// `access_threshold` claim an access token, and everyone is refunded if it fails
+pub fn initialize_group_buy(
+    ctx: Context<InitializeGroupBuy>,
+    deadline: i64,
+    seed: u64,
+    access_threshold: u64,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    // Campaign vaults hold lamports, so only priced SOL listings can be bought as a group
+    require!(
+        listing.payment_token_mint.is_none() && listing.price > 0,
+        EscrowError::InvalidPrice
+    );
+    require!(
+        access_threshold <= listing.price,
+        EscrowError::InvalidAccessThreshold
+    );
+    
+    let clock = Clock::get()?;
+    require!(deadline > clock.unix_timestamp, EscrowError::InvalidDeadline);
+    
+    let campaign = &mut ctx.accounts.campaign;
+    
+    campaign.creator = listing.creator;
+    campaign.content_id = listing.content_id;
+    campaign.goal = listing.price;
+    campaign.deadline = deadline;
+    campaign.total_raised = 0;
+    campaign.contributor_count = 0;
+    campaign.status = CampaignStatus::Active;
+    campaign.seed = seed;
+    campaign.bump = ctx.bumps.campaign;
+    campaign.listing = Some(listing.key());
+    campaign.access_threshold = access_threshold;
+    
+    // Keep the vault rent-exempt so a contribution or refund smaller than the
+    // rent minimum can't leave it below the threshold
+    let shortfall = CampaignState::vault_rent_shortfall(&ctx.accounts.vault.to_account_info())?;
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.organizer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+        msg!("Funded campaign vault rent reserve with {} lamports", shortfall);
+    }
+    
+    msg!("Group buy opened by: {}, price: {}, access threshold: {}", 
+        ctx.accounts.organizer.key(), campaign.goal, access_threshold);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(deadline: i64, seed: u64)]
+pub struct InitializeGroupBuy<'info> {
+    /// The buyer organizing the group buy
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    /// Listing being bought
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, listing.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Campaign state PDA backing the group buy
+    #[account(
+        init,
+        payer = organizer,
+        space = CampaignState::LEN,
+        seeds = [
+            CampaignState::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub campaign: Account<'info, CampaignState>,
+    
+    /// Campaign vault PDA that will hold contributions
+    #[account(
+        mut,
+        seeds = [CampaignState::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
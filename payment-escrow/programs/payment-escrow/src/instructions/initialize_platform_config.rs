@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::program::PaymentEscrow;
+use crate::state::*;
+use crate::errors::*;
+
+/// Initialize the global platform configuration
+/// Only the program's upgrade authority can initialize it and become the admin,
+/// so the config can't be claimed by whoever calls first after deployment
+pub fn initialize_platform_config(
+    ctx: Context<InitializePlatformConfig>,
+    require_creator_cosign: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    
+    config.admin = ctx.accounts.admin.key();
+    config.require_creator_cosign = require_creator_cosign;
+    config.whitelisted_extension_mints = Vec::new();
+    config.min_price_lamports = 0;
+    config.mint_min_prices = Vec::new();
+    config.loyalty_rate_bps = 0;
+    config.min_creator_bond = 0;
+    config.settlement_authority = Pubkey::default();
+    config.rebate_mint = Pubkey::default();
+    config.rebate_bps = 0;
+    config.event_sequence = 0;
+    config.payment_tolerance_bps = 0;
+    config.refund_overpayment = false;
+    config.max_cancellation_fee_bps = 0;
+    config.fee_exempt_wallets = Vec::new();
+    config.bump = ctx.bumps.platform_config;
+    
+    msg!("Platform config initialized by admin: {}, require_creator_cosign: {}", 
+        config.admin, require_creator_cosign);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    /// The platform admin, which must be the program's upgrade authority
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        init,
+        payer = admin,
+        space = PlatformConfig::LEN,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// This program, used to locate its program data account
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ EscrowError::Unauthorized,
+    )]
+    pub program: Program<'info, PaymentEscrow>,
+    
+    /// Program data account recording the upgrade authority
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ EscrowError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Open a priority access queue for a listing's drop
+/// Buyers register intents at the listing price until `register_end_ts`, then
+/// `capacity` winners are drawn with Switchboard randomness
+pub fn initialize_queue(
+    ctx: Context<InitializeQueue>,
+    capacity: u32,
+    register_end_ts: i64,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    // Queue entries escrow lamports, so only SOL listings can be queued
+    require!(
+        listing.payment_token_mint.is_none() && listing.price > 0,
+        EscrowError::InvalidQueueConfig
+    );
+    require!(
+        capacity > 0 && register_end_ts > clock.unix_timestamp,
+        EscrowError::InvalidQueueConfig
+    );
+    
+    let queue_state = &mut ctx.accounts.queue_state;
+    
+    queue_state.listing = listing.key();
+    queue_state.creator = listing.creator;
+    queue_state.content_id = listing.content_id;
+    queue_state.price = listing.price;
+    queue_state.capacity = capacity;
+    queue_state.register_end_ts = register_end_ts;
+    queue_state.entrants = 0;
+    queue_state.randomness_account = Pubkey::default();
+    queue_state.commit_slot = 0;
+    queue_state.winning_seed = [0u8; 32];
+    queue_state.status = QueueStatus::Registering;
+    queue_state.bump = ctx.bumps.queue_state;
+    
+    msg!("Queue initialized, capacity: {}, registration ends: {}", capacity, register_end_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeQueue<'info> {
+    /// The creator who owns the listing
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Queue state PDA
+    #[account(
+        init,
+        payer = creator,
+        space = QueueState::LEN,
+        seeds = [QueueState::SEED_PREFIX, listing.key().as_ref()],
+        bump
+    )]
+    pub queue_state: Account<'info, QueueState>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::compression::*;
+
+/// Set up a concurrent Merkle tree for compressed purchase receipts
+/// The tree account must already be allocated and owned by the compression program
+pub fn initialize_receipt_tree(
+    ctx: Context<InitializeReceiptTree>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    require!(
+        max_depth > 0 && max_depth <= ReceiptTree::MAX_DEPTH,
+        EscrowError::InvalidReceiptTree
+    );
+    
+    let merkle_tree_key = ctx.accounts.merkle_tree.key();
+    let bump = ctx.bumps.receipt_tree;
+    let authority_seeds: &[&[u8]] = &[ReceiptTree::SEED_PREFIX, merkle_tree_key.as_ref(), &[bump]];
+    
+    CompressionAccounts {
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        authority: ctx.accounts.receipt_tree.to_account_info(),
+        noop_program: ctx.accounts.noop_program.to_account_info(),
+        compression_program: ctx.accounts.compression_program.to_account_info(),
+    }
+    .init_empty_tree(authority_seeds, max_depth, max_buffer_size)?;
+    
+    let receipt_tree = &mut ctx.accounts.receipt_tree;
+    receipt_tree.merkle_tree = merkle_tree_key;
+    receipt_tree.num_receipts = 0;
+    receipt_tree.capacity = 1u64 << max_depth;
+    receipt_tree.bump = bump;
+    
+    msg!("Receipt tree initialized with depth {}", max_depth);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeReceiptTree<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Concurrent Merkle tree account, allocated by the admin beforehand
+    /// CHECK: Owned by the compression program, initialized via CPI
+    #[account(
+        mut,
+        owner = ACCOUNT_COMPRESSION_PROGRAM_ID @ EscrowError::InvalidReceiptTree,
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// Receipt tree PDA, the tree authority
+    #[account(
+        init,
+        payer = admin,
+        space = ReceiptTree::LEN,
+        seeds = [ReceiptTree::SEED_PREFIX, merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub receipt_tree: Account<'info, ReceiptTree>,
+    
+    /// Noop program
+    /// CHECK: Address checked
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+    
+    /// Account compression program
+    /// CHECK: Address checked
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
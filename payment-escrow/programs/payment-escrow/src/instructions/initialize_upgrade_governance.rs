@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Create the upgrade timelock with its mandatory delay
+/// Transfer the program's upgrade authority to this PDA afterwards
+pub fn initialize_upgrade_governance(
+    ctx: Context<InitializeUpgradeGovernance>,
+    delay: i64,
+) -> Result<()> {
+    require!(
+        delay >= UpgradeGovernance::MIN_DELAY,
+        EscrowError::InvalidUpgradeDelay
+    );
+    
+    let governance = &mut ctx.accounts.upgrade_governance;
+    governance.delay = delay;
+    governance.clear_proposal();
+    governance.bump = ctx.bumps.upgrade_governance;
+    
+    msg!("Upgrade governance initialized with a {}s delay", delay);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeUpgradeGovernance<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Upgrade governance PDA
+    #[account(
+        init,
+        payer = admin,
+        space = UpgradeGovernance::LEN,
+        seeds = [UpgradeGovernance::SEED_PREFIX],
+        bump,
+    )]
+    pub upgrade_governance: Account<'info, UpgradeGovernance>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
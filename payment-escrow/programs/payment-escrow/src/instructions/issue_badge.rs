@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Verify a creator by minting them a non-transferable creator badge
+pub fn issue_badge(ctx: Context<IssueBadge>) -> Result<()> {
+    let badge = &mut ctx.accounts.creator_badge;
+    let now = Clock::get()?.unix_timestamp;
+    
+    badge.creator = ctx.accounts.creator.key();
+    badge.issued_ts = now;
+    badge.bump = ctx.bumps.creator_badge;
+    
+    let config_seeds: &[&[u8]] = &[
+        PlatformConfig::SEED_PREFIX,
+        &[ctx.accounts.platform_config.bump],
+    ];
+    
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.badge_mint.to_account_info(),
+                to: ctx.accounts.creator_badge_token_account.to_account_info(),
+                authority: ctx.accounts.platform_config.to_account_info(),
+            },
+            &[config_seeds],
+        ),
+        1,
+    )?;
+    
+    emit!(CreatorBadgeIssued {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        creator: badge.creator,
+        badge_mint: ctx.accounts.badge_mint.key(),
+        timestamp: now,
+    });
+    
+    msg!("Creator badge issued to: {}", badge.creator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IssueBadge<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA (badge mint authority and permanent delegate)
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// The creator being verified
+    /// CHECK: Any wallet can be verified; only used as the badge owner
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Creator badge PDA
+    #[account(
+        init,
+        payer = admin,
+        space = CreatorBadge::LEN,
+        seeds = [CreatorBadge::SEED_PREFIX, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_badge: Account<'info, CreatorBadge>,
+    
+    /// Non-transferable creator badge mint
+    #[account(
+        mut,
+        seeds = [CreatorBadge::MINT_SEED_PREFIX],
+        bump,
+        mint::authority = platform_config,
+        mint::token_program = token_program,
+    )]
+    pub badge_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Creator's badge token account
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = badge_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_badge_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 program
+    pub token_program: Program<'info, Token2022>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer as SplTransfer};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{program::AccessMint, state::AccessMintState};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Escrow access tokens for resale at a fixed SOL price
+/// The listing's current resale royalty is locked in, so the creator can't raise it on the seller
+/// Rented access can't be resold: its expiry is tracked per holder and wouldn't follow the token
+pub fn list_for_resale(ctx: Context<ListForResale>, amount: u64, price: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(price > 0, EscrowError::InvalidPrice);
+    
+    // An access grant means the seller's tokens are a rental
+    require!(
+        ctx.accounts.seller_access_grant.data_is_empty(),
+        EscrowError::RentalNotResellable
+    );
+    
+    // Move the tokens into the resale listing's escrow account
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SplTransfer {
+                from: ctx.accounts.seller_access_token_account.to_account_info(),
+                to: ctx.accounts.resale_token_account.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let resale_listing = &mut ctx.accounts.resale_listing;
+    let clock = Clock::get()?;
+    
+    resale_listing.seller = ctx.accounts.seller.key();
+    resale_listing.listing = ctx.accounts.listing.key();
+    resale_listing.access_mint = ctx.accounts.access_mint.key();
+    resale_listing.amount = amount;
+    resale_listing.price = price;
+    resale_listing.royalty_bps = ctx.accounts.listing.resale_royalty_bps;
+    resale_listing.created_ts = clock.unix_timestamp;
+    resale_listing.bump = ctx.bumps.resale_listing;
+    
+    emit!(AccessListedForResale {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        resale_listing: resale_listing.key(),
+        seller: resale_listing.seller,
+        access_mint: resale_listing.access_mint,
+        amount,
+        price,
+    });
+    
+    msg!("Access listed for resale by: {}, price: {}", resale_listing.seller, price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ListForResale<'info> {
+    /// Holder selling their access tokens
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Primary listing of the content
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Access mint state for the listed content
+    #[account(
+        constraint = access_mint_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+        constraint = access_mint_state.mint == access_mint.key() @ EscrowError::InvalidAccessMint,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Seller's access token account
+    #[account(
+        mut,
+        associated_token::mint = access_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_access_token_account: Account<'info, TokenAccount>,
+    
+    /// Seller's access grant PDA; must not exist (rentals can't be resold)
+    /// CHECK: Validated by seeds against the access mint program
+    #[account(
+        seeds = [common::seeds::ACCESS_GRANT, access_mint.key().as_ref(), seller.key().as_ref()],
+        bump,
+        seeds::program = access_mint_program.key(),
+    )]
+    pub seller_access_grant: UncheckedAccount<'info>,
+    
+    /// Resale listing PDA
+    #[account(
+        init,
+        payer = seller,
+        space = ResaleListing::LEN,
+        seeds = [
+            ResaleListing::SEED_PREFIX,
+            seller.key().as_ref(),
+            access_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub resale_listing: Account<'info, ResaleListing>,
+    
+    /// Escrow token account owned by the resale listing
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = access_mint,
+        associated_token::authority = resale_listing,
+    )]
+    pub resale_token_account: Account<'info, TokenAccount>,
+    
+    /// Access mint program (owner of access grants)
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Token program for access tokens
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
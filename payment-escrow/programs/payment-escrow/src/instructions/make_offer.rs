@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+use crate::payment::pay_into_vault;
+
+/// Bid on a content by escrowing lamports at a proposed price
+/// The creator can accept the offer or the buyer can withdraw it at any time before then
+pub fn make_offer(
+    ctx: Context<MakeOffer>,
+    creator: Pubkey,
+    content_id: [u8; 32],
+    amount: u64,
+    seed: u64,
+) -> Result<()> {
+    validate_content_id(&content_id)?;
+    require!(amount > 0, EscrowError::InvalidPrice);
+    
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    // Escrow the offered lamports in the offer account
+    pay_into_vault(
+        ctx.accounts.buyer.to_account_info(),
+        ctx.accounts.offer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        None,
+        amount,
+    )?;
+    
+    let offer = &mut ctx.accounts.offer;
+    let clock = Clock::get()?;
+    
+    offer.buyer = ctx.accounts.buyer.key();
+    offer.creator = creator;
+    offer.content_id = content_id;
+    offer.amount = amount;
+    offer.seed = seed;
+    offer.created_ts = clock.unix_timestamp;
+    offer.bump = ctx.bumps.offer;
+    
+    emit!(OfferMade {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        offer: offer.key(),
+        buyer: offer.buyer,
+        creator,
+        content_id,
+        amount,
+    });
+    
+    msg!("Offer made by: {}, amount: {}", offer.buyer, amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey, content_id: [u8; 32], amount: u64, seed: u64)]
+pub struct MakeOffer<'info> {
+    /// The buyer making the offer
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Offer PDA
+    #[account(
+        init,
+        payer = buyer,
+        space = Offer::LEN,
+        seeds = [
+            Offer::SEED_PREFIX,
+            buyer.key().as_ref(),
+            content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub offer: Account<'info, Offer>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer as SplTransfer};
+use common::units::SOL_DECIMALS;
+use crate::state::*;
+use crate::errors::*;
+
+/// Migrate an escrow created before vault consolidation
+/// Creates the buyer vault the escrow now pays through, returns any balance stranded
+/// in its legacy per-escrow vault to the buyer and rewrites the escrow in the current layout
+pub fn migrate_legacy_vault(ctx: Context<MigrateLegacyVault>) -> Result<()> {
+    let escrow = LegacyEscrowState::load(&ctx.accounts.escrow_state)?;
+    let escrow_key = ctx.accounts.escrow_state.key();
+    
+    // The escrow must be this program's escrow PDA for the signing buyer
+    let expected_key = Pubkey::create_program_address(
+        &[
+            EscrowState::SEED_PREFIX,
+            escrow.buyer.as_ref(),
+            escrow.content_id.as_ref(),
+            escrow.seed.to_le_bytes().as_ref(),
+            &[escrow.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| EscrowError::NotALegacyEscrow)?;
+    require_keys_eq!(expected_key, escrow_key, EscrowError::NotALegacyEscrow);
+    require_keys_eq!(escrow.buyer, ctx.accounts.buyer.key(), EscrowError::InvalidBuyer);
+    
+    // The buyer vault is derived from the payment mint passed in, so it must be the escrow's
+    let payment_mint = ctx.accounts.payment_mint.as_ref();
+    require!(
+        escrow.payment_token_mint == payment_mint.map(|mint| mint.key()),
+        EscrowError::PaymentMintMismatch
+    );
+    let payment_decimals = payment_mint.map_or(SOL_DECIMALS, |mint| mint.decimals);
+    
+    let buyer_vault = &mut ctx.accounts.buyer_vault;
+    buyer_vault.buyer = escrow.buyer;
+    buyer_vault.payment_token_mint = escrow.payment_token_mint;
+    buyer_vault.bump = ctx.bumps.buyer_vault;
+    
+    let legacy_seeds = &[
+        LegacyEscrowState::VAULT_SEED_PREFIX,
+        escrow_key.as_ref(),
+        &[ctx.bumps.legacy_vault],
+    ];
+    let signer_seeds = &[&legacy_seeds[..]];
+    
+    // Sweep stranded SPL tokens and reclaim the legacy token account rent
+    if let Some(legacy_token_account) = &ctx.accounts.legacy_vault_token_account {
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(EscrowError::TokenAccountRequired)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(EscrowError::InvalidTokenProgram)?;
+        
+        require!(
+            escrow.payment_token_mint == Some(legacy_token_account.mint),
+            EscrowError::PaymentMintMismatch
+        );
+        
+        if legacy_token_account.amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    SplTransfer {
+                        from: legacy_token_account.to_account_info(),
+                        to: buyer_token_account.to_account_info(),
+                        authority: ctx.accounts.legacy_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                legacy_token_account.amount,
+            )?;
+            
+            msg!("Returned {} tokens from legacy vault", legacy_token_account.amount);
+        }
+        
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: legacy_token_account.to_account_info(),
+                destination: ctx.accounts.buyer.to_account_info(),
+                authority: ctx.accounts.legacy_vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    
+    // Sweep stranded lamports
+    let legacy_lamports = ctx.accounts.legacy_vault.lamports();
+    if legacy_lamports > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.legacy_vault.to_account_info(),
+                    to: ctx.accounts.buyer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            legacy_lamports,
+        )?;
+        
+        msg!("Returned {} lamports from legacy vault", legacy_lamports);
+    }
+    
+    // Rewrite the escrow in the current layout, the buyer paying rent for the larger account
+    let escrow_info = ctx.accounts.escrow_state.to_account_info();
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(EscrowState::LEN)
+        .saturating_sub(escrow_info.lamports());
+    if rent_shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: escrow_info.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+    escrow_info.resize(EscrowState::LEN)?;
+    
+    let escrow_state = escrow.into_escrow_state(ctx.bumps.buyer_vault, payment_decimals);
+    escrow_state.try_serialize(&mut &mut escrow_info.try_borrow_mut_data()?[..])?;
+    
+    msg!("Escrow migrated to buyer vault");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateLegacyVault<'info> {
+    /// The buyer who owns the escrow
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Escrow created with a per-escrow vault, still in the legacy layout
+    /// CHECK: Read as a LegacyEscrowState; owner, layout, PDA and buyer checked in the handler
+    #[account(mut)]
+    pub escrow_state: UncheckedAccount<'info>,
+    
+    /// Legacy per-escrow vault PDA
+    #[account(
+        mut,
+        seeds = [LegacyEscrowState::VAULT_SEED_PREFIX, escrow_state.key().as_ref()],
+        bump,
+    )]
+    pub legacy_vault: SystemAccount<'info>,
+    
+    /// The escrow's payment mint (SPL escrows only)
+    pub payment_mint: Option<Account<'info, Mint>>,
+    
+    /// Buyer vault the escrow pays through from now on (created if needed)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerVault::LEN,
+        seeds = [
+            BuyerVault::SEED_PREFIX,
+            buyer.key().as_ref(),
+            BuyerVault::mint_seed(&payment_mint.as_ref().map(|mint| mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    pub buyer_vault: Account<'info, BuyerVault>,
+    
+    /// Legacy vault's SPL token account (SPL escrows only)
+    #[account(
+        mut,
+        token::authority = legacy_vault,
+    )]
+    pub legacy_vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Buyer's SPL token account receiving stranded tokens
+    #[account(
+        mut,
+        token::authority = buyer,
+    )]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Token program (SPL escrows only)
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
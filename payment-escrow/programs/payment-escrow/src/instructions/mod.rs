@@ -1,7 +1,235 @@
 pub mod initialize_escrow;
 pub mod buy_and_mint;
 pub mod cancel_escrow;
+pub mod initialize_platform_config;
+pub mod update_platform_config;
+pub mod create_listing;
+pub mod update_listing_price;
+pub mod initialize_campaign;
+pub mod contribute;
+pub mod finalize_campaign;
+pub mod claim_campaign_access;
+pub mod claim_refund;
+pub mod set_rental_terms;
+pub mod rent_access;
+pub mod set_premium_price;
+pub mod upgrade_access;
+pub mod quote;
+pub mod commit_content_metadata;
+pub mod migrate_legacy_vault;
+pub mod initialize_and_buy;
+pub mod set_license_terms;
+pub mod set_extension_mint_whitelisted;
+pub mod schedule_sale;
+pub mod set_sale_phases;
+pub mod set_gate_mint;
+pub mod initialize_badge_mint;
+pub mod issue_badge;
+pub mod revoke_badge;
+pub mod set_require_creator_badge;
+pub mod initialize_upgrade_governance;
+pub mod propose_upgrade;
+pub mod cancel_upgrade;
+pub mod execute_upgrade;
+pub mod set_purchase_cooldown;
+pub mod freeze_content;
+pub mod unfreeze_content;
+pub mod add_to_denylist;
+pub mod remove_from_denylist;
+pub mod set_ignore_denylist;
+pub mod set_min_price;
+pub mod register_platform;
+pub mod update_platform;
+pub mod set_listing_platform;
+pub mod set_loyalty_rate;
+pub mod redeem_points;
+pub mod stake_bond;
+pub mod request_unstake;
+pub mod unstake_bond;
+pub mod record_dispute;
+pub mod slash_bond;
+pub mod set_min_creator_bond;
+pub mod sweep_surplus;
+pub mod register_foreign_emitter;
+pub mod redeem_crosschain_payment;
+pub mod set_settlement_authority;
+pub mod settle_fiat_purchase;
+pub mod initialize_attempt_log;
+pub mod initialize_receipt_tree;
+pub mod compress_receipt;
+pub mod set_resale_royalty;
+pub mod list_for_resale;
+pub mod buy_resale;
+pub mod cancel_resale;
+pub mod make_offer;
+pub mod accept_offer;
+pub mod withdraw_offer;
+pub mod initialize_commission;
+pub mod submit_milestone;
+pub mod approve_milestone;
+pub mod dispute_milestone;
+pub mod release_milestone;
+pub mod refund_commission;
+pub mod configure_platform_sale;
+pub mod fund_platform_sale;
+pub mod set_platform_sale_opt_in;
+pub mod set_rebate_config;
+pub mod opt_in_rebates;
+pub mod opt_out_rebates;
+pub mod set_strict_mode;
+pub mod initialize_queue;
+pub mod register_intent;
+pub mod commit_draw;
+pub mod draw_winners;
+pub mod claim_result;
+pub mod create_mystery_box;
+pub mod commit_mystery_box;
+pub mod reveal_mystery_box;
+pub mod fulfill_mystery_box;
+pub mod pay_for_access;
+pub mod claim_access;
+pub mod refund_pending_access;
+pub mod initialize_group_buy;
+pub mod dao_purchase;
+pub mod rate_content;
+pub mod configure_insurance_pool;
+pub mod fund_insurance_pool;
+pub mod withdraw_insurance_pool;
+pub mod pay_insurance_claim;
+pub mod get_statuses;
+pub mod set_payment_tolerance;
+pub mod set_refund_overpayment;
+pub mod cancel_expired_batch;
+pub mod create_billing_agreement;
+pub mod fund_billing_agreement;
+pub mod pull_billing_payment;
+pub mod cancel_billing_agreement;
+pub mod fund_deposit;
+pub mod withdraw_deposit;
+pub mod set_deposit_session_key;
+pub mod open_payment_channel;
+pub mod settle_payment_channel;
+pub mod request_channel_close;
+pub mod close_payment_channel;
+pub mod set_listing_region;
+pub mod set_max_cancellation_fee;
+pub mod set_cancellation_fee;
+pub mod set_fee_exempt;
 
 pub use initialize_escrow::*;
 pub use buy_and_mint::*;
 pub use cancel_escrow::*;
+pub use initialize_platform_config::*;
+pub use update_platform_config::*;
+pub use create_listing::*;
+pub use update_listing_price::*;
+pub use initialize_campaign::*;
+pub use contribute::*;
+pub use finalize_campaign::*;
+pub use claim_campaign_access::*;
+pub use claim_refund::*;
+pub use set_rental_terms::*;
+pub use rent_access::*;
+pub use set_premium_price::*;
+pub use upgrade_access::*;
+pub use quote::*;
+pub use commit_content_metadata::*;
+pub use migrate_legacy_vault::*;
+pub use initialize_and_buy::*;
+pub use set_license_terms::*;
+pub use set_extension_mint_whitelisted::*;
+pub use schedule_sale::*;
+pub use set_sale_phases::*;
+pub use set_gate_mint::*;
+pub use initialize_badge_mint::*;
+pub use issue_badge::*;
+pub use revoke_badge::*;
+pub use set_require_creator_badge::*;
+pub use initialize_upgrade_governance::*;
+pub use propose_upgrade::*;
+pub use cancel_upgrade::*;
+pub use execute_upgrade::*;
+pub use set_purchase_cooldown::*;
+pub use freeze_content::*;
+pub use unfreeze_content::*;
+pub use add_to_denylist::*;
+pub use remove_from_denylist::*;
+pub use set_ignore_denylist::*;
+pub use set_min_price::*;
+pub use register_platform::*;
+pub use update_platform::*;
+pub use set_listing_platform::*;
+pub use set_loyalty_rate::*;
+pub use redeem_points::*;
+pub use stake_bond::*;
+pub use request_unstake::*;
+pub use unstake_bond::*;
+pub use record_dispute::*;
+pub use slash_bond::*;
+pub use set_min_creator_bond::*;
+pub use sweep_surplus::*;
+pub use register_foreign_emitter::*;
+pub use redeem_crosschain_payment::*;
+pub use set_settlement_authority::*;
+pub use settle_fiat_purchase::*;
+pub use initialize_attempt_log::*;
+pub use initialize_receipt_tree::*;
+pub use compress_receipt::*;
+pub use set_resale_royalty::*;
+pub use list_for_resale::*;
+pub use buy_resale::*;
+pub use cancel_resale::*;
+pub use make_offer::*;
+pub use accept_offer::*;
+pub use withdraw_offer::*;
+pub use initialize_commission::*;
+pub use submit_milestone::*;
+pub use approve_milestone::*;
+pub use dispute_milestone::*;
+pub use release_milestone::*;
+pub use refund_commission::*;
+pub use configure_platform_sale::*;
+pub use fund_platform_sale::*;
+pub use set_platform_sale_opt_in::*;
+pub use set_rebate_config::*;
+pub use opt_in_rebates::*;
+pub use opt_out_rebates::*;
+pub use set_strict_mode::*;
+pub use initialize_queue::*;
+pub use register_intent::*;
+pub use commit_draw::*;
+pub use draw_winners::*;
+pub use claim_result::*;
+pub use create_mystery_box::*;
+pub use commit_mystery_box::*;
+pub use reveal_mystery_box::*;
+pub use fulfill_mystery_box::*;
+pub use pay_for_access::*;
+pub use claim_access::*;
+pub use refund_pending_access::*;
+pub use initialize_group_buy::*;
+pub use dao_purchase::*;
+pub use rate_content::*;
+pub use configure_insurance_pool::*;
+pub use fund_insurance_pool::*;
+pub use withdraw_insurance_pool::*;
+pub use pay_insurance_claim::*;
+pub use get_statuses::*;
+pub use set_payment_tolerance::*;
+pub use set_refund_overpayment::*;
+pub use cancel_expired_batch::*;
+pub use create_billing_agreement::*;
+pub use fund_billing_agreement::*;
+pub use pull_billing_payment::*;
+pub use cancel_billing_agreement::*;
+pub use fund_deposit::*;
+pub use withdraw_deposit::*;
+pub use set_deposit_session_key::*;
+pub use open_payment_channel::*;
+pub use settle_payment_channel::*;
+pub use request_channel_close::*;
+pub use close_payment_channel::*;
+pub use set_listing_region::*;
+pub use set_max_cancellation_fee::*;
+pub use set_cancellation_fee::*;
+pub use set_fee_exempt::*;
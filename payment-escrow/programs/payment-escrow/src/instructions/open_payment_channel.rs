@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Open a pay-per-view payment channel on a SOL listing, holding `deposit` for the creator
+/// to settle the buyer's signed usage vouchers against
+pub fn open_payment_channel(ctx: Context<OpenPaymentChannel>, deposit: u64) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    
+    // Frozen (taken down) content can't be sold, nor sold to denylisted wallets
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    check_not_denylisted(&ctx.accounts.denylist_entry, Some(listing))?;
+    
+    // Channels hold lamports, so only SOL listings qualify
+    require!(listing.payment_token_mint.is_none(), EscrowError::PaymentMintMismatch);
+    require!(deposit > 0, EscrowError::InvalidPrice);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.payment_channel.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+    
+    // The event sequence never repeats, so it tells this opening apart from earlier
+    // channels closed at the same address
+    let nonce = ctx.accounts.platform_config.next_event_sequence()?;
+    
+    let payment_channel = &mut ctx.accounts.payment_channel;
+    
+    payment_channel.buyer = ctx.accounts.buyer.key();
+    payment_channel.listing = listing.key();
+    payment_channel.creator = listing.creator;
+    payment_channel.content_id = listing.content_id;
+    payment_channel.deposit = deposit;
+    payment_channel.settled_amount = 0;
+    payment_channel.close_ready_ts = 0;
+    payment_channel.nonce = nonce;
+    payment_channel.bump = ctx.bumps.payment_channel;
+    
+    emit!(PaymentChannelOpened {
+        event_sequence: nonce,
+        payment_channel: payment_channel.key(),
+        buyer: payment_channel.buyer,
+        listing: payment_channel.listing,
+        deposit,
+    });
+    
+    msg!("Payment channel opened by: {}, deposit: {}", payment_channel.buyer, deposit);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenPaymentChannel<'info> {
+    /// The buyer funding the channel
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Listing PDA
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Payment channel PDA (holds the deposit)
+    #[account(
+        init,
+        payer = buyer,
+        space = PaymentChannel::LEN,
+        seeds = [PaymentChannel::SEED_PREFIX, buyer.key().as_ref(), listing.key().as_ref()],
+        bump
+    )]
+    pub payment_channel: Account<'info, PaymentChannel>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, listing.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's denylist entry; purchases are rejected while it exists
+    /// CHECK: Validated by seeds, only checked for existence
+    #[account(seeds = [DenylistEntry::SEED_PREFIX, buyer.key().as_ref()], bump)]
+    pub denylist_entry: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
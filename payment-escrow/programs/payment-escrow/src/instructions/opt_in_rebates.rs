@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Opt in to platform fee rebates on the creator's sales
+pub fn opt_in_rebates(ctx: Context<OptInRebates>) -> Result<()> {
+    let creator_rebate = &mut ctx.accounts.creator_rebate;
+    
+    creator_rebate.creator = ctx.accounts.creator.key();
+    creator_rebate.total_rebated = 0;
+    creator_rebate.bump = ctx.bumps.creator_rebate;
+    
+    msg!("Creator opted in to fee rebates: {}", creator_rebate.creator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OptInRebates<'info> {
+    /// The creator opting in
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Creator rebate PDA
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorRebate::LEN,
+        seeds = [CreatorRebate::SEED_PREFIX, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_rebate: Account<'info, CreatorRebate>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Opt out of platform fee rebates, returning the opt-in's rent to the creator
+pub fn opt_out_rebates(ctx: Context<OptOutRebates>) -> Result<()> {
+    msg!("Creator opted out of fee rebates: {}", ctx.accounts.creator.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OptOutRebates<'info> {
+    /// The creator opting out
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Creator rebate PDA
+    #[account(
+        mut,
+        seeds = [CreatorRebate::SEED_PREFIX, creator.key().as_ref()],
+        bump = creator_rebate.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+        close = creator,
+    )]
+    pub creator_rebate: Account<'info, CreatorRebate>,
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Pay for a listing's access now and mint the token with a later `claim_access`
+/// The price is held by the pending access account until claimed or refunded after expiry
+pub fn pay_for_access(ctx: Context<PayForAccess>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    // Frozen (taken down) content can't be sold, nor sold to denylisted wallets
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    check_not_denylisted(&ctx.accounts.denylist_entry, Some(listing))?;
+    
+    // Pending access holds lamports, so only priced SOL listings qualify
+    require!(
+        listing.payment_token_mint.is_none() && listing.price > 0,
+        EscrowError::InvalidPrice
+    );
+    require!(listing.is_on_sale(clock.unix_timestamp), EscrowError::SaleNotActive);
+    
+    let amount = listing.price;
+    
+    // Hold the price in the pending access account
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.pending_access.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let pending_access = &mut ctx.accounts.pending_access;
+    
+    pending_access.buyer = ctx.accounts.buyer.key();
+    pending_access.listing = listing.key();
+    pending_access.creator = listing.creator;
+    pending_access.content_id = listing.content_id;
+    pending_access.amount = amount;
+    pending_access.paid_ts = clock.unix_timestamp;
+    pending_access.expiry_ts = clock
+        .unix_timestamp
+        .checked_add(PendingAccess::CLAIM_WINDOW)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    pending_access.cancellation_fee_bps = listing.cancellation_fee_bps;
+    pending_access.bump = ctx.bumps.pending_access;
+    
+    emit!(PendingAccessCreated {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        pending_access: pending_access.key(),
+        buyer: pending_access.buyer,
+        content_id: pending_access.content_id,
+        amount,
+        expiry_ts: pending_access.expiry_ts,
+    });
+    
+    msg!("Access paid by: {}, claimable until: {}", pending_access.buyer, pending_access.expiry_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PayForAccess<'info> {
+    /// The buyer paying for access
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Listing PDA
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Pending access PDA
+    #[account(
+        init,
+        payer = buyer,
+        space = PendingAccess::LEN,
+        seeds = [PendingAccess::SEED_PREFIX, buyer.key().as_ref(), listing.key().as_ref()],
+        bump
+    )]
+    pub pending_access: Account<'info, PendingAccess>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, listing.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's denylist entry; purchases are rejected while it exists
+    /// CHECK: Validated by seeds, only checked for existence
+    #[account(seeds = [DenylistEntry::SEED_PREFIX, buyer.key().as_ref()], bump)]
+    pub denylist_entry: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
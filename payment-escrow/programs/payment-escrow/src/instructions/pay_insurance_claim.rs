@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Refund a buyer from the insurance pool when the creator is unresponsive in a dispute
+/// (admin only). Payouts are capped by the pool's max payout and the escrow's payment,
+/// and each escrow can be paid once
+pub fn pay_insurance_claim(ctx: Context<PayInsuranceClaim>, amount: u64) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_state;
+    let insurance_pool = &ctx.accounts.insurance_pool;
+    
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(
+        amount <= insurance_pool.max_payout && amount <= escrow.payment_amount,
+        EscrowError::InsurancePayoutTooLarge
+    );
+    require!(
+        amount <= insurance_pool.balance(&insurance_pool.to_account_info())?,
+        EscrowError::InsufficientFunds
+    );
+    
+    // The pool is program-owned, so lamports move directly
+    ctx.accounts.insurance_pool.sub_lamports(amount)?;
+    ctx.accounts.buyer.add_lamports(amount)?;
+    
+    let insurance_pool = &mut ctx.accounts.insurance_pool;
+    insurance_pool.total_paid = insurance_pool
+        .total_paid
+        .checked_add(amount)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    let claim = &mut ctx.accounts.insurance_claim;
+    let clock = Clock::get()?;
+    
+    claim.escrow = escrow.key();
+    claim.buyer = escrow.buyer;
+    claim.amount = amount;
+    claim.paid_ts = clock.unix_timestamp;
+    claim.bump = ctx.bumps.insurance_claim;
+    
+    emit!(InsuranceClaimPaid {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: claim.escrow,
+        buyer: claim.buyer,
+        amount,
+    });
+    
+    msg!("Insurance paid {} lamports to buyer: {}", amount, claim.buyer);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PayInsuranceClaim<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Insurance pool PDA
+    #[account(
+        mut,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// Completed SOL escrow of the disputed purchase
+    #[account(
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        constraint = escrow_state.status == EscrowStatus::Completed @ EscrowError::InvalidEscrowStatus,
+        constraint = escrow_state.payment_token_mint.is_none() @ EscrowError::PaymentMintMismatch,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Buyer receiving the refund
+    /// CHECK: Must match the escrow's buyer
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Insurance claim PDA (one per escrow)
+    #[account(
+        init,
+        payer = admin,
+        space = InsuranceClaim::LEN,
+        seeds = [InsuranceClaim::SEED_PREFIX, escrow_state.key().as_ref()],
+        bump
+    )]
+    pub insurance_claim: Account<'info, InsuranceClaim>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
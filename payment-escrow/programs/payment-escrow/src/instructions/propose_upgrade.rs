@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Announce a program upgrade, starting the timelock
+/// A new proposal replaces any pending one and restarts the delay
+pub fn propose_upgrade(
+    ctx: Context<ProposeUpgrade>,
+    buffer: Pubkey,
+    buffer_hash: [u8; 32],
+) -> Result<()> {
+    let governance = &mut ctx.accounts.upgrade_governance;
+    let now = Clock::get()?.unix_timestamp;
+    
+    governance.proposed_buffer = Some(buffer);
+    governance.proposed_hash = buffer_hash;
+    governance.proposed_ts = now;
+    governance.executable_ts = now
+        .checked_add(governance.delay)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    emit!(UpgradeProposed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        buffer,
+        buffer_hash,
+        proposed_ts: now,
+        executable_ts: governance.executable_ts,
+    });
+    
+    msg!("Upgrade proposed from buffer {}, executable at {}", buffer, governance.executable_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeUpgrade<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Upgrade governance PDA
+    #[account(
+        mut,
+        seeds = [UpgradeGovernance::SEED_PREFIX],
+        bump = upgrade_governance.bump,
+    )]
+    pub upgrade_governance: Account<'info, UpgradeGovernance>,
+}
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintRentalAccess as MintRentalAccounts,
+    cpi::mint_rental_access,
+};
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Pull a due period's payment from a billing agreement's deposit and renew the buyer's
+/// rental access for another period
+/// Only the platform's settlement authority can pull, and at most once per period
+pub fn pull_billing_payment<'info>(
+    ctx: Context<'_, '_, '_, 'info, PullBillingPayment<'info>>,
+) -> Result<()> {
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    // Platform listings must be paid out to the referenced platform
+    ctx.accounts.listing.check_platform(ctx.accounts.platform.as_ref(), &ctx.accounts.split_state)?;
+    
+    let clock = Clock::get()?;
+    let billing_agreement = &mut ctx.accounts.billing_agreement;
+    
+    require!(billing_agreement.is_due(clock.unix_timestamp), EscrowError::BillingNotDue);
+    
+    let amount = billing_agreement.amount;
+    let period = billing_agreement.period;
+    billing_agreement.record_pull(clock.unix_timestamp)?;
+    
+    // The billing agreement is program-owned, so lamports move directly
+    ctx.accounts.billing_agreement.sub_lamports(amount)?;
+    ctx.accounts.distribution_vault.add_lamports(amount)?;
+    
+    // CPI to Distribution program to split the payment; billing is SOL-only, so the
+    // SPL token accounts are never read and the vault and payees stand in for them
+    let remaining_accounts = ctx.remaining_accounts.to_vec();
+    
+    distribute(
+        CpiContext::new(
+            ctx.accounts.distribution_program.to_account_info(),
+            DistributeAccounts {
+                payer: ctx.accounts.settlement_authority.to_account_info(),
+                split_state: ctx.accounts.split_state.to_account_info(),
+                distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                vault: ctx.accounts.distribution_vault.to_account_info(),
+                creator: ctx.accounts.creator_payout.to_account_info(),
+                platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                vault_token_account: ctx.accounts.distribution_vault.to_account_info(),
+                creator_token_account: ctx.accounts.creator_payout.to_account_info(),
+                platform_treasury_token_account: ctx.accounts.platform_treasury.to_account_info(),
+                token_program: ctx.accounts.system_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                fee_waiver: None,
+            },
+        )
+        .with_remaining_accounts(remaining_accounts),
+        amount,
+    )?;
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept the rental
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program to renew the buyer's rental window for the period
+    mint_rental_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            MintRentalAccounts {
+                holder: ctx.accounts.buyer.to_account_info(),
+                payer: ctx.accounts.settlement_authority.to_account_info(),
+                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                mint: ctx.accounts.access_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                holder_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+                access_grant: ctx.accounts.access_grant.to_account_info(),
+                access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[access_minter_seeds],
+        ),
+        period,
+    )?;
+    
+    let billing_agreement = &ctx.accounts.billing_agreement;
+    
+    emit!(BillingPaymentPulled {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        billing_agreement: billing_agreement.key(),
+        buyer: billing_agreement.buyer,
+        amount,
+        next_pull_ts: billing_agreement.next_pull_ts,
+    });
+    
+    msg!("Billing payment pulled from: {}, next due: {}", billing_agreement.buyer, billing_agreement.next_pull_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PullBillingPayment<'info> {
+    /// Platform settlement authority pulling the payment (pays for any new accounts)
+    #[account(mut)]
+    pub settlement_authority: Signer<'info>,
+    
+    /// Platform config PDA, authorizes the settlement authority and stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        constraint = platform_config.is_settlement_authority(settlement_authority.key) @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// The buyer billed (receives the renewed access)
+    /// CHECK: Must match the billing agreement's buyer
+    #[account(address = billing_agreement.buyer @ EscrowError::InvalidBuyer)]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Billing agreement PDA (holds the deposit)
+    #[account(
+        mut,
+        seeds = [BillingAgreement::SEED_PREFIX, billing_agreement.buyer.as_ref(), billing_agreement.listing.as_ref()],
+        bump = billing_agreement.bump,
+    )]
+    pub billing_agreement: Box<Account<'info, BillingAgreement>>,
+    
+    /// Listing billed
+    #[account(address = billing_agreement.listing @ EscrowError::ListingMismatch)]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    /// Marketplace platform the listing is sold through (required when listing.platform is set)
+    #[account(
+        seeds = [Platform::SEED_PREFIX, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Option<Account<'info, Platform>>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, billing_agreement.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the billed content
+    #[account(
+        mut,
+        constraint = access_mint_state.creator == billing_agreement.creator @ EscrowError::InvalidCreator,
+        constraint = access_mint_state.content_id == billing_agreement.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the rental mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+    
+    /// Access grant PDA recording the rental window
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub access_grant: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the billed content
+    #[account(
+        mut,
+        constraint = split_state.creator == billing_agreement.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == billing_agreement.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// System-owned, so SOL moved into it can only leave through the distribution program
+    #[account(
+        mut,
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+        seeds::program = distribution_program.key(),
+    )]
+    pub distribution_vault: SystemAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator_payout: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts receiving their share
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use anchor_spl::token::TokenAccount;
+use distribution_cpi::state::SplitState;
+use crate::state::*;
+use crate::errors::*;
+
+/// Payout to a single collaborator in a quote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollaboratorPayout {
+    /// Collaborator's public key
+    pub pubkey: Pubkey,
+    
+    /// Amount the collaborator will receive
+    pub amount: u64,
+}
+
+/// Check-out breakdown returned by the quote instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PurchaseQuote {
+    /// Total the buyer pays in the listing's payment token
+    pub total_price: u64,
+    
+    /// Decimals of the payment token, for converting amounts to human-readable units
+    pub payment_decimals: u8,
+    
+    /// Lamports the buyer pays in rent for accounts created by the purchase
+    pub rent_lamports: u64,
+    
+    /// Amount the platform treasury will receive
+    pub platform_amount: u64,
+    
+    /// Amount the creator will receive
+    pub creator_amount: u64,
+    
+    /// Amounts each collaborator will receive
+    pub collaborator_amounts: Vec<CollaboratorPayout>,
+}
+
+/// Quote the exact cost and payouts of purchasing a listing
+/// Read-only - intended to be simulated, result is returned via return data
+pub fn quote(ctx: Context<Quote>) -> Result<PurchaseQuote> {
+    let listing = &ctx.accounts.listing;
+    let split_state = &ctx.accounts.split_state;
+    let rent = Rent::get()?;
+    let price = listing.price;
+    
+    // The escrow state is always created; the access ATA only if missing
+    let mut rent_lamports = rent.minimum_balance(EscrowState::LEN);
+    if ctx.accounts.buyer_access_token_account.lamports() == 0 {
+        rent_lamports = rent_lamports
+            .checked_add(rent.minimum_balance(TokenAccount::LEN))
+            .ok_or(EscrowError::NumericalOverflow)?;
+    }
+    
+    let collaborator_amounts = split_state
+        .collaborators
+        .iter()
+        .map(|collaborator| {
+            Ok(CollaboratorPayout {
+                pubkey: collaborator.pubkey,
+                amount: split_state.calculate_collaborator_share(price, collaborator.active_share_bps())?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    
+    Ok(PurchaseQuote {
+        total_price: price,
+        payment_decimals: listing.payment_decimals,
+        rent_lamports,
+        platform_amount: split_state.calculate_platform_fee(price)?,
+        creator_amount: split_state.calculate_creator_share(price)?,
+        collaborator_amounts,
+    })
+}
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    /// Listing being quoted
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Split state for the listed content
+    #[account(
+        constraint = split_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Must match the listing payment mint
+    #[account(
+        address = listing.payment_token_mint.unwrap_or(System::id()) @ EscrowError::PaymentMintMismatch,
+    )]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account, used to decide whether it must be created
+    /// CHECK: Only inspected for existence
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Rate a listing from 1 to 5 as a verified purchaser
+/// The buyer proves the purchase with a completed escrow for the content, and can rate once
+pub fn rate_content(ctx: Context<RateContent>, rating: u8) -> Result<()> {
+    require!(
+        (RatingState::MIN_RATING..=RatingState::MAX_RATING).contains(&rating),
+        EscrowError::InvalidRating
+    );
+    
+    let listing = &mut ctx.accounts.listing;
+    listing.record_rating(rating)?;
+    
+    let rating_state = &mut ctx.accounts.rating_state;
+    
+    rating_state.listing = listing.key();
+    rating_state.buyer = ctx.accounts.buyer.key();
+    rating_state.rating = rating;
+    rating_state.rated_ts = Clock::get()?.unix_timestamp;
+    rating_state.bump = ctx.bumps.rating_state;
+    
+    emit!(ContentRated {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        listing: listing.key(),
+        buyer: rating_state.buyer,
+        rating,
+        rating_count: listing.rating_count,
+        average_rating_x100: listing.average_rating_x100(),
+    });
+    
+    msg!("Content rated {} by: {}", rating, rating_state.buyer);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RateContent<'info> {
+    /// The buyer rating the content
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// A completed escrow proving the buyer purchased the content
+    #[account(
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        constraint = escrow_state.status == EscrowStatus::Completed @ EscrowError::InvalidEscrowStatus,
+        constraint = escrow_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = escrow_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Rating PDA (one per buyer per listing)
+    #[account(
+        init,
+        payer = buyer,
+        space = RatingState::LEN,
+        seeds = [RatingState::SEED_PREFIX, listing.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub rating_state: Account<'info, RatingState>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Record an upheld dispute of one of the creator's purchases against their bond
+/// Each escrow can be recorded once; its record caps what the buyer can be slashed
+pub fn record_dispute(ctx: Context<RecordDispute>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let escrow = &ctx.accounts.escrow_state;
+    let bond = &mut ctx.accounts.creator_bond;
+    
+    bond.record_dispute(now)?;
+    
+    let dispute_record = &mut ctx.accounts.dispute_record;
+    dispute_record.escrow = escrow.key();
+    dispute_record.creator = escrow.creator;
+    dispute_record.buyer = escrow.buyer;
+    dispute_record.payment_token_mint = escrow.payment_token_mint;
+    dispute_record.payment_amount = escrow.payment_amount;
+    dispute_record.slashed = 0;
+    dispute_record.recorded_ts = now;
+    dispute_record.bump = ctx.bumps.dispute_record;
+    
+    msg!("Dispute recorded against {}, disputes in window: {}", bond.creator, bond.disputes);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordDispute<'info> {
+    /// The platform admin, paying for the dispute record
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Creator bond PDA
+    #[account(
+        mut,
+        seeds = [CreatorBond::SEED_PREFIX, creator_bond.creator.as_ref()],
+        bump = creator_bond.bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+    
+    /// Completed escrow of the disputed purchase from this creator
+    #[account(
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        constraint = escrow_state.creator == creator_bond.creator @ EscrowError::InvalidCreator,
+        constraint = escrow_state.status == EscrowStatus::Completed @ EscrowError::InvalidEscrowStatus,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Dispute record PDA (one per escrow)
+    #[account(
+        init,
+        payer = admin,
+        space = DisputeRecord::LEN,
+        seeds = [DisputeRecord::SEED_PREFIX, escrow_state.key().as_ref()],
+        bump
+    )]
+    pub dispute_record: Account<'info, DisputeRecord>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+use crate::wormhole::*;
+
+/// Grant access for a payment made on another chain
+/// The posted VAA must come from the chain's registered emitter; anyone (e.g. a relayer)
+/// can submit it, and each VAA can only be redeemed once
+pub fn redeem_crosschain_payment(
+    ctx: Context<RedeemCrosschainPayment>,
+    emitter_chain: u16,
+    sequence: u64,
+) -> Result<()> {
+    let vaa = PostedVaa::load(&ctx.accounts.posted_vaa)?;
+    
+    // The instruction args seed the emitter and redemption PDAs, so they must match the VAA
+    require!(
+        vaa.emitter_chain == emitter_chain && vaa.sequence == sequence,
+        EscrowError::InvalidVaa
+    );
+    require!(
+        vaa.emitter_address == ctx.accounts.foreign_emitter.address,
+        EscrowError::InvalidEmitter
+    );
+    
+    let payment = CrosschainPayment::decode(&vaa.payload)?;
+    require!(
+        payment.access_mint_state == ctx.accounts.access_mint_state.key(),
+        EscrowError::InvalidCrosschainPayload
+    );
+    require!(
+        payment.recipient == ctx.accounts.recipient.key(),
+        EscrowError::InvalidCrosschainPayload
+    );
+    
+    // The payment must be for this content's listing, quoted at its current price
+    let listing = &ctx.accounts.listing;
+    require!(
+        payment.listing == listing.key(),
+        EscrowError::InvalidCrosschainPayload
+    );
+    require!(
+        payment.price_version == listing.price_version,
+        EscrowError::ListingPriceChanged
+    );
+    require!(
+        payment.amount >= listing.price,
+        EscrowError::CrosschainUnderpayment
+    );
+    
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept this flow
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program to mint access token to the mapped wallet
+    mint_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            AccessMintAccounts {
+                buyer: ctx.accounts.recipient.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                mint: ctx.accounts.access_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                buyer_token_account: ctx.accounts.recipient_access_token_account.to_account_info(),
+                purchase_receipt: None,
+                access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                preview_grant: None,
+                access_collection: None,
+                access_metadata: None,
+                collection_mint: None,
+                collection_metadata: None,
+                collection_master_edition: None,
+                token_metadata_program: None,
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[access_minter_seeds],
+        ),
+    )?;
+    
+    let timestamp = Clock::get()?.unix_timestamp;
+    let redemption = &mut ctx.accounts.redemption;
+    redemption.emitter_chain = emitter_chain;
+    redemption.sequence = sequence;
+    redemption.recipient = payment.recipient;
+    redemption.access_mint_state = payment.access_mint_state;
+    redemption.amount = payment.amount;
+    redemption.redeemed_ts = timestamp;
+    redemption.bump = ctx.bumps.redemption;
+    
+    emit!(CrosschainPaymentRedeemed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        emitter_chain,
+        sequence,
+        recipient: payment.recipient,
+        access_mint_state: payment.access_mint_state,
+        amount: payment.amount,
+        timestamp,
+    });
+    
+    msg!(
+        "Redeemed cross-chain payment from chain {} (sequence {}) for {}",
+        emitter_chain,
+        sequence,
+        payment.recipient
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(emitter_chain: u16, sequence: u64)]
+pub struct RedeemCrosschainPayment<'info> {
+    /// Pays for the redemption record and token account (buyer or relayer)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Wallet receiving access, as named in the VAA payload
+    /// CHECK: Must match the payload recipient
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Registered emitter for the paying chain
+    #[account(
+        seeds = [ForeignEmitter::SEED_PREFIX, emitter_chain.to_le_bytes().as_ref()],
+        bump = foreign_emitter.bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+    
+    /// Posted VAA verified by the Wormhole core bridge
+    /// CHECK: Owner and contents validated in the handler
+    pub posted_vaa: UncheckedAccount<'info>,
+    
+    /// Redemption record; its creation fails if the VAA was already redeemed
+    #[account(
+        init,
+        payer = payer,
+        space = CrosschainRedemption::LEN,
+        seeds = [
+            CrosschainRedemption::SEED_PREFIX,
+            emitter_chain.to_le_bytes().as_ref(),
+            foreign_emitter.address.as_ref(),
+            sequence.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub redemption: Account<'info, CrosschainRedemption>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, access_mint_state.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Listing the payment was made against
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            access_mint_state.creator.as_ref(),
+            access_mint_state.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the paid content
+    #[account(mut)]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Recipient's access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub recipient_access_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Convert loyalty points into a discount on the buyer's next SOL buy_and_mint
+pub fn redeem_points(ctx: Context<RedeemPoints>, points: u64) -> Result<()> {
+    let loyalty = &mut ctx.accounts.buyer_loyalty;
+    
+    loyalty.redeem(points)?;
+    
+    emit!(LoyaltyPointsRedeemed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        buyer: loyalty.buyer,
+        points,
+        discount_lamports: loyalty.discount_lamports,
+    });
+    
+    msg!("Redeemed {} points, discount available: {}", points, loyalty.discount_lamports);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    /// The buyer redeeming points
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Buyer loyalty PDA
+    #[account(
+        mut,
+        seeds = [BuyerLoyalty::SEED_PREFIX, buyer.key().as_ref()],
+        bump = buyer_loyalty.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer_loyalty: Account<'info, BuyerLoyalty>,
+}
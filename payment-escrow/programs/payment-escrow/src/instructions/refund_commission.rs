@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Return a commission's unreleased funds to the buyer, closing it
+/// Allowed once the current milestone has missed its deadline without a pending submission,
+/// or at any time the admin co-signs to uphold the buyer's dispute of it
+pub fn refund_commission(ctx: Context<RefundCommission>) -> Result<()> {
+    let milestone_state = &ctx.accounts.milestone_state;
+    
+    let dispute_upheld = ctx.accounts.admin.is_some()
+        && milestone_state
+            .milestones
+            .get(milestone_state.current as usize)
+            .is_some_and(|milestone| milestone.status == MilestoneStatus::Disputed);
+    require!(
+        dispute_upheld || milestone_state.is_overdue(Clock::get()?.unix_timestamp),
+        EscrowError::CommissionNotRefundable
+    );
+    
+    let amount = milestone_state.unreleased_amount();
+    
+    emit!(CommissionRefunded {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        milestone_state: milestone_state.key(),
+        buyer: milestone_state.buyer,
+        creator: milestone_state.creator,
+        amount,
+        dispute_upheld,
+    });
+    
+    msg!("Commission refunded to: {}, amount: {}", milestone_state.buyer, amount);
+    
+    // Closing the account returns the unreleased funds and its rent to the buyer
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundCommission<'info> {
+    /// The buyer who commissioned the content
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// The platform admin, co-signing to uphold the buyer's dispute
+    #[account(address = platform_config.admin @ EscrowError::Unauthorized)]
+    pub admin: Option<Signer<'info>>,
+    
+    /// Milestone state PDA (holds the unreleased funds)
+    #[account(
+        mut,
+        seeds = [
+            MilestoneState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            milestone_state.content_id.as_ref(),
+            milestone_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        close = buyer,
+    )]
+    pub milestone_state: Account<'info, MilestoneState>,
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::System;
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+
+/// Refund an unclaimed pending access once its claim window has expired
+/// The listing's cancellation fee (as set when paid, capped by the platform maximum) is paid
+/// to the creator through the distribution program; closing the account returns the rest
+/// of the held price and its rent to the buyer
+pub fn refund_pending_access<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundPendingAccess<'info>>,
+) -> Result<()> {
+    let pending_access = &ctx.accounts.pending_access;
+    
+    require!(
+        pending_access.is_expired(Clock::get()?.unix_timestamp),
+        EscrowError::PendingAccessNotExpired
+    );
+    
+    let cancellation_fee =
+        pending_access.cancellation_fee(ctx.accounts.platform_config.max_cancellation_fee_bps)?;
+    let refund_amount = pending_access
+        .amount
+        .checked_sub(cancellation_fee)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    if cancellation_fee > 0 {
+        let (
+            Some(distribution_program),
+            Some(split_state),
+            Some(distribution_record),
+            Some(revenue_bucket),
+            Some(distribution_vault),
+            Some(creator_payout),
+            Some(platform_treasury),
+        ) = (
+            ctx.accounts.distribution_program.as_ref(),
+            ctx.accounts.split_state.as_ref(),
+            ctx.accounts.distribution_record.as_ref(),
+            ctx.accounts.revenue_bucket.as_ref(),
+            ctx.accounts.distribution_vault.as_ref(),
+            ctx.accounts.creator_payout.as_ref(),
+            ctx.accounts.platform_treasury.as_ref(),
+        ) else {
+            return err!(EscrowError::CancellationFeeAccountsRequired);
+        };
+        
+        // The pending access account is program-owned, so lamports move directly
+        ctx.accounts.pending_access.sub_lamports(cancellation_fee)?;
+        distribution_vault.add_lamports(cancellation_fee)?;
+        
+        // Pending access is SOL-only, so the SPL token accounts are never read and the
+        // vault and payees stand in for them
+        let remaining_accounts = ctx.remaining_accounts.to_vec();
+        
+        distribute(
+            CpiContext::new(
+                distribution_program.to_account_info(),
+                DistributeAccounts {
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    split_state: split_state.to_account_info(),
+                    distribution_record: distribution_record.to_account_info(),
+                    revenue_bucket: revenue_bucket.to_account_info(),
+                    vault: distribution_vault.to_account_info(),
+                    creator: creator_payout.to_account_info(),
+                    platform_treasury: platform_treasury.to_account_info(),
+                    payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                    vault_token_account: distribution_vault.to_account_info(),
+                    creator_token_account: creator_payout.to_account_info(),
+                    platform_treasury_token_account: platform_treasury.to_account_info(),
+                    token_program: ctx.accounts.system_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    fee_waiver: None,
+                },
+            )
+            .with_remaining_accounts(remaining_accounts),
+            cancellation_fee,
+        )?;
+        
+        msg!("Cancellation fee of {} paid to creator", cancellation_fee);
+    }
+    
+    msg!("Pending access refunded to buyer: {}, amount: {}", ctx.accounts.buyer.key(), refund_amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundPendingAccess<'info> {
+    /// The buyer who paid
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, caps the cancellation fee
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Pending access PDA
+    #[account(
+        mut,
+        seeds = [PendingAccess::SEED_PREFIX, buyer.key().as_ref(), pending_access.listing.as_ref()],
+        bump = pending_access.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        close = buyer,
+    )]
+    pub pending_access: Box<Account<'info, PendingAccess>>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // ============ Distribution Program Accounts (cancellation fee only) ============
+    
+    /// Distribution program
+    pub distribution_program: Option<Program<'info, Distribution>>,
+    
+    /// Split state for the refunded content
+    #[account(
+        mut,
+        constraint = split_state.creator == pending_access.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == pending_access.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Option<Box<Account<'info, SplitState>>>,
+    
+    /// Distribution record PDA for the fee (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: Option<UncheckedAccount<'info>>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: Option<UncheckedAccount<'info>>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// CHECK: Validated by distribution program via CPI
+    #[account(mut)]
+    pub distribution_vault: Option<UncheckedAccount<'info>>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Validated against the split by distribution program via CPI
+    #[account(mut)]
+    pub creator_payout: Option<UncheckedAccount<'info>>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Validated against the split by distribution program via CPI
+    #[account(mut)]
+    pub platform_treasury: Option<UncheckedAccount<'info>>,
+    
+    // Remaining accounts: Collaborator accounts receiving their share of the fee
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Register (or replace) the emitter trusted to attest payments on a foreign chain
+pub fn register_foreign_emitter(
+    ctx: Context<RegisterForeignEmitter>,
+    chain: u16,
+    address: [u8; 32],
+) -> Result<()> {
+    // Chain id 0 is unset and 1 is Solana itself
+    require!(chain > 1, EscrowError::InvalidEmitter);
+    require!(address != [0; 32], EscrowError::InvalidEmitter);
+    
+    let emitter = &mut ctx.accounts.foreign_emitter;
+    emitter.chain = chain;
+    emitter.address = address;
+    emitter.bump = ctx.bumps.foreign_emitter;
+    
+    msg!("Registered foreign emitter for chain {}", chain);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RegisterForeignEmitter<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Foreign emitter PDA for the chain
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ForeignEmitter::LEN,
+        seeds = [ForeignEmitter::SEED_PREFIX, chain.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Register an intent to buy in a priority access queue, escrowing the queue price
+/// The price is paid to the creator if the entry wins the draw and refunded otherwise
+pub fn register_intent(ctx: Context<RegisterIntent>) -> Result<()> {
+    let clock = Clock::get()?;
+    let queue_state = &mut ctx.accounts.queue_state;
+    
+    require!(
+        queue_state.status == QueueStatus::Registering,
+        EscrowError::InvalidQueueStatus
+    );
+    require!(
+        clock.unix_timestamp < queue_state.register_end_ts,
+        EscrowError::QueueRegistrationClosed
+    );
+    
+    let amount = queue_state.price;
+    let index = queue_state.entrants;
+    queue_state.entrants = index.checked_add(1).ok_or(EscrowError::NumericalOverflow)?;
+    
+    // Escrow the price in the entry account
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.queue_entry.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let queue_entry = &mut ctx.accounts.queue_entry;
+    
+    queue_entry.queue = ctx.accounts.queue_state.key();
+    queue_entry.buyer = ctx.accounts.buyer.key();
+    queue_entry.index = index;
+    queue_entry.bump = ctx.bumps.queue_entry;
+    
+    emit!(QueueIntentRegistered {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        queue: queue_entry.queue,
+        buyer: queue_entry.buyer,
+        index,
+        amount,
+    });
+    
+    msg!("Queue intent registered by: {}, index: {}", queue_entry.buyer, index);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterIntent<'info> {
+    /// The buyer registering
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Queue state PDA
+    #[account(
+        mut,
+        seeds = [QueueState::SEED_PREFIX, queue_state.listing.as_ref()],
+        bump = queue_state.bump,
+    )]
+    pub queue_state: Account<'info, QueueState>,
+    
+    /// Queue entry PDA (one per buyer per queue)
+    #[account(
+        init,
+        payer = buyer,
+        space = QueueEntry::LEN,
+        seeds = [QueueEntry::SEED_PREFIX, queue_state.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub queue_entry: Account<'info, QueueEntry>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Register a marketplace platform with its own treasury and fee
+pub fn register_platform(ctx: Context<RegisterPlatform>, fee_bps: u16) -> Result<()> {
+    require!(fee_bps <= Platform::MAX_FEE_BPS, EscrowError::InvalidPlatformFee);
+    
+    let platform = &mut ctx.accounts.platform;
+    
+    platform.authority = ctx.accounts.authority.key();
+    platform.treasury = ctx.accounts.treasury.key();
+    platform.fee_bps = fee_bps;
+    platform.bump = ctx.bumps.platform;
+    
+    emit!(PlatformUpdated {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        platform: platform.key(),
+        treasury: platform.treasury,
+        fee_bps,
+    });
+    
+    msg!("Platform registered by {}: treasury {}, fee {}bps", 
+        platform.authority, platform.treasury, fee_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterPlatform<'info> {
+    /// Marketplace operator
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Treasury receiving the platform's fees
+    /// CHECK: Any wallet can be a treasury
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// Platform PDA
+    #[account(
+        init,
+        payer = authority,
+        space = Platform::LEN,
+        seeds = [Platform::SEED_PREFIX, authority.key().as_ref()],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Allow a denylisted wallet to buy again, refunding the entry rent to the admin
+pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>) -> Result<()> {
+    let wallet = ctx.accounts.denylist_entry.wallet;
+    
+    emit!(WalletRemovedFromDenylist {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Wallet removed from denylist: {}", wallet);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromDenylist<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Denylist entry PDA
+    #[account(
+        mut,
+        close = admin,
+        seeds = [DenylistEntry::SEED_PREFIX, denylist_entry.wallet.as_ref()],
+        bump = denylist_entry.bump,
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+}
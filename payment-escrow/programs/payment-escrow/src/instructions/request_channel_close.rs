@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Start closing a payment channel; the creator can still settle vouchers during the
+/// grace period, after which the buyer can close it and recover the rest of the deposit
+pub fn request_channel_close(ctx: Context<RequestChannelClose>) -> Result<()> {
+    let payment_channel = &mut ctx.accounts.payment_channel;
+    payment_channel.close_ready_ts = Clock::get()?
+        .unix_timestamp
+        .checked_add(PaymentChannel::CLOSE_GRACE_PERIOD)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    msg!("Payment channel close requested, closable from: {}", payment_channel.close_ready_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestChannelClose<'info> {
+    /// The buyer who owns the channel
+    pub buyer: Signer<'info>,
+    
+    /// Payment channel PDA
+    #[account(
+        mut,
+        seeds = [PaymentChannel::SEED_PREFIX, buyer.key().as_ref(), payment_channel.listing.as_ref()],
+        bump = payment_channel.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub payment_channel: Account<'info, PaymentChannel>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Start the cooldown for withdrawing part of the creator's bond
+/// A new request replaces any pending one and restarts the cooldown
+pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+    let bond = &mut ctx.accounts.creator_bond;
+    
+    require!(amount > 0, EscrowError::InvalidBondAmount);
+    require!(amount <= bond.staked, EscrowError::InsufficientBond);
+    
+    bond.unstake_amount = amount;
+    bond.unstake_available_ts = Clock::get()?
+        .unix_timestamp
+        .checked_add(CreatorBond::UNSTAKE_COOLDOWN)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    msg!("Unstake of {} requested, available at {}", amount, bond.unstake_available_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    /// The creator who owns the bond
+    pub creator: Signer<'info>,
+    
+    /// Creator bond PDA
+    #[account(
+        mut,
+        seeds = [CreatorBond::SEED_PREFIX, creator.key().as_ref()],
+        bump = creator_bond.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::randomness::Randomness;
+
+/// Reveal which content a mystery box purchase contains from its committed randomness
+pub fn reveal_mystery_box(ctx: Context<RevealMysteryBox>) -> Result<()> {
+    let clock = Clock::get()?;
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    require!(
+        escrow.status == EscrowStatus::Initialized && escrow.revealed_content_id.is_none(),
+        EscrowError::InvalidEscrowStatus
+    );
+    
+    let randomness = Randomness::load(&ctx.accounts.randomness_account)?;
+    
+    // A re-committed account would carry a different value than the one bound at purchase
+    require!(
+        randomness.seed_slot == escrow.randomness_commit_slot,
+        EscrowError::RandomnessExpired
+    );
+    
+    let content_id = ctx.accounts.mystery_box.select(&randomness.revealed_value(clock.slot)?);
+    escrow.revealed_content_id = Some(content_id);
+    
+    emit!(MysteryBoxRevealed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        box_id: ctx.accounts.mystery_box.box_id,
+        content_id,
+    });
+    
+    msg!("Mystery box revealed for buyer: {}", escrow.buyer);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealMysteryBox<'info> {
+    /// The buyer revealing the box
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Escrow state PDA of the box purchase
+    #[account(
+        mut,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            escrow_state.buyer.as_ref(),
+            escrow_state.content_id.as_ref(),
+            escrow_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = escrow_state.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Mystery box the escrow bought
+    #[account(
+        seeds = [MysteryBox::SEED_PREFIX, escrow_state.creator.as_ref(), escrow_state.content_id.as_ref()],
+        bump = mystery_box.bump,
+    )]
+    pub mystery_box: Account<'info, MysteryBox>,
+    
+    /// Switchboard randomness account committed for the purchase
+    /// CHECK: Must match the committed account; owner and layout are validated when read
+    #[account(
+        constraint = escrow_state.randomness_account == Some(randomness_account.key())
+            @ EscrowError::RandomnessAccountMismatch,
+    )]
+    pub randomness_account: UncheckedAccount<'info>,
+}
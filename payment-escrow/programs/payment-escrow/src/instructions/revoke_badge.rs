@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Revoke a creator's verification, burning their badge through the permanent delegate
+pub fn revoke_badge(ctx: Context<RevokeBadge>) -> Result<()> {
+    let config_seeds: &[&[u8]] = &[
+        PlatformConfig::SEED_PREFIX,
+        &[ctx.accounts.platform_config.bump],
+    ];
+    
+    let balance = ctx.accounts.creator_badge_token_account.amount;
+    if balance > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.badge_mint.to_account_info(),
+                    from: ctx.accounts.creator_badge_token_account.to_account_info(),
+                    authority: ctx.accounts.platform_config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            balance,
+        )?;
+    }
+    
+    emit!(CreatorBadgeRevoked {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        creator: ctx.accounts.creator.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Creator badge revoked from: {}", ctx.accounts.creator.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeBadge<'info> {
+    /// The platform admin (receives the badge record's rent)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA (badge permanent delegate)
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// The creator losing verification
+    /// CHECK: Matched against the badge record
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Creator badge PDA, closed on revocation
+    #[account(
+        mut,
+        close = admin,
+        seeds = [CreatorBadge::SEED_PREFIX, creator.key().as_ref()],
+        bump = creator_badge.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub creator_badge: Account<'info, CreatorBadge>,
+    
+    /// Non-transferable creator badge mint
+    #[account(
+        mut,
+        seeds = [CreatorBadge::MINT_SEED_PREFIX],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub badge_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Creator's badge token account
+    #[account(
+        mut,
+        associated_token::mint = badge_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_badge_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 program
+    pub token_program: Program<'info, Token2022>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
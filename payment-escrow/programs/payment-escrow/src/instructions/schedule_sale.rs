@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the window during which a listing can be bought
+/// A zero start opens the sale immediately and a zero end leaves it open indefinitely
+pub fn schedule_sale(
+    ctx: Context<ScheduleSale>,
+    sale_start_ts: i64,
+    sale_end_ts: i64,
+) -> Result<()> {
+    require!(
+        sale_start_ts >= 0 && (sale_end_ts == 0 || sale_end_ts > sale_start_ts),
+        EscrowError::InvalidSaleSchedule
+    );
+    
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.sale_start_ts = sale_start_ts;
+    listing.sale_end_ts = sale_end_ts;
+    
+    msg!("Sale scheduled: start={}, end={}", sale_start_ts, sale_end_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ScheduleSale<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the share of a paid pending access the creator keeps when the buyer refunds it unclaimed
+/// The fee applies to purchases paid after it's set
+pub fn set_cancellation_fee(ctx: Context<SetCancellationFee>, cancellation_fee_bps: u16) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    require!(
+        cancellation_fee_bps <= ctx.accounts.platform_config.max_cancellation_fee_bps,
+        EscrowError::CancellationFeeTooHigh
+    );
+    
+    // The fee is paid out through the SOL distribution path only
+    require!(
+        cancellation_fee_bps == 0 || listing.payment_token_mint.is_none(),
+        EscrowError::CancellationFeeRequiresSol
+    );
+    
+    listing.cancellation_fee_bps = cancellation_fee_bps;
+    
+    msg!("Listing cancellation fee set to {}bps", cancellation_fee_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCancellationFee<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Platform config PDA, caps the fee
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
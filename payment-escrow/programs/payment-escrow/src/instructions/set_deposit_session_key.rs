@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Authorize a session key to spend the deposit on purchases until `expiry_ts`
+/// Pubkey::default() revokes the current session key
+pub fn set_deposit_session_key(
+    ctx: Context<SetDepositSessionKey>,
+    session_key: Pubkey,
+    expiry_ts: i64,
+) -> Result<()> {
+    let buyer_deposit = &mut ctx.accounts.buyer_deposit;
+    buyer_deposit.session_key = session_key;
+    buyer_deposit.session_expiry_ts = expiry_ts;
+    
+    msg!("Deposit session key set: {}, expires: {}", session_key, expiry_ts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDepositSessionKey<'info> {
+    /// The buyer who owns the deposit
+    pub buyer: Signer<'info>,
+    
+    /// Buyer deposit PDA
+    #[account(
+        mut,
+        seeds = [
+            BuyerDeposit::SEED_PREFIX,
+            buyer.key().as_ref(),
+            BuyerVault::mint_seed(&buyer_deposit.payment_token_mint).as_ref(),
+        ],
+        bump = buyer_deposit.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer_deposit: Account<'info, BuyerDeposit>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Add or remove a Token-2022 payment mint from the extension whitelist
+/// Whitelisted mints are accepted even with a permanent delegate or confidential transfers
+pub fn set_extension_mint_whitelisted(
+    ctx: Context<SetExtensionMintWhitelisted>,
+    mint: Pubkey,
+    whitelisted: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    
+    if whitelisted {
+        if !config.is_extension_mint_whitelisted(&mint) {
+            require!(
+                config.whitelisted_extension_mints.len() < PlatformConfig::MAX_WHITELISTED_MINTS,
+                EscrowError::WhitelistFull
+            );
+            config.whitelisted_extension_mints.push(mint);
+        }
+    } else {
+        config.whitelisted_extension_mints.retain(|whitelisted_mint| *whitelisted_mint != mint);
+    }
+    
+    msg!("Extension mint {} whitelisted: {}", mint, whitelisted);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetExtensionMintWhitelisted<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
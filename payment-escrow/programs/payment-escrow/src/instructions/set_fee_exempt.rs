@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Add or remove a wallet from the fee-exempt list
+/// Purchases by and sales from exempt wallets pay the platform fee to the creator instead
+pub fn set_fee_exempt(
+    ctx: Context<SetFeeExempt>,
+    wallet: Pubkey,
+    exempt: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    
+    if exempt {
+        if !config.is_fee_exempt(&wallet) {
+            require!(
+                config.fee_exempt_wallets.len() < PlatformConfig::MAX_FEE_EXEMPT_WALLETS,
+                EscrowError::FeeExemptListFull
+            );
+            config.fee_exempt_wallets.push(wallet);
+        }
+    } else {
+        config.fee_exempt_wallets.retain(|exempt_wallet| *exempt_wallet != wallet);
+    }
+    
+    msg!("Wallet {} fee exempt: {}", wallet, exempt);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeExempt<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
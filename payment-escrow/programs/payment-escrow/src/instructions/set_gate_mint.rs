@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Require buyers of a listing to hold another access token (None removes the gate)
+pub fn set_gate_mint(ctx: Context<SetGateMint>, gate_mint: Option<Pubkey>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.gate_mint = gate_mint;
+    
+    msg!("Listing gate mint set: {:?}", gate_mint);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGateMint<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Opt a listing out of (or back into) the platform buyer denylist
+pub fn set_ignore_denylist(ctx: Context<SetIgnoreDenylist>, ignore_denylist: bool) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.ignore_denylist = ignore_denylist;
+    
+    msg!("Listing ignores denylist: {}", ignore_denylist);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetIgnoreDenylist<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the license terms hash buyers must accept for a listing
+/// Outstanding escrows must be completed against the new terms
+pub fn set_license_terms(ctx: Context<SetLicenseTerms>, license_terms_hash: [u8; 32]) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.license_terms_hash = license_terms_hash;
+    
+    msg!("License terms updated");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLicenseTerms<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Sell a listing through a marketplace platform (no platform account removes it)
+/// Purchases then require the content's split to pay that platform
+pub fn set_listing_platform(ctx: Context<SetListingPlatform>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.platform = ctx.accounts.platform.as_ref().map(|platform| platform.key());
+    
+    msg!("Listing platform set: {:?}", listing.platform);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetListingPlatform<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform the listing is sold through
+    #[account(
+        seeds = [Platform::SEED_PREFIX, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Option<Account<'info, Platform>>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Tag a listing with a region/market code for analytics and compliance tooling
+pub fn set_listing_region(ctx: Context<SetListingRegion>, region: u16) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.region = region;
+    
+    msg!("Listing region set: {}", region);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetListingRegion<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
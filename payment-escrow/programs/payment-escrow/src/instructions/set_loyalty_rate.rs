@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use common::bps::MAX_BPS;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the loyalty points buyers earn per lamport spent, in basis points (0 = no points)
+pub fn set_loyalty_rate(ctx: Context<SetLoyaltyRate>, loyalty_rate_bps: u16) -> Result<()> {
+    require!(loyalty_rate_bps <= MAX_BPS, EscrowError::InvalidLoyaltyRate);
+    
+    ctx.accounts.platform_config.loyalty_rate_bps = loyalty_rate_bps;
+    
+    msg!("Loyalty rate set to {}bps", loyalty_rate_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLoyaltyRate<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
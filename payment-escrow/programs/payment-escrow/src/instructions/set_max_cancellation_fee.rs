@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use common::bps::MAX_BPS;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the largest cancellation fee creators may charge on their listings (0 = no fees)
+pub fn set_max_cancellation_fee(ctx: Context<SetMaxCancellationFee>, max_cancellation_fee_bps: u16) -> Result<()> {
+    require!(max_cancellation_fee_bps <= MAX_BPS, EscrowError::CancellationFeeTooHigh);
+    
+    ctx.accounts.platform_config.max_cancellation_fee_bps = max_cancellation_fee_bps;
+    
+    msg!("Max cancellation fee set to {}bps", max_cancellation_fee_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxCancellationFee<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
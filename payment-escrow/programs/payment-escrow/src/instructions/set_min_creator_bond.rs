@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the bond creators must have staked to create a listing (0 = no bond required)
+pub fn set_min_creator_bond(ctx: Context<SetMinCreatorBond>, min_creator_bond: u64) -> Result<()> {
+    ctx.accounts.platform_config.min_creator_bond = min_creator_bond;
+    
+    msg!("Minimum creator bond set to {}", min_creator_bond);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMinCreatorBond<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the platform price floor for SOL (None) or an SPL payment mint
+/// A zero minimum removes the floor
+pub fn set_min_price(
+    ctx: Context<SetMinPrice>,
+    payment_token_mint: Option<Pubkey>,
+    min_price: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    
+    match payment_token_mint {
+        None => config.min_price_lamports = min_price,
+        Some(mint) => {
+            config.mint_min_prices.retain(|entry| entry.mint != mint);
+            if min_price > 0 {
+                require!(
+                    config.mint_min_prices.len() < PlatformConfig::MAX_MINT_MIN_PRICES,
+                    EscrowError::MinPriceListFull
+                );
+                config.mint_min_prices.push(MintMinPrice { mint, min_price });
+            }
+        }
+    }
+    
+    msg!("Minimum price for {:?} set to {}", payment_token_mint, min_price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMinPrice<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set how far a purchase payment may differ from the amount due, in basis points (0 = exact payment)
+pub fn set_payment_tolerance(ctx: Context<SetPaymentTolerance>, payment_tolerance_bps: u16) -> Result<()> {
+    require!(
+        payment_tolerance_bps <= PlatformConfig::MAX_PAYMENT_TOLERANCE_BPS,
+        EscrowError::InvalidPaymentTolerance
+    );
+    
+    ctx.accounts.platform_config.payment_tolerance_bps = payment_tolerance_bps;
+    
+    msg!("Payment tolerance set to {}bps", payment_tolerance_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentTolerance<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Opt a listing in or out of platform-wide sales
+pub fn set_platform_sale_opt_in(ctx: Context<SetPlatformSaleOptIn>, opt_in: bool) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.platform_sale_opt_in = opt_in;
+    
+    msg!("Listing platform sale opt-in set: {}", opt_in);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPlatformSaleOptIn<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Configure the premium tier price for a listing
+pub fn set_premium_price(ctx: Context<SetPremiumPrice>, premium_price: u64) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    // Premium must cost at least as much as basic access
+    require!(
+        premium_price == 0 || premium_price >= listing.price,
+        EscrowError::InvalidPrice
+    );
+    
+    listing.premium_price = premium_price;
+    
+    msg!("Premium price set to {}", premium_price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPremiumPrice<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
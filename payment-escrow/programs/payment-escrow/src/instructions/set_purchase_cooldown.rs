@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the minimum seconds between purchases of a listing by the same wallet (0 disables)
+pub fn set_purchase_cooldown(ctx: Context<SetPurchaseCooldown>, purchase_cooldown: i64) -> Result<()> {
+    require!(purchase_cooldown >= 0, EscrowError::InvalidPurchaseCooldown);
+    
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.purchase_cooldown = purchase_cooldown;
+    
+    msg!("Listing purchase cooldown set: {}s", purchase_cooldown);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPurchaseCooldown<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
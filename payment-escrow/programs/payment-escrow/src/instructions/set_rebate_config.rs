@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use common::bps::MAX_BPS;
+use crate::state::*;
+use crate::errors::*;
+
+/// Configure platform fee rebates (admin only)
+/// The rebate mint's mint authority must be the program's rebate authority PDA;
+/// omitting the mint disables rebates
+pub fn set_rebate_config(ctx: Context<SetRebateConfig>, rebate_bps: u16) -> Result<()> {
+    require!(rebate_bps <= MAX_BPS, EscrowError::InvalidRebateConfig);
+    
+    let rebate_mint = match ctx.accounts.rebate_mint.as_ref() {
+        Some(rebate_mint) => {
+            require!(
+                rebate_mint.mint_authority == Some(ctx.accounts.rebate_authority.key()).into(),
+                EscrowError::InvalidRebateConfig
+            );
+            rebate_mint.key()
+        }
+        None => Pubkey::default(),
+    };
+    
+    let config = &mut ctx.accounts.platform_config;
+    config.rebate_mint = rebate_mint;
+    config.rebate_bps = rebate_bps;
+    
+    msg!("Rebate config set: mint {}, {} bps", rebate_mint, rebate_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRebateConfig<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Platform token paid out as rebates (omit to disable rebates)
+    pub rebate_mint: Option<Account<'info, Mint>>,
+    
+    /// Rebate mint authority PDA
+    /// CHECK: Validated by seeds, only its address is used
+    #[account(seeds = [CreatorRebate::AUTHORITY_SEED_PREFIX], bump)]
+    pub rebate_authority: UncheckedAccount<'info>,
+}
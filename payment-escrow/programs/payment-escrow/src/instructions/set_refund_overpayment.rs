@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Turn overpayment refunds on or off: when on, purchases paying more than the amount
+/// due succeed and the excess is returned to the buyer instead of failing
+pub fn set_refund_overpayment(ctx: Context<SetRefundOverpayment>, enabled: bool) -> Result<()> {
+    ctx.accounts.platform_config.refund_overpayment = enabled;
+    
+    msg!("Overpayment refunds enabled: {}", enabled);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRefundOverpayment<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
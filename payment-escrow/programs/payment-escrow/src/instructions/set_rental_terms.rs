@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Configure time-boxed rental access for a listing
+pub fn set_rental_terms(
+    ctx: Context<SetRentalTerms>,
+    rental_price: u64,
+    rental_duration: i64,
+) -> Result<()> {
+    require!(rental_duration >= 0, EscrowError::InvalidRentalTerms);
+    
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.rental_price = rental_price;
+    listing.rental_duration = rental_duration;
+    
+    msg!("Rental terms set: price {}, duration {}s", rental_price, rental_duration);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRentalTerms<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Only sell a listing while its creator holds a platform-issued creator badge
+pub fn set_require_creator_badge(
+    ctx: Context<SetRequireCreatorBadge>,
+    require_creator_badge: bool,
+) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.require_creator_badge = require_creator_badge;
+    
+    msg!("Listing requires creator badge: {}", require_creator_badge);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRequireCreatorBadge<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the share of each resale of the content's access tokens paid to its split
+/// Resale listings snapshot the royalty when created, so changes apply to new listings only
+pub fn set_resale_royalty(ctx: Context<SetResaleRoyalty>, resale_royalty_bps: u16) -> Result<()> {
+    require!(
+        resale_royalty_bps <= Listing::MAX_RESALE_ROYALTY_BPS,
+        EscrowError::InvalidResaleRoyalty
+    );
+    
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.resale_royalty_bps = resale_royalty_bps;
+    
+    msg!("Listing resale royalty set: {} bps", resale_royalty_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetResaleRoyalty<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Replace a listing's sale phases
+/// Phases must be in chronological order without overlapping windows; their
+/// sold counters start from zero
+pub fn set_sale_phases(ctx: Context<SetSalePhases>, phases: Vec<SalePhase>) -> Result<()> {
+    require!(
+        phases.len() <= Listing::MAX_PHASES,
+        EscrowError::InvalidSalePhases
+    );
+    
+    for (i, phase) in phases.iter().enumerate() {
+        require!(phase.start_ts < phase.end_ts, EscrowError::InvalidSalePhases);
+        
+        if i > 0 {
+            require!(
+                phases[i - 1].end_ts <= phase.start_ts,
+                EscrowError::InvalidSalePhases
+            );
+        }
+    }
+    
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.phases = phases
+        .into_iter()
+        .map(|phase| SalePhase { sold: 0, ..phase })
+        .collect();
+    
+    msg!("Sale phases set: {}", listing.phases.len());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSalePhases<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
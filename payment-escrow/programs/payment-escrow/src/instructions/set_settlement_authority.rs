@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Set the authority allowed to settle fiat purchases (default pubkey disables fiat settlement)
+pub fn set_settlement_authority(
+    ctx: Context<SetSettlementAuthority>,
+    settlement_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.platform_config.settlement_authority = settlement_authority;
+    
+    msg!("Settlement authority set to {}", settlement_authority);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSettlementAuthority<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Reject purchases of a listing bundled with instructions from other programs
+pub fn set_strict_mode(ctx: Context<SetStrictMode>, strict_mode: bool) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    listing.strict_mode = strict_mode;
+    
+    msg!("Listing strict mode set: {}", strict_mode);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetStrictMode<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+}
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::AccessMintState,
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use common::units::SOL_DECIMALS;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+
+/// Grant access for a purchase paid off-chain (e.g. by card)
+/// The platform's settlement authority records the purchase as a completed escrow with
+/// `PaymentSource::Fiat`; each external payment id can only be settled once
+pub fn settle_fiat_purchase(
+    ctx: Context<SettleFiatPurchase>,
+    external_payment_id: [u8; 32],
+    seed: u64,
+) -> Result<()> {
+    // Frozen (taken down) content can't be sold, nor sold to denylisted wallets
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    check_not_denylisted(&ctx.accounts.denylist_entry, None)?;
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept this flow
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program to mint access token to the buyer
+    mint_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            AccessMintAccounts {
+                buyer: ctx.accounts.buyer.to_account_info(),
+                payer: ctx.accounts.settlement_authority.to_account_info(),
+                access_mint_state: ctx.accounts.access_mint_state.to_account_info(),
+                mint: ctx.accounts.access_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                buyer_token_account: ctx.accounts.buyer_access_token_account.to_account_info(),
+                purchase_receipt: None,
+                access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                preview_grant: None,
+                access_collection: None,
+                access_metadata: None,
+                collection_mint: None,
+                collection_metadata: None,
+                collection_master_edition: None,
+                token_metadata_program: None,
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[access_minter_seeds],
+        ),
+    )?;
+    
+    let clock = Clock::get()?;
+    let escrow = &mut ctx.accounts.escrow_state;
+    
+    // Record the completed purchase; no on-chain payment backs it
+    escrow.buyer = ctx.accounts.buyer.key();
+    escrow.creator = ctx.accounts.access_mint_state.creator;
+    escrow.content_id = ctx.accounts.access_mint_state.content_id;
+    escrow.price = 0;
+    escrow.payment_token_mint = None;
+    escrow.payment_amount = 0;
+    escrow.access_mint_address = Some(ctx.accounts.access_mint.key());
+    escrow.listing = None;
+    escrow.price_version = 0;
+    escrow.license_terms_hash = [0; 32];
+    escrow.memo = None;
+    escrow.created_ts = clock.unix_timestamp;
+    escrow.seed = seed;
+    // Created and completed in one step
+    escrow.status = EscrowStatus::Initialized;
+    escrow.transition_to(EscrowStatus::Completed)?;
+    escrow.bump = ctx.bumps.escrow_state;
+    // Payment never passes through the buyer vault
+    escrow.vault_bump = 0;
+    escrow.payment_decimals = SOL_DECIMALS;
+    escrow.rent_recipient = ctx.accounts.settlement_authority.key();
+    escrow.payment_source = PaymentSource::Fiat;
+    escrow.randomness_account = None;
+    escrow.randomness_commit_slot = 0;
+    escrow.revealed_content_id = None;
+    escrow.distributed_ts = 0;
+    
+    let settlement = &mut ctx.accounts.fiat_settlement;
+    settlement.external_payment_id = external_payment_id;
+    settlement.escrow = escrow.key();
+    settlement.settled_by = ctx.accounts.settlement_authority.key();
+    settlement.settled_ts = clock.unix_timestamp;
+    settlement.bump = ctx.bumps.fiat_settlement;
+    
+    emit!(FiatPurchaseSettled {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        escrow: escrow.key(),
+        buyer: escrow.buyer,
+        creator: escrow.creator,
+        content_id: escrow.content_id,
+        access_mint: ctx.accounts.access_mint.key(),
+        external_payment_id,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Fiat purchase settled for buyer: {}", escrow.buyer);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(external_payment_id: [u8; 32], seed: u64)]
+pub struct SettleFiatPurchase<'info> {
+    /// The platform's settlement authority, paying for the purchase records
+    #[account(mut)]
+    pub settlement_authority: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        constraint = platform_config.is_settlement_authority(settlement_authority.key) @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// The buyer receiving access
+    /// CHECK: Any wallet can receive access
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// Escrow state PDA recording the purchase
+    #[account(
+        init,
+        payer = settlement_authority,
+        space = EscrowState::LEN,
+        seeds = [
+            EscrowState::SEED_PREFIX,
+            buyer.key().as_ref(),
+            access_mint_state.content_id.as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Settlement record; its creation fails if the payment was already settled
+    #[account(
+        init,
+        payer = settlement_authority,
+        space = FiatSettlement::LEN,
+        seeds = [FiatSettlement::SEED_PREFIX, external_payment_id.as_ref()],
+        bump,
+    )]
+    pub fiat_settlement: Account<'info, FiatSettlement>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, access_mint_state.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's denylist entry; settlement is rejected while it exists
+    /// CHECK: Validated by seeds, only checked for existence
+    #[account(seeds = [DenylistEntry::SEED_PREFIX, buyer.key().as_ref()], bump)]
+    pub denylist_entry: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Access mint state for the purchased content
+    #[account(mut)]
+    pub access_mint_state: Account<'info, AccessMintState>,
+    
+    /// Access token mint
+    #[account(mut)]
+    pub access_mint: Account<'info, Mint>,
+    
+    /// Mint authority for access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Buyer's access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub buyer_access_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for access mint
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program::System;
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::introspection::*;
+
+/// Settle a buyer-signed voucher for cumulative usage, distributing the amount above what
+/// was already settled
+/// The voucher signature is verified by an Ed25519 program instruction placed right
+/// before this one, signed by the buyer over `PaymentChannel::voucher_message`
+pub fn settle_payment_channel<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettlePaymentChannel<'info>>,
+    cumulative_amount: u64,
+) -> Result<()> {
+    let payment_channel = &mut ctx.accounts.payment_channel;
+    
+    check_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &payment_channel.buyer,
+        &PaymentChannel::voucher_message(
+            &payment_channel.key(),
+            payment_channel.nonce,
+            cumulative_amount,
+        ),
+    )?;
+    
+    let amount = payment_channel.settle(cumulative_amount)?;
+    
+    // The payment channel is program-owned, so lamports move directly
+    ctx.accounts.payment_channel.sub_lamports(amount)?;
+    ctx.accounts.distribution_vault.add_lamports(amount)?;
+    
+    // CPI to Distribution program to split the usage; channels are SOL-only, so the
+    // SPL token accounts are never read and the vault and payees stand in for them
+    let remaining_accounts = ctx.remaining_accounts.to_vec();
+    
+    distribute(
+        CpiContext::new(
+            ctx.accounts.distribution_program.to_account_info(),
+            DistributeAccounts {
+                payer: ctx.accounts.creator.to_account_info(),
+                split_state: ctx.accounts.split_state.to_account_info(),
+                distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                vault: ctx.accounts.distribution_vault.to_account_info(),
+                creator: ctx.accounts.creator_payout.to_account_info(),
+                platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                payment_token_mint: ctx.accounts.system_program.to_account_info(),
+                vault_token_account: ctx.accounts.distribution_vault.to_account_info(),
+                creator_token_account: ctx.accounts.creator_payout.to_account_info(),
+                platform_treasury_token_account: ctx.accounts.platform_treasury.to_account_info(),
+                token_program: ctx.accounts.system_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                fee_waiver: None,
+            },
+        )
+        .with_remaining_accounts(remaining_accounts),
+        amount,
+    )?;
+    
+    emit!(PaymentChannelSettled {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        payment_channel: ctx.accounts.payment_channel.key(),
+        cumulative_amount,
+        settled: amount,
+    });
+    
+    msg!("Payment channel settled: {}, cumulative: {}", amount, cumulative_amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettlePaymentChannel<'info> {
+    /// The creator submitting the voucher (pays for the distribution record)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Payment channel PDA (holds the deposit)
+    #[account(
+        mut,
+        seeds = [PaymentChannel::SEED_PREFIX, payment_channel.buyer.as_ref(), payment_channel.listing.as_ref()],
+        bump = payment_channel.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub payment_channel: Box<Account<'info, PaymentChannel>>,
+    
+    /// Instructions sysvar, holding the Ed25519 verification of the voucher
+    /// CHECK: Address must be the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the metered content
+    #[account(
+        mut,
+        constraint = split_state.creator == payment_channel.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == payment_channel.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// System-owned, so SOL moved into it can only leave through the distribution program
+    #[account(
+        mut,
+        seeds = [SplitState::VAULT_SEED_PREFIX, split_state.key().as_ref()],
+        bump,
+        seeds::program = distribution_program.key(),
+    )]
+    pub distribution_vault: SystemAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator_payout: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts receiving their share
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Slash part of a creator's bond to compensate the buyer of a disputed purchase
+/// Only allowed once enough disputes have been recorded within the window, and the
+/// total slashed for a dispute is capped at what its buyer paid
+pub fn slash_bond(ctx: Context<SlashBond>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let bond = &mut ctx.accounts.creator_bond;
+    
+    require!(amount > 0, EscrowError::InvalidBondAmount);
+    require!(bond.is_slashable(now), EscrowError::BondNotSlashable);
+    ctx.accounts.dispute_record.record_slash(amount)?;
+    
+    bond.debit(amount)?;
+    bond.slashed_total = bond
+        .slashed_total
+        .checked_add(amount)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    ctx.accounts.creator_bond.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+    
+    emit!(BondSlashed {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        creator: ctx.accounts.creator_bond.creator,
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        staked: ctx.accounts.creator_bond.staked,
+    });
+    
+    msg!("Slashed {} from {}'s bond", amount, ctx.accounts.creator_bond.creator);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SlashBond<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Creator bond PDA
+    #[account(
+        mut,
+        seeds = [CreatorBond::SEED_PREFIX, creator_bond.creator.as_ref()],
+        bump = creator_bond.bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+    
+    /// Dispute recorded against this creator for the purchase being compensated
+    #[account(
+        mut,
+        seeds = [DisputeRecord::SEED_PREFIX, dispute_record.escrow.as_ref()],
+        bump = dispute_record.bump,
+        constraint = dispute_record.creator == creator_bond.creator @ EscrowError::InvalidCreator,
+    )]
+    pub dispute_record: Account<'info, DisputeRecord>,
+    
+    /// Buyer of the disputed purchase, compensated from the bond
+    /// CHECK: Must be the dispute record's buyer
+    #[account(
+        mut,
+        address = dispute_record.buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub recipient: UncheckedAccount<'info>,
+}
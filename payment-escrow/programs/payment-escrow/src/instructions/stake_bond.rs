@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Stake SOL into the creator's bond
+pub fn stake_bond(ctx: Context<StakeBond>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::InvalidBondAmount);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.creator_bond.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let bond = &mut ctx.accounts.creator_bond;
+    bond.creator = ctx.accounts.creator.key();
+    bond.staked = bond.staked.checked_add(amount).ok_or(EscrowError::NumericalOverflow)?;
+    bond.bump = ctx.bumps.creator_bond;
+    
+    emit!(BondStaked {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        creator: bond.creator,
+        amount,
+        staked: bond.staked,
+    });
+    
+    msg!("Creator {} staked {}, total staked: {}", bond.creator, amount, bond.staked);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StakeBond<'info> {
+    /// The creator staking SOL
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Creator bond PDA
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorBond::LEN,
+        seeds = [CreatorBond::SEED_PREFIX, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
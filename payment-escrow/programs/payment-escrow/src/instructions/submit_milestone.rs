@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Submit the current milestone for the buyer's review (creator only)
+/// A disputed milestone is resubmitted the same way
+pub fn submit_milestone(ctx: Context<SubmitMilestone>) -> Result<()> {
+    let milestone_state = &mut ctx.accounts.milestone_state;
+    let index = milestone_state.current;
+    let milestone = milestone_state.current_milestone()?;
+    
+    require!(
+        milestone.status == MilestoneStatus::Pending || milestone.status == MilestoneStatus::Disputed,
+        EscrowError::InvalidMilestoneStatus
+    );
+    
+    milestone.status = MilestoneStatus::Submitted;
+    
+    msg!("Milestone {} submitted", index);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    /// The creator delivering the commission
+    pub creator: Signer<'info>,
+    
+    /// Milestone state PDA
+    #[account(
+        mut,
+        seeds = [
+            MilestoneState::SEED_PREFIX,
+            milestone_state.buyer.as_ref(),
+            milestone_state.content_id.as_ref(),
+            milestone_state.seed.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_state.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub milestone_state: Account<'info, MilestoneState>,
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payment::*;
+
+/// Return lamports or tokens sent to a buyer vault outside the escrow flow to its buyer
+/// Only the balance above what the vault holds for escrows (and its rent reserve for
+/// SOL vaults) can be swept, so every escrow's payment stays fully backed
+pub fn sweep_surplus(ctx: Context<SweepSurplus>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let buyer_vault = &ctx.accounts.buyer_vault;
+    
+    // Only the vault's buyer or the platform admin can sweep, and the surplus always
+    // goes back to the buyer
+    require!(
+        authority == buyer_vault.buyer || authority == ctx.accounts.platform_config.admin,
+        EscrowError::Unauthorized
+    );
+    
+    let held_amount = buyer_vault.held_amount;
+    let payment_token_mint = buyer_vault.payment_token_mint;
+    
+    let surplus = if payment_token_mint.is_none() {
+        let surplus = BuyerVault::spendable_lamports(&ctx.accounts.buyer_vault.to_account_info())?
+            .saturating_sub(held_amount);
+        require!(surplus > 0, EscrowError::NoSurplus);
+        
+        // The buyer vault is program-owned, so lamports move directly
+        ctx.accounts.buyer_vault.sub_lamports(surplus)?;
+        ctx.accounts.buyer.add_lamports(surplus)?;
+        
+        msg!("Swept {} surplus lamports from buyer vault", surplus);
+        surplus
+    } else {
+        let vault_token_account = ctx
+            .accounts
+            .vault_token_account
+            .as_ref()
+            .ok_or(EscrowError::TokenAccountRequired)?;
+        let recipient_token_account = ctx
+            .accounts
+            .recipient_token_account
+            .as_ref()
+            .ok_or(EscrowError::TokenAccountRequired)?;
+        let payment_mint = ctx
+            .accounts
+            .payment_mint
+            .as_ref()
+            .ok_or(EscrowError::PaymentMintMismatch)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(EscrowError::InvalidTokenProgram)?;
+        
+        require!(
+            payment_token_mint == Some(payment_mint.key()),
+            EscrowError::PaymentMintMismatch
+        );
+        
+        let surplus = vault_token_account.amount.saturating_sub(held_amount);
+        require!(surplus > 0, EscrowError::NoSurplus);
+        
+        let buyer_key = ctx.accounts.buyer_vault.buyer;
+        let seeds = &[
+            BuyerVault::SEED_PREFIX,
+            buyer_key.as_ref(),
+            BuyerVault::mint_seed(&payment_token_mint).as_ref(),
+            &[ctx.accounts.buyer_vault.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        transfer_payment(
+            token_program.to_account_info(),
+            vault_token_account.to_account_info(),
+            payment_mint.to_account_info(),
+            recipient_token_account.to_account_info(),
+            ctx.accounts.buyer_vault.to_account_info(),
+            signer_seeds,
+            surplus,
+        )?;
+        
+        msg!("Swept {} surplus tokens from buyer vault", surplus);
+        surplus
+    };
+    
+    emit!(SurplusSwept {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        buyer_vault: ctx.accounts.buyer_vault.key(),
+        authority,
+        payment_token_mint,
+        amount: surplus,
+        held_amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SweepSurplus<'info> {
+    /// The vault's buyer or the platform admin
+    pub authority: Signer<'info>,
+    
+    /// The vault's buyer, receiving the surplus
+    #[account(mut, address = buyer_vault.buyer @ EscrowError::InvalidBuyer)]
+    pub buyer: SystemAccount<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Buyer vault holding payments for the buyer's escrows in this payment mint
+    #[account(
+        mut,
+        seeds = [
+            BuyerVault::SEED_PREFIX,
+            buyer_vault.buyer.as_ref(),
+            BuyerVault::mint_seed(&buyer_vault.payment_token_mint).as_ref(),
+        ],
+        bump = buyer_vault.bump,
+    )]
+    pub buyer_vault: Account<'info, BuyerVault>,
+    
+    /// Buyer vault's SPL token account (for SPL vaults)
+    #[account(
+        mut,
+        token::authority = buyer_vault,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Buyer's token account receiving swept tokens (for SPL vaults)
+    #[account(
+        mut,
+        token::authority = buyer,
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Payment token mint (for SPL vaults)
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// Token program (SPL Token or Token-2022, for SPL vaults)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Resume sales of a frozen content
+pub fn unfreeze_content(ctx: Context<UnfreezeContent>) -> Result<()> {
+    let freeze = &mut ctx.accounts.content_freeze;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(freeze.frozen, EscrowError::ContentNotFrozen);
+    
+    freeze.frozen = false;
+    freeze.updated_ts = now;
+    
+    emit!(ContentUnfrozen {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        content_id: freeze.content_id,
+        timestamp: now,
+    });
+    
+    msg!("Content unfrozen by admin: {}", ctx.accounts.admin.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeContent<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Content freeze PDA
+    #[account(
+        mut,
+        seeds = [ContentFreeze::SEED_PREFIX, content_freeze.content_id.as_ref()],
+        bump = content_freeze.bump,
+    )]
+    pub content_freeze: Account<'info, ContentFreeze>,
+}
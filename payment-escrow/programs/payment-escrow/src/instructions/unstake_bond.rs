@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Withdraw the requested part of the creator's bond once the cooldown has passed
+/// Bonds with disputes in the open window stay locked so they can still be slashed
+pub fn unstake_bond(ctx: Context<UnstakeBond>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let bond = &mut ctx.accounts.creator_bond;
+    let amount = bond.unstake_amount;
+    
+    require!(amount > 0, EscrowError::InvalidBondAmount);
+    require!(now >= bond.unstake_available_ts, EscrowError::UnstakeCooldownActive);
+    require!(bond.disputes_in_window(now) == 0, EscrowError::BondLocked);
+    
+    bond.debit(amount)?;
+    bond.unstake_amount = 0;
+    
+    ctx.accounts.creator_bond.sub_lamports(amount)?;
+    ctx.accounts.creator.add_lamports(amount)?;
+    
+    emit!(BondUnstaked {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        creator: ctx.accounts.creator.key(),
+        amount,
+        staked: ctx.accounts.creator_bond.staked,
+    });
+    
+    msg!("Unstaked {}, remaining staked: {}", amount, ctx.accounts.creator_bond.staked);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnstakeBond<'info> {
+    /// The creator who owns the bond
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Creator bond PDA
+    #[account(
+        mut,
+        seeds = [CreatorBond::SEED_PREFIX, creator.key().as_ref()],
+        bump = creator_bond.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Update the price of a listing, bumping its price version
+pub fn update_listing_price(
+    ctx: Context<UpdateListingPrice>,
+    new_price: u64,
+) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    
+    ctx.accounts.platform_config.check_price_floor(new_price, &listing.payment_token_mint)?;
+    
+    // Basic access can't cost more than the premium tier
+    require!(
+        listing.premium_price == 0 || new_price <= listing.premium_price,
+        EscrowError::InvalidPrice
+    );
+    
+    listing.price = new_price;
+    listing.price_version = listing
+        .price_version
+        .checked_add(1)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    msg!("Listing price updated to {}, price version: {}", new_price, listing.price_version);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateListingPrice<'info> {
+    /// The creator who owns the listing
+    pub creator: Signer<'info>,
+    
+    /// Listing PDA
+    #[account(
+        mut,
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+        has_one = creator @ EscrowError::InvalidCreator,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Platform config PDA (price floor)
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
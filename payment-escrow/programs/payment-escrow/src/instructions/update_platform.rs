@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Change a platform's treasury and fee
+/// Splits created for the old terms stop matching listings that reference the platform
+pub fn update_platform(ctx: Context<UpdatePlatform>, fee_bps: u16) -> Result<()> {
+    require!(fee_bps <= Platform::MAX_FEE_BPS, EscrowError::InvalidPlatformFee);
+    
+    let platform = &mut ctx.accounts.platform;
+    
+    platform.treasury = ctx.accounts.treasury.key();
+    platform.fee_bps = fee_bps;
+    
+    emit!(PlatformUpdated {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        platform: platform.key(),
+        treasury: platform.treasury,
+        fee_bps,
+    });
+    
+    msg!("Platform updated: treasury {}, fee {}bps", platform.treasury, fee_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatform<'info> {
+    /// Marketplace operator
+    pub authority: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// New treasury receiving the platform's fees
+    /// CHECK: Any wallet can be a treasury
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// Platform PDA
+    #[account(
+        mut,
+        seeds = [Platform::SEED_PREFIX, authority.key().as_ref()],
+        bump = platform.bump,
+        has_one = authority @ EscrowError::Unauthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Update the global platform configuration
+pub fn update_platform_config(
+    ctx: Context<UpdatePlatformConfig>,
+    require_creator_cosign: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    
+    config.require_creator_cosign = require_creator_cosign;
+    
+    msg!("Platform config updated, require_creator_cosign: {}", require_creator_cosign);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    /// The platform admin
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
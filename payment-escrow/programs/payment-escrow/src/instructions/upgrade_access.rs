@@ -0,0 +1,355 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer, System};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use access_mint_cpi::{
+    program::AccessMint,
+    state::{AccessGrant, AccessMintState, AccessTier},
+    cpi::accounts::MintAccess as AccessMintAccounts,
+    cpi::mint_access,
+};
+use distribution_cpi::{
+    program::Distribution,
+    state::SplitState,
+    cpi::accounts::Distribute as DistributeAccounts,
+    cpi::distribute,
+};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::content::*;
+use crate::payment::*;
+
+/// Swap a basic-tier access token for a premium-tier token by paying the price difference
+/// Only permanently held access qualifies; rentals and previews can't be upgraded
+pub fn upgrade_access<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpgradeAccess<'info>>,
+) -> Result<()> {
+    // Frozen (taken down) content can't be sold
+    check_not_frozen(&ctx.accounts.content_freeze)?;
+    
+    let listing = &ctx.accounts.listing;
+    
+    // Platform listings must be paid out to the referenced platform
+    listing.check_platform(ctx.accounts.platform.as_ref(), &ctx.accounts.split_state)?;
+    
+    require!(listing.premium_price > 0, EscrowError::UpgradeNotAvailable);
+    
+    let upgrade_price = listing
+        .premium_price
+        .checked_sub(listing.price)
+        .ok_or(EscrowError::NumericalOverflow)?;
+    
+    // Rentals and previews carry an expiring grant; a preview made permanent by a purchase doesn't
+    let buyer_access_grant = &ctx.accounts.buyer_access_grant;
+    if !buyer_access_grant.data_is_empty() {
+        let grant = AccessGrant::try_deserialize(&mut &buyer_access_grant.try_borrow_data()?[..])?;
+        require!(
+            grant.end_ts == AccessGrant::PERMANENT_END_TS,
+            EscrowError::RentalNotUpgradable
+        );
+    }
+    
+    // Burn the basic-tier access units granted by one purchase
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.access_token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.basic_mint.to_account_info(),
+                from: ctx.accounts.buyer_basic_token_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        ctx.accounts.basic_access_mint_state.purchase_units(),
+    )?;
+    
+    if upgrade_price > 0 {
+        // Pay the price difference straight into the distribution vault, distributing what arrives after any transfer fee
+        let distribution_amount = if listing.payment_token_mint.is_none() {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.buyer.to_account_info(),
+                        to: ctx.accounts.distribution_vault.to_account_info(),
+                    },
+                ),
+                upgrade_price,
+            )?;
+            upgrade_price
+        } else {
+            require!(
+                ctx.accounts.buyer_token_account.key() != System::id(),
+                EscrowError::TokenAccountRequired
+            );
+            
+            transfer_payment(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.buyer_token_account.to_account_info(),
+                ctx.accounts.payment_token_mint.to_account_info(),
+                ctx.accounts.distribution_vault_token_account.to_account_info(),
+                ctx.accounts.buyer.to_account_info(),
+                &[],
+                upgrade_price,
+            )?
+        };
+        
+        // CPI to Distribution program to split the upgrade payment
+        let remaining_accounts = ctx.remaining_accounts.to_vec();
+        
+        distribute(
+            CpiContext::new(
+                ctx.accounts.distribution_program.to_account_info(),
+                DistributeAccounts {
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    split_state: ctx.accounts.split_state.to_account_info(),
+                    distribution_record: ctx.accounts.distribution_record.to_account_info(),
+                    revenue_bucket: ctx.accounts.revenue_bucket.to_account_info(),
+                    vault: ctx.accounts.distribution_vault.to_account_info(),
+                    creator: ctx.accounts.creator.to_account_info(),
+                    platform_treasury: ctx.accounts.platform_treasury.to_account_info(),
+                    payment_token_mint: ctx.accounts.payment_token_mint.to_account_info(),
+                    vault_token_account: ctx.accounts.distribution_vault_token_account.to_account_info(),
+                    creator_token_account: ctx.accounts.creator_token_account.to_account_info(),
+                    platform_treasury_token_account: ctx.accounts.platform_treasury_token_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    fee_waiver: None,
+                },
+            )
+            .with_remaining_accounts(remaining_accounts),
+            distribution_amount,
+        )?;
+    }
+    
+    // Sign as the access minter so mints requiring a purchase receipt accept this flow
+    let access_minter_bump = [ctx.bumps.access_minter];
+    let access_minter_seeds: &[&[u8]] = &[PlatformConfig::ACCESS_MINTER_SEED, &access_minter_bump];
+    
+    // CPI to Access Mint program to mint the premium-tier token
+    mint_access(
+        CpiContext::new_with_signer(
+            ctx.accounts.access_mint_program.to_account_info(),
+            AccessMintAccounts {
+                buyer: ctx.accounts.buyer.to_account_info(),
+                payer: ctx.accounts.buyer.to_account_info(),
+                access_mint_state: ctx.accounts.premium_access_mint_state.to_account_info(),
+                mint: ctx.accounts.premium_mint.to_account_info(),
+                mint_authority: ctx.accounts.premium_mint_authority.to_account_info(),
+                buyer_token_account: ctx.accounts.buyer_premium_token_account.to_account_info(),
+                purchase_receipt: None,
+                access_minter: Some(ctx.accounts.access_minter.to_account_info()),
+                preview_grant: None,
+                access_collection: None,
+                access_metadata: None,
+                collection_mint: None,
+                collection_metadata: None,
+                collection_master_edition: None,
+                token_metadata_program: None,
+                token_program: ctx.accounts.access_token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[access_minter_seeds],
+        ),
+    )?;
+    
+    emit!(AccessUpgraded {
+        event_sequence: ctx.accounts.platform_config.next_event_sequence()?,
+        listing: ctx.accounts.listing.key(),
+        buyer: ctx.accounts.buyer.key(),
+        basic_mint: ctx.accounts.basic_mint.key(),
+        premium_mint: ctx.accounts.premium_mint.key(),
+        price_paid: upgrade_price,
+    });
+    
+    msg!("Access upgraded to premium for: {}, paid: {}", ctx.accounts.buyer.key(), upgrade_price);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpgradeAccess<'info> {
+    /// The holder upgrading their access
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Platform config PDA, stamps event sequence numbers
+    #[account(
+        mut,
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    
+    /// Listing offering the premium tier
+    #[account(
+        seeds = [
+            Listing::SEED_PREFIX,
+            listing.creator.as_ref(),
+            listing.content_id.as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// Marketplace platform the listing is sold through (required when listing.platform is set)
+    #[account(
+        seeds = [Platform::SEED_PREFIX, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Option<Account<'info, Platform>>,
+    
+    /// Content freeze PDA; sales are halted while it exists and is frozen
+    /// CHECK: Validated by seeds, read only if initialized
+    #[account(seeds = [ContentFreeze::SEED_PREFIX, listing.content_id.as_ref()], bump)]
+    pub content_freeze: UncheckedAccount<'info>,
+    
+    /// Buyer's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Token program (SPL Token or Token-2022, for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    pub token_program: UncheckedAccount<'info>,
+    
+    // ============ Access Mint Program Accounts ============
+    
+    /// Access mint program
+    pub access_mint_program: Program<'info, AccessMint>,
+    
+    /// Basic-tier access mint state for the listed content
+    #[account(
+        constraint = basic_access_mint_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = basic_access_mint_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+        constraint = basic_access_mint_state.tier == AccessTier::Basic @ EscrowError::InvalidTier,
+        constraint = basic_access_mint_state.mint == basic_mint.key() @ EscrowError::InvalidTier,
+    )]
+    pub basic_access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Basic-tier access token mint
+    #[account(mut)]
+    pub basic_mint: Box<Account<'info, Mint>>,
+    
+    /// Buyer's basic-tier access token account
+    #[account(
+        mut,
+        associated_token::mint = basic_mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_basic_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Buyer's access grant for the basic tier; time-boxed access can't be upgraded
+    /// CHECK: Validated by seeds against the access mint program, read only if initialized
+    #[account(
+        seeds = [common::seeds::ACCESS_GRANT, basic_mint.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        seeds::program = access_mint_program.key(),
+    )]
+    pub buyer_access_grant: UncheckedAccount<'info>,
+    
+    /// Premium-tier access mint state for the listed content
+    #[account(
+        mut,
+        constraint = premium_access_mint_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = premium_access_mint_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+        constraint = premium_access_mint_state.tier == AccessTier::Premium @ EscrowError::InvalidTier,
+    )]
+    pub premium_access_mint_state: Box<Account<'info, AccessMintState>>,
+    
+    /// Premium-tier access token mint
+    #[account(mut)]
+    pub premium_mint: Box<Account<'info, Mint>>,
+    
+    /// Mint authority for premium-tier access tokens
+    /// CHECK: Validated by access mint program via CPI
+    pub premium_mint_authority: UncheckedAccount<'info>,
+    
+    /// Escrow program authority signing the access mint for mints requiring a purchase receipt
+    /// CHECK: PDA validated by seeds; signs the CPI only
+    #[account(seeds = [PlatformConfig::ACCESS_MINTER_SEED], bump)]
+    pub access_minter: UncheckedAccount<'info>,
+    
+    /// Buyer's premium-tier access token account (will be created if needed)
+    /// CHECK: Validated and potentially created by access mint program via CPI
+    #[account(mut)]
+    pub buyer_premium_token_account: UncheckedAccount<'info>,
+    
+    /// Token program for access mints
+    pub access_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    // ============ Distribution Program Accounts ============
+    
+    /// Distribution program
+    pub distribution_program: Program<'info, Distribution>,
+    
+    /// Split state for the listed content
+    #[account(
+        mut,
+        constraint = split_state.creator == listing.creator @ EscrowError::InvalidCreator,
+        constraint = split_state.content_id == listing.content_id @ EscrowError::InvalidContentId,
+    )]
+    pub split_state: Box<Account<'info, SplitState>>,
+    
+    /// Distribution record PDA for this sale (next index of the split)
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub distribution_record: UncheckedAccount<'info>,
+    
+    /// Creator revenue bucket for the current month
+    /// CHECK: Validated and initialized by distribution program via CPI
+    #[account(mut)]
+    pub revenue_bucket: UncheckedAccount<'info>,
+    
+    /// Distribution vault PDA (derived from split_state)
+    /// CHECK: Validated by distribution program via CPI
+    #[account(mut)]
+    pub distribution_vault: UncheckedAccount<'info>,
+    
+    /// Distribution vault's SPL token account (for SPL payments)
+    /// CHECK: Optional account, validated when SPL payment is used
+    #[account(mut)]
+    pub distribution_vault_token_account: UncheckedAccount<'info>,
+    
+    /// Creator payout account (receives the creator share)
+    /// CHECK: Must match the split's payout address
+    #[account(
+        mut,
+        address = split_state.payout_address @ EscrowError::InvalidCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Platform treasury (receives platform fees)
+    /// CHECK: Must match the split's platform treasury
+    #[account(
+        mut,
+        address = split_state.platform_treasury @ EscrowError::Unauthorized,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Must match the listing payment mint
+    #[account(
+        address = listing.payment_token_mint.unwrap_or(System::id()) @ EscrowError::PaymentMintMismatch,
+    )]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Creator's token account (for SPL payments)
+    /// CHECK: Optional, validated by distribution program when SPL payment is used
+    #[account(mut)]
+    pub creator_token_account: UncheckedAccount<'info>,
+    
+    /// Platform treasury token account (for SPL payments)
+    /// CHECK: Optional, validated by distribution program when SPL payment is used
+    #[account(mut)]
+    pub platform_treasury_token_account: UncheckedAccount<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    // Remaining accounts: Collaborator accounts (SOL) or token accounts (SPL)
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::payment::*;
+
+/// Withdraw unspent funds from the buyer's deposit back to the buyer
+pub fn withdraw_deposit(ctx: Context<WithdrawDeposit>, amount: u64) -> Result<()> {
+    let buyer_deposit = &ctx.accounts.buyer_deposit;
+    
+    let spl = match buyer_deposit.payment_token_mint {
+        None => None,
+        Some(payment_token_mint) => {
+            validate_vault_token_account(
+                &ctx.accounts.deposit_token_account,
+                &buyer_deposit.key(),
+                &payment_token_mint,
+            )?;
+            
+            Some(SplPayment {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.payment_token_mint.to_account_info(),
+                from: ctx.accounts.deposit_token_account.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+            })
+        }
+    };
+    
+    let buyer_key = buyer_deposit.buyer;
+    let seeds = &[
+        BuyerDeposit::SEED_PREFIX,
+        buyer_key.as_ref(),
+        BuyerVault::mint_seed(&buyer_deposit.payment_token_mint).as_ref(),
+        &[buyer_deposit.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    refund_from_vault(
+        ctx.accounts.buyer_deposit.to_account_info(),
+        ctx.accounts.buyer.to_account_info(),
+        spl,
+        signer_seeds,
+        amount,
+    )?;
+    
+    let buyer_deposit = &mut ctx.accounts.buyer_deposit;
+    buyer_deposit.debit(amount)?;
+    
+    msg!("Deposit withdrawn: {}, balance: {}", amount, buyer_deposit.balance);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawDeposit<'info> {
+    /// The buyer who owns the deposit
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Buyer deposit PDA
+    #[account(
+        mut,
+        seeds = [
+            BuyerDeposit::SEED_PREFIX,
+            buyer.key().as_ref(),
+            BuyerVault::mint_seed(&buyer_deposit.payment_token_mint).as_ref(),
+        ],
+        bump = buyer_deposit.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+    )]
+    pub buyer_deposit: Account<'info, BuyerDeposit>,
+    
+    /// Payment token mint (System::id() for SOL, token mint for SPL)
+    /// CHECK: Must match the deposit's payment mint
+    #[account(
+        address = buyer_deposit.payment_token_mint.unwrap_or(System::id()) @ EscrowError::PaymentMintMismatch,
+    )]
+    pub payment_token_mint: UncheckedAccount<'info>,
+    
+    /// Buyer's SPL token account (for SPL deposits)
+    /// CHECK: Optional account, validated by the token program when an SPL deposit is withdrawn
+    #[account(mut)]
+    pub buyer_token_account: UncheckedAccount<'info>,
+    
+    /// Deposit's SPL token account, owned by the deposit PDA (for SPL deposits)
+    /// CHECK: Optional account, validated when an SPL deposit is withdrawn
+    #[account(mut)]
+    pub deposit_token_account: UncheckedAccount<'info>,
+    
+    /// Token program (SPL Token or Token-2022, for SPL deposits)
+    /// CHECK: Optional account, validated when an SPL deposit is withdrawn
+    pub token_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Withdraw lamports from the refund insurance pool to the admin (admin only)
+/// The pool's rent reserve always stays behind
+pub fn withdraw_insurance_pool(ctx: Context<WithdrawInsurancePool>, amount: u64) -> Result<()> {
+    let insurance_pool = &ctx.accounts.insurance_pool;
+    
+    require!(
+        amount > 0 && amount <= insurance_pool.balance(&insurance_pool.to_account_info())?,
+        EscrowError::InsufficientFunds
+    );
+    
+    // The pool is program-owned, so lamports move directly
+    ctx.accounts.insurance_pool.sub_lamports(amount)?;
+    ctx.accounts.admin.add_lamports(amount)?;
+    
+    msg!("Withdrew {} lamports from the insurance pool", amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawInsurancePool<'info> {
+    /// The platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Platform config PDA
+    #[account(
+        seeds = [PlatformConfig::SEED_PREFIX],
+        bump = platform_config.bump,
+        has_one = admin @ EscrowError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Insurance pool PDA
+    #[account(
+        mut,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Withdraw an unaccepted offer, returning the escrowed lamports and rent to the buyer
+pub fn withdraw_offer(ctx: Context<WithdrawOffer>) -> Result<()> {
+    msg!("Offer withdrawn by: {}, amount: {}", ctx.accounts.buyer.key(), ctx.accounts.offer.amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawOffer<'info> {
+    /// The buyer who made the offer
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Offer PDA
+    #[account(
+        mut,
+        seeds = [
+            Offer::SEED_PREFIX,
+            buyer.key().as_ref(),
+            offer.content_id.as_ref(),
+            offer.seed.to_le_bytes().as_ref(),
+        ],
+        bump = offer.bump,
+        has_one = buyer @ EscrowError::InvalidBuyer,
+        close = buyer,
+    )]
+    pub offer: Account<'info, Offer>,
+}
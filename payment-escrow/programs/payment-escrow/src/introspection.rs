@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::errors::EscrowError;
+
+/// Compute budget program, whose instructions only set the transaction's limits and fees
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// Ed25519 signature verification precompile
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Check that every instruction in the transaction belongs to this program, the compute
+/// budget program or the associated token program, so a strict-mode purchase can't be
+/// sandwiched between instructions that move its price within the same transaction
+pub fn check_no_foreign_instructions(instructions_sysvar: &AccountInfo) -> Result<()> {
+    let mut index = 0;
+    
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        let program_id = instruction.program_id;
+        if program_id != crate::ID
+            && program_id != COMPUTE_BUDGET_PROGRAM_ID
+            && program_id != anchor_spl::associated_token::ID
+        {
+            msg!("Foreign instruction {} from program {}", index, program_id);
+            
+            return err!(EscrowError::ForeignInstruction);
+        }
+        
+        index += 1;
+    }
+    
+    Ok(())
+}
+
+/// Offset of the first signature's offsets in an Ed25519 program instruction
+const ED25519_OFFSETS_START: usize = 2;
+
+/// Size of one signature's offsets in an Ed25519 program instruction
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+/// Check that the instruction right before this one is an Ed25519 program verification
+/// of `signer`'s signature over exactly `message`
+/// The precompile fails the transaction on a bad signature, so only its inputs are checked:
+/// one signature, with the key, signature and message all in its own instruction data
+pub fn check_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, EscrowError::InvalidVoucherSignature);
+    
+    let instruction = load_instruction_at_checked(usize::from(current_index - 1), instructions_sysvar)?;
+    require_keys_eq!(instruction.program_id, ED25519_PROGRAM_ID, EscrowError::InvalidVoucherSignature);
+    
+    let data = &instruction.data;
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+        EscrowError::InvalidVoucherSignature
+    );
+    
+    let read_u16 = |index: usize| {
+        let start = ED25519_OFFSETS_START + 2 * index;
+        usize::from(u16::from_le_bytes([data[start], data[start + 1]]))
+    };
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2);
+    let public_key_instruction_index = read_u16(3);
+    let message_offset = read_u16(4);
+    let message_size = read_u16(5);
+    let message_instruction_index = read_u16(6);
+    
+    // u16::MAX points each input at the Ed25519 instruction's own data
+    let this_instruction = usize::from(u16::MAX);
+    require!(
+        signature_instruction_index == this_instruction
+            && public_key_instruction_index == this_instruction
+            && message_instruction_index == this_instruction,
+        EscrowError::InvalidVoucherSignature
+    );
+    
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(EscrowError::InvalidVoucherSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(EscrowError::InvalidVoucherSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        EscrowError::InvalidVoucherSignature
+    );
+    
+    Ok(())
+}
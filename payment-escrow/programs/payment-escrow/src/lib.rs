@@ -6,6 +6,14 @@ declare_id!("2T3AsDRbQdpLWaxEU5vbFXuzRHQnq7JT3wCQCmvdiKmJ");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod content;
+pub mod payment;
+pub mod receipt;
+pub mod wormhole;
+pub mod compression;
+pub mod introspection;
+pub mod randomness;
+pub mod events;
 
 use instructions::*;
 
@@ -20,12 +28,14 @@ pub mod payment_escrow {
     /// * `price` - Price in lamports (SOL) or token amount (SPL)
     /// * `payment_token_mint` - Optional SPL token mint (None for SOL payments)
     /// * `seed` - Trade nonce for uniqueness (allows multiple purchases)
+    /// * `memo` - Optional order reference or invoice id (max 64 bytes)
     pub fn initialize_escrow(
         ctx: Context<InitializeEscrow>,
         content_id: [u8; 32],
         price: u64,
         payment_token_mint: Option<Pubkey>,
         seed: u64,
+        memo: Option<String>,
     ) -> Result<()> {
         instructions::initialize_escrow::initialize_escrow(
             ctx,
@@ -33,6 +43,7 @@ pub mod payment_escrow {
             price,
             payment_token_mint,
             seed,
+            memo,
         )
     }
 
@@ -40,15 +51,1049 @@ pub mod payment_escrow {
     /// 
     /// # Arguments
     /// * `payment_amount` - Amount to pay (must match escrow price)
+    /// * `license_terms_hash` - Hash of the accepted license terms (must match the listing)
+    /// * `allowlist_proof` - Merkle proof for the active sale phase's allowlist (empty if public)
+    /// * `receipt_uri` - Metadata URI for an optional soulbound receipt NFT (None = no receipt)
+    /// * `direct_split` - Pay each share straight to its recipient instead of through the vaults
+    /// * `referrer` - Wallet the purchase is attributed to (None = not referred)
     pub fn buy_and_mint<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyAndMint<'info>>,
         payment_amount: u64,
+        license_terms_hash: [u8; 32],
+        allowlist_proof: Vec<[u8; 32]>,
+        receipt_uri: Option<String>,
+        direct_split: bool,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::buy_and_mint::buy_and_mint(ctx, payment_amount)
+        instructions::buy_and_mint::buy_and_mint(
+            ctx,
+            payment_amount,
+            license_terms_hash,
+            allowlist_proof,
+            receipt_uri,
+            direct_split,
+            referrer,
+        )
     }
 
     /// Cancel an escrow and refund the buyer
     pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
         instructions::cancel_escrow::cancel_escrow(ctx)
     }
+
+    /// Initialize the global platform configuration (program upgrade authority only)
+    /// 
+    /// # Arguments
+    /// * `require_creator_cosign` - Require creator signature (or listing) on escrow initialization
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        require_creator_cosign: bool,
+    ) -> Result<()> {
+        instructions::initialize_platform_config::initialize_platform_config(
+            ctx,
+            require_creator_cosign,
+        )
+    }
+
+    /// Update the global platform configuration (admin only)
+    /// 
+    /// # Arguments
+    /// * `require_creator_cosign` - Require creator signature (or listing) on escrow initialization
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        require_creator_cosign: bool,
+    ) -> Result<()> {
+        instructions::update_platform_config::update_platform_config(
+            ctx,
+            require_creator_cosign,
+        )
+    }
+
+    /// Create a creator-signed listing for a content
+    /// 
+    /// # Arguments
+    /// * `content_id` - 32-byte unique identifier for the content
+    /// * `price` - Price in lamports (SOL) or token amount (SPL), 0 for free claim-only content
+    /// * `payment_token_mint` - Optional SPL token mint (None for SOL payments)
+    /// * `price_change_policy` - How outstanding escrows are treated after a price change
+    /// * `license_terms_hash` - Hash of the license terms buyers agree to
+    pub fn create_listing(
+        ctx: Context<CreateListing>,
+        content_id: [u8; 32],
+        price: u64,
+        payment_token_mint: Option<Pubkey>,
+        price_change_policy: state::PriceChangePolicy,
+        license_terms_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_listing::create_listing(
+            ctx,
+            content_id,
+            price,
+            payment_token_mint,
+            price_change_policy,
+            license_terms_hash,
+        )
+    }
+
+    /// Update a listing's price (creator only)
+    /// 
+    /// # Arguments
+    /// * `new_price` - New price in lamports (SOL) or token amount (SPL), 0 for free
+    pub fn update_listing_price(
+        ctx: Context<UpdateListingPrice>,
+        new_price: u64,
+    ) -> Result<()> {
+        instructions::update_listing_price::update_listing_price(ctx, new_price)
+    }
+
+    /// Initialize a crowdfunding campaign for a content
+    /// 
+    /// # Arguments
+    /// * `content_id` - 32-byte unique identifier for the content
+    /// * `goal` - Funding goal in lamports
+    /// * `deadline` - Unix timestamp after which contributions close
+    /// * `seed` - Seed for PDA derivation (allows multiple campaigns per content)
+    pub fn initialize_campaign(
+        ctx: Context<InitializeCampaign>,
+        content_id: [u8; 32],
+        goal: u64,
+        deadline: i64,
+        seed: u64,
+    ) -> Result<()> {
+        instructions::initialize_campaign::initialize_campaign(
+            ctx,
+            content_id,
+            goal,
+            deadline,
+            seed,
+        )
+    }
+
+    /// Contribute SOL to an active campaign
+    /// 
+    /// # Arguments
+    /// * `amount` - Contribution in lamports
+    pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+        instructions::contribute::contribute(ctx, amount)
+    }
+
+    /// Finalize a campaign - distributes funds on success, opens refunds on failure
+    pub fn finalize_campaign<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeCampaign<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_campaign::finalize_campaign(ctx)
+    }
+
+    /// Mint an access token to a contributor of a successful campaign
+    pub fn claim_campaign_access(ctx: Context<ClaimCampaignAccess>) -> Result<()> {
+        instructions::claim_campaign_access::claim_campaign_access(ctx)
+    }
+
+    /// Refund a contributor of a failed campaign
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::claim_refund::claim_refund(ctx)
+    }
+
+    /// Configure rental access for a listing (creator only)
+    /// 
+    /// # Arguments
+    /// * `rental_price` - Rental price in lamports (SOL) or token amount (SPL)
+    /// * `rental_duration` - Rental length in seconds (0 disables rentals)
+    pub fn set_rental_terms(
+        ctx: Context<SetRentalTerms>,
+        rental_price: u64,
+        rental_duration: i64,
+    ) -> Result<()> {
+        instructions::set_rental_terms::set_rental_terms(ctx, rental_price, rental_duration)
+    }
+
+    /// Pay the rental price and mint a time-boxed access token atomically
+    pub fn rent_access<'info>(
+        ctx: Context<'_, '_, '_, 'info, RentAccess<'info>>,
+    ) -> Result<()> {
+        instructions::rent_access::rent_access(ctx)
+    }
+
+    /// Configure the premium tier price for a listing (creator only)
+    /// 
+    /// # Arguments
+    /// * `premium_price` - Premium access price (0 disables the premium tier)
+    pub fn set_premium_price(ctx: Context<SetPremiumPrice>, premium_price: u64) -> Result<()> {
+        instructions::set_premium_price::set_premium_price(ctx, premium_price)
+    }
+
+    /// Upgrade basic-tier access to premium by paying the price difference
+    pub fn upgrade_access<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpgradeAccess<'info>>,
+    ) -> Result<()> {
+        instructions::upgrade_access::upgrade_access(ctx)
+    }
+
+    /// Quote the total cost, rent and per-recipient payouts of purchasing a listing
+    /// Intended to be simulated - the quote is returned via return data
+    pub fn quote(ctx: Context<Quote>) -> Result<PurchaseQuote> {
+        instructions::quote::quote(ctx)
+    }
+
+    /// Commit the URI of the canonical metadata blob whose sha256 is the content id
+    /// 
+    /// # Arguments
+    /// * `uri` - Location of the metadata blob (max 200 bytes)
+    /// * `preimage` - Optional metadata blob, checked against the content id on-chain
+    pub fn commit_content_metadata(
+        ctx: Context<CommitContentMetadata>,
+        uri: String,
+        preimage: Option<Vec<u8>>,
+    ) -> Result<()> {
+        instructions::commit_content_metadata::commit_content_metadata(ctx, uri, preimage)
+    }
+
+    /// Move an escrow created before vault consolidation onto its buyer vault,
+    /// returning any balance stranded in the legacy per-escrow vault and rewriting
+    /// the escrow in the current layout
+    pub fn migrate_legacy_vault(ctx: Context<MigrateLegacyVault>) -> Result<()> {
+        instructions::migrate_legacy_vault::migrate_legacy_vault(ctx)
+    }
+
+    /// Create an escrow, pay, mint access and distribute in a single instruction
+    /// The two-step initialize_escrow / buy_and_mint flow remains for async purchases
+    /// 
+    /// # Arguments
+    /// * `content_id` - 32-byte unique identifier for the content
+    /// * `price` - Price in lamports (SOL) or token amount (SPL)
+    /// * `payment_token_mint` - Optional SPL token mint (None for SOL payments)
+    /// * `seed` - Trade nonce for uniqueness (allows multiple purchases)
+    /// * `license_terms_hash` - Hash of the accepted license terms (must match the listing)
+    /// * `allowlist_proof` - Merkle proof for the active sale phase's allowlist (empty if public)
+    pub fn initialize_and_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeAndBuy<'info>>,
+        content_id: [u8; 32],
+        price: u64,
+        payment_token_mint: Option<Pubkey>,
+        seed: u64,
+        license_terms_hash: [u8; 32],
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::initialize_and_buy::initialize_and_buy(
+            ctx,
+            content_id,
+            price,
+            payment_token_mint,
+            seed,
+            license_terms_hash,
+            allowlist_proof,
+        )
+    }
+
+    /// Set the license terms hash buyers must accept for a listing (creator only)
+    /// 
+    /// # Arguments
+    /// * `license_terms_hash` - Hash of the license terms document
+    pub fn set_license_terms(
+        ctx: Context<SetLicenseTerms>,
+        license_terms_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_license_terms::set_license_terms(ctx, license_terms_hash)
+    }
+
+    /// Whitelist a Token-2022 payment mint whose extensions would otherwise be rejected (admin only)
+    /// 
+    /// # Arguments
+    /// * `mint` - Payment token mint
+    /// * `whitelisted` - Whether the mint is accepted despite its extensions
+    pub fn set_extension_mint_whitelisted(
+        ctx: Context<SetExtensionMintWhitelisted>,
+        mint: Pubkey,
+        whitelisted: bool,
+    ) -> Result<()> {
+        instructions::set_extension_mint_whitelisted::set_extension_mint_whitelisted(ctx, mint, whitelisted)
+    }
+
+    /// Schedule the window during which a listing can be bought (creator only)
+    /// 
+    /// # Arguments
+    /// * `sale_start_ts` - Timestamp when the sale opens (0 = open immediately)
+    /// * `sale_end_ts` - Timestamp when the sale closes (0 = never closes)
+    pub fn schedule_sale(
+        ctx: Context<ScheduleSale>,
+        sale_start_ts: i64,
+        sale_end_ts: i64,
+    ) -> Result<()> {
+        instructions::schedule_sale::schedule_sale(ctx, sale_start_ts, sale_end_ts)
+    }
+
+    /// Replace a listing's sale phases (creator only)
+    /// 
+    /// # Arguments
+    /// * `phases` - Phases in chronological order with non-overlapping windows (empty to sell at the listing price)
+    pub fn set_sale_phases(ctx: Context<SetSalePhases>, phases: Vec<state::SalePhase>) -> Result<()> {
+        instructions::set_sale_phases::set_sale_phases(ctx, phases)
+    }
+
+    /// Require buyers of a listing to hold another access token (creator only)
+    /// 
+    /// # Arguments
+    /// * `gate_mint` - Access token mint buyers must hold (None removes the gate)
+    pub fn set_gate_mint(ctx: Context<SetGateMint>, gate_mint: Option<Pubkey>) -> Result<()> {
+        instructions::set_gate_mint::set_gate_mint(ctx, gate_mint)
+    }
+
+    /// Create the non-transferable creator badge mint (admin only)
+    pub fn initialize_badge_mint(ctx: Context<InitializeBadgeMint>) -> Result<()> {
+        instructions::initialize_badge_mint::initialize_badge_mint(ctx)
+    }
+
+    /// Verify a creator by minting a non-transferable creator badge (admin only)
+    pub fn issue_badge(ctx: Context<IssueBadge>) -> Result<()> {
+        instructions::issue_badge::issue_badge(ctx)
+    }
+
+    /// Revoke a creator's verification and burn their badge (admin only)
+    pub fn revoke_badge(ctx: Context<RevokeBadge>) -> Result<()> {
+        instructions::revoke_badge::revoke_badge(ctx)
+    }
+
+    /// Only sell a listing while its creator holds a creator badge (creator only)
+    /// 
+    /// # Arguments
+    /// * `require_creator_badge` - Whether sales require a verified creator
+    pub fn set_require_creator_badge(
+        ctx: Context<SetRequireCreatorBadge>,
+        require_creator_badge: bool,
+    ) -> Result<()> {
+        instructions::set_require_creator_badge::set_require_creator_badge(ctx, require_creator_badge)
+    }
+
+    /// Create the upgrade timelock PDA (admin only)
+    /// The program's BPF upgrade authority should then be handed to this PDA
+    /// 
+    /// # Arguments
+    /// * `delay` - Seconds between proposing and executing an upgrade (min 48 hours)
+    pub fn initialize_upgrade_governance(
+        ctx: Context<InitializeUpgradeGovernance>,
+        delay: i64,
+    ) -> Result<()> {
+        instructions::initialize_upgrade_governance::initialize_upgrade_governance(ctx, delay)
+    }
+
+    /// Announce a program upgrade, executable once the timelock delay has elapsed (admin only)
+    /// 
+    /// # Arguments
+    /// * `buffer` - Buffer account holding the new program binary
+    /// * `buffer_hash` - SHA-256 hash of the program binary in the buffer
+    pub fn propose_upgrade(
+        ctx: Context<ProposeUpgrade>,
+        buffer: Pubkey,
+        buffer_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::propose_upgrade::propose_upgrade(ctx, buffer, buffer_hash)
+    }
+
+    /// Withdraw the pending upgrade proposal (admin only)
+    pub fn cancel_upgrade(ctx: Context<CancelUpgrade>) -> Result<()> {
+        instructions::cancel_upgrade::cancel_upgrade(ctx)
+    }
+
+    /// Upgrade the program from the proposed buffer after the timelock (admin only)
+    pub fn execute_upgrade(ctx: Context<ExecuteUpgrade>) -> Result<()> {
+        instructions::execute_upgrade::execute_upgrade(ctx)
+    }
+
+    /// Throttle purchases of a listing to one per wallet per cooldown (creator only)
+    /// 
+    /// # Arguments
+    /// * `purchase_cooldown` - Minimum seconds between purchases by the same wallet (0 disables)
+    pub fn set_purchase_cooldown(
+        ctx: Context<SetPurchaseCooldown>,
+        purchase_cooldown: i64,
+    ) -> Result<()> {
+        instructions::set_purchase_cooldown::set_purchase_cooldown(ctx, purchase_cooldown)
+    }
+
+    /// Halt new escrows and purchases of a content (admin only)
+    /// 
+    /// # Arguments
+    /// * `content_id` - Content to freeze
+    /// * `reason` - Public reason for the takedown (max 128 bytes)
+    pub fn freeze_content(
+        ctx: Context<FreezeContent>,
+        content_id: [u8; 32],
+        reason: String,
+    ) -> Result<()> {
+        instructions::freeze_content::freeze_content(ctx, content_id, reason)
+    }
+
+    /// Resume sales of a frozen content (admin only)
+    pub fn unfreeze_content(ctx: Context<UnfreezeContent>) -> Result<()> {
+        instructions::unfreeze_content::unfreeze_content(ctx)
+    }
+
+    /// Deny a wallet from buying on the platform (admin only)
+    pub fn add_to_denylist(ctx: Context<AddToDenylist>) -> Result<()> {
+        instructions::add_to_denylist::add_to_denylist(ctx)
+    }
+
+    /// Remove a wallet from the platform denylist (admin only)
+    pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>) -> Result<()> {
+        instructions::remove_from_denylist::remove_from_denylist(ctx)
+    }
+
+    /// Opt a listing out of the platform buyer denylist (creator only)
+    /// 
+    /// # Arguments
+    /// * `ignore_denylist` - Whether denylisted wallets may buy this listing
+    pub fn set_ignore_denylist(ctx: Context<SetIgnoreDenylist>, ignore_denylist: bool) -> Result<()> {
+        instructions::set_ignore_denylist::set_ignore_denylist(ctx, ignore_denylist)
+    }
+
+    /// Set the platform price floor for SOL or an SPL payment mint (admin only)
+    /// 
+    /// # Arguments
+    /// * `payment_token_mint` - Payment mint the floor applies to (None = SOL)
+    /// * `min_price` - Minimum price in lamports or token base units (0 removes the floor)
+    pub fn set_min_price(
+        ctx: Context<SetMinPrice>,
+        payment_token_mint: Option<Pubkey>,
+        min_price: u64,
+    ) -> Result<()> {
+        instructions::set_min_price::set_min_price(ctx, payment_token_mint, min_price)
+    }
+
+    /// Register a marketplace platform with its own treasury and fee
+    /// 
+    /// # Arguments
+    /// * `fee_bps` - Platform fee in basis points (max 1000)
+    pub fn register_platform(ctx: Context<RegisterPlatform>, fee_bps: u16) -> Result<()> {
+        instructions::register_platform::register_platform(ctx, fee_bps)
+    }
+
+    /// Change a platform's treasury and fee (platform authority only)
+    /// 
+    /// # Arguments
+    /// * `fee_bps` - New platform fee in basis points (max 1000)
+    pub fn update_platform(ctx: Context<UpdatePlatform>, fee_bps: u16) -> Result<()> {
+        instructions::update_platform::update_platform(ctx, fee_bps)
+    }
+
+    /// Sell a listing through a marketplace platform, or none (creator only)
+    pub fn set_listing_platform(ctx: Context<SetListingPlatform>) -> Result<()> {
+        instructions::set_listing_platform::set_listing_platform(ctx)
+    }
+
+    /// Set the loyalty points buyers earn on SOL purchases (admin only)
+    /// 
+    /// # Arguments
+    /// * `loyalty_rate_bps` - Points per lamport spent, in basis points (0 = no points)
+    pub fn set_loyalty_rate(ctx: Context<SetLoyaltyRate>, loyalty_rate_bps: u16) -> Result<()> {
+        instructions::set_loyalty_rate::set_loyalty_rate(ctx, loyalty_rate_bps)
+    }
+
+    /// Convert loyalty points into a discount on the next SOL buy_and_mint
+    /// 
+    /// # Arguments
+    /// * `points` - Points to redeem (one point = one lamport of discount)
+    pub fn redeem_points(ctx: Context<RedeemPoints>, points: u64) -> Result<()> {
+        instructions::redeem_points::redeem_points(ctx, points)
+    }
+
+    /// Stake SOL into the creator's bond
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to stake
+    pub fn stake_bond(ctx: Context<StakeBond>, amount: u64) -> Result<()> {
+        instructions::stake_bond::stake_bond(ctx, amount)
+    }
+
+    /// Request a withdrawal from the creator's bond, starting the cooldown
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to withdraw once the cooldown has passed
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        instructions::request_unstake::request_unstake(ctx, amount)
+    }
+
+    /// Withdraw the requested amount from the creator's bond after the cooldown
+    pub fn unstake_bond(ctx: Context<UnstakeBond>) -> Result<()> {
+        instructions::unstake_bond::unstake_bond(ctx)
+    }
+
+    /// Record an upheld dispute of a completed purchase against its creator's bond (admin only)
+    pub fn record_dispute(ctx: Context<RecordDispute>) -> Result<()> {
+        instructions::record_dispute::record_dispute(ctx)
+    }
+
+    /// Slash a creator's bond to compensate the buyer of a recorded dispute (admin only)
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports paid from the bond to the buyer, up to what they paid
+    pub fn slash_bond(ctx: Context<SlashBond>, amount: u64) -> Result<()> {
+        instructions::slash_bond::slash_bond(ctx, amount)
+    }
+
+    /// Set the bond creators must stake to create a listing (admin only)
+    /// 
+    /// # Arguments
+    /// * `min_creator_bond` - Minimum lamports staked (0 = no bond required)
+    pub fn set_min_creator_bond(ctx: Context<SetMinCreatorBond>, min_creator_bond: u64) -> Result<()> {
+        instructions::set_min_creator_bond::set_min_creator_bond(ctx, min_creator_bond)
+    }
+
+    /// Return lamports or tokens sent to a buyer vault outside the escrow flow to the buyer
+    /// Callable by the buyer or the platform admin
+    pub fn sweep_surplus(ctx: Context<SweepSurplus>) -> Result<()> {
+        instructions::sweep_surplus::sweep_surplus(ctx)
+    }
+
+    /// Register the emitter trusted to attest payments on a foreign chain (admin only)
+    /// 
+    /// # Arguments
+    /// * `chain` - Wormhole chain id
+    /// * `address` - Emitter address on that chain (left-padded to 32 bytes)
+    pub fn register_foreign_emitter(
+        ctx: Context<RegisterForeignEmitter>,
+        chain: u16,
+        address: [u8; 32],
+    ) -> Result<()> {
+        instructions::register_foreign_emitter::register_foreign_emitter(ctx, chain, address)
+    }
+
+    /// Grant access for a payment attested by a Wormhole VAA from another chain
+    /// 
+    /// # Arguments
+    /// * `emitter_chain` - Wormhole chain id of the VAA emitter
+    /// * `sequence` - VAA sequence number
+    pub fn redeem_crosschain_payment(
+        ctx: Context<RedeemCrosschainPayment>,
+        emitter_chain: u16,
+        sequence: u64,
+    ) -> Result<()> {
+        instructions::redeem_crosschain_payment::redeem_crosschain_payment(ctx, emitter_chain, sequence)
+    }
+
+    /// Set the authority allowed to settle fiat purchases (admin only)
+    /// 
+    /// # Arguments
+    /// * `settlement_authority` - Settlement authority (default pubkey disables fiat settlement)
+    pub fn set_settlement_authority(
+        ctx: Context<SetSettlementAuthority>,
+        settlement_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_settlement_authority::set_settlement_authority(ctx, settlement_authority)
+    }
+
+    /// Grant access for a purchase paid off-chain (settlement authority only)
+    /// 
+    /// # Arguments
+    /// * `external_payment_id` - External payment reference (e.g. hash of the processor's id)
+    /// * `seed` - Escrow seed for the purchase record
+    pub fn settle_fiat_purchase(
+        ctx: Context<SettleFiatPurchase>,
+        external_payment_id: [u8; 32],
+        seed: u64,
+    ) -> Result<()> {
+        instructions::settle_fiat_purchase::settle_fiat_purchase(ctx, external_payment_id, seed)
+    }
+
+    /// Create a listing's purchase attempt log
+    pub fn initialize_attempt_log(ctx: Context<InitializeAttemptLog>) -> Result<()> {
+        instructions::initialize_attempt_log::initialize_attempt_log(ctx)
+    }
+
+    /// Set up a concurrent Merkle tree for compressed purchase receipts (admin only)
+    /// 
+    /// # Arguments
+    /// * `max_depth` - Tree depth (capacity is 2^max_depth receipts)
+    /// * `max_buffer_size` - Concurrent changelog buffer size
+    pub fn initialize_receipt_tree(
+        ctx: Context<InitializeReceiptTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::initialize_receipt_tree::initialize_receipt_tree(ctx, max_depth, max_buffer_size)
+    }
+
+    /// Compress a completed purchase into a receipt tree and close its escrow
+    pub fn compress_receipt(ctx: Context<CompressReceipt>) -> Result<()> {
+        instructions::compress_receipt::compress_receipt(ctx)
+    }
+
+    /// Set the royalty paid to the content's split on every resale (creator only)
+    /// 
+    /// # Arguments
+    /// * `resale_royalty_bps` - Royalty in basis points of the resale price (at most 25%)
+    pub fn set_resale_royalty(ctx: Context<SetResaleRoyalty>, resale_royalty_bps: u16) -> Result<()> {
+        instructions::set_resale_royalty::set_resale_royalty(ctx, resale_royalty_bps)
+    }
+
+    /// Escrow access tokens for resale at a fixed SOL price
+    /// 
+    /// # Arguments
+    /// * `amount` - Number of access tokens to sell
+    /// * `price` - Asking price in lamports
+    pub fn list_for_resale(ctx: Context<ListForResale>, amount: u64, price: u64) -> Result<()> {
+        instructions::list_for_resale::list_for_resale(ctx, amount, price)
+    }
+
+    /// Buy escrowed access tokens, paying the royalty to the content's split
+    pub fn buy_resale<'info>(ctx: Context<'_, '_, '_, 'info, BuyResale<'info>>) -> Result<()> {
+        instructions::buy_resale::buy_resale(ctx)
+    }
+
+    /// Withdraw a resale listing and reclaim the escrowed tokens (seller only)
+    pub fn cancel_resale(ctx: Context<CancelResale>) -> Result<()> {
+        instructions::cancel_resale::cancel_resale(ctx)
+    }
+
+    /// Bid on a content by escrowing lamports at a proposed price
+    /// 
+    /// # Arguments
+    /// * `creator` - Creator the offer is made to
+    /// * `content_id` - Content identifier (32 bytes)
+    /// * `amount` - Offered amount in lamports
+    /// * `seed` - Seed distinguishing the buyer's offers for the content
+    pub fn make_offer(
+        ctx: Context<MakeOffer>,
+        creator: Pubkey,
+        content_id: [u8; 32],
+        amount: u64,
+        seed: u64,
+    ) -> Result<()> {
+        instructions::make_offer::make_offer(ctx, creator, content_id, amount, seed)
+    }
+
+    /// Accept an offer, distributing it and minting access to the buyer (creator only)
+    pub fn accept_offer<'info>(ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>) -> Result<()> {
+        instructions::accept_offer::accept_offer(ctx)
+    }
+
+    /// Withdraw an unaccepted offer (buyer only)
+    pub fn withdraw_offer(ctx: Context<WithdrawOffer>) -> Result<()> {
+        instructions::withdraw_offer::withdraw_offer(ctx)
+    }
+
+    /// Fund a commissioned content paid out per milestone
+    /// 
+    /// # Arguments
+    /// * `creator` - Creator delivering the commission
+    /// * `content_id` - Content identifier (32 bytes)
+    /// * `seed` - Seed distinguishing the buyer's commissions for the content
+    /// * `milestone_amounts` - Lamports released at each milestone, in delivery order
+    pub fn initialize_commission(
+        ctx: Context<InitializeCommission>,
+        creator: Pubkey,
+        content_id: [u8; 32],
+        seed: u64,
+        milestone_amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::initialize_commission::initialize_commission(
+            ctx,
+            creator,
+            content_id,
+            seed,
+            milestone_amounts,
+        )
+    }
+
+    /// Submit the current milestone for review (creator only)
+    pub fn submit_milestone(ctx: Context<SubmitMilestone>) -> Result<()> {
+        instructions::submit_milestone::submit_milestone(ctx)
+    }
+
+    /// Approve the submitted milestone (buyer only)
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+        instructions::approve_milestone::approve_milestone(ctx)
+    }
+
+    /// Dispute the submitted milestone, sending it back to the creator (buyer only)
+    pub fn dispute_milestone(ctx: Context<DisputeMilestone>) -> Result<()> {
+        instructions::dispute_milestone::dispute_milestone(ctx)
+    }
+
+    /// Release the approved milestone's funds, minting access at the final one (creator only)
+    pub fn release_milestone<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseMilestone<'info>>,
+    ) -> Result<()> {
+        instructions::release_milestone::release_milestone(ctx)
+    }
+
+    /// Refund a commission's unreleased milestones to the buyer, once the current milestone
+    /// is overdue or the admin co-signs to uphold the buyer's dispute (buyer only)
+    pub fn refund_commission(ctx: Context<RefundCommission>) -> Result<()> {
+        instructions::refund_commission::refund_commission(ctx)
+    }
+
+    /// Configure the platform-wide sale window (admin only)
+    /// 
+    /// # Arguments
+    /// * `discount_bps` - Discount on opted-in listings, in basis points
+    /// * `start_ts` - Timestamp when the sale starts
+    /// * `end_ts` - Timestamp when the sale ends
+    /// * `subsidized` - Pay the discount to creators from the sale's subsidy budget
+    pub fn configure_platform_sale(
+        ctx: Context<ConfigurePlatformSale>,
+        discount_bps: u16,
+        start_ts: i64,
+        end_ts: i64,
+        subsidized: bool,
+    ) -> Result<()> {
+        instructions::configure_platform_sale::configure_platform_sale(
+            ctx,
+            discount_bps,
+            start_ts,
+            end_ts,
+            subsidized,
+        )
+    }
+
+    /// Add lamports to the platform sale's subsidy budget (admin only)
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to add
+    pub fn fund_platform_sale(ctx: Context<FundPlatformSale>, amount: u64) -> Result<()> {
+        instructions::fund_platform_sale::fund_platform_sale(ctx, amount)
+    }
+
+    /// Opt a listing in or out of platform-wide sales (creator only)
+    /// 
+    /// # Arguments
+    /// * `opt_in` - Whether the listing is discounted during platform sales
+    pub fn set_platform_sale_opt_in(ctx: Context<SetPlatformSaleOptIn>, opt_in: bool) -> Result<()> {
+        instructions::set_platform_sale_opt_in::set_platform_sale_opt_in(ctx, opt_in)
+    }
+
+    /// Configure platform fee rebates paid to opted-in creators (admin only)
+    /// 
+    /// # Arguments
+    /// * `rebate_bps` - Share of the platform fee rebated, in basis points
+    pub fn set_rebate_config(ctx: Context<SetRebateConfig>, rebate_bps: u16) -> Result<()> {
+        instructions::set_rebate_config::set_rebate_config(ctx, rebate_bps)
+    }
+
+    /// Opt in to platform fee rebates on the creator's sales
+    pub fn opt_in_rebates(ctx: Context<OptInRebates>) -> Result<()> {
+        instructions::opt_in_rebates::opt_in_rebates(ctx)
+    }
+
+    /// Opt out of platform fee rebates
+    pub fn opt_out_rebates(ctx: Context<OptOutRebates>) -> Result<()> {
+        instructions::opt_out_rebates::opt_out_rebates(ctx)
+    }
+
+    /// Reject purchases of a listing bundled with other programs' instructions (creator only)
+    /// 
+    /// # Arguments
+    /// * `strict_mode` - Whether buy_and_mint and initialize_and_buy check the transaction's other instructions
+    pub fn set_strict_mode(ctx: Context<SetStrictMode>, strict_mode: bool) -> Result<()> {
+        instructions::set_strict_mode::set_strict_mode(ctx, strict_mode)
+    }
+
+    /// Open a priority access queue for a listing's drop (creator only)
+    /// 
+    /// # Arguments
+    /// * `capacity` - Number of winners drawn
+    /// * `register_end_ts` - Timestamp when registration closes
+    pub fn initialize_queue(
+        ctx: Context<InitializeQueue>,
+        capacity: u32,
+        register_end_ts: i64,
+    ) -> Result<()> {
+        instructions::initialize_queue::initialize_queue(ctx, capacity, register_end_ts)
+    }
+
+    /// Register an intent in a priority access queue, escrowing the queue price
+    pub fn register_intent(ctx: Context<RegisterIntent>) -> Result<()> {
+        instructions::register_intent::register_intent(ctx)
+    }
+
+    /// Close queue registration and bind the draw to a Switchboard randomness commitment (creator only)
+    pub fn commit_draw(ctx: Context<CommitDraw>) -> Result<()> {
+        instructions::commit_draw::commit_draw(ctx)
+    }
+
+    /// Draw a queue's winners from the revealed randomness
+    pub fn draw_winners(ctx: Context<DrawWinners>) -> Result<()> {
+        instructions::draw_winners::draw_winners(ctx)
+    }
+
+    /// Claim a queue result: winners are minted access, losers are refunded
+    /// Every entrant is refunded when the queue isn't drawn within its deadline
+    pub fn claim_result<'info>(ctx: Context<'_, '_, '_, 'info, ClaimResult<'info>>) -> Result<()> {
+        instructions::claim_result::claim_result(ctx)
+    }
+
+    /// Create a mystery box selling one of several contents at a single price
+    /// 
+    /// # Arguments
+    /// * `box_id` - Mystery box identifier (used as the purchase escrow's content id)
+    /// * `price` - Price in lamports
+    /// * `items` - Content identifiers that can be drawn
+    pub fn create_mystery_box(
+        ctx: Context<CreateMysteryBox>,
+        box_id: [u8; 32],
+        price: u64,
+        items: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::create_mystery_box::create_mystery_box(ctx, box_id, price, items)
+    }
+
+    /// Buy a mystery box, committing the purchase to a Switchboard randomness account
+    /// 
+    /// # Arguments
+    /// * `seed` - Unique seed for the purchase escrow
+    pub fn commit_mystery_box(ctx: Context<CommitMysteryBox>, seed: u64) -> Result<()> {
+        instructions::commit_mystery_box::commit_mystery_box(ctx, seed)
+    }
+
+    /// Reveal the content a mystery box purchase contains
+    pub fn reveal_mystery_box(ctx: Context<RevealMysteryBox>) -> Result<()> {
+        instructions::reveal_mystery_box::reveal_mystery_box(ctx)
+    }
+
+    /// Distribute a revealed mystery box's price and mint the revealed content's access
+    pub fn fulfill_mystery_box<'info>(
+        ctx: Context<'_, '_, '_, 'info, FulfillMysteryBox<'info>>,
+    ) -> Result<()> {
+        instructions::fulfill_mystery_box::fulfill_mystery_box(ctx)
+    }
+
+    /// Pay for a listing's access now and mint it with a later claim_access
+    pub fn pay_for_access(ctx: Context<PayForAccess>) -> Result<()> {
+        instructions::pay_for_access::pay_for_access(ctx)
+    }
+
+    /// Claim paid access, minting the token to the buyer (buyer only; any payer may submit)
+    pub fn claim_access<'info>(ctx: Context<'_, '_, '_, 'info, ClaimAccess<'info>>) -> Result<()> {
+        instructions::claim_access::claim_access(ctx)
+    }
+
+    /// Refund pending access whose claim window has expired, less the listing's cancellation fee
+    pub fn refund_pending_access<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundPendingAccess<'info>>,
+    ) -> Result<()> {
+        instructions::refund_pending_access::refund_pending_access(ctx)
+    }
+
+    /// Open a group buy splitting a listing's price between several buyers
+    /// 
+    /// # Arguments
+    /// * `deadline` - Unix timestamp after which the group buy fails if not fully funded
+    /// * `seed` - Unique seed for the campaign PDA
+    /// * `access_threshold` - Minimum contribution that earns an access token (0 = everyone)
+    pub fn initialize_group_buy(
+        ctx: Context<InitializeGroupBuy>,
+        deadline: i64,
+        seed: u64,
+        access_threshold: u64,
+    ) -> Result<()> {
+        instructions::initialize_group_buy::initialize_group_buy(ctx, deadline, seed, access_threshold)
+    }
+
+    /// Buy a listing's access for DAO members, paid by a treasury PDA signing through CPI
+    /// 
+    /// # Arguments
+    /// * `member_count` - Number of trailing (member wallet, token account) pairs in the remaining accounts
+    pub fn dao_purchase<'info>(
+        ctx: Context<'_, '_, '_, 'info, DaoPurchase<'info>>,
+        member_count: u8,
+    ) -> Result<()> {
+        instructions::dao_purchase::dao_purchase(ctx, member_count)
+    }
+
+    /// Rate a listing as a verified purchaser (once per buyer)
+    /// 
+    /// # Arguments
+    /// * `rating` - Rating from 1 to 5
+    pub fn rate_content(ctx: Context<RateContent>, rating: u8) -> Result<()> {
+        instructions::rate_content::rate_content(ctx, rating)
+    }
+
+    /// Configure the refund insurance pool (admin only)
+    /// 
+    /// # Arguments
+    /// * `contribution_bps` - Share of direct-split platform fees diverted into the pool
+    /// * `max_payout` - Largest payout for a single claim in lamports
+    pub fn configure_insurance_pool(
+        ctx: Context<ConfigureInsurancePool>,
+        contribution_bps: u16,
+        max_payout: u64,
+    ) -> Result<()> {
+        instructions::configure_insurance_pool::configure_insurance_pool(ctx, contribution_bps, max_payout)
+    }
+
+    /// Top up the refund insurance pool (admin only)
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to add
+    pub fn fund_insurance_pool(ctx: Context<FundInsurancePool>, amount: u64) -> Result<()> {
+        instructions::fund_insurance_pool::fund_insurance_pool(ctx, amount)
+    }
+
+    /// Withdraw from the refund insurance pool (admin only)
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to withdraw
+    pub fn withdraw_insurance_pool(ctx: Context<WithdrawInsurancePool>, amount: u64) -> Result<()> {
+        instructions::withdraw_insurance_pool::withdraw_insurance_pool(ctx, amount)
+    }
+
+    /// Refund a buyer from the insurance pool for an unresolved dispute (admin only)
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to refund
+    pub fn pay_insurance_claim(ctx: Context<PayInsuranceClaim>, amount: u64) -> Result<()> {
+        instructions::pay_insurance_claim::pay_insurance_claim(ctx, amount)
+    }
+
+    /// Status byte of each escrow passed in remaining accounts, so wallets can poll
+    /// many pending purchases at once
+    /// Intended to be simulated - the packed statuses are returned via return data
+    pub fn get_statuses(ctx: Context<GetStatuses>) -> Result<Vec<u8>> {
+        instructions::get_statuses::get_statuses(ctx)
+    }
+
+    /// Set how far a purchase payment may differ from the amount due (admin only)
+    /// 
+    /// # Arguments
+    /// * `payment_tolerance_bps` - Tolerance in basis points of the amount due (0 = exact payment)
+    pub fn set_payment_tolerance(ctx: Context<SetPaymentTolerance>, payment_tolerance_bps: u16) -> Result<()> {
+        instructions::set_payment_tolerance::set_payment_tolerance(ctx, payment_tolerance_bps)
+    }
+
+    /// Return the excess of purchases paying more than the amount due instead of failing (admin only)
+    /// 
+    /// # Arguments
+    /// * `enabled` - Whether overpayments are accepted and refunded
+    pub fn set_refund_overpayment(ctx: Context<SetRefundOverpayment>, enabled: bool) -> Result<()> {
+        instructions::set_refund_overpayment::set_refund_overpayment(ctx, enabled)
+    }
+
+    /// Close expired, unpaid Initialized escrows in one transaction (permissionless)
+    /// Remaining accounts: for each escrow, the escrow (mut) then its rent recipient (mut)
+    pub fn cancel_expired_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelExpiredBatch<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_expired_batch::cancel_expired_batch(ctx)
+    }
+
+    /// Authorize recurring billing of a listing's rental price every rental period
+    /// 
+    /// # Arguments
+    /// * `deposit` - Lamports pre-funding the agreement
+    pub fn create_billing_agreement(ctx: Context<CreateBillingAgreement>, deposit: u64) -> Result<()> {
+        instructions::create_billing_agreement::create_billing_agreement(ctx, deposit)
+    }
+
+    /// Top up a billing agreement's deposit
+    /// 
+    /// # Arguments
+    /// * `amount` - Lamports to add
+    pub fn fund_billing_agreement(ctx: Context<FundBillingAgreement>, amount: u64) -> Result<()> {
+        instructions::fund_billing_agreement::fund_billing_agreement(ctx, amount)
+    }
+
+    /// Pull a due period's payment from a billing agreement and renew rental access (settlement authority only)
+    /// Remaining accounts: Collaborator accounts receiving their share
+    pub fn pull_billing_payment<'info>(
+        ctx: Context<'_, '_, '_, 'info, PullBillingPayment<'info>>,
+    ) -> Result<()> {
+        instructions::pull_billing_payment::pull_billing_payment(ctx)
+    }
+
+    /// Cancel a billing agreement, refunding the unspent deposit to the buyer
+    pub fn cancel_billing_agreement(ctx: Context<CancelBillingAgreement>) -> Result<()> {
+        instructions::cancel_billing_agreement::cancel_billing_agreement(ctx)
+    }
+
+    /// Add funds to the buyer's deposit for a payment mint
+    /// 
+    /// # Arguments
+    /// * `amount` - Amount to deposit
+    pub fn fund_deposit(ctx: Context<FundDeposit>, amount: u64) -> Result<()> {
+        instructions::fund_deposit::fund_deposit(ctx, amount)
+    }
+
+    /// Withdraw unspent funds from the buyer's deposit
+    /// 
+    /// # Arguments
+    /// * `amount` - Amount to withdraw
+    pub fn withdraw_deposit(ctx: Context<WithdrawDeposit>, amount: u64) -> Result<()> {
+        instructions::withdraw_deposit::withdraw_deposit(ctx, amount)
+    }
+
+    /// Authorize a session key to pay for purchases from the buyer's deposit
+    /// 
+    /// # Arguments
+    /// * `session_key` - Key signing purchases (Pubkey::default() to revoke)
+    /// * `expiry_ts` - Timestamp after which the session key stops working
+    pub fn set_deposit_session_key(
+        ctx: Context<SetDepositSessionKey>,
+        session_key: Pubkey,
+        expiry_ts: i64,
+    ) -> Result<()> {
+        instructions::set_deposit_session_key::set_deposit_session_key(ctx, session_key, expiry_ts)
+    }
+
+    /// Open a pay-per-view payment channel holding a deposit for metered usage
+    /// 
+    /// # Arguments
+    /// * `deposit` - Lamports deposited into the channel
+    pub fn open_payment_channel(ctx: Context<OpenPaymentChannel>, deposit: u64) -> Result<()> {
+        instructions::open_payment_channel::open_payment_channel(ctx, deposit)
+    }
+
+    /// Settle a buyer-signed cumulative usage voucher, distributing the unsettled delta (creator only)
+    /// Requires an Ed25519 program instruction verifying the voucher right before this one
+    /// Remaining accounts: Collaborator accounts receiving their share
+    /// 
+    /// # Arguments
+    /// * `cumulative_amount` - Total usage the voucher authorizes
+    pub fn settle_payment_channel<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettlePaymentChannel<'info>>,
+        cumulative_amount: u64,
+    ) -> Result<()> {
+        instructions::settle_payment_channel::settle_payment_channel(ctx, cumulative_amount)
+    }
+
+    /// Start the grace period after which the buyer can close a payment channel
+    pub fn request_channel_close(ctx: Context<RequestChannelClose>) -> Result<()> {
+        instructions::request_channel_close::request_channel_close(ctx)
+    }
+
+    /// Close a payment channel after its grace period, refunding the unsettled deposit
+    pub fn close_payment_channel(ctx: Context<ClosePaymentChannel>) -> Result<()> {
+        instructions::close_payment_channel::close_payment_channel(ctx)
+    }
+
+    /// Tag a listing with a region/market code copied into purchase events and receipts (creator only)
+    /// 
+    /// # Arguments
+    /// * `region` - Region/market code (0 = untagged)
+    pub fn set_listing_region(ctx: Context<SetListingRegion>, region: u16) -> Result<()> {
+        instructions::set_listing_region::set_listing_region(ctx, region)
+    }
+
+    /// Set the largest cancellation fee creators may put on listings (admin only)
+    /// 
+    /// # Arguments
+    /// * `max_cancellation_fee_bps` - Maximum fee in basis points (0 = no fees)
+    pub fn set_max_cancellation_fee(ctx: Context<SetMaxCancellationFee>, max_cancellation_fee_bps: u16) -> Result<()> {
+        instructions::set_max_cancellation_fee::set_max_cancellation_fee(ctx, max_cancellation_fee_bps)
+    }
+
+    /// Set the share of an unclaimed pending access kept for the creator on refund (creator only)
+    /// 
+    /// # Arguments
+    /// * `cancellation_fee_bps` - Fee in basis points of the paid price (0 = full refund)
+    pub fn set_cancellation_fee(ctx: Context<SetCancellationFee>, cancellation_fee_bps: u16) -> Result<()> {
+        instructions::set_cancellation_fee::set_cancellation_fee(ctx, cancellation_fee_bps)
+    }
+
+    /// Add or remove a wallet whose purchases and sales skip the platform fee (admin only)
+    /// 
+    /// # Arguments
+    /// * `wallet` - Buyer or creator wallet
+    /// * `exempt` - Whether the wallet skips the platform fee
+    pub fn set_fee_exempt(ctx: Context<SetFeeExempt>, wallet: Pubkey, exempt: bool) -> Result<()> {
+        instructions::set_fee_exempt::set_fee_exempt(ctx, wallet, exempt)
+    }
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as Token2022Mint;
+use anchor_spl::token_interface::TokenAccount;
+use common::units::SOL_DECIMALS;
+use crate::errors::*;
+use crate::state::PlatformConfig;
+
+pub use common::payments::{
+    is_token_program, mint_decimals, pay_into_vault, refund_from_vault, transfer_fee, transfer_payment,
+    SplPayment,
+};
+
+/// Token-2022 mint extensions that let someone other than the holder move or hide vault balances
+pub const REJECTED_MINT_EXTENSIONS: [ExtensionType; 2] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::ConfidentialTransferMint,
+];
+
+/// Reject Token-2022 payment mints with a permanent delegate or confidential transfers,
+/// unless the platform admin has whitelisted the mint
+pub fn validate_payment_mint(mint: &AccountInfo, platform_config: &PlatformConfig) -> Result<()> {
+    if *mint.owner != anchor_spl::token_2022::ID
+        || platform_config.is_extension_mint_whitelisted(mint.key)
+    {
+        return Ok(());
+    }
+    
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+    
+    for extension in mint_state.get_extension_types()? {
+        require!(
+            !REJECTED_MINT_EXTENSIONS.contains(&extension),
+            EscrowError::UnsupportedMintExtension
+        );
+    }
+    
+    Ok(())
+}
+
+/// Decimals prices in the payment mint are expressed in (SOL_DECIMALS for SOL)
+pub fn payment_decimals(mint: &AccountInfo) -> Result<u8> {
+    if *mint.key == System::id() {
+        return Ok(SOL_DECIMALS);
+    }
+    
+    mint_decimals(mint)
+}
+
+/// Check that the payment mint still has the decimals the price was set in and that
+/// the SPL token accounts moving the payment hold that mint
+pub fn validate_payment_units(
+    mint: &AccountInfo,
+    expected_decimals: u8,
+    token_accounts: &[&AccountInfo],
+) -> Result<()> {
+    require_eq!(
+        payment_decimals(mint)?,
+        expected_decimals,
+        EscrowError::PaymentDecimalsMismatch
+    );
+    
+    for token_account in token_accounts {
+        let token_account = TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])?;
+        require_keys_eq!(token_account.mint, *mint.key, EscrowError::PaymentMintMismatch);
+    }
+    
+    Ok(())
+}
+
+/// Check that a vault token account is a token-program account owned by `vault` holding `mint`,
+/// so payments only move through token accounts the vault PDA controls
+pub fn validate_vault_token_account(
+    token_account: &AccountInfo,
+    vault: &Pubkey,
+    mint: &Pubkey,
+) -> Result<()> {
+    require!(is_token_program(token_account.owner), EscrowError::InvalidTokenProgram);
+    
+    let token_account = TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])?;
+    require_keys_eq!(token_account.owner, *vault, EscrowError::VaultOwnerMismatch);
+    require_keys_eq!(token_account.mint, *mint, EscrowError::VaultMintMismatch);
+    
+    Ok(())
+}
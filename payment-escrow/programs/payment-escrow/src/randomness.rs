@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+/// Switchboard On-Demand program; it writes randomness values only after oracle verification
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Anchor discriminator of Switchboard's `RandomnessAccountData`
+pub const RANDOMNESS_ACCOUNT_DISCRIMINATOR: [u8; 8] = [10, 66, 229, 135, 220, 239, 217, 114];
+
+/// Randomness account fields before the seed slot: discriminator (8) + authority (32)
+/// + queue (32) + seed_slothash (32)
+const SEED_SLOT_OFFSET: usize = 8 + 32 + 32 + 32;
+
+/// Offset of the reveal slot: seed_slot (8) + oracle (32) after the seed slot
+const REVEAL_SLOT_OFFSET: usize = SEED_SLOT_OFFSET + 8 + 32;
+
+/// Offset of the revealed value, after the reveal slot (8)
+const VALUE_OFFSET: usize = REVEAL_SLOT_OFFSET + 8;
+
+/// Verified fields read from a Switchboard randomness account
+pub struct Randomness {
+    /// Slot the randomness was committed to
+    pub seed_slot: u64,
+    
+    /// Slot the value was revealed in (0 until revealed)
+    pub reveal_slot: u64,
+    
+    /// Revealed random value
+    pub value: [u8; 32],
+}
+
+impl Randomness {
+    /// Read a randomness account, which must be owned by the Switchboard On-Demand program
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require!(
+            *account.owner == SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+            EscrowError::InvalidRandomnessAccount
+        );
+        
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= VALUE_OFFSET + 32 && data[..8] == RANDOMNESS_ACCOUNT_DISCRIMINATOR,
+            EscrowError::InvalidRandomnessAccount
+        );
+        
+        let read_u64 = |offset: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let mut value = [0u8; 32];
+        value.copy_from_slice(&data[VALUE_OFFSET..VALUE_OFFSET + 32]);
+        
+        Ok(Self {
+            seed_slot: read_u64(SEED_SLOT_OFFSET),
+            reveal_slot: read_u64(REVEAL_SLOT_OFFSET),
+            value,
+        })
+    }
+    
+    /// Check the randomness was committed in the previous slot, so nobody could know
+    /// its value when it was bound to a draw
+    pub fn check_fresh_commit(&self, slot: u64) -> Result<()> {
+        require!(
+            self.seed_slot == slot.saturating_sub(1),
+            EscrowError::RandomnessExpired
+        );
+        
+        Ok(())
+    }
+    
+    /// The revealed value, which like the Switchboard SDK is only accepted in its reveal slot
+    pub fn revealed_value(&self, slot: u64) -> Result<[u8; 32]> {
+        require!(self.reveal_slot == slot, EscrowError::RandomnessNotResolved);
+        
+        Ok(self.value)
+    }
+}
+
+/// Map a random value onto `0..len`
+pub fn random_index(value: &[u8; 32], len: u64) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&value[..8]);
+    u64::from_le_bytes(bytes) % len.max(1)
+}
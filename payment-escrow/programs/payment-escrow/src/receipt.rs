@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::associated_token::{self, Create};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType, instruction::AuthorityType, state::Mint as Token2022Mint,
+};
+use anchor_spl::token_2022::{
+    initialize_mint2, mint_to, set_authority, InitializeMint2, MintTo, SetAuthority,
+};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_2022_extensions::{
+    metadata_pointer_initialize, non_transferable_mint_initialize, token_metadata_initialize,
+    token_metadata_update_field, MetadataPointerInitialize, NonTransferableMintInitialize,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+use crate::errors::*;
+
+/// Maximum receipt metadata URI length in bytes
+pub const MAX_RECEIPT_URI_LEN: usize = 200;
+
+/// Receipt token name
+pub const RECEIPT_NAME: &str = "Purchase Receipt";
+
+/// Receipt token symbol
+pub const RECEIPT_SYMBOL: &str = "RCPT";
+
+/// Accounts used to mint a purchase receipt
+pub struct ReceiptAccounts<'info> {
+    /// Fresh receipt mint keypair (signer)
+    pub mint: AccountInfo<'info>,
+    
+    /// Owner's receipt associated token account (created here)
+    pub token_account: AccountInfo<'info>,
+    
+    /// Receipt owner, also paying for the mint and token account
+    pub owner: AccountInfo<'info>,
+    
+    /// PDA acting as mint and metadata update authority
+    pub authority: AccountInfo<'info>,
+    
+    /// Token-2022 program
+    pub token_program: AccountInfo<'info>,
+    
+    /// Associated token program
+    pub associated_token_program: AccountInfo<'info>,
+    
+    /// System program
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Mint a single non-transferable receipt token carrying the purchase details
+/// as Token-2022 metadata, then drop the mint authority so no more can be minted
+pub fn mint_receipt<'info>(
+    accounts: &ReceiptAccounts<'info>,
+    authority_seeds: &[&[u8]],
+    uri: String,
+    fields: Vec<(String, String)>,
+) -> Result<()> {
+    require!(uri.len() <= MAX_RECEIPT_URI_LEN, EscrowError::UriTooLong);
+    
+    let signer_seeds = &[authority_seeds];
+    let token_program = &accounts.token_program;
+    let authority = accounts.authority.key();
+    
+    // Token-2022 reallocs the mint as metadata is written, so fund the full size up front
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(authority))?,
+        mint: accounts.mint.key(),
+        name: RECEIPT_NAME.to_string(),
+        symbol: RECEIPT_SYMBOL.to_string(),
+        uri: uri.clone(),
+        additional_metadata: fields.clone(),
+    };
+    let mint_len = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let metadata_len = metadata.tlv_size_of()?;
+    
+    create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.owner.clone(),
+                to: accounts.mint.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(mint_len + metadata_len),
+        mint_len as u64,
+        token_program.key,
+    )?;
+    
+    // Extensions must be initialized before the mint itself
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: accounts.mint.clone(),
+        },
+    ))?;
+    
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(authority),
+        Some(accounts.mint.key()),
+    )?;
+    
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: accounts.mint.clone(),
+            },
+        ),
+        0,
+        &authority,
+        None,
+    )?;
+    
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program.clone(),
+                metadata: accounts.mint.clone(),
+                update_authority: accounts.authority.clone(),
+                mint_authority: accounts.authority.clone(),
+                mint: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        RECEIPT_NAME.to_string(),
+        RECEIPT_SYMBOL.to_string(),
+        uri,
+    )?;
+    
+    for (key, value) in fields {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program.clone(),
+                    metadata: accounts.mint.clone(),
+                    update_authority: accounts.authority.clone(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(key),
+            value,
+        )?;
+    }
+    
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        Create {
+            payer: accounts.owner.clone(),
+            associated_token: accounts.token_account.clone(),
+            authority: accounts.owner.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+    
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.token_account.clone(),
+                authority: accounts.authority.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    
+    set_authority(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            SetAuthority {
+                current_authority: accounts.authority.clone(),
+                account_or_mint: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    Ok(())
+}
+
+/// Lowercase hex encoding used for byte fields in receipt metadata
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
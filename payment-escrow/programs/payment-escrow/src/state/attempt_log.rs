@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Attempt Log - ring buffer of a listing's recent purchase attempts
+/// Gives creators on-chain conversion analytics without an indexer
+#[account]
+pub struct AttemptLog {
+    /// Listing the attempts were made against
+    pub listing: Pubkey,
+    
+    /// Index the next entry is written to
+    pub head: u16,
+    
+    /// Lifetime purchase attempts
+    pub total_attempts: u64,
+    
+    /// Lifetime successful purchases
+    pub total_successes: u64,
+    
+    /// Most recent attempts, oldest overwritten first
+    pub entries: Vec<AttemptEntry>,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AttemptLog {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u16 (2) + u64 (8) + u64 (8)
+    /// + Vec length (4) + AttemptEntry * CAPACITY + u8 (1)
+    pub const LEN: usize = 8 + 32 + 2 + 8 + 8 + 4 + AttemptEntry::LEN * Self::CAPACITY + 1;
+    
+    /// Number of attempts kept
+    pub const CAPACITY: usize = 32;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ATTEMPT_LOG;
+    
+    /// Record an attempt, overwriting the oldest entry once the buffer is full
+    pub fn record(&mut self, buyer: Pubkey, outcome: AttemptOutcome, timestamp: i64) -> Result<()> {
+        let entry = AttemptEntry {
+            buyer,
+            outcome,
+            timestamp,
+        };
+        
+        let head = self.head as usize;
+        if head < self.entries.len() {
+            self.entries[head] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+        self.head = ((head + 1) % Self::CAPACITY) as u16;
+        
+        self.total_attempts = self
+            .total_attempts
+            .checked_add(1)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        if outcome == AttemptOutcome::Success {
+            self.total_successes = self
+                .total_successes
+                .checked_add(1)
+                .ok_or(EscrowError::NumericalOverflow)?;
+        }
+        
+        Ok(())
+    }
+}
+
+/// One logged purchase attempt
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct AttemptEntry {
+    /// Wallet attempting the purchase
+    pub buyer: Pubkey,
+    
+    /// How the attempt ended
+    pub outcome: AttemptOutcome,
+    
+    /// Timestamp of the attempt
+    pub timestamp: i64,
+}
+
+impl AttemptEntry {
+    /// Pubkey (32) + AttemptOutcome (1) + i64 (8)
+    pub const LEN: usize = 32 + 1 + 8;
+}
+
+/// Outcome of a logged purchase attempt
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptOutcome {
+    /// Purchase went through
+    Success,
+    /// The active sale phase had no supply left
+    SoldOut,
+    /// The buyer was not on the active phase's allowlist
+    NotAllowlisted,
+}
+
+impl AttemptOutcome {
+    /// Outcome logged for a purchase rejected with `error`, if it is a logged failure
+    pub fn from_error(error: &Error) -> Option<Self> {
+        if *error == EscrowError::PhaseSoldOut.into() {
+            Some(Self::SoldOut)
+        } else if *error == EscrowError::NotAllowlisted.into() {
+            Some(Self::NotAllowlisted)
+        } else {
+            None
+        }
+    }
+}
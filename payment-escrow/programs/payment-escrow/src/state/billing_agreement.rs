@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// BillingAgreement - a buyer's standing authorization for the platform to pull a fixed
+/// amount each period from the deposit held by this account, renewing rental access on
+/// every successful pull. The terms are fixed when the agreement is created, and the
+/// buyer can cancel anytime, closing the account and recovering the unspent deposit
+#[account]
+pub struct BillingAgreement {
+    /// The buyer funding the agreement and receiving access
+    pub buyer: Pubkey,
+    
+    /// Listing billed (its rental access is renewed on each pull)
+    pub listing: Pubkey,
+    
+    /// The listing's creator
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Lamports pulled each period
+    pub amount: u64,
+    
+    /// Billing period in seconds (also the length of each access renewal)
+    pub period: i64,
+    
+    /// Timestamp from which the next payment can be pulled
+    pub next_pull_ts: i64,
+    
+    /// Lamports of the deposit not yet pulled
+    pub deposit: u64,
+    
+    /// Number of successful pulls
+    pub pull_count: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BillingAgreement {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8)
+    /// + i64 (8) + i64 (8) + u64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::BILLING_AGREEMENT;
+    
+    /// Whether a payment can be pulled at `now`
+    pub fn is_due(&self, now: i64) -> bool {
+        now >= self.next_pull_ts
+    }
+    
+    /// Record a deposit top-up
+    pub fn fund(&mut self, amount: u64) -> Result<()> {
+        self.deposit = self
+            .deposit
+            .checked_add(amount)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        Ok(())
+    }
+    
+    /// Record a pull at `now`, scheduling the next one a period later
+    pub fn record_pull(&mut self, now: i64) -> Result<()> {
+        self.deposit = self
+            .deposit
+            .checked_sub(self.amount)
+            .ok_or(EscrowError::InsufficientDeposit)?;
+        self.next_pull_ts = now
+            .checked_add(self.period)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        self.pull_count = self
+            .pull_count
+            .checked_add(1)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        Ok(())
+    }
+}
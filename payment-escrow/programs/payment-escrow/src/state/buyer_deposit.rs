@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Buyer Deposit - funds a buyer parks for one payment mint so purchases can draw on them
+/// A buy_and_mint paying from the deposit only needs the signature of the deposit's
+/// session key, letting wallets offer one-click repeat purchases without a wallet prompt.
+/// SOL is held as the account's lamports; SPL tokens in a token account it owns
+#[account]
+pub struct BuyerDeposit {
+    /// The buyer who owns the deposit
+    pub buyer: Pubkey,
+    
+    /// Payment token mint (None = SOL, Some = SPL token)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Funds available to purchases
+    pub balance: u64,
+    
+    /// Key allowed to authorize purchases from the deposit (default = none)
+    pub session_key: Pubkey,
+    
+    /// Timestamp after which the session key can no longer authorize purchases
+    pub session_expiry_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BuyerDeposit {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Option<Pubkey> (1 + 32) + u64 (8) + Pubkey (32)
+    /// + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 33 + 8 + 32 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::BUYER_DEPOSIT;
+    
+    /// Record funds added to the deposit
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.balance = self
+            .balance
+            .checked_add(amount)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        Ok(())
+    }
+    
+    /// Record funds leaving the deposit (spent or withdrawn)
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.balance = self
+            .balance
+            .checked_sub(amount)
+            .ok_or(EscrowError::InsufficientDeposit)?;
+        Ok(())
+    }
+    
+    /// Whether `key` is the deposit's session key and the session is still open at `now`
+    pub fn is_session_key(&self, key: &Pubkey, now: i64) -> bool {
+        self.session_key != Pubkey::default()
+            && self.session_key == *key
+            && now <= self.session_expiry_ts
+    }
+}
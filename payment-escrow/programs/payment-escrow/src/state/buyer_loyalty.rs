@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use common::bps::apply_bps;
+use crate::errors::EscrowError;
+
+/// Buyer Loyalty - points a wallet earns on SOL purchases
+/// One point is worth one lamport of discount once redeemed
+#[account]
+pub struct BuyerLoyalty {
+    /// Wallet earning the points
+    pub buyer: Pubkey,
+    
+    /// Points available to redeem
+    pub points: u64,
+    
+    /// Redeemed discount (lamports) applied to the next buy_and_mint
+    pub discount_lamports: u64,
+    
+    /// Points earned over the wallet's lifetime
+    pub lifetime_points: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BuyerLoyalty {
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + u64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::BUYER_LOYALTY;
+    
+    /// Credit points for a purchase of `amount` lamports at `rate_bps`, returning the points earned
+    pub fn credit(&mut self, amount: u64, rate_bps: u16) -> Result<u64> {
+        let earned = apply_bps(amount, rate_bps).ok_or(EscrowError::NumericalOverflow)?;
+        
+        self.points = self.points.checked_add(earned).ok_or(EscrowError::NumericalOverflow)?;
+        self.lifetime_points = self
+            .lifetime_points
+            .checked_add(earned)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        
+        Ok(earned)
+    }
+    
+    /// Convert `points` into discount for future purchases
+    pub fn redeem(&mut self, points: u64) -> Result<()> {
+        require!(
+            points > 0 && points <= self.points,
+            EscrowError::InsufficientLoyaltyPoints
+        );
+        
+        self.points -= points;
+        self.discount_lamports = self
+            .discount_lamports
+            .checked_add(points)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Use as much redeemed discount as a purchase at `price` allows, returning the discount
+    pub fn take_discount(&mut self, price: u64) -> u64 {
+        let discount = self.discount_lamports.min(price);
+        self.discount_lamports -= discount;
+        
+        discount
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Buyer Vault - holds a buyer's in-flight payments for one payment mint
+/// Shared by all of the buyer's escrows in that mint, so purchases no longer
+/// create a vault per escrow
+#[account]
+pub struct BuyerVault {
+    /// The buyer who owns the vault
+    pub buyer: Pubkey,
+    
+    /// Payment token mint (None = SOL, Some = SPL token)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Payments currently held on behalf of the buyer's escrows
+    pub held_amount: u64,
+    
+    /// Lifetime payments received into the vault
+    pub total_deposited: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BuyerVault {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Option<Pubkey> (1 + 32) + u64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 33 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::BUYER_VAULT;
+    
+    /// Payment mint seed used for SOL vaults (the system program id)
+    pub const SOL_MINT: Pubkey = anchor_lang::system_program::ID;
+    
+    /// Payment mint seed for a vault holding the given payment token
+    pub fn mint_seed(payment_token_mint: &Option<Pubkey>) -> &Pubkey {
+        payment_token_mint.as_ref().unwrap_or(&Self::SOL_MINT)
+    }
+    
+    /// Record a payment received for an escrow
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.held_amount = self
+            .held_amount
+            .checked_add(amount)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        Ok(())
+    }
+    
+    /// Record a payment leaving the vault (distributed or refunded)
+    pub fn release(&mut self, amount: u64) -> Result<()> {
+        self.held_amount = self
+            .held_amount
+            .checked_sub(amount)
+            .ok_or(EscrowError::InsufficientFunds)?;
+        Ok(())
+    }
+    
+    /// Lamports needed to bring the vault up to its rent-exempt minimum
+    pub fn rent_shortfall(vault: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+        Ok(rent_exempt.saturating_sub(vault.lamports()))
+    }
+    
+    /// Lamports the vault can pay out while staying rent-exempt
+    pub fn spendable_lamports(vault: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+        Ok(vault.lamports().saturating_sub(rent_exempt))
+    }
+    
+    /// Check a SOL payout leaves the vault's rent reserve untouched
+    pub fn check_sol_payout(vault: &AccountInfo, amount: u64) -> Result<()> {
+        require!(
+            Self::spendable_lamports(vault)? >= amount,
+            EscrowError::VaultBelowRentExempt
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+/// Campaign State - a crowdfunded purchase that unlocks once its goal is reached
+/// Also backs group buys, where buyers split one listing's price in a shared escrow
+#[account]
+pub struct CampaignState {
+    /// The creator's public key
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Funding goal in lamports
+    pub goal: u64,
+    
+    /// Unix timestamp after which contributions are no longer accepted
+    pub deadline: i64,
+    
+    /// Total lamports contributed so far
+    pub total_raised: u64,
+    
+    /// Number of distinct contributors
+    pub contributor_count: u32,
+    
+    /// Status of the campaign
+    pub status: CampaignStatus,
+    
+    /// Seed for PDA derivation
+    pub seed: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+    
+    /// Listing a group buy purchases (None = creator-run campaign)
+    pub listing: Option<Pubkey>,
+    
+    /// Minimum contribution that earns an access token (0 = every contributor)
+    pub access_threshold: u64,
+}
+
+impl CampaignState {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + u64 (8) + i64 (8)
+    /// + u64 (8) + u32 (4) + CampaignStatus (1) + u64 (8) + u8 (1)
+    /// + Option<Pubkey> (1 + 32) + u64 (8)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 4 + 1 + 8 + 1 + 33 + 8;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CAMPAIGN;
+    
+    /// PDA seed prefix for the campaign vault
+    pub const VAULT_SEED_PREFIX: &'static [u8] = common::seeds::CAMPAIGN_VAULT;
+    
+    /// Whether this campaign is a group buy of a listing
+    pub fn is_group_buy(&self) -> bool {
+        self.listing.is_some()
+    }
+    
+    /// Whether a contributor's total earns an access token once the campaign succeeds
+    pub fn earns_access(&self, contribution: u64) -> bool {
+        contribution > 0 && contribution >= self.access_threshold
+    }
+    
+    /// Lamports needed to bring a campaign vault up to its rent-exempt minimum
+    /// The reserve is funded at creation; contributions, refunds and finalization only
+    /// ever move contributed lamports, so it stays in the vault
+    pub fn vault_rent_shortfall(vault: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+        Ok(rent_exempt.saturating_sub(vault.lamports()))
+    }
+}
+
+/// Contribution - a single contributor's stake in a campaign
+#[account]
+pub struct Contribution {
+    /// Campaign this contribution belongs to
+    pub campaign: Pubkey,
+    
+    /// The contributor's public key
+    pub contributor: Pubkey,
+    
+    /// Total lamports contributed
+    pub amount: u64,
+    
+    /// Whether the contributor has claimed their access token
+    pub access_claimed: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Contribution {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u64 (8) + bool (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CONTRIBUTION;
+}
+
+/// Campaign status enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CampaignStatus {
+    /// Accepting contributions
+    Active,
+    /// Goal reached, funds distributed and access claimable
+    Succeeded,
+    /// Deadline passed without reaching the goal, refunds claimable
+    Failed,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Content Freeze - platform takedown record halting sales of a content
+/// Existing access tokens are unaffected; only new escrows and purchases are blocked
+#[account]
+pub struct ContentFreeze {
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Whether sales of the content are currently halted
+    pub frozen: bool,
+    
+    /// Reason given for the most recent freeze (e.g. a DMCA notice reference)
+    pub reason: String,
+    
+    /// Timestamp of the most recent freeze or unfreeze
+    pub updated_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ContentFreeze {
+    /// Maximum reason length in bytes
+    pub const MAX_REASON_LEN: usize = 128;
+    
+    /// Discriminator (8) + [u8; 32] (32) + bool (1) + String (4 + MAX_REASON_LEN) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 1 + 4 + Self::MAX_REASON_LEN + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CONTENT_FREEZE;
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Content Metadata - creator commitment to where the content id preimage lives
+#[account]
+pub struct ContentMetadata {
+    /// Listing the metadata belongs to
+    pub listing: Pubkey,
+    
+    /// URI of the canonical metadata blob whose sha256 is the content id
+    pub uri: String,
+    
+    /// Whether the preimage was checked against the content id on-chain
+    pub verified: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ContentMetadata {
+    /// Maximum URI length in bytes
+    pub const MAX_URI_LEN: usize = 200;
+    
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + String (4 + 200) + bool (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 4 + Self::MAX_URI_LEN + 1 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CONTENT_METADATA;
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Creator Badge - records a creator verified by the platform admin
+/// The matching non-transferable badge token is held in the creator's wallet
+#[account]
+pub struct CreatorBadge {
+    /// The verified creator
+    pub creator: Pubkey,
+    
+    /// Timestamp when the badge was issued
+    pub issued_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorBadge {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CREATOR_BADGE;
+    
+    /// Badge mint PDA seed prefix
+    pub const MINT_SEED_PREFIX: &'static [u8] = common::seeds::CREATOR_BADGE_MINT;
+}
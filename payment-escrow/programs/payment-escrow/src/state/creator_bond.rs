@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Creator Bond - SOL a creator stakes as a guarantee to buyers
+/// Creators accumulating disputes within the window can be slashed to compensate the
+/// buyers of the disputed purchases
+#[account]
+pub struct CreatorBond {
+    /// The creator staking the bond
+    pub creator: Pubkey,
+    
+    /// Lamports staked (excluding the account's rent)
+    pub staked: u64,
+    
+    /// Lamports requested for withdrawal
+    pub unstake_amount: u64,
+    
+    /// Timestamp after which the requested withdrawal can be made
+    pub unstake_available_ts: i64,
+    
+    /// Disputes recorded in the current window
+    pub disputes: u32,
+    
+    /// Start of the current dispute window
+    pub dispute_window_start_ts: i64,
+    
+    /// Lamports slashed over the bond's lifetime
+    pub slashed_total: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorBond {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + u64 (8) + i64 (8) + u32 (4) + i64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 4 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CREATOR_BOND;
+    
+    /// Wait between requesting and withdrawing an unstake (7 days)
+    pub const UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+    
+    /// Length of the window disputes are counted in (30 days)
+    pub const DISPUTE_WINDOW: i64 = 30 * 24 * 60 * 60;
+    
+    /// Disputes within one window after which the bond can be slashed
+    pub const SLASH_DISPUTE_THRESHOLD: u32 = 3;
+    
+    /// Disputes counted in the window still open at `now`
+    pub fn disputes_in_window(&self, now: i64) -> u32 {
+        if now < self.dispute_window_start_ts.saturating_add(Self::DISPUTE_WINDOW) {
+            self.disputes
+        } else {
+            0
+        }
+    }
+    
+    /// Record a refund or dispute against the creator at `now`, opening a new window if the last one closed
+    pub fn record_dispute(&mut self, now: i64) -> Result<()> {
+        if self.disputes_in_window(now) == 0 {
+            self.disputes = 0;
+            self.dispute_window_start_ts = now;
+        }
+        
+        self.disputes = self.disputes.checked_add(1).ok_or(EscrowError::NumericalOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Whether the creator has had enough disputes in the current window to be slashed
+    pub fn is_slashable(&self, now: i64) -> bool {
+        self.disputes_in_window(now) >= Self::SLASH_DISPUTE_THRESHOLD
+    }
+    
+    /// Remove `amount` from the stake, trimming any pending withdrawal to what is left
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.staked = self.staked.checked_sub(amount).ok_or(EscrowError::InsufficientBond)?;
+        self.unstake_amount = self.unstake_amount.min(self.staked);
+        
+        Ok(())
+    }
+}
+
+/// DisputeRecord - marks an escrow as counted against its creator's bond, so each purchase
+/// counts once, and caps what its buyer can be compensated from the bond
+#[account]
+pub struct DisputeRecord {
+    /// Escrow of the disputed purchase
+    pub escrow: Pubkey,
+    
+    /// Creator whose bond the dispute counts against
+    pub creator: Pubkey,
+    
+    /// Buyer of the disputed purchase, the only wallet slashes can pay
+    pub buyer: Pubkey,
+    
+    /// Payment mint of the purchase (None for SOL)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount the buyer paid, capping the total slashed for this dispute
+    pub payment_amount: u64,
+    
+    /// Lamports slashed to the buyer for this dispute so far
+    pub slashed: u64,
+    
+    /// Timestamp the dispute was recorded
+    pub recorded_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DisputeRecord {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + Option<Pubkey> (33) + u64 (8) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 33 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::DISPUTE_RECORD;
+    
+    /// Add `amount` to what has been slashed for this dispute
+    /// Slashes are paid in lamports, so only SOL purchases can be compensated, up to what was paid
+    pub fn record_slash(&mut self, amount: u64) -> Result<()> {
+        require!(self.payment_token_mint.is_none(), EscrowError::PaymentMintMismatch);
+        
+        let slashed = self.slashed.checked_add(amount).ok_or(EscrowError::NumericalOverflow)?;
+        require!(slashed <= self.payment_amount, EscrowError::SlashExceedsPayment);
+        self.slashed = slashed;
+        
+        Ok(())
+    }
+}
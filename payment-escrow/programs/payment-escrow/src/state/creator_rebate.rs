@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Creator Rebate - a creator's opt-in to platform fee rebates paid in the platform token
+#[account]
+pub struct CreatorRebate {
+    /// The creator receiving rebates
+    pub creator: Pubkey,
+    
+    /// Total rebate tokens minted to the creator
+    pub total_rebated: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorRebate {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CREATOR_REBATE;
+    
+    /// Rebate mint authority PDA seed prefix
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = common::seeds::REBATE_AUTHORITY;
+}
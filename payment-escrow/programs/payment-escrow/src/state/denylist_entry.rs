@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Denylist Entry - a wallet the platform refuses to sell to (e.g. sanctioned)
+/// One PDA per wallet so the list can grow without bound
+#[account]
+pub struct DenylistEntry {
+    /// Denied wallet
+    pub wallet: Pubkey,
+    
+    /// Timestamp the wallet was added
+    pub added_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DenylistEntry {
+    /// Discriminator (8) + Pubkey (32) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::DENYLIST;
+}
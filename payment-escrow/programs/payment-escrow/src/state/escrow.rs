@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Maximum memo length in bytes (sizes the memo for InitSpace)
+const MAX_MEMO_LEN: usize = 64;
 
 /// Escrow State Account - stores purchase metadata
 #[account]
+#[derive(InitSpace)]
 pub struct EscrowState {
     /// The buyer's public key
     pub buyer: Pubkey,
@@ -19,11 +24,25 @@ pub struct EscrowState {
     pub payment_token_mint: Option<Pubkey>,
     
     /// Amount actually paid (should match price)
+    /// This is the escrow's share of the buyer vault balance
     pub payment_amount: u64,
     
     /// Optional access mint address that was created
     pub access_mint_address: Option<Pubkey>,
     
+    /// Listing this escrow was created against (None = unlisted)
+    pub listing: Option<Pubkey>,
+    
+    /// Listing price version at escrow creation
+    pub price_version: u32,
+    
+    /// Hash of the license terms agreed at purchase
+    pub license_terms_hash: [u8; 32],
+    
+    /// Buyer-supplied reference (e.g. order or invoice id) for off-chain reconciliation
+    #[max_len(MAX_MEMO_LEN)]
+    pub memo: Option<String>,
+    
     /// Timestamp when escrow was created
     pub created_ts: i64,
     
@@ -35,21 +54,75 @@ pub struct EscrowState {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// Canonical bump of the buyer vault holding this escrow's payment
+    pub vault_bump: u8,
+    
+    /// Decimals of the payment mint the price is expressed in (9 for SOL)
+    pub payment_decimals: u8,
+    
+    /// Account that paid the escrow's rent and gets it back when the escrow closes
+    /// (the buyer, or a relayer sponsoring the transaction)
+    pub rent_recipient: Pubkey,
+    
+    /// How the purchase was paid for
+    pub payment_source: PaymentSource,
+    
+    /// Switchboard randomness account committed for a mystery box purchase (None = regular purchase)
+    pub randomness_account: Option<Pubkey>,
+    
+    /// Slot the mystery box randomness was committed in
+    pub randomness_commit_slot: u64,
+    
+    /// Content the mystery box revealed, once the randomness is revealed
+    pub revealed_content_id: Option<[u8; 32]>,
+    
+    /// When this escrow's payment was handed to the revenue split (0 = not yet)
+    pub distributed_ts: i64,
 }
 
 impl EscrowState {
     /// Size calculation for account allocation
-    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8) 
-    /// + Option<Pubkey> (1 + 32) + u64 (8) + Option<Pubkey> (1 + 32) 
-    /// + i64 (8) + u64 (8) + EscrowStatus (1) + u8 (1)
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 33 + 8 + 33 + 8 + 8 + 1 + 1;
+    /// Discriminator (8) + fields at their largest (derived by InitSpace)
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+    
+    /// Maximum memo length in bytes
+    pub const MAX_MEMO_LEN: usize = MAX_MEMO_LEN;
     
     /// PDA seed prefix
-    pub const SEED_PREFIX: &'static [u8] = b"escrow";
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::ESCROW;
+    
+    /// Seconds an unpaid checkout stays open before anyone can close it
+    pub const CHECKOUT_EXPIRY_SECONDS: i64 = 24 * 60 * 60;
+    
+    /// Whether this is an abandoned checkout at `now`: still Initialized, never paid
+    /// and older than the checkout expiry
+    pub fn is_abandoned(&self, now: i64) -> bool {
+        self.status == EscrowStatus::Initialized
+            && self.payment_amount == 0
+            && now >= self.created_ts.saturating_add(Self::CHECKOUT_EXPIRY_SECONDS)
+    }
+    
+    /// Record that the payment was handed to the revenue split at `now`
+    /// Each escrow's payment is distributed exactly once, however many of a content's
+    /// escrows are pending at the same time
+    pub fn mark_distributed(&mut self, now: i64) -> Result<()> {
+        require!(self.distributed_ts == 0, EscrowError::EscrowAlreadyDistributed);
+        self.distributed_ts = now;
+        
+        Ok(())
+    }
+    
+    /// Move the escrow to `to`, failing if its current status doesn't allow it
+    pub fn transition_to(&mut self, to: EscrowStatus) -> Result<()> {
+        self.status = self.status.transition(to)?;
+        
+        Ok(())
+    }
 }
 
 /// Escrow status enum
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
 pub enum EscrowStatus {
     /// Escrow initialized but payment not yet received
     Initialized,
@@ -58,3 +131,64 @@ pub enum EscrowStatus {
     /// Escrow cancelled and refunded
     Cancelled,
 }
+
+impl EscrowStatus {
+    /// The status reached by moving from `self` to `to`, or the reason the move isn't allowed
+    /// Every pair is spelled out (no wildcard) so a new status can't compile until its
+    /// transitions are decided
+    pub fn transition(self, to: EscrowStatus) -> Result<EscrowStatus> {
+        use EscrowStatus::*;
+        
+        match (self, to) {
+            // Paid (or settled) and access minted, or abandoned before then
+            (Initialized, Completed) | (Initialized, Cancelled) => Ok(to),
+            (Initialized, Initialized) => err!(EscrowError::InvalidEscrowStatus),
+            
+            // Completed and cancelled escrows are final
+            (Completed, Initialized) | (Completed, Completed) | (Completed, Cancelled) => {
+                err!(EscrowError::EscrowAlreadyCompleted)
+            }
+            (Cancelled, Initialized) | (Cancelled, Completed) | (Cancelled, Cancelled) => {
+                err!(EscrowError::EscrowAlreadyCancelled)
+            }
+        }
+    }
+}
+
+/// How a purchase was paid for
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentSource {
+    /// Paid on-chain in SOL or an SPL token
+    Onchain,
+    /// Paid off-chain (e.g. by card) and settled by the platform's settlement authority
+    Fiat,
+}
+
+/// Fiat Settlement - links an external payment to the escrow it settled
+/// Its PDA is derived from the external payment id, so a payment can only be settled once
+#[account]
+pub struct FiatSettlement {
+    /// External payment id (e.g. a hash of the card processor's payment reference)
+    pub external_payment_id: [u8; 32],
+    
+    /// Escrow recording the purchase
+    pub escrow: Pubkey,
+    
+    /// Settlement authority that settled the payment
+    pub settled_by: Pubkey,
+    
+    /// Timestamp of the settlement
+    pub settled_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FiatSettlement {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + [u8; 32] (32) + Pubkey (32) + Pubkey (32) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::FIAT_SETTLEMENT;
+}
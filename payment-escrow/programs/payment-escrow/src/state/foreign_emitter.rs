@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// Foreign Emitter - the contract on another chain trusted to attest payments
+/// One per Wormhole chain id, registered by the platform admin
+#[account]
+pub struct ForeignEmitter {
+    /// Wormhole chain id of the emitting chain
+    pub chain: u16,
+    
+    /// Emitter address on that chain (left-padded to 32 bytes)
+    pub address: [u8; 32],
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ForeignEmitter {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + u16 (2) + [u8; 32] (32) + u8 (1)
+    pub const LEN: usize = 8 + 2 + 32 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::FOREIGN_EMITTER;
+}
+
+/// Crosschain Redemption - marks a payment VAA as redeemed so it can't be replayed
+#[account]
+pub struct CrosschainRedemption {
+    /// Wormhole chain id the payment was made on
+    pub emitter_chain: u16,
+    
+    /// VAA sequence number
+    pub sequence: u64,
+    
+    /// Solana wallet that received access
+    pub recipient: Pubkey,
+    
+    /// Access mint state access was granted for
+    pub access_mint_state: Pubkey,
+    
+    /// Amount paid on the foreign chain, in that chain's token units
+    pub amount: u64,
+    
+    /// Timestamp of the redemption
+    pub redeemed_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CrosschainRedemption {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + u16 (2) + u64 (8) + Pubkey (32) + Pubkey (32) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 2 + 8 + 32 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::CROSSCHAIN_REDEMPTION;
+}
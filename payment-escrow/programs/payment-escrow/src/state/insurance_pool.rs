@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use common::bps::apply_bps;
+use crate::errors::EscrowError;
+
+/// InsurancePool - pooled lamports refunding buyers whose creator is unresponsive in a dispute
+/// Funded by a slice of the platform fee on direct-split SOL sales (where the escrow program
+/// pays the platform share itself) and by admin top-ups
+#[account]
+pub struct InsurancePool {
+    /// Share of the platform fee diverted into the pool, in basis points
+    pub contribution_bps: u16,
+    
+    /// Largest payout for a single claim in lamports
+    pub max_payout: u64,
+    
+    /// Lifetime lamports contributed from platform fees
+    pub total_contributed: u64,
+    
+    /// Lifetime lamports paid out to buyers
+    pub total_paid: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + u16 (2) + u64 (8) + u64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 2 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::INSURANCE_POOL;
+    
+    /// Slice of `platform_fee` diverted into the pool
+    pub fn premium(&self, platform_fee: u64) -> Result<u64> {
+        apply_bps(platform_fee, self.contribution_bps).ok_or_else(|| EscrowError::NumericalOverflow.into())
+    }
+    
+    /// Lamports available for payouts, above the rent-exempt minimum
+    pub fn balance(&self, pool_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(pool_info.data_len());
+        Ok(pool_info.lamports().saturating_sub(rent_exempt))
+    }
+}
+
+/// InsuranceClaim - records the payout made for an escrow so it can only be paid once
+#[account]
+pub struct InsuranceClaim {
+    /// Escrow the payout refunded
+    pub escrow: Pubkey,
+    
+    /// Buyer refunded
+    pub buyer: Pubkey,
+    
+    /// Lamports paid out
+    pub amount: u64,
+    
+    /// Timestamp of the payout
+    pub paid_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InsuranceClaim {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::INSURANCE_CLAIM;
+}
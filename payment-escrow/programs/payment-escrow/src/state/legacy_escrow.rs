@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::EscrowError;
+use super::escrow::*;
+
+/// Escrow layout written before listings and buyer vaults were added
+/// Escrows created then still hold it, so they can only be read field by field
+/// until migrate_legacy_vault rewrites them as an EscrowState
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyEscrowState {
+    /// The buyer's public key
+    pub buyer: Pubkey,
+    
+    /// The creator's public key who will receive payment
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Price in lamports or SPL token amount
+    pub price: u64,
+    
+    /// Optional payment token mint (None = SOL, Some = SPL token)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Amount actually paid (should match price)
+    pub payment_amount: u64,
+    
+    /// Optional access mint address that was created
+    pub access_mint_address: Option<Pubkey>,
+    
+    /// Timestamp when escrow was created
+    pub created_ts: i64,
+    
+    /// Trade nonce for uniqueness (allows multiple purchases)
+    pub seed: u64,
+    
+    /// Status of the escrow
+    pub status: EscrowStatus,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LegacyEscrowState {
+    /// Size of a legacy escrow account
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8)
+    /// + Option<Pubkey> (1 + 32) + u64 (8) + Option<Pubkey> (1 + 32)
+    /// + i64 (8) + u64 (8) + EscrowStatus (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 33 + 8 + 33 + 8 + 8 + 1 + 1;
+    
+    /// Seed prefix of the per-escrow vault legacy escrows were paid through
+    /// Kept byte-for-byte so the vaults those escrows created can still be found
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+    
+    /// Read an escrow still in the legacy layout, checking its owner, size and discriminator
+    /// Escrows already in the current layout are larger, so they can't be read as legacy ones
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*account.owner, crate::ID, EscrowError::NotALegacyEscrow);
+        
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() == Self::LEN && data[..8] == *EscrowState::DISCRIMINATOR,
+            EscrowError::NotALegacyEscrow
+        );
+        
+        Self::deserialize(&mut &data[8..]).map_err(|_| EscrowError::NotALegacyEscrow.into())
+    }
+    
+    /// The escrow in the current layout, paying through the buyer vault with `vault_bump`
+    /// Fields the legacy layout lacks start empty; completed purchases were distributed
+    /// when they were bought, so they're marked distributed at their creation time
+    pub fn into_escrow_state(self, vault_bump: u8, payment_decimals: u8) -> EscrowState {
+        let distributed_ts = if self.status == EscrowStatus::Completed {
+            self.created_ts
+        } else {
+            0
+        };
+        
+        EscrowState {
+            buyer: self.buyer,
+            creator: self.creator,
+            content_id: self.content_id,
+            price: self.price,
+            payment_token_mint: self.payment_token_mint,
+            payment_amount: self.payment_amount,
+            access_mint_address: self.access_mint_address,
+            listing: None,
+            price_version: 0,
+            license_terms_hash: [0; 32],
+            memo: None,
+            created_ts: self.created_ts,
+            seed: self.seed,
+            status: self.status,
+            bump: self.bump,
+            vault_bump,
+            payment_decimals,
+            rent_recipient: self.buyer,
+            payment_source: PaymentSource::Onchain,
+            randomness_account: None,
+            randomness_commit_slot: 0,
+            revealed_content_id: None,
+            distributed_ts,
+        }
+    }
+}
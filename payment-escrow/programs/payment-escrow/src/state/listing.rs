@@ -0,0 +1,283 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use common::merkle;
+use crate::errors::EscrowError;
+use distribution_cpi::state::SplitState;
+use crate::state::{CreatorBadge, Platform, PurchaseCounter};
+
+/// Listing - a creator-signed offer to sell a specific content
+#[account]
+pub struct Listing {
+    /// The creator's public key
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Price in lamports or SPL token amount
+    pub price: u64,
+    
+    /// Optional payment token mint (None = SOL, Some = SPL token)
+    pub payment_token_mint: Option<Pubkey>,
+    
+    /// Incremented every time the price changes
+    pub price_version: u32,
+    
+    /// How in-flight escrows are treated after a price change
+    pub price_change_policy: PriceChangePolicy,
+    
+    /// Price for time-boxed rental access
+    pub rental_price: u64,
+    
+    /// Rental length in seconds (0 = rentals disabled)
+    pub rental_duration: i64,
+    
+    /// Price of premium-tier access (0 = no premium tier)
+    pub premium_price: u64,
+    
+    /// Hash of the license terms buyers agree to
+    pub license_terms_hash: [u8; 32],
+    
+    /// Timestamp when the sale opens (0 = open immediately)
+    pub sale_start_ts: i64,
+    
+    /// Timestamp when the sale closes (0 = never closes)
+    pub sale_end_ts: i64,
+    
+    /// Sale phases (e.g. presale then public), each with its own price, supply and allowlist
+    /// Empty when the listing sells at `price` without phases
+    pub phases: Vec<SalePhase>,
+    
+    /// Access token mint buyers must already hold (e.g. a season pass), if any
+    pub gate_mint: Option<Pubkey>,
+    
+    /// Only sell while the creator holds a platform-issued creator badge
+    pub require_creator_badge: bool,
+    
+    /// Minimum seconds between purchases by the same wallet (0 = no throttle)
+    pub purchase_cooldown: i64,
+    
+    /// Sell to wallets on the platform denylist (creator opt-out)
+    pub ignore_denylist: bool,
+    
+    /// Marketplace platform the listing is sold through, if any
+    pub platform: Option<Pubkey>,
+    
+    /// Decimals of the payment mint the price is expressed in (9 for SOL)
+    pub payment_decimals: u8,
+    
+    /// Share of each resale paid to the content's split, in basis points
+    pub resale_royalty_bps: u16,
+    
+    /// Sell at the platform-wide sale discount while a platform sale is active
+    pub platform_sale_opt_in: bool,
+    
+    /// Reject purchases bundled with instructions from other programs (anti-sandwich)
+    pub strict_mode: bool,
+    
+    /// Region/market code copied into purchase events and receipts (0 = untagged)
+    /// Informational only; the program never restricts sales by region
+    pub region: u16,
+    
+    /// Share of a paid pending access kept for the creator when the buyer takes a refund
+    /// instead of claiming, in basis points (0 = full refund; capped by the platform maximum)
+    pub cancellation_fee_bps: u16,
+    
+    /// Number of verified-purchase ratings
+    pub rating_count: u32,
+    
+    /// Sum of all ratings (average = rating_sum / rating_count)
+    pub rating_sum: u64,
+    
+    /// Timestamp when the listing was created
+    pub created_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Listing {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + u64 (8)
+    /// + Option<Pubkey> (1 + 32) + u32 (4) + PriceChangePolicy (1) + u64 (8) + i64 (8)
+    /// + u64 (8) + [u8; 32] (32) + i64 (8) + i64 (8) + Vec length (4)
+    /// + SalePhase * MAX_PHASES + Option<Pubkey> (1 + 32) + bool (1) + i64 (8) + bool (1)
+    /// + Option<Pubkey> (1 + 32) + u8 (1) + u16 (2) + bool (1) + bool (1) + u16 (2) + u16 (2) + u32 (4)
+    /// + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 33 + 4 + 1 + 8 + 8 + 8 + 32 + 8 + 8
+        + 4 + SalePhase::LEN * Self::MAX_PHASES + 33 + 1 + 8 + 1 + 33 + 1 + 2 + 1 + 1 + 2 + 2 + 4 + 8 + 8 + 1;
+    
+    /// Maximum number of sale phases per listing
+    pub const MAX_PHASES: usize = 4;
+    
+    /// Largest resale royalty a creator can set (25%), leaving resellers most of the price
+    pub const MAX_RESALE_ROYALTY_BPS: u16 = 2_500;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::LISTING;
+    
+    /// Record a verified-purchase rating
+    pub fn record_rating(&mut self, rating: u8) -> Result<()> {
+        self.rating_count = self.rating_count.checked_add(1).ok_or(EscrowError::NumericalOverflow)?;
+        self.rating_sum = self
+            .rating_sum
+            .checked_add(rating as u64)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        Ok(())
+    }
+    
+    /// Average rating scaled by 100 (e.g. 450 = 4.5 stars), 0 when unrated
+    pub fn average_rating_x100(&self) -> u64 {
+        (self.rating_sum * 100).checked_div(self.rating_count as u64).unwrap_or(0)
+    }
+    
+    /// Whether the sale window is open at `now`
+    pub fn is_on_sale(&self, now: i64) -> bool {
+        now >= self.sale_start_ts && (self.sale_end_ts == 0 || now < self.sale_end_ts)
+    }
+    
+    /// Whether `price` is the listing price or the price of one of its phases
+    pub fn offers_price(&self, price: u64) -> bool {
+        self.price == price || self.phases.iter().any(|phase| phase.price == price)
+    }
+    
+    /// Check that the buyer holds the gating token when the listing requires one
+    pub fn check_gate(&self, gate_token_account: Option<&TokenAccount>) -> Result<()> {
+        if let Some(gate_mint) = self.gate_mint {
+            let gate_token_account = gate_token_account.ok_or(EscrowError::GateTokenRequired)?;
+            require!(
+                gate_token_account.mint == gate_mint && gate_token_account.amount >= 1,
+                EscrowError::GateTokenRequired
+            );
+        }
+        
+        Ok(())
+    }
+    
+    /// Check that the creator holds a badge when the listing requires a verified creator
+    pub fn check_creator_badge(&self, creator_badge: Option<&CreatorBadge>) -> Result<()> {
+        if self.require_creator_badge {
+            let creator_badge = creator_badge.ok_or(EscrowError::CreatorNotVerified)?;
+            require!(
+                creator_badge.creator == self.creator,
+                EscrowError::CreatorNotVerified
+            );
+        }
+        
+        Ok(())
+    }
+    
+    /// Check that a listing sold through a platform is bought with that platform's
+    /// account and that the content's split pays the platform's treasury and fee
+    pub fn check_platform(
+        &self,
+        platform: Option<&Account<Platform>>,
+        split_state: &SplitState,
+    ) -> Result<()> {
+        if let Some(listing_platform) = self.platform {
+            let platform = platform.ok_or(EscrowError::PlatformMismatch)?;
+            require!(platform.key() == listing_platform, EscrowError::PlatformMismatch);
+            platform.check_split(split_state)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Enforce the per-wallet purchase cooldown when the listing is throttled,
+    /// counting the purchase at `now`
+    pub fn check_purchase_cooldown(
+        &self,
+        purchase_counter: Option<&mut PurchaseCounter>,
+        now: i64,
+    ) -> Result<()> {
+        if self.purchase_cooldown > 0 {
+            let purchase_counter = purchase_counter.ok_or(EscrowError::PurchaseCounterRequired)?;
+            purchase_counter.record_purchase(now, self.purchase_cooldown)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Enforce the phase active at `now` for a purchase at `price` and count it against
+    /// the phase supply; listings without phases accept every purchase
+    pub fn record_phase_purchase(
+        &mut self,
+        now: i64,
+        price: u64,
+        buyer: &Pubkey,
+        allowlist_proof: &[[u8; 32]],
+    ) -> Result<()> {
+        if self.phases.is_empty() {
+            return Ok(());
+        }
+        
+        let phase = self
+            .phases
+            .iter_mut()
+            .find(|phase| phase.is_active(now))
+            .ok_or(EscrowError::NoActivePhase)?;
+        
+        require!(phase.price == price, EscrowError::PhasePriceMismatch);
+        require!(
+            phase.supply == 0 || phase.sold < phase.supply,
+            EscrowError::PhaseSoldOut
+        );
+        
+        if !phase.is_public() {
+            require!(
+                merkle::verify(allowlist_proof, &phase.allowlist_root, merkle::leaf(&buyer.to_bytes())),
+                EscrowError::NotAllowlisted
+            );
+        }
+        
+        phase.sold = phase.sold.checked_add(1).ok_or(EscrowError::NumericalOverflow)?;
+        
+        Ok(())
+    }
+}
+
+/// A sale phase of a listing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SalePhase {
+    /// Price during this phase
+    pub price: u64,
+    
+    /// Maximum purchases during this phase (0 = unlimited)
+    pub supply: u32,
+    
+    /// Purchases made during this phase
+    pub sold: u32,
+    
+    /// Merkle root of allowlisted buyers (all zeros = open to everyone)
+    pub allowlist_root: [u8; 32],
+    
+    /// Timestamp when the phase starts
+    pub start_ts: i64,
+    
+    /// Timestamp when the phase ends (exclusive)
+    pub end_ts: i64,
+}
+
+impl SalePhase {
+    /// u64 (8) + u32 (4) + u32 (4) + [u8; 32] (32) + i64 (8) + i64 (8)
+    pub const LEN: usize = 8 + 4 + 4 + 32 + 8 + 8;
+    
+    /// Whether the phase is running at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.start_ts && now < self.end_ts
+    }
+    
+    /// Whether the phase is open to every buyer
+    pub fn is_public(&self) -> bool {
+        self.allowlist_root == [0; 32]
+    }
+}
+
+/// Policy applied to Initialized escrows when the listing price changes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PriceChangePolicy {
+    /// Outstanding escrows keep the price they were created with
+    Grandfather,
+    /// Outstanding escrows can no longer be completed
+    Invalidate,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Milestone State - a commissioned content funded up front and paid out per milestone
+/// The funded lamports are held by the account itself; access mints when the last milestone is released.
+/// The buyer can take back the unreleased funds if a milestone misses its deadline or the
+/// admin upholds their dispute
+#[account]
+pub struct MilestoneState {
+    /// The buyer commissioning the content
+    pub buyer: Pubkey,
+    
+    /// The creator delivering the content
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Seed distinguishing a buyer's commissions for the same content
+    pub seed: u64,
+    
+    /// Milestones in delivery order
+    pub milestones: Vec<Milestone>,
+    
+    /// Index of the milestone currently being worked on (== milestones.len() once complete)
+    pub current: u8,
+    
+    /// Total lamports released to the creator so far
+    pub released_amount: u64,
+    
+    /// Timestamp when the commission was funded
+    pub created_ts: i64,
+    
+    /// Timestamp when work on the current milestone began (funding or the previous release)
+    pub milestone_started_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MilestoneState {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8)
+    /// + Vec length (4) + Milestone * MAX_MILESTONES + u8 (1) + u64 (8) + i64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 4 + Milestone::LEN * Self::MAX_MILESTONES
+        + 1 + 8 + 8 + 8 + 1;
+    
+    /// Maximum number of milestones per commission
+    pub const MAX_MILESTONES: usize = 8;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::MILESTONE_STATE;
+    
+    /// Seconds the creator has to deliver each milestone (30 days)
+    pub const MILESTONE_DEADLINE: i64 = 30 * 24 * 60 * 60;
+    
+    /// Whether every milestone has been released
+    pub fn is_complete(&self) -> bool {
+        self.current as usize >= self.milestones.len()
+    }
+    
+    /// Lamports of the milestones not yet released
+    pub fn unreleased_amount(&self) -> u64 {
+        self.milestones
+            .iter()
+            .skip(self.current as usize)
+            .map(|milestone| milestone.amount)
+            .sum()
+    }
+    
+    /// Whether the current milestone missed its deadline at `now` without a submission
+    /// awaiting the buyer's review
+    pub fn is_overdue(&self, now: i64) -> bool {
+        let Some(milestone) = self.milestones.get(self.current as usize) else {
+            return false;
+        };
+        
+        matches!(milestone.status, MilestoneStatus::Pending | MilestoneStatus::Disputed)
+            && now >= self.milestone_started_ts.saturating_add(Self::MILESTONE_DEADLINE)
+    }
+    
+    /// The milestone currently being worked on
+    pub fn current_milestone(&mut self) -> Result<&mut Milestone> {
+        let index = self.current as usize;
+        self.milestones
+            .get_mut(index)
+            .ok_or_else(|| EscrowError::CommissionComplete.into())
+    }
+}
+
+/// A milestone of a commission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Milestone {
+    /// Lamports released when the milestone is approved
+    pub amount: u64,
+    
+    /// Progress of the milestone
+    pub status: MilestoneStatus,
+}
+
+impl Milestone {
+    /// Serialized size: u64 (8) + MilestoneStatus (1)
+    pub const LEN: usize = 8 + 1;
+}
+
+/// Milestone status enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MilestoneStatus {
+    /// Being worked on by the creator
+    Pending,
+    /// Creator submitted the milestone for review
+    Submitted,
+    /// Buyer approved the submission; funds can be released
+    Approved,
+    /// Funds released to the creator
+    Released,
+    /// Buyer disputed the submission; the creator must resubmit
+    Disputed,
+}
@@ -1,3 +1,65 @@
 pub mod escrow;
+pub mod platform_config;
+pub mod listing;
+pub mod campaign;
+pub mod content_metadata;
+pub mod buyer_vault;
+pub mod creator_badge;
+pub mod upgrade_governance;
+pub mod purchase_counter;
+pub mod content_freeze;
+pub mod denylist_entry;
+pub mod platform;
+pub mod buyer_loyalty;
+pub mod creator_bond;
+pub mod foreign_emitter;
+pub mod attempt_log;
+pub mod receipt_tree;
+pub mod resale_listing;
+pub mod offer;
+pub mod milestone;
+pub mod platform_sale;
+pub mod creator_rebate;
+pub mod queue;
+pub mod mystery_box;
+pub mod pending_access;
+pub mod rating;
+pub mod insurance_pool;
+pub mod billing_agreement;
+pub mod buyer_deposit;
+pub mod payment_channel;
+pub mod referrer_stats;
+pub mod legacy_escrow;
 
 pub use escrow::*;
+pub use platform_config::*;
+pub use listing::*;
+pub use campaign::*;
+pub use content_metadata::*;
+pub use buyer_vault::*;
+pub use creator_badge::*;
+pub use upgrade_governance::*;
+pub use purchase_counter::*;
+pub use content_freeze::*;
+pub use denylist_entry::*;
+pub use platform::*;
+pub use buyer_loyalty::*;
+pub use creator_bond::*;
+pub use foreign_emitter::*;
+pub use attempt_log::*;
+pub use receipt_tree::*;
+pub use resale_listing::*;
+pub use offer::*;
+pub use milestone::*;
+pub use platform_sale::*;
+pub use creator_rebate::*;
+pub use queue::*;
+pub use mystery_box::*;
+pub use pending_access::*;
+pub use rating::*;
+pub use insurance_pool::*;
+pub use billing_agreement::*;
+pub use buyer_deposit::*;
+pub use payment_channel::*;
+pub use referrer_stats::*;
+pub use legacy_escrow::*;
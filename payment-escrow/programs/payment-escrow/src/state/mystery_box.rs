@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// MysteryBox - a set of contents sold at one price, where the content a buyer receives
+/// is chosen by Switchboard randomness after the purchase is committed
+#[account]
+pub struct MysteryBox {
+    /// The creator of every content in the box
+    pub creator: Pubkey,
+    
+    /// Mystery box identifier (32 bytes); box purchases use it as the escrow's content id
+    pub box_id: [u8; 32],
+    
+    /// Price in lamports
+    pub price: u64,
+    
+    /// Content identifiers that can be drawn
+    pub items: Vec<[u8; 32]>,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MysteryBox {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + u64 (8) + Vec length (4)
+    /// + [u8; 32] * MAX_ITEMS + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 4 + 32 * Self::MAX_ITEMS + 1;
+    
+    /// Maximum number of contents in a box
+    pub const MAX_ITEMS: usize = 8;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::MYSTERY_BOX;
+    
+    /// The content selected by a revealed random value
+    pub fn select(&self, value: &[u8; 32]) -> [u8; 32] {
+        self.items[crate::randomness::random_index(value, self.items.len() as u64) as usize]
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Offer - a buyer's bid for access to a content at a proposed price
+/// The offered lamports are held by the offer account itself until accepted or withdrawn
+#[account]
+pub struct Offer {
+    /// The buyer making the offer
+    pub buyer: Pubkey,
+    
+    /// The creator the offer is made to
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Offered amount in lamports
+    pub amount: u64,
+    
+    /// Seed distinguishing a buyer's offers for the same content
+    pub seed: u64,
+    
+    /// Timestamp when the offer was made
+    pub created_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Offer {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8) + u64 (8)
+    /// + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::OFFER;
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// PaymentChannel - a buyer's deposit metering pay-per-view usage of a listing off-chain
+/// The buyer signs vouchers for their cumulative usage; the creator periodically submits
+/// the latest one and the program distributes the amount above what was already settled.
+/// The buyer can close the channel after a grace period that lets the creator settle first
+#[account]
+pub struct PaymentChannel {
+    /// The buyer funding the channel and signing vouchers
+    pub buyer: Pubkey,
+    
+    /// Listing metered
+    pub listing: Pubkey,
+    
+    /// The listing's creator, who submits vouchers
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Lamports deposited into the channel
+    pub deposit: u64,
+    
+    /// Cumulative usage already settled and distributed
+    pub settled_amount: u64,
+    
+    /// Timestamp from which the buyer can close the channel (0 = close not requested)
+    pub close_ready_ts: i64,
+    
+    /// Platform event sequence when the channel was opened, unique to this opening
+    /// Vouchers sign it, so ones signed for an earlier channel at the same PDA don't settle
+    pub nonce: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PaymentChannel {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8)
+    /// + u64 (8) + i64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::PAYMENT_CHANNEL;
+    
+    /// Seconds the creator has to settle after the buyer requests a close (24 hours)
+    pub const CLOSE_GRACE_PERIOD: i64 = 24 * 60 * 60;
+    
+    /// Message a buyer signs to authorize `cumulative_amount` of usage on the opening of
+    /// `channel` with `nonce`
+    pub fn voucher_message(channel: &Pubkey, nonce: u64, cumulative_amount: u64) -> [u8; 48] {
+        let mut message = [0u8; 48];
+        message[..32].copy_from_slice(channel.as_ref());
+        message[32..40].copy_from_slice(&nonce.to_le_bytes());
+        message[40..].copy_from_slice(&cumulative_amount.to_le_bytes());
+        message
+    }
+    
+    /// Record a voucher for `cumulative_amount`, returning the newly owed delta
+    pub fn settle(&mut self, cumulative_amount: u64) -> Result<u64> {
+        require!(
+            cumulative_amount > self.settled_amount && cumulative_amount <= self.deposit,
+            EscrowError::InvalidVoucherAmount
+        );
+        
+        let delta = cumulative_amount - self.settled_amount;
+        self.settled_amount = cumulative_amount;
+        Ok(delta)
+    }
+    
+    /// Deposit not yet settled
+    pub fn unsettled(&self) -> u64 {
+        self.deposit.saturating_sub(self.settled_amount)
+    }
+    
+    /// Whether the buyer can close the channel at `now`
+    pub fn is_close_ready(&self, now: i64) -> bool {
+        self.close_ready_ts != 0 && now >= self.close_ready_ts
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use common::bps::apply_bps;
+use crate::errors::EscrowError;
+
+/// PendingAccess - a paid purchase whose access token is minted by a later `claim_access`
+/// Lets payment settle in one transaction and the mint land in another (e.g. a relayer
+/// submitting a durable nonce transaction the buyer pre-signed). The paid lamports are held
+/// by the account itself until claimed, or refunded (less the listing's cancellation fee)
+/// once the claim window expires
+#[account]
+pub struct PendingAccess {
+    /// The buyer who paid and the only wallet that can claim
+    pub buyer: Pubkey,
+    
+    /// Listing the access was bought from
+    pub listing: Pubkey,
+    
+    /// The listing's creator
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Price paid in lamports
+    pub amount: u64,
+    
+    /// Timestamp when the purchase was paid
+    pub paid_ts: i64,
+    
+    /// Timestamp after which the access can no longer be claimed and the buyer can be refunded
+    pub expiry_ts: i64,
+    
+    /// Listing's cancellation fee when paid, in basis points, kept from an unclaimed refund
+    pub cancellation_fee_bps: u16,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PendingAccess {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8)
+    /// + i64 (8) + i64 (8) + u16 (2) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 2 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::PENDING_ACCESS;
+    
+    /// Seconds a paid purchase can be claimed for (7 days)
+    pub const CLAIM_WINDOW: i64 = 7 * 24 * 60 * 60;
+    
+    /// Whether the claim window has passed at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expiry_ts
+    }
+    
+    /// Fee kept from a refund, at the fee paid under but never above the platform's current maximum
+    pub fn cancellation_fee(&self, max_cancellation_fee_bps: u16) -> Result<u64> {
+        let fee_bps = self.cancellation_fee_bps.min(max_cancellation_fee_bps);
+        apply_bps(self.amount, fee_bps).ok_or_else(|| EscrowError::NumericalOverflow.into())
+    }
+}
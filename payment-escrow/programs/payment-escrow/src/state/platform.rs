@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use distribution_cpi::state::SplitState;
+use crate::errors::EscrowError;
+
+/// Platform - a marketplace integrating the programs with its own treasury and fee
+#[account]
+pub struct Platform {
+    /// Marketplace operator allowed to update the platform
+    pub authority: Pubkey,
+    
+    /// Treasury receiving the platform's fees
+    pub treasury: Pubkey,
+    
+    /// Platform fee in basis points (e.g., 250 = 2.5%)
+    pub fee_bps: u16,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Platform {
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u16 (2) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::PLATFORM;
+    
+    /// Maximum platform fee (matches the distribution program's cap of 10%)
+    pub const MAX_FEE_BPS: u16 = 1000;
+    
+    /// Check that a split pays this platform's treasury at this platform's fee
+    pub fn check_split(&self, split_state: &SplitState) -> Result<()> {
+        require!(
+            split_state.platform_treasury == self.treasury
+                && split_state.platform_fee_bps == self.fee_bps,
+            EscrowError::PlatformMismatch
+        );
+        
+        Ok(())
+    }
+}
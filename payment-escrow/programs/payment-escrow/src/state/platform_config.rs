@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use common::bps::{apply_bps, is_within_tolerance};
+use crate::errors::EscrowError;
+
+/// Platform Config - global settings enforced by the escrow program
+#[account]
+pub struct PlatformConfig {
+    /// Admin allowed to update the platform configuration
+    pub admin: Pubkey,
+    
+    /// Require the creator to co-sign escrow initialization (or a creator-signed listing)
+    pub require_creator_cosign: bool,
+    
+    /// Token-2022 payment mints accepted despite carrying extensions rejected by default
+    pub whitelisted_extension_mints: Vec<Pubkey>,
+    
+    /// Minimum price for SOL sales in lamports (0 = no floor)
+    pub min_price_lamports: u64,
+    
+    /// Minimum prices for SPL payment mints, in token base units
+    pub mint_min_prices: Vec<MintMinPrice>,
+    
+    /// Loyalty points earned per lamport spent, in basis points (0 = no points)
+    pub loyalty_rate_bps: u16,
+    
+    /// Lamports a creator must have staked in their bond to create a listing (0 = no bond required)
+    pub min_creator_bond: u64,
+    
+    /// Authority allowed to settle fiat (card) purchases (default pubkey = fiat settlement disabled)
+    pub settlement_authority: Pubkey,
+    
+    /// Platform token minted to opted-in creators as a platform fee rebate (default pubkey = rebates disabled)
+    pub rebate_mint: Pubkey,
+    
+    /// Share of the platform fee rebated to creators, in basis points
+    pub rebate_bps: u16,
+    
+    /// Sequence number of the last emitted event (0 = none yet)
+    pub event_sequence: u64,
+    
+    /// How far a payment may differ from the amount due, in basis points (0 = exact payment)
+    pub payment_tolerance_bps: u16,
+    
+    /// Accept payments above the amount due and return the excess to the buyer
+    pub refund_overpayment: bool,
+    
+    /// Largest cancellation fee creators may set on their listings, in basis points (0 = no fees)
+    pub max_cancellation_fee_bps: u16,
+    
+    /// Wallets whose purchases and sales skip the platform fee (e.g. the platform's curation account)
+    pub fee_exempt_wallets: Vec<Pubkey>,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + bool (1) + Vec length (4) + Pubkey (32) * MAX_WHITELISTED_MINTS
+    /// + u64 (8) + Vec length (4) + MintMinPrice * MAX_MINT_MIN_PRICES + u16 (2) + u64 (8)
+    /// + Pubkey (32) + Pubkey (32) + u16 (2) + u64 (8) + u16 (2) + bool (1) + u16 (2)
+    /// + Vec length (4) + Pubkey (32) * MAX_FEE_EXEMPT_WALLETS + u8 (1)
+    pub const LEN: usize = 8 + 32 + 1 + 4 + 32 * Self::MAX_WHITELISTED_MINTS
+        + 8 + 4 + MintMinPrice::LEN * Self::MAX_MINT_MIN_PRICES + 2 + 8 + 32 + 32 + 2 + 8 + 2 + 1 + 2
+        + 4 + 32 * Self::MAX_FEE_EXEMPT_WALLETS + 1;
+    
+    /// Maximum number of whitelisted extension mints
+    pub const MAX_WHITELISTED_MINTS: usize = 8;
+    
+    /// Maximum number of per-mint minimum prices
+    pub const MAX_MINT_MIN_PRICES: usize = 8;
+    
+    /// Maximum number of fee-exempt wallets
+    pub const MAX_FEE_EXEMPT_WALLETS: usize = 8;
+    
+    /// Fee waiver PDA seed; the escrow signs distributions with it to waive the platform fee
+    pub const FEE_WAIVER_SEED: &'static [u8] = common::seeds::FEE_WAIVER;
+    
+    /// Access minter PDA seed; flows minting access without an escrow sign with it so
+    /// mints requiring a purchase receipt accept them
+    pub const ACCESS_MINTER_SEED: &'static [u8] = common::seeds::ACCESS_MINTER;
+    
+    /// Largest payment tolerance the admin can configure (1%)
+    pub const MAX_PAYMENT_TOLERANCE_BPS: u16 = 100;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::PLATFORM_CONFIG;
+    
+    /// Advance the event sequence and return the number to stamp into the next event
+    /// Consumers that see a gap between consecutive sequence numbers missed an event
+    pub fn next_event_sequence(&mut self) -> Result<u64> {
+        self.event_sequence = self
+            .event_sequence
+            .checked_add(1)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        
+        Ok(self.event_sequence)
+    }
+    
+    /// Whether `authority` may settle fiat purchases
+    pub fn is_settlement_authority(&self, authority: &Pubkey) -> bool {
+        self.settlement_authority != Pubkey::default() && self.settlement_authority == *authority
+    }
+    
+    /// Rebate owed on a platform fee (0 while rebates are disabled)
+    pub fn rebate_amount(&self, platform_fee: u64) -> Result<u64> {
+        if self.rebate_mint == Pubkey::default() {
+            return Ok(0);
+        }
+        
+        apply_bps(platform_fee, self.rebate_bps).ok_or_else(|| EscrowError::NumericalOverflow.into())
+    }
+    
+    /// Whether the admin has accepted this mint despite its extensions
+    pub fn is_extension_mint_whitelisted(&self, mint: &Pubkey) -> bool {
+        self.whitelisted_extension_mints.contains(mint)
+    }
+    
+    /// Whether purchases by or sales from `wallet` skip the platform fee
+    pub fn is_fee_exempt(&self, wallet: &Pubkey) -> bool {
+        self.fee_exempt_wallets.contains(wallet)
+    }
+    
+    /// Minimum price for sales paid in `payment_token_mint` (None = SOL)
+    pub fn min_price(&self, payment_token_mint: &Option<Pubkey>) -> u64 {
+        match payment_token_mint {
+            None => self.min_price_lamports,
+            Some(mint) => self
+                .mint_min_prices
+                .iter()
+                .find(|entry| entry.mint == *mint)
+                .map_or(0, |entry| entry.min_price),
+        }
+    }
+    
+    /// Reject dust prices below the platform floor; free content (price 0) is exempt
+    pub fn check_price_floor(&self, price: u64, payment_token_mint: &Option<Pubkey>) -> Result<()> {
+        require!(
+            price == 0 || price >= self.min_price(payment_token_mint),
+            EscrowError::PriceBelowFloor
+        );
+        
+        Ok(())
+    }
+    
+    /// Amount to charge a buyer offering `payment_amount` for a purchase costing `amount_due`
+    /// Oracle-priced and discounted purchases can be off by rounding, so payments within
+    /// the tolerance are accepted: a short payment is charged as offered, and the buyer is
+    /// never charged more than is due
+    /// With overpayment refunds on, any payment above the amount due is accepted; the
+    /// caller returns the excess
+    pub fn settle_payment(&self, payment_amount: u64, amount_due: u64) -> Result<u64> {
+        let refunded = self.refund_overpayment && payment_amount > amount_due;
+        let accepted = refunded
+            || is_within_tolerance(payment_amount, amount_due, self.payment_tolerance_bps)
+                .ok_or(EscrowError::NumericalOverflow)?;
+        require!(accepted, EscrowError::InvalidPaymentAmount);
+        
+        if payment_amount > amount_due && !refunded {
+            msg!("Overpayment of {} not charged", payment_amount - amount_due);
+        }
+        
+        Ok(payment_amount.min(amount_due))
+    }
+}
+
+/// Minimum price for one SPL payment mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MintMinPrice {
+    /// Payment token mint
+    pub mint: Pubkey,
+    
+    /// Minimum price in token base units
+    pub min_price: u64,
+}
+
+impl MintMinPrice {
+    /// Pubkey (32) + u64 (8)
+    pub const LEN: usize = 32 + 8;
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use common::bps::apply_bps;
+use crate::errors::EscrowError;
+
+/// Platform Sale - a platform-wide discount window for listings that opted in
+/// When subsidized, the account's own lamports cover the discount so creators are paid in full
+#[account]
+pub struct PlatformSale {
+    /// Discount applied to opted-in listings, in basis points
+    pub discount_bps: u16,
+    
+    /// Timestamp when the sale starts
+    pub start_ts: i64,
+    
+    /// Timestamp when the sale ends
+    pub end_ts: i64,
+    
+    /// Pay the discount to creators out of the sale's subsidy budget
+    pub subsidized: bool,
+    
+    /// Total lamports of subsidy paid out
+    pub subsidy_paid: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformSale {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + u16 (2) + i64 (8) + i64 (8) + bool (1) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 2 + 8 + 8 + 1 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::PLATFORM_SALE;
+    
+    /// Whether the sale window is open at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.discount_bps > 0 && now >= self.start_ts && now < self.end_ts
+    }
+    
+    /// Discount on `price` while the sale is active at `now`
+    pub fn discount(&self, price: u64, now: i64) -> Result<u64> {
+        if !self.is_active(now) {
+            return Ok(0);
+        }
+        
+        apply_bps(price, self.discount_bps).ok_or_else(|| EscrowError::NumericalOverflow.into())
+    }
+    
+    /// Lamports available to subsidize discounts, above the rent-exempt minimum
+    pub fn subsidy_budget(&self, sale_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(sale_info.data_len());
+        Ok(sale_info.lamports().saturating_sub(rent_exempt))
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Purchase Counter - tracks a wallet's purchases of one content for throttling
+#[account]
+pub struct PurchaseCounter {
+    /// Wallet making the purchases
+    pub buyer: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Number of throttled purchases made
+    pub purchases: u32,
+    
+    /// Timestamp of the most recent purchase
+    pub last_purchase_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PurchaseCounter {
+    /// Discriminator (8) + Pubkey (32) + [u8; 32] (32) + u32 (4) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::PURCHASE_COUNTER;
+    
+    /// Record a purchase at `now`, rejecting it if the previous purchase was
+    /// less than `cooldown` seconds ago
+    pub fn record_purchase(&mut self, now: i64, cooldown: i64) -> Result<()> {
+        if self.purchases > 0 {
+            require!(
+                now >= self.last_purchase_ts.saturating_add(cooldown),
+                EscrowError::PurchaseCooldownActive
+            );
+        }
+        
+        self.purchases = self.purchases.checked_add(1).ok_or(EscrowError::NumericalOverflow)?;
+        self.last_purchase_ts = now;
+        
+        Ok(())
+    }
+}
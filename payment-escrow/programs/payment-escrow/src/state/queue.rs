@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+/// QueueState - a priority access raffle for a high-demand drop
+/// Buyers register intents (escrowing the price) until `register_end_ts`; a Switchboard
+/// randomness value then picks `capacity` winners, who are minted access, and the rest are refunded.
+/// A queue not drawn within `DRAW_TIMEOUT` of registration closing refunds every entrant
+#[account]
+pub struct QueueState {
+    /// Listing the queue sells
+    pub listing: Pubkey,
+    
+    /// The listing's creator
+    pub creator: Pubkey,
+    
+    /// Content identifier (32 bytes)
+    pub content_id: [u8; 32],
+    
+    /// Price each entrant escrows, in lamports
+    pub price: u64,
+    
+    /// Number of winners drawn
+    pub capacity: u32,
+    
+    /// Timestamp when registration closes
+    pub register_end_ts: i64,
+    
+    /// Number of registered intents (also the next entry index)
+    pub entrants: u32,
+    
+    /// Switchboard randomness account committed for the draw
+    pub randomness_account: Pubkey,
+    
+    /// Slot the randomness was committed in
+    pub commit_slot: u64,
+    
+    /// Revealed randomness the winners are selected with
+    pub winning_seed: [u8; 32],
+    
+    /// Current status of the queue
+    pub status: QueueStatus,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl QueueState {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + [u8; 32] (32) + u64 (8) + u32 (4)
+    /// + i64 (8) + u32 (4) + Pubkey (32) + u64 (8) + [u8; 32] (32) + QueueStatus (1) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 4 + 8 + 4 + 32 + 8 + 32 + 1 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::QUEUE_STATE;
+    
+    /// Seconds after registration closes for the draw to complete (3 days)
+    pub const DRAW_TIMEOUT: i64 = 3 * 24 * 60 * 60;
+    
+    /// Whether the draw missed its deadline at `now`, leaving every entrant to be refunded
+    /// Covers a creator who never commits and randomness that is never revealed
+    pub fn is_abandoned(&self, now: i64) -> bool {
+        self.status != QueueStatus::Drawn
+            && now >= self.register_end_ts.saturating_add(Self::DRAW_TIMEOUT)
+    }
+    
+    /// Whether the entry registered at `index` won the draw
+    /// Winners are the `capacity` consecutive indices (wrapping) starting at a random offset,
+    /// so exactly `capacity` entries win without storing the winner set
+    pub fn is_winner(&self, index: u32) -> bool {
+        let entrants = self.entrants as u64;
+        if entrants <= self.capacity as u64 {
+            return true;
+        }
+        
+        let offset = crate::randomness::random_index(&self.winning_seed, entrants);
+        let position = (index as u64 + entrants - offset) % entrants;
+        position < self.capacity as u64
+    }
+}
+
+/// QueueEntry - a buyer's registered intent in a queue
+/// The escrowed price is held by the entry account itself until the result is claimed
+#[account]
+pub struct QueueEntry {
+    /// Queue the entry is registered in
+    pub queue: Pubkey,
+    
+    /// The registered buyer
+    pub buyer: Pubkey,
+    
+    /// Registration order within the queue
+    pub index: u32,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl QueueEntry {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u32 (4) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::QUEUE_ENTRY;
+}
+
+/// Queue status enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum QueueStatus {
+    /// Accepting intents until registration closes
+    Registering,
+    /// Randomness committed; waiting for the oracle to reveal it
+    Committed,
+    /// Winners drawn; entrants can claim their result
+    Drawn,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// RatingState - a verified purchaser's 1-5 rating of a listing
+/// Its PDA is derived from the listing and buyer, so each purchaser rates once
+#[account]
+pub struct RatingState {
+    /// Listing rated
+    pub listing: Pubkey,
+    
+    /// Buyer who rated
+    pub buyer: Pubkey,
+    
+    /// Rating from 1 to 5
+    pub rating: u8,
+    
+    /// Timestamp of the rating
+    pub rated_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RatingState {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + u8 (1) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::RATING;
+    
+    /// Lowest allowed rating
+    pub const MIN_RATING: u8 = 1;
+    
+    /// Highest allowed rating
+    pub const MAX_RATING: u8 = 5;
+}
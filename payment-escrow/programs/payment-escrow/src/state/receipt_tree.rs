@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Receipt Tree - a concurrent Merkle tree of compressed purchase receipts
+/// The PDA is the tree authority, so only this program can append to it
+#[account]
+pub struct ReceiptTree {
+    /// Concurrent Merkle tree account
+    pub merkle_tree: Pubkey,
+    
+    /// Leaves appended so far (the next receipt's leaf index)
+    pub num_receipts: u64,
+    
+    /// Maximum leaves the tree can hold (2^max_depth)
+    pub capacity: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReceiptTree {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + u64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+    
+    /// Maximum supported tree depth
+    pub const MAX_DEPTH: u32 = 30;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::RECEIPT_TREE;
+    
+    /// Reserve the next leaf index, failing once the tree is full
+    pub fn next_leaf_index(&mut self) -> Result<u64> {
+        require!(self.num_receipts < self.capacity, EscrowError::ReceiptTreeFull);
+        
+        let index = self.num_receipts;
+        self.num_receipts += 1;
+        
+        Ok(index)
+    }
+}
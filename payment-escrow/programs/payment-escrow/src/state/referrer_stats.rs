@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+
+/// Referrer Stats - purchases attributed to a referrer through buy_and_mint
+/// Recorded whether or not a referral fee is paid, so attribution can be measured on-chain
+#[account]
+pub struct ReferrerStats {
+    /// The referrer credited
+    pub referrer: Pubkey,
+    
+    /// Number of referred purchases
+    pub referred_purchases: u64,
+    
+    /// Lamports paid by referred SOL purchases
+    pub referred_sol_volume: u64,
+    
+    /// Timestamp of the latest referred purchase
+    pub last_referred_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReferrerStats {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + u64 (8) + u64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::REFERRER_STATS;
+    
+    /// Record a referred purchase; `sol_amount` is zero for SPL purchases
+    pub fn record(&mut self, sol_amount: u64, now: i64) -> Result<()> {
+        self.referred_purchases = self
+            .referred_purchases
+            .checked_add(1)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        self.referred_sol_volume = self
+            .referred_sol_volume
+            .checked_add(sol_amount)
+            .ok_or(EscrowError::NumericalOverflow)?;
+        self.last_referred_ts = now;
+        Ok(())
+    }
+}
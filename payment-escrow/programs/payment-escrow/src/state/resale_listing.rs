@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Resale Listing - access tokens a holder has escrowed for sale to another wallet
+/// The PDA owns the escrowed tokens until they are bought or the listing is cancelled
+#[account]
+pub struct ResaleListing {
+    /// Holder selling the access tokens
+    pub seller: Pubkey,
+    
+    /// Primary listing of the content
+    pub listing: Pubkey,
+    
+    /// Access token mint
+    pub access_mint: Pubkey,
+    
+    /// Number of access tokens escrowed
+    pub amount: u64,
+    
+    /// Asking price in lamports
+    pub price: u64,
+    
+    /// Royalty paid to the content's split, snapshotted from the listing when listed
+    pub royalty_bps: u16,
+    
+    /// Timestamp when the tokens were listed
+    pub created_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResaleListing {
+    /// Size calculation for account allocation
+    /// Discriminator (8) + Pubkey (32) + Pubkey (32) + Pubkey (32) + u64 (8) + u64 (8)
+    /// + u16 (2) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 2 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::RESALE_LISTING;
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Upgrade Governance - timelock in front of program upgrades
+/// The PDA is meant to hold the program's BPF upgrade authority, so upgrades
+/// can only happen through `execute_upgrade` after the announced delay
+#[account]
+pub struct UpgradeGovernance {
+    /// Seconds between proposing an upgrade and being able to execute it
+    pub delay: i64,
+    
+    /// Buffer holding the proposed program binary
+    pub proposed_buffer: Option<Pubkey>,
+    
+    /// SHA-256 hash of the proposed program binary
+    pub proposed_hash: [u8; 32],
+    
+    /// Timestamp the current proposal was made
+    pub proposed_ts: i64,
+    
+    /// Earliest timestamp the current proposal can be executed
+    pub executable_ts: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UpgradeGovernance {
+    /// Discriminator (8) + i64 (8) + Option<Pubkey> (1 + 32) + [u8; 32] (32) + i64 (8) + i64 (8) + u8 (1)
+    pub const LEN: usize = 8 + 8 + 33 + 32 + 8 + 8 + 1;
+    
+    /// PDA seed prefix
+    pub const SEED_PREFIX: &'static [u8] = common::seeds::UPGRADE_GOVERNANCE;
+    
+    /// Shortest delay the timelock accepts (48 hours)
+    pub const MIN_DELAY: i64 = 48 * 60 * 60;
+    
+    /// Whether a proposal exists and its delay has elapsed at `now`
+    pub fn is_executable(&self, now: i64) -> bool {
+        self.proposed_buffer.is_some() && now >= self.executable_ts
+    }
+    
+    /// Drop the current proposal
+    pub fn clear_proposal(&mut self) {
+        self.proposed_buffer = None;
+        self.proposed_hash = [0; 32];
+        self.proposed_ts = 0;
+        self.executable_ts = 0;
+    }
+}
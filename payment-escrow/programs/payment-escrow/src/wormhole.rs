@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+/// Wormhole core bridge program; it only writes posted VAAs after verifying guardian signatures
+pub const CORE_BRIDGE_PROGRAM_ID: Pubkey = pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+
+/// Prefix of a posted VAA account written by the core bridge
+pub const POSTED_VAA_MAGIC: &[u8; 3] = b"vaa";
+
+/// Posted VAA header before the sequence: magic (3) + version (1) + consistency (1)
+/// + vaa_time (4) + signature set (32) + submission_time (4) + nonce (4)
+pub const POSTED_VAA_HEADER_LEN: usize = 3 + 1 + 1 + 4 + 32 + 4 + 4;
+
+/// Payload id of a cross-chain access payment
+pub const PAYMENT_PAYLOAD_ID: u8 = 1;
+
+/// Verified VAA fields read from a core bridge posted VAA account
+pub struct PostedVaa {
+    /// Wormhole chain id of the emitter
+    pub emitter_chain: u16,
+    
+    /// Emitter address (left-padded to 32 bytes)
+    pub emitter_address: [u8; 32],
+    
+    /// Emitter sequence number
+    pub sequence: u64,
+    
+    /// Application payload
+    pub payload: Vec<u8>,
+}
+
+impl PostedVaa {
+    /// Read a posted VAA account, which must be owned by the core bridge
+    /// Layout after the header: sequence (8), emitter_chain (2), emitter_address (32),
+    /// payload (4 + len), all little-endian
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require!(*account.owner == CORE_BRIDGE_PROGRAM_ID, EscrowError::InvalidVaa);
+        
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() > POSTED_VAA_HEADER_LEN && &data[..3] == POSTED_VAA_MAGIC,
+            EscrowError::InvalidVaa
+        );
+        
+        let mut body = &data[POSTED_VAA_HEADER_LEN..];
+        let sequence = u64::deserialize(&mut body).map_err(|_| EscrowError::InvalidVaa)?;
+        let emitter_chain = u16::deserialize(&mut body).map_err(|_| EscrowError::InvalidVaa)?;
+        let emitter_address = <[u8; 32]>::deserialize(&mut body).map_err(|_| EscrowError::InvalidVaa)?;
+        let payload = Vec::<u8>::deserialize(&mut body).map_err(|_| EscrowError::InvalidVaa)?;
+        
+        Ok(Self {
+            emitter_chain,
+            emitter_address,
+            sequence,
+            payload,
+        })
+    }
+}
+
+/// Access payment attested by a foreign emitter
+/// Payload: payload_id (1) + listing (32) + price_version (4, big-endian) + access_mint_state (32)
+/// + recipient (32) + amount (8, big-endian)
+pub struct CrosschainPayment {
+    /// Listing the buyer paid for
+    pub listing: Pubkey,
+    
+    /// Listing price version the payment was quoted at
+    pub price_version: u32,
+    
+    /// Access mint state the buyer paid for
+    pub access_mint_state: Pubkey,
+    
+    /// Solana wallet receiving access
+    pub recipient: Pubkey,
+    
+    /// Amount paid on the foreign chain, in the listing's price units
+    pub amount: u64,
+}
+
+impl CrosschainPayment {
+    /// Encoded payload length
+    pub const LEN: usize = 1 + 32 + 4 + 32 + 32 + 8;
+    
+    /// Decode an access payment payload
+    pub fn decode(payload: &[u8]) -> Result<Self> {
+        require!(
+            payload.len() == Self::LEN && payload[0] == PAYMENT_PAYLOAD_ID,
+            EscrowError::InvalidCrosschainPayload
+        );
+        
+        let invalid = |_| EscrowError::InvalidCrosschainPayload;
+        
+        Ok(Self {
+            listing: Pubkey::try_from(&payload[1..33]).map_err(invalid)?,
+            price_version: u32::from_be_bytes(payload[33..37].try_into().map_err(invalid)?),
+            access_mint_state: Pubkey::try_from(&payload[37..69]).map_err(invalid)?,
+            recipient: Pubkey::try_from(&payload[69..101]).map_err(invalid)?,
+            amount: u64::from_be_bytes(payload[101..109].try_into().map_err(invalid)?),
+        })
+    }
+    
+    /// Encode an access payment payload, as the foreign emitter does
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(Self::LEN);
+        payload.push(PAYMENT_PAYLOAD_ID);
+        payload.extend_from_slice(self.listing.as_ref());
+        payload.extend_from_slice(&self.price_version.to_be_bytes());
+        payload.extend_from_slice(self.access_mint_state.as_ref());
+        payload.extend_from_slice(self.recipient.as_ref());
+        payload.extend_from_slice(&self.amount.to_be_bytes());
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn payment() -> CrosschainPayment {
+        CrosschainPayment {
+            listing: Pubkey::new_unique(),
+            price_version: 3,
+            access_mint_state: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            amount: 1_000_000,
+        }
+    }
+    
+    fn posted_vaa_data(payload: &[u8]) -> Vec<u8> {
+        let mut data = POSTED_VAA_MAGIC.to_vec();
+        data.resize(POSTED_VAA_HEADER_LEN, 0);
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        data.extend_from_slice(payload);
+        data
+    }
+    
+    fn load(owner: &Pubkey, data: &mut [u8]) -> Result<PostedVaa> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+        PostedVaa::load(&account)
+    }
+    
+    #[test]
+    fn payment_payload_round_trips() {
+        let payment = payment();
+        let decoded = CrosschainPayment::decode(&payment.encode()).unwrap();
+        assert_eq!(decoded.listing, payment.listing);
+        assert_eq!(decoded.price_version, payment.price_version);
+        assert_eq!(decoded.access_mint_state, payment.access_mint_state);
+        assert_eq!(decoded.recipient, payment.recipient);
+        assert_eq!(decoded.amount, payment.amount);
+    }
+    
+    #[test]
+    fn decode_rejects_malformed_payloads() {
+        let payload = payment().encode();
+        assert!(CrosschainPayment::decode(&payload[..CrosschainPayment::LEN - 1]).is_err());
+        assert!(CrosschainPayment::decode(&[payload.as_slice(), &[0]].concat()).is_err());
+        
+        let mut wrong_id = payload;
+        wrong_id[0] = PAYMENT_PAYLOAD_ID + 1;
+        assert!(CrosschainPayment::decode(&wrong_id).is_err());
+    }
+    
+    #[test]
+    fn load_reads_posted_vaa_fields() {
+        let payload = payment().encode();
+        let vaa = load(&CORE_BRIDGE_PROGRAM_ID, &mut posted_vaa_data(&payload)).unwrap();
+        assert_eq!(vaa.sequence, 42);
+        assert_eq!(vaa.emitter_chain, 2);
+        assert_eq!(vaa.emitter_address, [7; 32]);
+        assert_eq!(vaa.payload, payload);
+    }
+    
+    #[test]
+    fn load_rejects_accounts_not_written_by_the_core_bridge() {
+        let payload = payment().encode();
+        assert!(load(&Pubkey::new_unique(), &mut posted_vaa_data(&payload)).is_err());
+        
+        let mut bad_magic = posted_vaa_data(&payload);
+        bad_magic[0] = b'x';
+        assert!(load(&CORE_BRIDGE_PROGRAM_ID, &mut bad_magic).is_err());
+        
+        let mut truncated = posted_vaa_data(&payload);
+        truncated.truncate(truncated.len() - 1);
+        assert!(load(&CORE_BRIDGE_PROGRAM_ID, &mut truncated).is_err());
+    }
+}
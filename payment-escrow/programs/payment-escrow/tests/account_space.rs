@@ -0,0 +1,91 @@
+//! Account space audits
+//!
+//! Serializes accounts holding their largest possible values and checks them
+//! against their `LEN` constants; unlike the end-to-end tests these need no
+//! compiled programs and run with a plain `cargo test`.
+
+use access_mint_cpi::state::{AccessMintState, AccessTier};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use payment_escrow::state::{EscrowState, EscrowStatus, PaymentSource};
+
+fn serialized_len<T: AccountSerialize>(account: &T) -> usize {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.len()
+}
+
+fn max_size_escrow() -> EscrowState {
+    EscrowState {
+        buyer: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        content_id: [u8::MAX; 32],
+        price: u64::MAX,
+        payment_token_mint: Some(Pubkey::new_unique()),
+        payment_amount: u64::MAX,
+        access_mint_address: Some(Pubkey::new_unique()),
+        listing: Some(Pubkey::new_unique()),
+        price_version: u32::MAX,
+        license_terms_hash: [u8::MAX; 32],
+        memo: Some("m".repeat(EscrowState::MAX_MEMO_LEN)),
+        created_ts: i64::MAX,
+        seed: u64::MAX,
+        status: EscrowStatus::Cancelled,
+        bump: u8::MAX,
+        vault_bump: u8::MAX,
+        payment_decimals: u8::MAX,
+        rent_recipient: Pubkey::new_unique(),
+        payment_source: PaymentSource::Fiat,
+        randomness_account: Some(Pubkey::new_unique()),
+        randomness_commit_slot: u64::MAX,
+        revealed_content_id: Some([u8::MAX; 32]),
+        distributed_ts: i64::MAX,
+    }
+}
+
+fn max_size_access_mint_state() -> AccessMintState {
+    AccessMintState {
+        creator: Pubkey::new_unique(),
+        content_id: [u8::MAX; 32],
+        mint: Pubkey::new_unique(),
+        mint_authority: Pubkey::new_unique(),
+        seed: u64::MAX,
+        total_minted: u64::MAX,
+        max_supply: u64::MAX,
+        units_per_purchase: u64::MAX,
+        tier: AccessTier::Premium,
+        created_ts: i64::MAX,
+        bump: u8::MAX,
+        authority_bump: u8::MAX,
+        require_purchase_receipt: true,
+        collection: Some(Pubkey::new_unique()),
+        collection_verified: true,
+    }
+}
+
+#[test]
+fn escrow_len_fits_max_size_escrow_exactly() {
+    assert_eq!(serialized_len(&max_size_escrow()), EscrowState::LEN);
+}
+
+#[test]
+fn escrow_len_fits_unpaid_escrow() {
+    let escrow = EscrowState {
+        payment_token_mint: None,
+        access_mint_address: None,
+        listing: None,
+        memo: None,
+        randomness_account: None,
+        revealed_content_id: None,
+        ..max_size_escrow()
+    };
+    assert!(serialized_len(&escrow) < EscrowState::LEN);
+}
+
+#[test]
+fn access_mint_state_len_keeps_reserved_headroom() {
+    assert_eq!(
+        serialized_len(&max_size_access_mint_state()) + AccessMintState::RESERVED_SPACE,
+        AccessMintState::LEN
+    );
+}
@@ -0,0 +1,195 @@
+//! Commission refunds
+//!
+//! Pure checks of the milestone deadline run with a plain `cargo test`; the refund
+//! flows need the programs built with `anchor build` and run with `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use payment_escrow::state::{Milestone, MilestoneState, MilestoneStatus};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    signature::Signer,
+};
+use support::*;
+
+const STARTED_TS: i64 = 1_000;
+const AMOUNTS: [u64; 2] = [LAMPORTS_PER_SOL / 2, LAMPORTS_PER_SOL];
+
+fn commission() -> MilestoneState {
+    MilestoneState {
+        buyer: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        content_id: CONTENT_ID,
+        seed: 0,
+        milestones: AMOUNTS
+            .iter()
+            .map(|&amount| Milestone { amount, status: MilestoneStatus::Pending })
+            .collect(),
+        current: 0,
+        released_amount: 0,
+        created_ts: STARTED_TS,
+        milestone_started_ts: STARTED_TS,
+        bump: 255,
+    }
+}
+
+#[test]
+fn milestone_is_overdue_only_past_its_deadline_without_a_pending_submission() {
+    let mut commission = commission();
+    let deadline = STARTED_TS + MilestoneState::MILESTONE_DEADLINE;
+    assert!(!commission.is_overdue(deadline - 1));
+    assert!(commission.is_overdue(deadline));
+
+    // A submission awaiting the buyer's review holds the deadline off
+    for status in [MilestoneStatus::Submitted, MilestoneStatus::Approved] {
+        commission.milestones[0].status = status;
+        assert!(!commission.is_overdue(deadline));
+    }
+    commission.milestones[0].status = MilestoneStatus::Disputed;
+    assert!(commission.is_overdue(deadline));
+}
+
+#[test]
+fn only_unreleased_milestones_are_refundable() {
+    let mut commission = commission();
+    assert_eq!(commission.unreleased_amount(), AMOUNTS[0] + AMOUNTS[1]);
+
+    commission.milestones[0].status = MilestoneStatus::Released;
+    commission.current = 1;
+    assert_eq!(commission.unreleased_amount(), AMOUNTS[1]);
+
+    // A completed commission has nothing left to refund or miss
+    commission.current = 2;
+    assert_eq!(commission.unreleased_amount(), 0);
+    assert!(!commission.is_overdue(i64::MAX));
+}
+
+fn milestone_state_address(buyer: &Pubkey, seed: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[MilestoneState::SEED_PREFIX, buyer.as_ref(), &CONTENT_ID, &seed.to_le_bytes()],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+/// Fund a two-milestone commission from the content's buyer to its creator
+async fn setup_commission(ctx: &mut ProgramTestContext, content: &Content, seed: u64) -> Pubkey {
+    let milestone_state = milestone_state_address(&content.buyer.pubkey(), seed);
+    let initialize = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::InitializeCommission {
+            buyer: content.buyer.pubkey(),
+            milestone_state,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::InitializeCommission {
+            creator: content.creator.pubkey(),
+            content_id: CONTENT_ID,
+            seed,
+            milestone_amounts: AMOUNTS.to_vec(),
+        }
+        .data(),
+    };
+    send(ctx, &[initialize], &[&content.buyer]).await.unwrap();
+    milestone_state
+}
+
+fn refund_commission_ix(
+    content: &Content,
+    milestone_state: Pubkey,
+    admin: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RefundCommission {
+            buyer: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            admin,
+            milestone_state,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RefundCommission {}.data(),
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn overdue_commission_refunds_the_buyer() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let milestone_state = setup_commission(&mut ctx, &content, 70).await;
+
+    // The creator still has time to deliver
+    let refund = refund_commission_ix(&content, milestone_state, None);
+    assert!(send(&mut ctx, std::slice::from_ref(&refund), &[&content.buyer]).await.is_err());
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + MilestoneState::MILESTONE_DEADLINE,
+        ..clock
+    });
+
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    let held = lamports(&mut ctx, &milestone_state).await;
+    send(&mut ctx, &[refund], &[&content.buyer]).await.unwrap();
+
+    // Every milestone was unreleased, so the buyer gets back the full amount and the rent
+    assert!(held > AMOUNTS[0] + AMOUNTS[1]);
+    assert!(ctx.banks_client.get_account(milestone_state).await.unwrap().is_none());
+    assert_eq!(lamports(&mut ctx, &content.buyer.pubkey()).await, buyer_before + held);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn upheld_dispute_refunds_the_buyer_before_the_deadline() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let milestone_state = setup_commission(&mut ctx, &content, 71).await;
+
+    let submit = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SubmitMilestone {
+            creator: content.creator.pubkey(),
+            milestone_state,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SubmitMilestone {}.data(),
+    };
+    let dispute = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::DisputeMilestone {
+            buyer: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            milestone_state,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::DisputeMilestone {}.data(),
+    };
+    send(&mut ctx, &[submit], &[&content.creator]).await.unwrap();
+    send(&mut ctx, &[dispute], &[&content.buyer]).await.unwrap();
+
+    // The buyer's dispute alone doesn't end the commission
+    let refund = refund_commission_ix(&content, milestone_state, None);
+    assert!(send(&mut ctx, &[refund], &[&content.buyer]).await.is_err());
+
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    let held = lamports(&mut ctx, &milestone_state).await;
+    let admin = ctx.payer.pubkey();
+    send(
+        &mut ctx,
+        &[refund_commission_ix(&content, milestone_state, Some(admin))],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    assert!(ctx.banks_client.get_account(milestone_state).await.unwrap().is_none());
+    assert_eq!(lamports(&mut ctx, &content.buyer.pubkey()).await, buyer_before + held);
+}
@@ -0,0 +1,177 @@
+//! Compressing completed purchases into a receipt tree
+//!
+//! A stub stands in for the account compression program, so these tests cover the
+//! escrow side (which purchases compress and what gets closed), not the tree contents.
+//! They need the programs built with `anchor build` and run with `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use payment_escrow::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use payment_escrow::state::{EscrowState, EscrowStatus, ReceiptTree};
+use solana_program_test::{processor, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    signature::Signer,
+};
+use support::*;
+
+const MAX_DEPTH: u32 = 14;
+const MAX_BUFFER_SIZE: u32 = 64;
+
+/// Accepts every tree instruction the escrow program sends
+fn compression_stub(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+async fn start_with_compression() -> ProgramTestContext {
+    let mut program_test = program_test();
+    program_test.add_program(
+        "spl_account_compression",
+        ACCOUNT_COMPRESSION_PROGRAM_ID,
+        processor!(compression_stub),
+    );
+    start_with(program_test).await
+}
+
+/// Allocate a tree account owned by the compression program and register it as a receipt tree
+async fn setup_receipt_tree(ctx: &mut ProgramTestContext, content: &Content) -> (Pubkey, Pubkey) {
+    let merkle_tree = Pubkey::new_unique();
+    ctx.set_account(
+        &merkle_tree,
+        &Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: vec![0; 1024],
+            owner: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+    let (receipt_tree, _) = Pubkey::find_program_address(
+        &[ReceiptTree::SEED_PREFIX, merkle_tree.as_ref()],
+        &payment_escrow::ID,
+    );
+
+    let initialize = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::InitializeReceiptTree {
+            admin: ctx.payer.pubkey(),
+            platform_config: content.platform_config,
+            merkle_tree,
+            receipt_tree,
+            noop_program: NOOP_PROGRAM_ID,
+            compression_program: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::InitializeReceiptTree {
+            max_depth: MAX_DEPTH,
+            max_buffer_size: MAX_BUFFER_SIZE,
+        }
+        .data(),
+    };
+    send(ctx, &[initialize], &[]).await.unwrap();
+    (merkle_tree, receipt_tree)
+}
+
+fn compress_receipt_ix(
+    content: &Content,
+    escrow_state: Pubkey,
+    merkle_tree: Pubkey,
+    receipt_tree: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::CompressReceipt {
+            authority: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            escrow_state,
+            rent_recipient: content.buyer.pubkey(),
+            receipt_tree,
+            merkle_tree,
+            noop_program: NOOP_PROGRAM_ID,
+            compression_program: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::CompressReceipt {}.data(),
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn completed_escrow_compresses_and_closes() {
+    let mut ctx = start_with_compression().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+    let (merkle_tree, receipt_tree) = setup_receipt_tree(&mut ctx, &content).await;
+
+    let seed = 40;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+            buy_and_mint_ix(&content, escrow_state, None, 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    let escrow_rent = lamports(&mut ctx, &escrow_state).await;
+    send(
+        &mut ctx,
+        &[compress_receipt_ix(&content, escrow_state, merkle_tree, receipt_tree)],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // The purchase is now a leaf and the escrow's rent is back with the buyer
+    let tree: ReceiptTree = fetch(&mut ctx, &receipt_tree).await;
+    assert_eq!(tree.num_receipts, 1);
+    assert_eq!(tree.capacity, 1 << MAX_DEPTH);
+    assert!(ctx.banks_client.get_account(escrow_state).await.unwrap().is_none());
+    assert_eq!(lamports(&mut ctx, &content.buyer.pubkey()).await, buyer_before + escrow_rent);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn unfinished_escrow_cannot_be_compressed() {
+    let mut ctx = start_with_compression().await;
+    let content = setup_content(&mut ctx).await;
+    let (merkle_tree, receipt_tree) = setup_receipt_tree(&mut ctx, &content).await;
+
+    let seed = 41;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[initialize_escrow_ix(&content, escrow_state, None, seed)],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // An escrow still awaiting payment isn't a historical record yet
+    let result = send(
+        &mut ctx,
+        &[compress_receipt_ix(&content, escrow_state, merkle_tree, receipt_tree)],
+        &[&content.buyer],
+    )
+    .await;
+    assert!(result.is_err());
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Initialized);
+    let tree: ReceiptTree = fetch(&mut ctx, &receipt_tree).await;
+    assert_eq!(tree.num_receipts, 0);
+}
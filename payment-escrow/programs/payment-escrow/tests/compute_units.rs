@@ -0,0 +1,129 @@
+//! Compute unit budgets for the purchase path
+//!
+//! Each instruction is measured on its own against a cap, and the full
+//! atomic purchase (escrow + payment + mint CPI + distribute CPI) must fit
+//! in the default 200k CU transaction budget. Build the programs with
+//! `anchor build` and run with `cargo test -- --ignored --nocapture`.
+
+mod support;
+
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use support::*;
+
+/// Default per-transaction compute budget
+const TRANSACTION_CU_BUDGET: u64 = 200_000;
+
+const INITIALIZE_ESCROW_CU_CAP: u64 = 25_000;
+const BUY_AND_MINT_CU_CAP: u64 = 150_000;
+const CANCEL_ESCROW_CU_CAP: u64 = 15_000;
+const INITIALIZE_AND_BUY_CU_CAP: u64 = 175_000;
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn sol_purchase_fits_compute_budget() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let seed = 20;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+
+    let initialize = send_measured(
+        &mut ctx,
+        initialize_escrow_ix(&content, escrow_state, None, seed),
+        &[&content.buyer],
+    )
+    .await;
+    let buy = send_measured(
+        &mut ctx,
+        buy_and_mint_ix(&content, escrow_state, None, 0),
+        &[&content.buyer],
+    )
+    .await;
+
+    println!("initialize_escrow: {} CU", initialize);
+    println!("buy_and_mint (SOL): {} CU", buy);
+
+    assert!(initialize <= INITIALIZE_ESCROW_CU_CAP);
+    assert!(buy <= BUY_AND_MINT_CU_CAP);
+    assert!(initialize + buy <= TRANSACTION_CU_BUDGET);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn spl_purchase_fits_compute_budget() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let seed = 21;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let tokens = setup_token_accounts(&mut ctx, &content).await;
+
+    let initialize = send_measured(
+        &mut ctx,
+        initialize_escrow_ix(&content, escrow_state, Some(tokens.payment_mint), seed),
+        &[&content.buyer],
+    )
+    .await;
+    let buy = send_measured(
+        &mut ctx,
+        buy_and_mint_ix(&content, escrow_state, Some(&tokens), 0),
+        &[&content.buyer],
+    )
+    .await;
+
+    println!("initialize_escrow: {} CU", initialize);
+    println!("buy_and_mint (SPL): {} CU", buy);
+
+    assert!(initialize <= INITIALIZE_ESCROW_CU_CAP);
+    assert!(buy <= BUY_AND_MINT_CU_CAP);
+    assert!(initialize + buy <= TRANSACTION_CU_BUDGET);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn cancel_escrow_fits_compute_cap() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let seed = 22;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send_measured(
+        &mut ctx,
+        initialize_escrow_ix(&content, escrow_state, None, seed),
+        &[&content.buyer],
+    )
+    .await;
+
+    let cancel = send_measured(
+        &mut ctx,
+        cancel_escrow_ix(&content, escrow_state),
+        &[&content.buyer],
+    )
+    .await;
+
+    println!("cancel_escrow: {} CU", cancel);
+    assert!(cancel <= CANCEL_ESCROW_CU_CAP);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn initialize_and_buy_fits_compute_budget() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let seed = 23;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+
+    let used = send_measured(
+        &mut ctx,
+        initialize_and_buy_ix(&content, escrow_state, seed, 0),
+        &[&content.buyer],
+    )
+    .await;
+
+    println!("initialize_and_buy (SOL): {} CU", used);
+
+    assert!(used <= INITIALIZE_AND_BUY_CU_CAP);
+}
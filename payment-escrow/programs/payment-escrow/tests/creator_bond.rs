@@ -0,0 +1,154 @@
+//! Recording disputes against a creator's bond and slashing it to compensate buyers
+//!
+//! The tests need the programs built with `anchor build` and run with
+//! `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use payment_escrow::state::{CreatorBond, DisputeRecord};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    signature::Signer,
+};
+use support::*;
+
+const STAKE: u64 = 5 * LAMPORTS_PER_SOL;
+
+fn creator_bond_address(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CreatorBond::SEED_PREFIX, creator.as_ref()], &payment_escrow::ID).0
+}
+
+fn dispute_record_address(escrow_state: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[DisputeRecord::SEED_PREFIX, escrow_state.as_ref()], &payment_escrow::ID).0
+}
+
+fn record_dispute_ix(admin: &Pubkey, content: &Content, escrow_state: Pubkey) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RecordDispute {
+            admin: *admin,
+            platform_config: content.platform_config,
+            creator_bond: creator_bond_address(&content.creator.pubkey()),
+            escrow_state,
+            dispute_record: dispute_record_address(&escrow_state),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RecordDispute {}.data(),
+    }
+}
+
+fn slash_bond_ix(
+    admin: &Pubkey,
+    content: &Content,
+    escrow_state: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SlashBond {
+            admin: *admin,
+            platform_config: content.platform_config,
+            creator_bond: creator_bond_address(&content.creator.pubkey()),
+            dispute_record: dispute_record_address(&escrow_state),
+            recipient,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SlashBond { amount }.data(),
+    }
+}
+
+/// Stake the creator's bond and complete three purchases, returning their escrows
+async fn setup_disputes(ctx: &mut ProgramTestContext, content: &Content) -> Vec<Pubkey> {
+    fund(ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+    let stake = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::StakeBond {
+            creator: content.creator.pubkey(),
+            platform_config: content.platform_config,
+            creator_bond: creator_bond_address(&content.creator.pubkey()),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::StakeBond { amount: STAKE }.data(),
+    };
+    send(ctx, &[stake], &[&content.creator]).await.unwrap();
+
+    let mut escrows = Vec::new();
+    for (distribution_index, seed) in (70..73).enumerate() {
+        let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+        send(
+            ctx,
+            &[
+                initialize_escrow_ix(content, escrow_state, None, seed),
+                buy_and_mint_ix(content, escrow_state, None, distribution_index as u64),
+            ],
+            &[&content.buyer],
+        )
+        .await
+        .unwrap();
+        escrows.push(escrow_state);
+    }
+    escrows
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn each_purchase_counts_as_one_dispute() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let escrows = setup_disputes(&mut ctx, &content).await;
+    let admin = ctx.payer.pubkey();
+
+    let record = record_dispute_ix(&admin, &content, escrows[0]);
+    send(&mut ctx, std::slice::from_ref(&record), &[]).await.unwrap();
+
+    // Recording the same purchase again can't push the creator over the slash threshold
+    assert!(send(&mut ctx, &[record], &[]).await.is_err());
+    let bond: CreatorBond = fetch(&mut ctx, &creator_bond_address(&content.creator.pubkey())).await;
+    assert_eq!(bond.disputes, 1);
+
+    let dispute: DisputeRecord = fetch(&mut ctx, &dispute_record_address(&escrows[0])).await;
+    assert_eq!(dispute.buyer, content.buyer.pubkey());
+    assert_eq!(dispute.payment_amount, PRICE);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn slashes_only_pay_the_disputed_buyer_up_to_their_payment() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let escrows = setup_disputes(&mut ctx, &content).await;
+    let admin = ctx.payer.pubkey();
+    for escrow_state in &escrows {
+        send(&mut ctx, &[record_dispute_ix(&admin, &content, *escrow_state)], &[]).await.unwrap();
+    }
+
+    // The bond can't be paid out to anyone but the buyer of the disputed purchase
+    let stranger = Pubkey::new_unique();
+    let result = send(&mut ctx, &[slash_bond_ix(&admin, &content, escrows[0], stranger, PRICE)], &[]).await;
+    assert!(result.is_err());
+
+    // Nor for more than they paid
+    let buyer = content.buyer.pubkey();
+    let result = send(&mut ctx, &[slash_bond_ix(&admin, &content, escrows[0], buyer, PRICE + 1)], &[]).await;
+    assert!(result.is_err());
+
+    let buyer_before = lamports(&mut ctx, &buyer).await;
+    send(&mut ctx, &[slash_bond_ix(&admin, &content, escrows[0], buyer, PRICE)], &[]).await.unwrap();
+    assert_eq!(lamports(&mut ctx, &buyer).await, buyer_before + PRICE);
+
+    // The dispute is fully compensated, so it can't be slashed again
+    let result = send(&mut ctx, &[slash_bond_ix(&admin, &content, escrows[0], buyer, 1)], &[]).await;
+    assert!(result.is_err());
+
+    let bond: CreatorBond = fetch(&mut ctx, &creator_bond_address(&content.creator.pubkey())).await;
+    assert_eq!(bond.staked, STAKE - PRICE);
+    assert_eq!(bond.slashed_total, PRICE);
+}
@@ -0,0 +1,212 @@
+//! Redeeming access payments attested by Wormhole VAAs from another chain
+//!
+//! Posted VAAs are written directly as core bridge accounts. The tests need the
+//! programs built with `anchor build` and run with `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use payment_escrow::state::{CrosschainRedemption, ForeignEmitter, Listing};
+use payment_escrow::wormhole::{CrosschainPayment, CORE_BRIDGE_PROGRAM_ID, POSTED_VAA_HEADER_LEN, POSTED_VAA_MAGIC};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+use support::*;
+
+const EMITTER_CHAIN: u16 = 2;
+const EMITTER_ADDRESS: [u8; 32] = [9; 32];
+
+fn foreign_emitter_address() -> Pubkey {
+    Pubkey::find_program_address(
+        &[ForeignEmitter::SEED_PREFIX, EMITTER_CHAIN.to_le_bytes().as_ref()],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+fn redemption_address(sequence: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            CrosschainRedemption::SEED_PREFIX,
+            EMITTER_CHAIN.to_le_bytes().as_ref(),
+            EMITTER_ADDRESS.as_ref(),
+            sequence.to_le_bytes().as_ref(),
+        ],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+/// List the content and register the foreign emitter
+async fn setup_crosschain(ctx: &mut ProgramTestContext, content: &Content) {
+    let creator = content.creator.pubkey();
+    send(ctx, &[create_listing_ix(content, &creator, CONTENT_ID)], &[&content.creator]).await.unwrap();
+
+    let register = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RegisterForeignEmitter {
+            admin: ctx.payer.pubkey(),
+            platform_config: content.platform_config,
+            foreign_emitter: foreign_emitter_address(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RegisterForeignEmitter {
+            chain: EMITTER_CHAIN,
+            address: EMITTER_ADDRESS,
+        }
+        .data(),
+    };
+    send(ctx, &[register], &[]).await.unwrap();
+}
+
+/// Write a posted VAA from the registered emitter carrying `payment`
+fn post_vaa(ctx: &mut ProgramTestContext, sequence: u64, payment: &CrosschainPayment) -> Pubkey {
+    let payload = payment.encode();
+    let mut data = POSTED_VAA_MAGIC.to_vec();
+    data.resize(POSTED_VAA_HEADER_LEN, 0);
+    data.extend_from_slice(&sequence.to_le_bytes());
+    data.extend_from_slice(&EMITTER_CHAIN.to_le_bytes());
+    data.extend_from_slice(&EMITTER_ADDRESS);
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(&payload);
+
+    let posted_vaa = Pubkey::new_unique();
+    ctx.set_account(
+        &posted_vaa,
+        &Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: CORE_BRIDGE_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+    posted_vaa
+}
+
+fn payment(content: &Content, recipient: &Pubkey, price_version: u32, amount: u64) -> CrosschainPayment {
+    CrosschainPayment {
+        listing: listing_address(&content.creator.pubkey(), &CONTENT_ID),
+        price_version,
+        access_mint_state: content.access_mint_state,
+        recipient: *recipient,
+        amount,
+    }
+}
+
+fn redeem_ix(payer: &Pubkey, content: &Content, posted_vaa: Pubkey, recipient: &Pubkey, sequence: u64) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RedeemCrosschainPayment {
+            payer: *payer,
+            platform_config: content.platform_config,
+            recipient: *recipient,
+            foreign_emitter: foreign_emitter_address(),
+            posted_vaa,
+            redemption: redemption_address(sequence),
+            content_freeze: content_freeze_address(),
+            listing: listing_address(&content.creator.pubkey(), &CONTENT_ID),
+            access_mint_program: access_mint_cpi::ID,
+            access_mint_state: content.access_mint_state,
+            access_mint: content.access_mint.pubkey(),
+            mint_authority: content.mint_authority,
+            access_minter: access_minter_address(),
+            recipient_access_token_account: get_associated_token_address(
+                recipient,
+                &content.access_mint.pubkey(),
+            ),
+            access_token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RedeemCrosschainPayment {
+            emitter_chain: EMITTER_CHAIN,
+            sequence,
+        }
+        .data(),
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn redeems_a_payment_at_the_listing_price_once() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    setup_crosschain(&mut ctx, &content).await;
+    let recipient = Keypair::new().pubkey();
+    let payer = ctx.payer.pubkey();
+
+    let posted_vaa = post_vaa(&mut ctx, 1, &payment(&content, &recipient, 0, PRICE));
+    let redeem = redeem_ix(&payer, &content, posted_vaa, &recipient, 1);
+    send(&mut ctx, std::slice::from_ref(&redeem), &[]).await.unwrap();
+
+    let recipient_access = get_associated_token_address(&recipient, &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &recipient_access).await, 1);
+    let redemption: CrosschainRedemption = fetch(&mut ctx, &redemption_address(1)).await;
+    assert_eq!(redemption.amount, PRICE);
+
+    // The same VAA can't grant access twice
+    assert!(send(&mut ctx, &[redeem], &[]).await.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn rejects_payments_below_the_listing_price() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    setup_crosschain(&mut ctx, &content).await;
+    let recipient = Keypair::new().pubkey();
+    let payer = ctx.payer.pubkey();
+
+    let posted_vaa = post_vaa(&mut ctx, 1, &payment(&content, &recipient, 0, PRICE - 1));
+    let result = send(&mut ctx, &[redeem_ix(&payer, &content, posted_vaa, &recipient, 1)], &[]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn rejects_payments_quoted_at_a_stale_price() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    setup_crosschain(&mut ctx, &content).await;
+    let recipient = Keypair::new().pubkey();
+    let payer = ctx.payer.pubkey();
+
+    // Paid at the original price, which the creator then raised
+    let posted_vaa = post_vaa(&mut ctx, 1, &payment(&content, &recipient, 0, PRICE));
+    let listing = listing_address(&content.creator.pubkey(), &CONTENT_ID);
+    let update_price = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::UpdateListingPrice {
+            creator: content.creator.pubkey(),
+            listing,
+            platform_config: content.platform_config,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::UpdateListingPrice { new_price: 2 * PRICE }.data(),
+    };
+    send(&mut ctx, &[update_price], &[&content.creator]).await.unwrap();
+
+    let result = send(&mut ctx, &[redeem_ix(&payer, &content, posted_vaa, &recipient, 1)], &[]).await;
+    assert!(result.is_err());
+
+    // Quoting the current version still has to cover the new price
+    let posted_vaa = post_vaa(&mut ctx, 2, &payment(&content, &recipient, 1, PRICE));
+    let result = send(&mut ctx, &[redeem_ix(&payer, &content, posted_vaa, &recipient, 2)], &[]).await;
+    assert!(result.is_err());
+
+    let listing: Listing = fetch(&mut ctx, &listing).await;
+    let posted_vaa = post_vaa(&mut ctx, 3, &payment(&content, &recipient, listing.price_version, listing.price));
+    send(&mut ctx, &[redeem_ix(&payer, &content, posted_vaa, &recipient, 3)], &[]).await.unwrap();
+}
@@ -0,0 +1,124 @@
+//! DAO treasury purchases minting access to a list of member wallets
+//!
+//! A keypair stands in for the governance PDA, which signs the same way through CPI.
+//! The tests need the programs built with `anchor build` and run with
+//! `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use distribution_cpi::state::DistributionRecord;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+use support::*;
+
+fn dao_purchase_ix(content: &Content, treasury: &Pubkey, members: &[Pubkey]) -> Instruction {
+    let mut accounts = payment_escrow::accounts::DaoPurchase {
+        treasury: *treasury,
+        platform_config: content.platform_config,
+        listing: listing_address(&content.creator.pubkey(), &CONTENT_ID),
+        content_freeze: content_freeze_address(),
+        access_mint_program: access_mint_cpi::ID,
+        access_mint_state: content.access_mint_state,
+        access_mint: content.access_mint.pubkey(),
+        mint_authority: content.mint_authority,
+        access_minter: access_minter_address(),
+        access_token_program: spl_token::ID,
+        associated_token_program: spl_associated_token_account::ID,
+        distribution_program: distribution_cpi::ID,
+        split_state: content.split_state,
+        distribution_record: distribution_record_address(&content.split_state, 0),
+        revenue_bucket: revenue_bucket_address(content, &system_program::ID),
+        distribution_vault: content.distribution_vault,
+        creator_payout: content.creator.pubkey(),
+        platform_treasury: content.treasury.pubkey(),
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(content.collaborator.pubkey(), false));
+    for member in members {
+        accounts.push(AccountMeta::new_readonly(*member, false));
+        accounts.push(AccountMeta::new(
+            get_associated_token_address(member, &content.access_mint.pubkey()),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts,
+        data: payment_escrow::instruction::DaoPurchase {
+            member_count: members.len() as u8,
+        }
+        .data(),
+    }
+}
+
+async fn setup_dao(ctx: &mut ProgramTestContext) -> (Content, Keypair) {
+    let content = setup_content(ctx).await;
+    fund(ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+    send(
+        ctx,
+        &[create_listing_ix(&content, &content.creator.pubkey(), CONTENT_ID)],
+        &[&content.creator],
+    )
+    .await
+    .unwrap();
+
+    let treasury = Keypair::new();
+    fund(ctx, &treasury.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    (content, treasury)
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn dao_purchase_mints_access_to_every_member() {
+    let mut ctx = start().await;
+    let (content, treasury) = setup_dao(&mut ctx).await;
+    let members = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    send(
+        &mut ctx,
+        &[dao_purchase_ix(&content, &treasury.pubkey(), &members)],
+        &[&treasury],
+    )
+    .await
+    .unwrap();
+
+    for member in &members {
+        let access_token_account = get_associated_token_address(member, &content.access_mint.pubkey());
+        assert_eq!(token_balance(&mut ctx, &access_token_account).await, 1);
+    }
+
+    // The treasury paid the listing price once per member, distributed in one go
+    let record: DistributionRecord =
+        fetch(&mut ctx, &distribution_record_address(&content.split_state, 0)).await;
+    assert_eq!(record.total_amount, 2 * PRICE);
+    assert_eq!(lamports(&mut ctx, &content.distribution_vault).await, 0);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn dao_purchase_requires_the_treasury_signature() {
+    let mut ctx = start().await;
+    let (content, treasury) = setup_dao(&mut ctx).await;
+    let members = [Pubkey::new_unique()];
+    let treasury_before = lamports(&mut ctx, &treasury.pubkey()).await;
+
+    // Someone else can't spend the treasury by naming it without its signature
+    let mut purchase = dao_purchase_ix(&content, &treasury.pubkey(), &members);
+    purchase.accounts[0].is_signer = false;
+    assert!(send(&mut ctx, &[purchase], &[]).await.is_err());
+
+    assert_eq!(lamports(&mut ctx, &treasury.pubkey()).await, treasury_before);
+    let access_token_account = get_associated_token_address(&members[0], &content.access_mint.pubkey());
+    assert!(ctx.banks_client.get_account(access_token_account).await.unwrap().is_none());
+}
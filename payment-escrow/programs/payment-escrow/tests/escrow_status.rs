@@ -0,0 +1,41 @@
+//! Escrow status transitions
+//!
+//! Pure checks of `EscrowStatus::transition`; unlike the end-to-end tests
+//! these need no compiled programs and run with a plain `cargo test`.
+
+use payment_escrow::errors::EscrowError;
+use payment_escrow::state::EscrowStatus::{self, *};
+
+const ALL: [EscrowStatus; 3] = [Initialized, Completed, Cancelled];
+
+#[test]
+fn initialized_escrow_can_complete_or_cancel() {
+    assert!(Initialized.transition(Completed).unwrap() == Completed);
+    assert!(Initialized.transition(Cancelled).unwrap() == Cancelled);
+}
+
+#[test]
+fn initialized_escrow_cannot_reinitialize() {
+    assert!(Initialized.transition(Initialized).err() == Some(EscrowError::InvalidEscrowStatus.into()));
+}
+
+#[test]
+fn completed_escrow_is_final() {
+    for to in ALL {
+        assert!(Completed.transition(to).err() == Some(EscrowError::EscrowAlreadyCompleted.into()));
+    }
+}
+
+#[test]
+fn cancelled_escrow_is_final() {
+    for to in ALL {
+        assert!(Cancelled.transition(to).err() == Some(EscrowError::EscrowAlreadyCancelled.into()));
+    }
+}
+
+#[test]
+fn no_status_transitions_back_to_initialized() {
+    for from in ALL {
+        assert!(from.transition(Initialized).is_err());
+    }
+}
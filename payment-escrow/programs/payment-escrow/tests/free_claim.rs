@@ -0,0 +1,147 @@
+//! Claiming free (price = 0) listings
+//!
+//! The tests need the programs built with `anchor build` and run with
+//! `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use payment_escrow::state::{EscrowState, EscrowStatus, PriceChangePolicy, PurchaseCounter};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::Signer,
+};
+use support::*;
+
+/// List CONTENT_ID for free, with `purchase_cooldown` limiting claims per wallet
+async fn setup_free_listing(ctx: &mut ProgramTestContext, content: &Content, purchase_cooldown: i64) -> Pubkey {
+    let listing = listing_address(&content.creator.pubkey(), &CONTENT_ID);
+    let mut create_listing = create_listing_ix(content, &content.creator.pubkey(), CONTENT_ID);
+    create_listing.data = payment_escrow::instruction::CreateListing {
+        content_id: CONTENT_ID,
+        price: 0,
+        payment_token_mint: None,
+        price_change_policy: PriceChangePolicy::Grandfather,
+        license_terms_hash: [0; 32],
+    }
+    .data();
+    let set_cooldown = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetPurchaseCooldown {
+            creator: content.creator.pubkey(),
+            listing,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetPurchaseCooldown { purchase_cooldown }.data(),
+    };
+    send(ctx, &[create_listing, set_cooldown], &[&content.creator]).await.unwrap();
+    listing
+}
+
+/// Open a zero-price escrow against the free listing and claim it
+fn free_claim_ixs(content: &Content, listing: Pubkey, escrow_state: Pubkey, seed: u64) -> [Instruction; 2] {
+    let mut initialize = initialize_escrow_ix(content, escrow_state, None, seed);
+    // listing follows content_freeze in InitializeEscrow
+    initialize.accounts[5] = AccountMeta::new_readonly(listing, false);
+    initialize.data = payment_escrow::instruction::InitializeEscrow {
+        content_id: CONTENT_ID,
+        price: 0,
+        payment_token_mint: None,
+        seed,
+        memo: None,
+    }
+    .data();
+
+    let purchase_counter = Pubkey::find_program_address(
+        &[
+            PurchaseCounter::SEED_PREFIX,
+            content.creator.pubkey().as_ref(),
+            &CONTENT_ID,
+            content.buyer.pubkey().as_ref(),
+        ],
+        &payment_escrow::ID,
+    )
+    .0;
+    let mut claim = buy_and_mint_ix(content, escrow_state, None, 0);
+    claim.accounts[8] = AccountMeta::new(listing, false);
+    claim.accounts[12] = AccountMeta::new(purchase_counter, false);
+    claim.data = payment_escrow::instruction::BuyAndMint {
+        payment_amount: 0,
+        license_terms_hash: [0; 32],
+        allowlist_proof: vec![],
+        receipt_uri: None,
+        direct_split: false,
+        referrer: None,
+    }
+    .data();
+
+    [initialize, claim]
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn zero_price_listing_mints_access_without_payment() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let listing = setup_free_listing(&mut ctx, &content, 0).await;
+
+    let creator_before = lamports(&mut ctx, &content.creator.pubkey()).await;
+    let seed = 50;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &free_claim_ixs(&content, listing, escrow_state, seed),
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_amount, 0);
+    let access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &access_token_account).await, 1);
+
+    // Nothing was paid, so nothing was distributed
+    assert_eq!(lamports(&mut ctx, &content.creator.pubkey()).await, creator_before);
+    assert!(ctx
+        .banks_client
+        .get_account(distribution_record_address(&content.split_state, 0))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn second_free_claim_is_rejected() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let listing = setup_free_listing(&mut ctx, &content, i64::MAX).await;
+
+    let seed = 51;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let [initialize, claim] = free_claim_ixs(&content, listing, escrow_state, seed);
+    send(&mut ctx, &[initialize], &[&content.buyer]).await.unwrap();
+    send(&mut ctx, std::slice::from_ref(&claim), &[&content.buyer]).await.unwrap();
+
+    // The completed escrow can't be claimed again
+    assert!(send(&mut ctx, &[claim], &[&content.buyer]).await.is_err());
+
+    // Nor can a fresh escrow get around the listing's per-wallet limit
+    let seed = 52;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let [initialize, claim] = free_claim_ixs(&content, listing, escrow_state, seed);
+    send(&mut ctx, &[initialize], &[&content.buyer]).await.unwrap();
+    assert!(send(&mut ctx, &[claim], &[&content.buyer]).await.is_err());
+
+    let access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &access_token_account).await, 1);
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Initialized);
+}
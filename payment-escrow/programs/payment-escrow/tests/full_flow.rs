@@ -0,0 +1,1329 @@
+//! End-to-end tests running access-mint, distribution and payment-escrow
+//! together under solana-program-test.
+//!
+//! The programs are loaded from their compiled `.so` files, so build all three
+//! workspaces with `anchor build` and run with `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions},
+};
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use distribution_cpi::state::DistributionRecord;
+use payment_escrow::state::{
+    BuyerDeposit, BuyerVault, CampaignState, CampaignStatus, Contribution, EscrowState, EscrowStatus,
+    ContentFreeze, PendingAccess, PlatformConfig, ReferrerStats, UpgradeGovernance,
+};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use support::*;
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn buy_with_sol_mints_access_and_distributes() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let seed = 10;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+
+    // Pre-fund the collaborator so their share does not need to cover rent
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let creator_before = lamports(&mut ctx, &content.creator.pubkey()).await;
+    let collaborator_before = lamports(&mut ctx, &content.collaborator.pubkey()).await;
+
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+            buy_and_mint_ix(&content, escrow_state, None, 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_amount, PRICE);
+    assert_eq!(escrow.access_mint_address, Some(content.access_mint.pubkey()));
+
+    let access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &access_token_account).await, 1);
+
+    let (platform, collaborator, creator) = expected_shares();
+    assert_eq!(lamports(&mut ctx, &content.treasury.pubkey()).await, platform);
+    assert_eq!(
+        lamports(&mut ctx, &content.collaborator.pubkey()).await - collaborator_before,
+        collaborator
+    );
+    assert_eq!(lamports(&mut ctx, &content.creator.pubkey()).await - creator_before, creator);
+    assert_eq!(lamports(&mut ctx, &content.distribution_vault).await, 0);
+
+    // The distribution is snapshotted for auditing
+    let record: DistributionRecord =
+        fetch(&mut ctx, &distribution_record_address(&content.split_state, 0)).await;
+    assert_eq!(record.total_amount, PRICE);
+    assert_eq!(
+        record.payouts.iter().map(|payout| payout.amount).collect::<Vec<_>>(),
+        vec![platform, collaborator, creator]
+    );
+    assert_eq!(record.payouts[2].recipient, content.creator.pubkey());
+
+    let buyer_vault: BuyerVault =
+        fetch(&mut ctx, &buyer_vault_address(&content.buyer.pubkey(), None)).await;
+    assert_eq!(escrow.vault_bump, buyer_vault.bump);
+    assert_eq!(escrow.payment_decimals, common::units::SOL_DECIMALS);
+    assert_eq!(buyer_vault.held_amount, 0);
+    assert_eq!(buyer_vault.total_deposited, PRICE);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn purchases_share_one_buyer_vault() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let vault = buyer_vault_address(&content.buyer.pubkey(), None);
+
+    for (distribution_index, seed) in [13, 14].into_iter().enumerate() {
+        let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+        send(
+            &mut ctx,
+            &[
+                initialize_escrow_ix(&content, escrow_state, None, seed),
+                buy_and_mint_ix(&content, escrow_state, None, distribution_index as u64),
+            ],
+            &[&content.buyer],
+        )
+        .await
+        .unwrap();
+
+        // Each escrow's payment is distributed once, independently of the other
+        let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+        assert!(escrow.distributed_ts > 0);
+    }
+
+    // Only the first purchase pays rent for the vault
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    assert_eq!(lamports(&mut ctx, &vault).await, rent.minimum_balance(BuyerVault::LEN));
+
+    let buyer_vault: BuyerVault = fetch(&mut ctx, &vault).await;
+    assert_eq!(buyer_vault.buyer, content.buyer.pubkey());
+    assert_eq!(buyer_vault.held_amount, 0);
+    assert_eq!(buyer_vault.total_deposited, 2 * PRICE);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn released_buyer_vault_keeps_its_rent_reserve() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let vault = buyer_vault_address(&content.buyer.pubkey(), None);
+    let seed = 15;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+            buy_and_mint_ix(&content, escrow_state, None, 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // Releasing the payment leaves exactly the rent reserve behind
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let reserve = rent.minimum_balance(BuyerVault::LEN);
+    assert_eq!(lamports(&mut ctx, &vault).await, reserve);
+
+    let sweep = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SweepSurplus {
+            authority: content.buyer.pubkey(),
+            buyer: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            buyer_vault: vault,
+            vault_token_account: None,
+            recipient_token_account: None,
+            payment_mint: None,
+            token_program: None,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SweepSurplus {}.data(),
+    };
+
+    // The reserve alone can't be withdrawn
+    assert!(send(&mut ctx, std::slice::from_ref(&sweep), &[&content.buyer]).await.is_err());
+    assert_eq!(lamports(&mut ctx, &vault).await, reserve);
+
+    // Lamports sent on top of it can, but only down to the reserve
+    let surplus = 1_000;
+    fund(&mut ctx, &vault, surplus).await;
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    send(&mut ctx, &[sweep], &[&content.buyer]).await.unwrap();
+    assert_eq!(lamports(&mut ctx, &vault).await, reserve);
+    assert_eq!(lamports(&mut ctx, &content.buyer.pubkey()).await, buyer_before + surplus);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn direct_split_pays_recipients_without_vaults() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let seed = 15;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let creator_before = lamports(&mut ctx, &content.creator.pubkey()).await;
+    let collaborator_before = lamports(&mut ctx, &content.collaborator.pubkey()).await;
+
+    let mut buy_ix = buy_and_mint_ix(&content, escrow_state, None, 0);
+    buy_ix.data = payment_escrow::instruction::BuyAndMint {
+        payment_amount: PRICE,
+        license_terms_hash: [0; 32],
+        allowlist_proof: vec![],
+        receipt_uri: None,
+        direct_split: true,
+        referrer: None,
+    }
+    .data();
+
+    send(
+        &mut ctx,
+        &[initialize_escrow_ix(&content, escrow_state, None, seed), buy_ix],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+
+    let (platform, collaborator, creator) = expected_shares();
+    assert_eq!(lamports(&mut ctx, &content.treasury.pubkey()).await, platform);
+    assert_eq!(
+        lamports(&mut ctx, &content.collaborator.pubkey()).await - collaborator_before,
+        collaborator
+    );
+    assert_eq!(lamports(&mut ctx, &content.creator.pubkey()).await - creator_before, creator);
+
+    // Neither vault is touched and no distribution record is written
+    let buyer_vault: BuyerVault =
+        fetch(&mut ctx, &buyer_vault_address(&content.buyer.pubkey(), None)).await;
+    assert_eq!(buyer_vault.total_deposited, 0);
+    assert_eq!(lamports(&mut ctx, &content.distribution_vault).await, 0);
+    assert_eq!(
+        lamports(&mut ctx, &distribution_record_address(&content.split_state, 0)).await,
+        0
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn buy_with_spl_mints_access_and_distributes() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let seed = 11;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let tokens = setup_token_accounts(&mut ctx, &content).await;
+
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, Some(tokens.payment_mint), seed),
+            buy_and_mint_ix(&content, escrow_state, Some(&tokens), 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+
+    let access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &access_token_account).await, 1);
+
+    let (platform, collaborator, creator) = expected_shares();
+    assert_eq!(token_balance(&mut ctx, &tokens.buyer).await, 0);
+    assert_eq!(token_balance(&mut ctx, &tokens.buyer_vault).await, 0);
+    assert_eq!(token_balance(&mut ctx, &tokens.distribution_vault).await, 0);
+    assert_eq!(token_balance(&mut ctx, &tokens.treasury).await, platform);
+    assert_eq!(token_balance(&mut ctx, &tokens.collaborator).await, collaborator);
+    assert_eq!(token_balance(&mut ctx, &tokens.creator).await, creator);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn initialize_escrow_creates_vault_ata_idempotently() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let tokens = setup_token_accounts(&mut ctx, &content).await;
+
+    // Only the buyer vault's own ATA is accepted
+    let seed = 13;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let vault_ata = tokens.buyer_vault;
+    let tokens = TokenAccounts {
+        buyer_vault: tokens.buyer,
+        ..tokens
+    };
+    let ix = initialize_escrow_with_vault_ata_ix(
+        &content,
+        escrow_state,
+        Some(tokens.payment_mint),
+        seed,
+        Some(&tokens),
+    );
+    assert!(send(&mut ctx, &[ix], &[&content.buyer]).await.is_err());
+
+    let tokens = TokenAccounts {
+        buyer_vault: vault_ata,
+        ..tokens
+    };
+
+    // The vault's ATA already exists, so creating it again is a no-op
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_with_vault_ata_ix(
+                &content,
+                escrow_state,
+                Some(tokens.payment_mint),
+                seed,
+                Some(&tokens),
+            ),
+            buy_and_mint_ix(&content, escrow_state, Some(&tokens), 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn buy_with_transfer_fee_mint_distributes_net_amount() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let seed = 12;
+    let fee_bps = 100;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let tokens = setup_token_2022_accounts(&mut ctx, &content, fee_bps).await;
+
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, Some(tokens.payment_mint), seed),
+            buy_and_mint_ix(&content, escrow_state, Some(&tokens), 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // Every hop withholds the mint's transfer fee, rounded up
+    let net_of_fee = |amount: u64| amount - (amount * fee_bps as u64).div_ceil(10_000);
+    let received = net_of_fee(PRICE);
+    let distributed = net_of_fee(received);
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_amount, PRICE);
+
+    let buyer_vault: BuyerVault = fetch(
+        &mut ctx,
+        &buyer_vault_address(&content.buyer.pubkey(), Some(tokens.payment_mint)),
+    )
+    .await;
+    assert_eq!(buyer_vault.held_amount, 0);
+    assert_eq!(buyer_vault.total_deposited, received);
+
+    let platform = distributed * PLATFORM_FEE_BPS as u64 / 10_000;
+    let collaborator = distributed * COLLABORATOR_SHARE_BPS as u64 / 10_000;
+    let creator = distributed - platform - collaborator;
+    assert_eq!(token_balance(&mut ctx, &tokens.buyer_vault).await, 0);
+    assert_eq!(token_balance(&mut ctx, &tokens.distribution_vault).await, 0);
+    assert_eq!(token_balance(&mut ctx, &tokens.treasury).await, net_of_fee(platform));
+    assert_eq!(token_balance(&mut ctx, &tokens.collaborator).await, net_of_fee(collaborator));
+    assert_eq!(token_balance(&mut ctx, &tokens.creator).await, net_of_fee(creator));
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn buy_with_receipt_mints_soulbound_receipt() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let seed = 13;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let receipt_mint = Keypair::new();
+
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+            buy_and_mint_with_receipt_ix(
+                &content,
+                escrow_state,
+                None,
+                0,
+                Some(receipt_mint.pubkey()),
+            ),
+        ],
+        &[&content.buyer, &receipt_mint],
+    )
+    .await
+    .unwrap();
+
+    let receipt_account = get_associated_token_address_with_program_id(
+        &content.buyer.pubkey(),
+        &receipt_mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+    assert_eq!(token_balance(&mut ctx, &receipt_account).await, 1);
+
+    let mint_account = ctx
+        .banks_client
+        .get_account(receipt_mint.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint.base.supply, 1);
+    assert!(mint.base.mint_authority.is_none());
+    assert!(mint.get_extension::<NonTransferable>().is_ok());
+
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>().unwrap();
+    assert_eq!(metadata.uri, RECEIPT_URI);
+    let field = |key: &str| {
+        metadata
+            .additional_metadata
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.clone())
+    };
+    assert_eq!(field("amount"), Some(PRICE.to_string()));
+    assert_eq!(field("escrow"), Some(escrow_state.to_string()));
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn permanent_delegate_mint_requires_whitelisting() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let payment_mint = create_permanent_delegate_mint(&mut ctx).await;
+
+    let seed = 13;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let initialize = initialize_escrow_ix(&content, escrow_state, Some(payment_mint), seed);
+
+    let result = send(&mut ctx, std::slice::from_ref(&initialize), &[&content.buyer]).await;
+    assert!(result.is_err());
+
+    let admin = ctx.payer.pubkey();
+    let whitelist = set_extension_mint_whitelisted_ix(&content, admin, payment_mint, true);
+    send(&mut ctx, &[whitelist], &[]).await.unwrap();
+
+    send(&mut ctx, &[initialize], &[&content.buyer]).await.unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert_eq!(escrow.payment_token_mint, Some(payment_mint));
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn initialize_and_buy_completes_in_one_instruction() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let seed = 15;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let creator_before = lamports(&mut ctx, &content.creator.pubkey()).await;
+
+    send(
+        &mut ctx,
+        &[initialize_and_buy_ix(&content, escrow_state, seed, 0)],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_amount, PRICE);
+    assert_eq!(escrow.access_mint_address, Some(content.access_mint.pubkey()));
+
+    let access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &access_token_account).await, 1);
+
+    let (platform, _, creator) = expected_shares();
+    assert_eq!(lamports(&mut ctx, &content.treasury.pubkey()).await, platform);
+    assert_eq!(lamports(&mut ctx, &content.creator.pubkey()).await - creator_before, creator);
+
+    // The one-shot path never touches the buyer vault
+    let vault = buyer_vault_address(&content.buyer.pubkey(), None);
+    assert!(ctx.banks_client.get_account(vault).await.unwrap().is_none());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn initialize_and_buy_enforces_strict_mode() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let listing = listing_address(&content.creator.pubkey(), &CONTENT_ID);
+    let create_listing = create_listing_ix(&content, &content.creator.pubkey(), CONTENT_ID);
+    let strict_mode = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetStrictMode {
+            creator: content.creator.pubkey(),
+            listing,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetStrictMode { strict_mode: true }.data(),
+    };
+    send(&mut ctx, &[create_listing, strict_mode], &[&content.creator]).await.unwrap();
+
+    let seed = 24;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let mut buy = initialize_and_buy_ix(&content, escrow_state, seed, 0);
+    buy.accounts[5] = AccountMeta::new(listing, false);
+
+    // Without the instructions sysvar the transaction can't be checked
+    let result = send(&mut ctx, std::slice::from_ref(&buy), &[&content.buyer]).await;
+    assert!(result.is_err());
+
+    // Bundled with another program's instruction the purchase is rejected
+    buy.accounts[11] = AccountMeta::new_readonly(solana_sdk::sysvar::instructions::ID, false);
+    let transfer = system_instruction::transfer(&content.buyer.pubkey(), &content.creator.pubkey(), 1);
+    let result = send(&mut ctx, &[transfer, buy.clone()], &[&content.buyer]).await;
+    assert!(result.is_err());
+
+    send(&mut ctx, &[buy], &[&content.buyer]).await.unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn cancel_escrow_closes_account_and_blocks_purchase() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let seed = 12;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+
+    send(
+        &mut ctx,
+        &[initialize_escrow_ix(&content, escrow_state, None, seed)],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    let escrow_rent = lamports(&mut ctx, &escrow_state).await;
+
+    let cancel = cancel_escrow_ix(&content, escrow_state);
+    send(&mut ctx, &[cancel], &[&content.buyer]).await.unwrap();
+
+    // Escrow rent returns to the buyer and the account is gone
+    assert!(ctx.banks_client.get_account(escrow_state).await.unwrap().is_none());
+    assert_eq!(lamports(&mut ctx, &content.buyer.pubkey()).await, buyer_before + escrow_rent);
+
+    let result = send(
+        &mut ctx,
+        &[buy_and_mint_ix(&content, escrow_state, None, 0)],
+        &[&content.buyer],
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn failed_campaign_refunds_contributor() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let deadline = clock.unix_timestamp + 60;
+    let goal = 5 * LAMPORTS_PER_SOL;
+    // Below the rent-exempt minimum, so only the vault's reserve keeps it alive
+    let contribution_amount = Rent::default().minimum_balance(0) / 2;
+    let seed: u64 = 1;
+
+    let (campaign, _) = Pubkey::find_program_address(
+        &[CampaignState::SEED_PREFIX, content.creator.pubkey().as_ref(), &CONTENT_ID, &seed.to_le_bytes()],
+        &payment_escrow::ID,
+    );
+    let (campaign_vault, _) = Pubkey::find_program_address(
+        &[CampaignState::VAULT_SEED_PREFIX, campaign.as_ref()],
+        &payment_escrow::ID,
+    );
+    let (contribution, _) = Pubkey::find_program_address(
+        &[Contribution::SEED_PREFIX, campaign.as_ref(), content.buyer.pubkey().as_ref()],
+        &payment_escrow::ID,
+    );
+
+    let initialize_campaign = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::InitializeCampaign {
+            creator: content.creator.pubkey(),
+            campaign,
+            vault: campaign_vault,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::InitializeCampaign {
+            content_id: CONTENT_ID,
+            goal,
+            deadline,
+            seed,
+        }
+        .data(),
+    };
+    let contribute = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::Contribute {
+            contributor: content.buyer.pubkey(),
+            campaign,
+            contribution,
+            vault: campaign_vault,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::Contribute { amount: contribution_amount }.data(),
+    };
+    send(&mut ctx, &[initialize_campaign, contribute], &[&content.creator, &content.buyer])
+        .await
+        .unwrap();
+
+    // Move past the deadline without reaching the goal
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: deadline + 1,
+        ..clock
+    });
+
+    let finalize = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::FinalizeCampaign {
+            payer: ctx.payer.pubkey(),
+            campaign,
+            vault: campaign_vault,
+            distribution_program: distribution_cpi::ID,
+            split_state: content.split_state,
+            distribution_record: distribution_record_address(&content.split_state, 0),
+            revenue_bucket: revenue_bucket_address(&content, &system_program::ID),
+            distribution_vault: content.distribution_vault,
+            creator: content.creator.pubkey(),
+            platform_treasury: content.treasury.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::FinalizeCampaign {}.data(),
+    };
+    send(&mut ctx, &[finalize], &[]).await.unwrap();
+
+    let campaign_state: CampaignState = fetch(&mut ctx, &campaign).await;
+    assert!(campaign_state.status == CampaignStatus::Failed);
+
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    let contribution_rent = lamports(&mut ctx, &contribution).await;
+
+    let claim_refund = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::ClaimRefund {
+            contributor: content.buyer.pubkey(),
+            campaign,
+            contribution,
+            vault: campaign_vault,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::ClaimRefund {}.data(),
+    };
+    send(&mut ctx, &[claim_refund], &[&content.buyer]).await.unwrap();
+
+    assert_eq!(
+        lamports(&mut ctx, &content.buyer.pubkey()).await,
+        buyer_before + contribution_amount + contribution_rent
+    );
+    // The rent reserve funded at creation is never refunded
+    assert_eq!(lamports(&mut ctx, &campaign_vault).await, Rent::default().minimum_balance(0));
+    assert!(ctx.banks_client.get_account(contribution).await.unwrap().is_none());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn upgrade_proposal_starts_timelock_and_can_be_cancelled() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let admin = ctx.payer.pubkey();
+    let delay = UpgradeGovernance::MIN_DELAY;
+
+    let (upgrade_governance, _) =
+        Pubkey::find_program_address(&[UpgradeGovernance::SEED_PREFIX], &payment_escrow::ID);
+
+    // Delays below the minimum are rejected
+    let initialize = |delay| Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::InitializeUpgradeGovernance {
+            admin,
+            platform_config: content.platform_config,
+            upgrade_governance,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::InitializeUpgradeGovernance { delay }.data(),
+    };
+    assert!(send(&mut ctx, &[initialize(delay - 1)], &[]).await.is_err());
+    send(&mut ctx, &[initialize(delay)], &[]).await.unwrap();
+
+    let buffer = Pubkey::new_unique();
+    let buffer_hash = [7; 32];
+    let propose = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::ProposeUpgrade {
+            admin,
+            platform_config: content.platform_config,
+            upgrade_governance,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::ProposeUpgrade { buffer, buffer_hash }.data(),
+    };
+    send(&mut ctx, &[propose], &[]).await.unwrap();
+
+    let governance: UpgradeGovernance = fetch(&mut ctx, &upgrade_governance).await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(governance.proposed_buffer, Some(buffer));
+    assert_eq!(governance.proposed_hash, buffer_hash);
+    assert_eq!(governance.executable_ts, governance.proposed_ts + delay);
+    assert!(!governance.is_executable(clock.unix_timestamp));
+
+    let cancel = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::CancelUpgrade {
+            admin,
+            platform_config: content.platform_config,
+            upgrade_governance,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::CancelUpgrade {}.data(),
+    };
+    send(&mut ctx, &[cancel], &[]).await.unwrap();
+
+    let governance: UpgradeGovernance = fetch(&mut ctx, &upgrade_governance).await;
+    assert_eq!(governance.proposed_buffer, None);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn frozen_content_rejects_new_escrows_until_unfrozen() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let admin = ctx.payer.pubkey();
+    let content_freeze = content_freeze_address();
+
+    let freeze = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::FreezeContent {
+            admin,
+            platform_config: content.platform_config,
+            content_freeze,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::FreezeContent {
+            content_id: CONTENT_ID,
+            reason: "DMCA notice 42".to_string(),
+        }
+        .data(),
+    };
+    send(&mut ctx, &[freeze], &[]).await.unwrap();
+
+    let seed = 21;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let initialize = initialize_escrow_ix(&content, escrow_state, None, seed);
+    let result = send(&mut ctx, std::slice::from_ref(&initialize), &[&content.buyer]).await;
+    assert!(result.is_err());
+
+    let unfreeze = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::UnfreezeContent {
+            admin,
+            platform_config: content.platform_config,
+            content_freeze,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::UnfreezeContent {}.data(),
+    };
+    send(&mut ctx, &[unfreeze], &[]).await.unwrap();
+
+    // The takedown record stays behind for transparency
+    let freeze_record: ContentFreeze = fetch(&mut ctx, &content_freeze).await;
+    assert!(!freeze_record.frozen);
+    assert_eq!(freeze_record.reason, "DMCA notice 42");
+
+    send(&mut ctx, &[initialize], &[&content.buyer]).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn denylisted_buyer_cannot_purchase_until_removed() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+    let admin = ctx.payer.pubkey();
+    let denylist_entry = denylist_entry_address(&content.buyer.pubkey());
+
+    let add = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::AddToDenylist {
+            admin,
+            platform_config: content.platform_config,
+            wallet: content.buyer.pubkey(),
+            denylist_entry,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::AddToDenylist {}.data(),
+    };
+    send(&mut ctx, &[add], &[]).await.unwrap();
+
+    let seed = 22;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let buy = initialize_and_buy_ix(&content, escrow_state, seed, 0);
+    let result = send(&mut ctx, std::slice::from_ref(&buy), &[&content.buyer]).await;
+    assert!(result.is_err());
+
+    let remove = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RemoveFromDenylist {
+            admin,
+            platform_config: content.platform_config,
+            denylist_entry,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RemoveFromDenylist {}.data(),
+    };
+    send(&mut ctx, &[remove], &[]).await.unwrap();
+    assert!(ctx.banks_client.get_account(denylist_entry).await.unwrap().is_none());
+
+    send(&mut ctx, &[buy], &[&content.buyer]).await.unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn foreign_listing_cannot_waive_the_denylist() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let admin = ctx.payer.pubkey();
+    let denylist_entry = denylist_entry_address(&content.buyer.pubkey());
+
+    // The escrow is created without a listing, before the buyer is denylisted
+    let seed = 23;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let initialize = initialize_escrow_ix(&content, escrow_state, None, seed);
+    send(&mut ctx, &[initialize], &[&content.buyer]).await.unwrap();
+
+    let add = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::AddToDenylist {
+            admin,
+            platform_config: content.platform_config,
+            wallet: content.buyer.pubkey(),
+            denylist_entry,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::AddToDenylist {}.data(),
+    };
+    send(&mut ctx, &[add], &[]).await.unwrap();
+
+    // An unrelated listing that opts out of the denylist
+    let other_creator = Keypair::new();
+    fund(&mut ctx, &other_creator.pubkey(), LAMPORTS_PER_SOL).await;
+    let other_content_id = [9; 32];
+    let listing = listing_address(&other_creator.pubkey(), &other_content_id);
+    let create_listing = create_listing_ix(&content, &other_creator.pubkey(), other_content_id);
+    let ignore_denylist = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetIgnoreDenylist {
+            creator: other_creator.pubkey(),
+            listing,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetIgnoreDenylist { ignore_denylist: true }.data(),
+    };
+    send(&mut ctx, &[create_listing, ignore_denylist], &[&other_creator]).await.unwrap();
+
+    // Passing that listing alongside the escrow must not let the denylisted buyer through
+    let mut buy = buy_and_mint_ix(&content, escrow_state, None, 0);
+    buy.accounts[8] = AccountMeta::new(listing, false);
+    let result = send(&mut ctx, &[buy], &[&content.buyer]).await;
+    assert!(result.is_err());
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status != EscrowStatus::Completed);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn get_statuses_packs_one_byte_per_escrow() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let pending = escrow_address(&content.buyer.pubkey(), 23);
+    let completed = escrow_address(&content.buyer.pubkey(), 24);
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, pending, None, 23),
+            initialize_escrow_ix(&content, completed, None, 24),
+            buy_and_mint_ix(&content, completed, None, 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let get_statuses = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: [pending, completed, content.buyer.pubkey()]
+            .into_iter()
+            .map(|escrow| AccountMeta::new_readonly(escrow, false))
+            .collect(),
+        data: payment_escrow::instruction::GetStatuses {}.data(),
+    };
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[get_statuses],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        blockhash,
+    );
+    let simulation = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(return_data.program_id, payment_escrow::ID);
+    assert_eq!(
+        return_data.data,
+        [
+            EscrowStatus::Initialized as u8,
+            EscrowStatus::Completed as u8,
+            payment_escrow::instructions::ESCROW_STATUS_UNKNOWN,
+        ]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn overpayment_is_refunded_when_enabled() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let enable_refunds = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetRefundOverpayment {
+            admin: ctx.payer.pubkey(),
+            platform_config: content.platform_config,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetRefundOverpayment { enabled: true }.data(),
+    };
+    send(&mut ctx, &[enable_refunds], &[]).await.unwrap();
+
+    let seed = 25;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let mut buy = buy_and_mint_ix(&content, escrow_state, None, 0);
+    buy.data = payment_escrow::instruction::BuyAndMint {
+        payment_amount: PRICE + 1_000,
+        license_terms_hash: [0; 32],
+        allowlist_proof: vec![],
+        receipt_uri: None,
+        direct_split: false,
+        referrer: None,
+    }
+    .data();
+    send(
+        &mut ctx,
+        &[initialize_escrow_ix(&content, escrow_state, None, seed), buy],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // Only the price stays in the vault and is distributed
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_amount, PRICE);
+
+    let buyer_vault: BuyerVault =
+        fetch(&mut ctx, &buyer_vault_address(&content.buyer.pubkey(), None)).await;
+    assert_eq!(buyer_vault.held_amount, 0);
+    assert_eq!(buyer_vault.total_deposited, PRICE);
+
+    let (platform, _, _) = expected_shares();
+    assert_eq!(lamports(&mut ctx, &content.treasury.pubkey()).await, platform);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn cancel_expired_batch_closes_abandoned_checkouts() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    let escrows = [26, 27].map(|seed| escrow_address(&content.buyer.pubkey(), seed));
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrows[0], None, 26),
+            initialize_escrow_ix(&content, escrows[1], None, 27),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrows[0]).await;
+    let mut accounts = payment_escrow::accounts::CancelExpiredBatch {
+        authority: ctx.payer.pubkey(),
+    }
+    .to_account_metas(None);
+    for escrow_state in escrows {
+        accounts.push(AccountMeta::new(escrow_state, false));
+        accounts.push(AccountMeta::new(escrow.rent_recipient, false));
+    }
+    let cancel = Instruction {
+        program_id: payment_escrow::ID,
+        accounts,
+        data: payment_escrow::instruction::CancelExpiredBatch {}.data(),
+    };
+
+    // Checkouts stay open until they expire
+    assert!(send(&mut ctx, std::slice::from_ref(&cancel), &[]).await.is_err());
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: escrow.created_ts + EscrowState::CHECKOUT_EXPIRY_SECONDS,
+        ..clock
+    });
+
+    let recipient_before = lamports(&mut ctx, &escrow.rent_recipient).await;
+    send(&mut ctx, &[cancel], &[]).await.unwrap();
+
+    for escrow_state in escrows {
+        assert!(ctx.banks_client.get_account(escrow_state).await.unwrap().is_none());
+    }
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        lamports(&mut ctx, &escrow.rent_recipient).await - recipient_before,
+        2 * rent.minimum_balance(EscrowState::LEN)
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn session_key_buys_from_deposit() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let buyer_deposit = buyer_deposit_address(&content.buyer.pubkey(), None);
+    let session_key = Keypair::new();
+    fund(&mut ctx, &session_key.pubkey(), LAMPORTS_PER_SOL / 10).await;
+
+    let fund_deposit = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::FundDeposit {
+            buyer: content.buyer.pubkey(),
+            buyer_deposit,
+            payment_token_mint: system_program::ID,
+            buyer_token_account: system_program::ID,
+            deposit_token_account: system_program::ID,
+            token_program: system_program::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::FundDeposit { amount: 2 * PRICE }.data(),
+    };
+    let set_session_key = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetDepositSessionKey {
+            buyer: content.buyer.pubkey(),
+            buyer_deposit,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetDepositSessionKey {
+            session_key: session_key.pubkey(),
+            expiry_ts: i64::MAX,
+        }
+        .data(),
+    };
+    let seed = 28;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[
+            fund_deposit,
+            set_session_key,
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // Only the session key signs; the buyer's wallet is never asked
+    let mut buy = buy_and_mint_ix(&content, escrow_state, None, 0);
+    buy.accounts[0].is_signer = false;
+    buy.accounts[1] = AccountMeta::new(session_key.pubkey(), true);
+    buy.accounts[3] = AccountMeta::new(buyer_deposit, false);
+    buy.accounts[5] = AccountMeta::new_readonly(session_key.pubkey(), true);
+    send(&mut ctx, &[buy], &[&session_key]).await.unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+
+    let deposit: BuyerDeposit = fetch(&mut ctx, &buyer_deposit).await;
+    assert_eq!(deposit.balance, PRICE);
+
+    let (platform, _, _) = expected_shares();
+    assert_eq!(lamports(&mut ctx, &content.treasury.pubkey()).await, platform);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn referred_purchase_is_attributed_to_referrer() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let referrer = Pubkey::new_unique();
+    let referrer_stats = Pubkey::find_program_address(
+        &[ReferrerStats::SEED_PREFIX, referrer.as_ref()],
+        &payment_escrow::ID,
+    )
+    .0;
+
+    let seed = 29;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let mut buy = buy_and_mint_ix(&content, escrow_state, None, 0);
+    // referrer_stats follows buyer_loyalty in BuyAndMint
+    buy.accounts[19] = AccountMeta::new(referrer_stats, false);
+    buy.data = payment_escrow::instruction::BuyAndMint {
+        payment_amount: PRICE,
+        license_terms_hash: [0; 32],
+        allowlist_proof: vec![],
+        receipt_uri: None,
+        direct_split: false,
+        referrer: Some(referrer),
+    }
+    .data();
+    send(
+        &mut ctx,
+        &[initialize_escrow_ix(&content, escrow_state, None, seed), buy],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // No referral fee is configured, yet the purchase is still attributed
+    let stats: ReferrerStats = fetch(&mut ctx, &referrer_stats).await;
+    assert_eq!(stats.referrer, referrer);
+    assert_eq!(stats.referred_purchases, 1);
+    assert_eq!(stats.referred_sol_volume, PRICE);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn fee_exempt_buyer_skips_platform_fee() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let exempt_buyer = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetFeeExempt {
+            admin: ctx.payer.pubkey(),
+            platform_config: content.platform_config,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetFeeExempt {
+            wallet: content.buyer.pubkey(),
+            exempt: true,
+        }
+        .data(),
+    };
+    send(&mut ctx, &[exempt_buyer], &[]).await.unwrap();
+
+    let creator_before = lamports(&mut ctx, &content.creator.pubkey()).await;
+    let fee_waiver =
+        Pubkey::find_program_address(&[PlatformConfig::FEE_WAIVER_SEED], &payment_escrow::ID).0;
+
+    let seed = 30;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    let mut buy = buy_and_mint_ix(&content, escrow_state, None, 0);
+    // fee_waiver precedes the system program and the collaborator account
+    let fee_waiver_index = buy.accounts.len() - 3;
+    buy.accounts[fee_waiver_index] = AccountMeta::new_readonly(fee_waiver, false);
+    send(
+        &mut ctx,
+        &[initialize_escrow_ix(&content, escrow_state, None, seed), buy],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // The platform share goes to the creator instead of the treasury
+    let (platform, _, creator) = expected_shares();
+    assert_eq!(lamports(&mut ctx, &content.treasury.pubkey()).await, 0);
+    assert_eq!(
+        lamports(&mut ctx, &content.creator.pubkey()).await - creator_before,
+        creator + platform
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn unclaimed_pending_access_refund_pays_cancellation_fee() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+    let admin = ctx.payer.pubkey();
+    let listing = listing_address(&content.creator.pubkey(), &CONTENT_ID);
+    let (pending_access, _) = Pubkey::find_program_address(
+        &[PendingAccess::SEED_PREFIX, content.buyer.pubkey().as_ref(), listing.as_ref()],
+        &payment_escrow::ID,
+    );
+
+    let set_max_fee = |max_cancellation_fee_bps| Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetMaxCancellationFee {
+            admin,
+            platform_config: content.platform_config,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetMaxCancellationFee { max_cancellation_fee_bps }.data(),
+    };
+    let set_fee = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetCancellationFee {
+            creator: content.creator.pubkey(),
+            platform_config: content.platform_config,
+            listing,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetCancellationFee { cancellation_fee_bps: 500 }.data(),
+    };
+    let pay = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::PayForAccess {
+            buyer: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            listing,
+            pending_access,
+            content_freeze: content_freeze_address(),
+            denylist_entry: denylist_entry_address(&content.buyer.pubkey()),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::PayForAccess {}.data(),
+    };
+    send(&mut ctx, &[set_max_fee(1_000)], &[]).await.unwrap();
+    send(
+        &mut ctx,
+        &[create_listing_ix(&content, &content.creator.pubkey(), CONTENT_ID), set_fee],
+        &[&content.creator],
+    )
+    .await
+    .unwrap();
+    send(&mut ctx, &[pay], &[&content.buyer]).await.unwrap();
+
+    // Lowering the platform maximum after payment caps the fee already snapshotted
+    send(&mut ctx, &[set_max_fee(200)], &[]).await.unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + PendingAccess::CLAIM_WINDOW + 1,
+        ..clock
+    });
+
+    let mut refund = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RefundPendingAccess {
+            buyer: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            pending_access,
+            system_program: system_program::ID,
+            distribution_program: Some(distribution_cpi::ID),
+            split_state: Some(content.split_state),
+            distribution_record: Some(distribution_record_address(&content.split_state, 0)),
+            revenue_bucket: Some(revenue_bucket_address(&content, &system_program::ID)),
+            distribution_vault: Some(content.distribution_vault),
+            creator_payout: Some(content.creator.pubkey()),
+            platform_treasury: Some(content.treasury.pubkey()),
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RefundPendingAccess {}.data(),
+    };
+    refund.accounts.push(AccountMeta::new(content.collaborator.pubkey(), false));
+
+    let buyer_before = lamports(&mut ctx, &content.buyer.pubkey()).await;
+    let pending_lamports = lamports(&mut ctx, &pending_access).await;
+    send(&mut ctx, &[refund], &[&content.buyer]).await.unwrap();
+
+    // 2% of the price went through the split; the rest and the account rent came back,
+    // less the distribution accounts the buyer paid for
+    let fee = PRICE * 200 / 10_000;
+    let distribution_record = distribution_record_address(&content.split_state, 0);
+    let record: DistributionRecord = fetch(&mut ctx, &distribution_record).await;
+    assert_eq!(record.total_amount, fee);
+    let distribution_rent = lamports(&mut ctx, &distribution_record).await
+        + lamports(&mut ctx, &revenue_bucket_address(&content, &system_program::ID)).await;
+    assert_eq!(
+        lamports(&mut ctx, &content.buyer.pubkey()).await,
+        buyer_before + pending_lamports - fee - distribution_rent
+    );
+    assert!(ctx.banks_client.get_account(pending_access).await.unwrap().is_none());
+}
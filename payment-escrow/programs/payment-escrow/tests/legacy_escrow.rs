@@ -0,0 +1,160 @@
+//! Migrating escrows still in the layout written before vault consolidation
+//!
+//! The fixture is serialized field by field in that layout; the layout checks are
+//! pure, the end-to-end test needs the programs built with `anchor build` and runs
+//! with `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{
+    AccountDeserialize, AnchorDeserialize, AnchorSerialize, Discriminator, InstructionData, ToAccountMetas,
+};
+use payment_escrow::state::{BuyerVault, EscrowState, EscrowStatus, LegacyEscrowState, PaymentSource};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+use support::*;
+
+const SEED: u64 = 3;
+const CREATED_TS: i64 = 1_690_000_000;
+
+/// Escrow account data as the program wrote it before listings and buyer vaults
+fn baseline_escrow_data(buyer: &Pubkey, creator: &Pubkey, bump: u8) -> Vec<u8> {
+    let mut data = EscrowState::DISCRIMINATOR.to_vec();
+    (
+        *buyer,
+        *creator,
+        CONTENT_ID,
+        PRICE,
+        None::<Pubkey>,
+        PRICE,
+        Some(Pubkey::new_unique()),
+        CREATED_TS,
+        SEED,
+        1u8, // Completed
+        bump,
+    )
+        .serialize(&mut data)
+        .unwrap();
+    // Accounts were allocated at the layout's largest size
+    data.resize(LegacyEscrowState::LEN, 0);
+    data
+}
+
+#[test]
+fn baseline_layout_only_reads_as_legacy_escrow() {
+    let buyer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let data = baseline_escrow_data(&buyer, &creator, 254);
+
+    // The current layout inserted fields before created_ts, so it can't read the fixture
+    assert!(EscrowState::try_deserialize(&mut data.as_slice()).is_err());
+
+    let legacy = LegacyEscrowState::deserialize(&mut &data[8..]).unwrap();
+    assert_eq!(legacy.buyer, buyer);
+    assert_eq!(legacy.created_ts, CREATED_TS);
+    assert_eq!(legacy.seed, SEED);
+    assert!(legacy.status == EscrowStatus::Completed);
+
+    let escrow = legacy.into_escrow_state(252, 9);
+    assert_eq!(escrow.creator, creator);
+    assert_eq!(escrow.price, PRICE);
+    assert_eq!(escrow.listing, None);
+    assert_eq!(escrow.vault_bump, 252);
+    assert_eq!(escrow.rent_recipient, buyer);
+    assert!(escrow.payment_source == PaymentSource::Onchain);
+    // Completed legacy purchases were distributed when bought
+    assert_eq!(escrow.distributed_ts, CREATED_TS);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn migrate_legacy_vault_rewrites_baseline_escrow() {
+    let mut ctx = start().await;
+    let creator = Pubkey::new_unique();
+    let buyer = Keypair::new();
+    fund(&mut ctx, &buyer.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let (escrow_state, bump) = Pubkey::find_program_address(
+        &[EscrowState::SEED_PREFIX, buyer.pubkey().as_ref(), &CONTENT_ID, &SEED.to_le_bytes()],
+        &payment_escrow::ID,
+    );
+    let (legacy_vault, _) = Pubkey::find_program_address(
+        &[LegacyEscrowState::VAULT_SEED_PREFIX, escrow_state.as_ref()],
+        &payment_escrow::ID,
+    );
+    let buyer_vault = buyer_vault_address(&buyer.pubkey(), None);
+
+    // A baseline escrow and its per-escrow vault with lamports stranded in it
+    let data = baseline_escrow_data(&buyer.pubkey(), &creator, bump);
+    ctx.set_account(
+        &escrow_state,
+        &Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: payment_escrow::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+    let stranded = LAMPORTS_PER_SOL / 10;
+    ctx.set_account(
+        &legacy_vault,
+        &Account {
+            lamports: stranded,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let migrate = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::MigrateLegacyVault {
+            buyer: buyer.pubkey(),
+            escrow_state,
+            legacy_vault,
+            payment_mint: None,
+            buyer_vault,
+            legacy_vault_token_account: None,
+            buyer_token_account: None,
+            token_program: None,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::MigrateLegacyVault {}.data(),
+    };
+    let buyer_before = lamports(&mut ctx, &buyer.pubkey()).await;
+    send(&mut ctx, std::slice::from_ref(&migrate), &[&buyer]).await.unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert_eq!(escrow.buyer, buyer.pubkey());
+    assert_eq!(escrow.creator, creator);
+    assert_eq!(escrow.created_ts, CREATED_TS);
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.payment_decimals, 9);
+
+    let vault: BuyerVault = fetch(&mut ctx, &buyer_vault).await;
+    assert_eq!(vault.buyer, buyer.pubkey());
+    assert_eq!(escrow.vault_bump, vault.bump);
+
+    // The stranded lamports came back, less the buyer vault and escrow growth rent
+    let rent = Rent::default();
+    let rent_paid = rent.minimum_balance(BuyerVault::LEN)
+        + rent.minimum_balance(EscrowState::LEN)
+        - rent.minimum_balance(LegacyEscrowState::LEN);
+    assert_eq!(lamports(&mut ctx, &buyer.pubkey()).await, buyer_before + stranded - rent_paid);
+    assert_eq!(lamports(&mut ctx, &legacy_vault).await, 0);
+
+    // Once rewritten the escrow is no longer in the legacy layout
+    assert!(send(&mut ctx, &[migrate], &[&buyer]).await.is_err());
+}
@@ -0,0 +1,236 @@
+//! Payment channel voucher settlement
+//!
+//! Pure checks of `PaymentChannel::settle` run with a plain `cargo test`; the voucher
+//! replay test needs the programs built with `anchor build` and runs with
+//! `cargo test -- --ignored`.
+
+mod support;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use payment_escrow::errors::EscrowError;
+use payment_escrow::state::PaymentChannel;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+use support::*;
+
+const DEPOSIT: u64 = 1_000;
+
+fn channel() -> PaymentChannel {
+    PaymentChannel {
+        buyer: Pubkey::new_unique(),
+        listing: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        content_id: [7; 32],
+        deposit: DEPOSIT,
+        settled_amount: 0,
+        close_ready_ts: 0,
+        nonce: 1,
+        bump: 255,
+    }
+}
+
+#[test]
+fn vouchers_settle_only_the_cumulative_delta() {
+    let mut channel = channel();
+
+    assert_eq!(channel.settle(300).unwrap(), 300);
+    assert_eq!(channel.settle(450).unwrap(), 150);
+    assert_eq!(channel.settled_amount, 450);
+    assert_eq!(channel.unsettled(), DEPOSIT - 450);
+}
+
+#[test]
+fn stale_vouchers_are_rejected() {
+    let mut channel = channel();
+    channel.settle(300).unwrap();
+
+    for cumulative_amount in [300, 299, 0] {
+        assert!(channel.settle(cumulative_amount).err() == Some(EscrowError::InvalidVoucherAmount.into()));
+    }
+    assert_eq!(channel.settled_amount, 300);
+}
+
+#[test]
+fn vouchers_cannot_exceed_the_deposit() {
+    let mut channel = channel();
+
+    assert!(channel.settle(DEPOSIT + 1).err() == Some(EscrowError::InvalidVoucherAmount.into()));
+    assert_eq!(channel.settle(DEPOSIT).unwrap(), DEPOSIT);
+}
+
+#[test]
+fn voucher_message_binds_channel_nonce_and_amount() {
+    let channel = Pubkey::new_unique();
+    let message = PaymentChannel::voucher_message(&channel, 1, 42);
+
+    assert_eq!(&message[..32], channel.as_ref());
+    assert_eq!(&message[32..40], &1u64.to_le_bytes());
+    assert_eq!(&message[40..], &42u64.to_le_bytes());
+    assert_ne!(message, PaymentChannel::voucher_message(&channel, 1, 43));
+    assert_ne!(message, PaymentChannel::voucher_message(&channel, 2, 42));
+    assert_ne!(message, PaymentChannel::voucher_message(&Pubkey::new_unique(), 1, 42));
+}
+
+#[test]
+fn channel_closes_only_after_requested_grace_period() {
+    let mut channel = channel();
+    assert!(!channel.is_close_ready(i64::MAX));
+
+    channel.close_ready_ts = 100;
+    assert!(!channel.is_close_ready(99));
+    assert!(channel.is_close_ready(100));
+}
+
+fn payment_channel_address(buyer: &Pubkey, listing: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PaymentChannel::SEED_PREFIX, buyer.as_ref(), listing.as_ref()],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+fn open_channel_ix(content: &Content, listing: Pubkey, payment_channel: Pubkey) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::OpenPaymentChannel {
+            buyer: content.buyer.pubkey(),
+            platform_config: content.platform_config,
+            listing,
+            payment_channel,
+            content_freeze: content_freeze_address(),
+            denylist_entry: denylist_entry_address(&content.buyer.pubkey()),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::OpenPaymentChannel { deposit: LAMPORTS_PER_SOL }.data(),
+    }
+}
+
+/// The buyer's Ed25519-verified voucher followed by the creator settling it
+fn settle_ixs(
+    content: &Content,
+    payment_channel: Pubkey,
+    nonce: u64,
+    cumulative_amount: u64,
+    record_index: u64,
+) -> [Instruction; 2] {
+    let message = PaymentChannel::voucher_message(&payment_channel, nonce, cumulative_amount);
+    let signature = content.buyer.sign_message(&message);
+    let verify = solana_ed25519_program::new_ed25519_instruction_with_signature(
+        &message,
+        signature.as_array(),
+        &content.buyer.pubkey().to_bytes(),
+    );
+
+    let mut accounts = payment_escrow::accounts::SettlePaymentChannel {
+        creator: content.creator.pubkey(),
+        platform_config: content.platform_config,
+        payment_channel,
+        instructions_sysvar: sysvar_instructions::ID,
+        distribution_program: distribution_cpi::ID,
+        split_state: content.split_state,
+        distribution_record: distribution_record_address(&content.split_state, record_index),
+        revenue_bucket: revenue_bucket_address(content, &system_program::ID),
+        distribution_vault: content.distribution_vault,
+        creator_payout: content.creator.pubkey(),
+        platform_treasury: content.treasury.pubkey(),
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(content.collaborator.pubkey(), false));
+
+    let settle = Instruction {
+        program_id: payment_escrow::ID,
+        accounts,
+        data: payment_escrow::instruction::SettlePaymentChannel { cumulative_amount }.data(),
+    };
+    [verify, settle]
+}
+
+/// Request a close, wait out the grace period and close the channel
+async fn close_channel(
+    ctx: &mut ProgramTestContext,
+    buyer: &Keypair,
+    platform_config: Pubkey,
+    payment_channel: Pubkey,
+) {
+    let request_close = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RequestChannelClose {
+            buyer: buyer.pubkey(),
+            payment_channel,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RequestChannelClose {}.data(),
+    };
+    send(ctx, &[request_close], &[buyer]).await.unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + PaymentChannel::CLOSE_GRACE_PERIOD,
+        ..clock
+    });
+
+    let close = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::ClosePaymentChannel {
+            buyer: buyer.pubkey(),
+            platform_config,
+            payment_channel,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::ClosePaymentChannel {}.data(),
+    };
+    send(ctx, &[close], &[buyer]).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn reopened_channel_rejects_vouchers_from_the_earlier_opening() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    fund(&mut ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+    send(
+        &mut ctx,
+        &[create_listing_ix(&content, &content.creator.pubkey(), CONTENT_ID)],
+        &[&content.creator],
+    )
+    .await
+    .unwrap();
+    let listing = listing_address(&content.creator.pubkey(), &CONTENT_ID);
+    let payment_channel = payment_channel_address(&content.buyer.pubkey(), &listing);
+
+    send(&mut ctx, &[open_channel_ix(&content, listing, payment_channel)], &[&content.buyer])
+        .await
+        .unwrap();
+    let first_nonce = fetch::<PaymentChannel>(&mut ctx, &payment_channel).await.nonce;
+    let old_voucher = settle_ixs(&content, payment_channel, first_nonce, 1_000, 0);
+    send(&mut ctx, &old_voucher, &[&content.creator]).await.unwrap();
+    close_channel(&mut ctx, &content.buyer, content.platform_config, payment_channel).await;
+
+    // The same PDA opens again from zero, with a new nonce
+    send(&mut ctx, &[open_channel_ix(&content, listing, payment_channel)], &[&content.buyer])
+        .await
+        .unwrap();
+    let channel: PaymentChannel = fetch(&mut ctx, &payment_channel).await;
+    assert_ne!(channel.nonce, first_nonce);
+    assert_eq!(channel.settled_amount, 0);
+
+    // The voucher from the first opening no longer verifies
+    let replay = settle_ixs(&content, payment_channel, first_nonce, 1_000, 1);
+    assert!(send(&mut ctx, &replay, &[&content.creator]).await.is_err());
+    assert_eq!(fetch::<PaymentChannel>(&mut ctx, &payment_channel).await.settled_amount, 0);
+
+    // A voucher signed for this opening does
+    let voucher = settle_ixs(&content, payment_channel, channel.nonce, 1_000, 1);
+    send(&mut ctx, &voucher, &[&content.creator]).await.unwrap();
+    assert_eq!(fetch::<PaymentChannel>(&mut ctx, &payment_channel).await.settled_amount, 1_000);
+}
@@ -0,0 +1,41 @@
+//! Initializing the global platform config
+//!
+//! The tests need the programs built with `anchor build` and run with
+//! `cargo test -- --ignored`.
+
+mod support;
+
+use payment_escrow::state::PlatformConfig;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+use support::*;
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn only_the_upgrade_authority_can_initialize_the_platform_config() {
+    let mut ctx = start().await;
+
+    // Whoever calls first after deployment can't claim the admin role
+    let stranger = Keypair::new();
+    fund(&mut ctx, &stranger.pubkey(), LAMPORTS_PER_SOL).await;
+    let result = send(
+        &mut ctx,
+        &[initialize_platform_config_ix(&stranger.pubkey())],
+        &[&stranger],
+    )
+    .await;
+    assert!(result.is_err());
+    assert!(ctx
+        .banks_client
+        .get_account(platform_config_address())
+        .await
+        .unwrap()
+        .is_none());
+
+    let admin = ctx.payer.pubkey();
+    send(&mut ctx, &[initialize_platform_config_ix(&admin)], &[]).await.unwrap();
+    let config: PlatformConfig = fetch(&mut ctx, &platform_config_address()).await;
+    assert_eq!(config.admin, admin);
+}
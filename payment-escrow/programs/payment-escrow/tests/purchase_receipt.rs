@@ -0,0 +1,176 @@
+//! Escrows as purchase receipts for access mints that require one
+//!
+//! The layout checks are pure; the end-to-end test needs the programs built with
+//! `anchor build` and runs with `cargo test -- --ignored`.
+
+mod support;
+
+use access_mint_cpi::receipt::{EscrowReceipt, ESCROW_STATE_DISCRIMINATOR};
+use access_mint_cpi::state::AccessMintState;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{AccountSerialize, AnchorDeserialize, Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use payment_escrow::state::{EscrowState, EscrowStatus, PaymentSource};
+use solana_sdk::{instruction::Instruction, signature::Signer};
+use support::*;
+
+fn completed_escrow() -> EscrowState {
+    EscrowState {
+        buyer: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        content_id: CONTENT_ID,
+        price: PRICE,
+        payment_token_mint: None,
+        payment_amount: PRICE,
+        access_mint_address: None,
+        listing: Some(Pubkey::new_unique()),
+        price_version: 2,
+        license_terms_hash: [3; 32],
+        memo: Some("order-1001".to_string()),
+        created_ts: 1_700_000_000,
+        seed: 9,
+        status: EscrowStatus::Completed,
+        bump: 254,
+        vault_bump: 253,
+        payment_decimals: 9,
+        rent_recipient: Pubkey::new_unique(),
+        payment_source: PaymentSource::Onchain,
+        randomness_account: None,
+        randomness_commit_slot: 0,
+        revealed_content_id: None,
+        distributed_ts: 0,
+    }
+}
+
+#[test]
+fn discriminator_matches_escrow_state() {
+    assert_eq!(ESCROW_STATE_DISCRIMINATOR.as_slice(), EscrowState::DISCRIMINATOR);
+}
+
+#[test]
+fn receipt_reads_escrow_state_prefix() {
+    let escrow = completed_escrow();
+    let mut data = Vec::new();
+    escrow.try_serialize(&mut data).unwrap();
+
+    let receipt = EscrowReceipt::deserialize(&mut &data[8..]).unwrap();
+    assert_eq!(receipt.buyer, escrow.buyer);
+    assert_eq!(receipt.creator, escrow.creator);
+    assert_eq!(receipt.content_id, escrow.content_id);
+    assert_eq!(receipt.seed, escrow.seed);
+    assert_eq!(receipt.status, EscrowReceipt::STATUS_COMPLETED);
+    receipt
+        .check_purchase(&escrow.buyer, &escrow.creator, &CONTENT_ID)
+        .unwrap();
+
+    // Each escrow proves one purchase: once access is recorded it can't mint again
+    let escrow = EscrowState {
+        access_mint_address: Some(Pubkey::new_unique()),
+        ..escrow
+    };
+    let mut data = Vec::new();
+    escrow.try_serialize(&mut data).unwrap();
+    let receipt = EscrowReceipt::deserialize(&mut &data[8..]).unwrap();
+    assert!(receipt
+        .check_purchase(&escrow.buyer, &escrow.creator, &CONTENT_ID)
+        .is_err());
+}
+
+fn mint_access_ix(content: &Content, purchase_receipt: Option<Pubkey>) -> Instruction {
+    Instruction {
+        program_id: access_mint_cpi::ID,
+        accounts: access_mint_cpi::accounts::MintAccess {
+            buyer: content.buyer.pubkey(),
+            payer: content.buyer.pubkey(),
+            access_mint_state: content.access_mint_state,
+            mint: content.access_mint.pubkey(),
+            mint_authority: content.mint_authority,
+            buyer_token_account: get_associated_token_address(
+                &content.buyer.pubkey(),
+                &content.access_mint.pubkey(),
+            ),
+            purchase_receipt,
+            access_minter: None,
+            preview_grant: None,
+            access_collection: None,
+            access_metadata: None,
+            collection_mint: None,
+            collection_metadata: None,
+            collection_master_edition: None,
+            token_metadata_program: None,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: access_mint_cpi::instruction::MintAccess {}.data(),
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn new_mints_only_mint_through_escrow_purchases() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    // Mints require a purchase receipt unless the creator opts out
+    let access_mint_state: AccessMintState = fetch(&mut ctx, &content.access_mint_state).await;
+    assert!(access_mint_state.require_purchase_receipt);
+
+    // Calling the access mint directly without a purchase fails
+    assert!(send(&mut ctx, &[mint_access_ix(&content, None)], &[&content.buyer])
+        .await
+        .is_err());
+
+    let seed = 21;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+            buy_and_mint_ix(&content, escrow_state, None, 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    assert_eq!(escrow.access_mint_address, Some(content.access_mint.pubkey()));
+
+    // The escrow has already been redeemed, so it can't be replayed
+    assert!(send(
+        &mut ctx,
+        &[mint_access_ix(&content, Some(escrow_state))],
+        &[&content.buyer]
+    )
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn receipt_required_mint_accepts_other_escrow_flows() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+
+    // initialize_and_buy mints without presenting an escrow, signing as the access minter
+    let seed = 22;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[initialize_and_buy_ix(&content, escrow_state, seed, 0)],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    let escrow: EscrowState = fetch(&mut ctx, &escrow_state).await;
+    assert!(escrow.status == EscrowStatus::Completed);
+    let buyer_access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &buyer_access_token_account).await, 1);
+}
@@ -0,0 +1,68 @@
+//! Queue winner selection
+//!
+//! Pure checks of `QueueState::is_winner` over drawn queues and of the draw deadline.
+
+use anchor_lang::prelude::Pubkey;
+use payment_escrow::state::{QueueState, QueueStatus};
+
+fn drawn_queue(entrants: u32, capacity: u32, winning_seed: [u8; 32]) -> QueueState {
+    QueueState {
+        listing: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        content_id: [1u8; 32],
+        price: 1_000_000,
+        capacity,
+        register_end_ts: 0,
+        entrants,
+        randomness_account: Pubkey::new_unique(),
+        commit_slot: 0,
+        winning_seed,
+        status: QueueStatus::Drawn,
+        bump: 255,
+    }
+}
+
+fn winners(queue: &QueueState) -> Vec<u32> {
+    (0..queue.entrants).filter(|&index| queue.is_winner(index)).collect()
+}
+
+#[test]
+fn draws_exactly_capacity_winners() {
+    for byte in [0u8, 3, 7, 42, 255] {
+        let queue = drawn_queue(10, 3, [byte; 32]);
+        assert_eq!(winners(&queue).len(), 3);
+    }
+}
+
+#[test]
+fn winners_follow_the_random_offset() {
+    let mut seed = [0u8; 32];
+    seed[0] = 8;
+    
+    // Offset 8 of 10 entrants: the window wraps from index 8 to index 0
+    let queue = drawn_queue(10, 3, seed);
+    assert_eq!(winners(&queue), vec![0, 8, 9]);
+}
+
+#[test]
+fn undersubscribed_queue_lets_everyone_win() {
+    let queue = drawn_queue(4, 5, [9u8; 32]);
+    assert_eq!(winners(&queue), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn undrawn_queue_is_abandoned_after_the_draw_timeout() {
+    let mut queue = drawn_queue(10, 3, [0u8; 32]);
+    queue.register_end_ts = 1_000;
+    let deadline = 1_000 + QueueState::DRAW_TIMEOUT;
+
+    for status in [QueueStatus::Registering, QueueStatus::Committed] {
+        queue.status = status;
+        assert!(!queue.is_abandoned(deadline - 1));
+        assert!(queue.is_abandoned(deadline));
+    }
+
+    // Drawn queues pay out their winners however late they're claimed
+    queue.status = QueueStatus::Drawn;
+    assert!(!queue.is_abandoned(i64::MAX));
+}
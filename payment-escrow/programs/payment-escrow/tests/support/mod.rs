@@ -0,0 +1,868 @@
+//! Shared setup for the program-test suites
+//!
+//! The programs are loaded from their compiled `.so` files, so build all three
+//! workspaces with `anchor build` before running the ignored tests.
+
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use anchor_lang::prelude::{AccountMeta, Pubkey, Rent};
+use anchor_lang::solana_program::bpf_loader_upgradeable::{
+    self, get_program_data_address, UpgradeableLoaderState,
+};
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::system_program;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    spl_associated_token_account,
+};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee, ExtensionType, StateWithExtensions},
+    instruction::initialize_permanent_delegate,
+};
+use access_mint_cpi::state::{AccessMintState, AccessTier};
+use distribution_cpi::state::{Collaborator, DistributionRecord, RevenueBucket, SplitState};
+use payment_escrow::state::{
+    BuyerDeposit, BuyerVault, ContentFreeze, CreatorRebate, DenylistEntry, EscrowState, Listing, PlatformConfig,
+    PriceChangePolicy,
+};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader,
+    clock::Clock,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+pub const CONTENT_ID: [u8; 32] = [7; 32];
+pub const PRICE: u64 = LAMPORTS_PER_SOL;
+pub const PLATFORM_FEE_BPS: u16 = 500;
+pub const COLLABORATOR_SHARE_BPS: u16 = 2000;
+pub const RECEIPT_URI: &str = "https://example.com/receipts/7.json";
+
+/// Read a program from its workspace's `anchor build` output
+fn read_program(workspace: &str, name: &str) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../..")
+        .join(workspace)
+        .join("target/deploy")
+        .join(format!("{}.so", name));
+    std::fs::read(&path).unwrap_or_else(|_| {
+        panic!("{} not found, run `anchor build` in {}", path.display(), workspace)
+    })
+}
+
+/// Load a program from its workspace's `anchor build` output
+pub fn add_program(program_test: &mut ProgramTest, program_id: Pubkey, workspace: &str, name: &str) {
+    let data = read_program(workspace, name);
+
+    program_test.add_account(
+        program_id,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()).max(1),
+            data,
+            owner: bpf_loader::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Load a program through the upgradeable loader, so it has a program data account
+/// recording an upgrade authority (set to the payer by `start_with`)
+pub fn add_upgradeable_program(program_test: &mut ProgramTest, program_id: Pubkey, workspace: &str, name: &str) {
+    let data = read_program(workspace, name);
+    let rent = Rent::default();
+    let programdata_address = get_program_data_address(&program_id);
+
+    let mut program_data = vec![0; UpgradeableLoaderState::size_of_programdata_metadata()];
+    program_data[..4].copy_from_slice(&3u32.to_le_bytes());
+    program_data.extend_from_slice(&data);
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: rent.minimum_balance(program_data.len()),
+            data: program_data,
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut program = 2u32.to_le_bytes().to_vec();
+    program.extend_from_slice(programdata_address.as_ref());
+    program_test.add_account(
+        program_id,
+        Account {
+            lamports: rent.minimum_balance(program.len()),
+            data: program,
+            owner: bpf_loader_upgradeable::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Program test with the access mint, distribution and payment escrow programs loaded
+pub fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    add_program(&mut program_test, access_mint_cpi::ID, "access-mint", "access_mint");
+    add_program(&mut program_test, distribution_cpi::ID, "distribution", "distribution");
+    add_upgradeable_program(&mut program_test, payment_escrow::ID, "payment-escrow", "payment_escrow");
+    program_test
+}
+
+pub async fn start() -> ProgramTestContext {
+    start_with(program_test()).await
+}
+
+/// Start `program_test` with the payer as payment escrow's upgrade authority,
+/// which initialize_platform_config requires of the admin
+pub async fn start_with(program_test: ProgramTest) -> ProgramTestContext {
+    let mut ctx = program_test.start_with_context().await;
+    let programdata_address = program_data_address();
+    let mut program_data = ctx.banks_client.get_account(programdata_address).await.unwrap().unwrap();
+    // ProgramData metadata: tag (4), slot (8), then the optional authority (1 + 32)
+    program_data.data[12] = 1;
+    program_data.data[13..45].copy_from_slice(ctx.payer.pubkey().as_ref());
+    ctx.set_account(&programdata_address, &program_data.into());
+    ctx
+}
+
+pub fn program_data_address() -> Pubkey {
+    get_program_data_address(&payment_escrow::ID)
+}
+
+pub async fn send(
+    ctx: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await
+}
+
+/// Send a single instruction and return the compute units it consumed
+pub async fn send_measured(
+    ctx: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> u64 {
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    let outcome = ctx.banks_client.process_transaction_with_metadata(tx).await.unwrap();
+    outcome.result.unwrap();
+    outcome.metadata.unwrap().compute_units_consumed
+}
+
+pub async fn fund(ctx: &mut ProgramTestContext, to: &Pubkey, lamports: u64) {
+    let ix = system_instruction::transfer(&ctx.payer.pubkey(), to, lamports);
+    send(ctx, &[ix], &[]).await.unwrap();
+}
+
+pub async fn lamports(ctx: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    ctx.banks_client.get_balance(*address).await.unwrap()
+}
+
+pub async fn token_balance(ctx: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    let account = ctx.banks_client.get_account(*address).await.unwrap().unwrap();
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+pub async fn fetch<T: AccountDeserialize>(ctx: &mut ProgramTestContext, address: &Pubkey) -> T {
+    let account = ctx.banks_client.get_account(*address).await.unwrap().unwrap();
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+/// Participants and program accounts for one piece of content
+pub struct Content {
+    pub creator: Keypair,
+    pub buyer: Keypair,
+    pub collaborator: Keypair,
+    pub treasury: Keypair,
+    pub access_mint: Keypair,
+    pub access_mint_state: Pubkey,
+    pub mint_authority: Pubkey,
+    pub split_state: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub platform_config: Pubkey,
+    /// Revenue reporting period (yyyymm) at setup time
+    pub revenue_period: u32,
+}
+
+/// Initialize the platform config with `admin`, which must be payment escrow's upgrade authority
+pub fn initialize_platform_config_ix(admin: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::InitializePlatformConfig {
+            admin: *admin,
+            platform_config: platform_config_address(),
+            program: payment_escrow::ID,
+            program_data: program_data_address(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::InitializePlatformConfig {
+            require_creator_cosign: false,
+        }
+        .data(),
+    }
+}
+
+/// Initialize the platform config, access mint and revenue split for CONTENT_ID
+pub async fn setup_content(ctx: &mut ProgramTestContext) -> Content {
+    let creator = Keypair::new();
+    let buyer = Keypair::new();
+    let collaborator = Keypair::new();
+    let treasury = Keypair::new();
+    let access_mint = Keypair::new();
+
+    fund(ctx, &creator.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    fund(ctx, &buyer.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    let seed: u64 = 1;
+    let (access_mint_state, _) = Pubkey::find_program_address(
+        &[AccessMintState::SEED_PREFIX, creator.pubkey().as_ref(), &CONTENT_ID, &seed.to_le_bytes()],
+        &access_mint_cpi::ID,
+    );
+    let (mint_authority, _) = Pubkey::find_program_address(
+        &[AccessMintState::AUTHORITY_SEED_PREFIX, creator.pubkey().as_ref(), &CONTENT_ID, &seed.to_le_bytes()],
+        &access_mint_cpi::ID,
+    );
+    let (split_state, _) = Pubkey::find_program_address(
+        &[SplitState::SEED_PREFIX, creator.pubkey().as_ref(), &CONTENT_ID, &seed.to_le_bytes()],
+        &distribution_cpi::ID,
+    );
+    let (distribution_vault, _) =
+        Pubkey::find_program_address(&[b"vault", split_state.as_ref()], &distribution_cpi::ID);
+    let (platform_config, _) =
+        Pubkey::find_program_address(&[PlatformConfig::SEED_PREFIX], &payment_escrow::ID);
+
+    let initialize_config = initialize_platform_config_ix(&ctx.payer.pubkey());
+
+    let initialize_mint = Instruction {
+        program_id: access_mint_cpi::ID,
+        accounts: access_mint_cpi::accounts::InitializeMint {
+            creator: creator.pubkey(),
+            access_mint_state,
+            mint: access_mint.pubkey(),
+            mint_authority,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: solana_sdk::sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: access_mint_cpi::instruction::InitializeMint {
+            content_id: CONTENT_ID,
+            seed,
+            tier: AccessTier::Basic,
+            decimals: 0,
+            units_per_purchase: 1,
+        }
+        .data(),
+    };
+
+    let initialize_split = Instruction {
+        program_id: distribution_cpi::ID,
+        accounts: distribution_cpi::accounts::InitializeSplit {
+            creator: creator.pubkey(),
+            platform_treasury: treasury.pubkey(),
+            split_state,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: distribution_cpi::instruction::InitializeSplit {
+            content_id: CONTENT_ID,
+            platform_fee_bps: PLATFORM_FEE_BPS,
+            collaborators: vec![Collaborator {
+                pubkey: collaborator.pubkey(),
+                share_bps: COLLABORATOR_SHARE_BPS,
+                accepted: false,
+            }],
+            seed,
+        }
+        .data(),
+    };
+
+    // The collaborator's share only activates once they accept the invite
+    let accept_collaboration = Instruction {
+        program_id: distribution_cpi::ID,
+        accounts: distribution_cpi::accounts::AcceptCollaboration {
+            collaborator: collaborator.pubkey(),
+            split_state,
+        }
+        .to_account_metas(None),
+        data: distribution_cpi::instruction::AcceptCollaboration {}.data(),
+    };
+
+    send(
+        ctx,
+        &[initialize_config, initialize_mint, initialize_split, accept_collaboration],
+        &[&creator, &access_mint, &collaborator],
+    )
+    .await
+    .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let revenue_period = RevenueBucket::period_of(clock.unix_timestamp);
+
+    Content {
+        creator,
+        buyer,
+        collaborator,
+        treasury,
+        access_mint,
+        access_mint_state,
+        mint_authority,
+        split_state,
+        distribution_vault,
+        platform_config,
+        revenue_period,
+    }
+}
+
+pub fn escrow_address(buyer: &Pubkey, seed: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[EscrowState::SEED_PREFIX, buyer.as_ref(), &CONTENT_ID, &seed.to_le_bytes()],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+/// Audit record written by the split's `index`-th distribution
+pub fn distribution_record_address(split_state: &Pubkey, index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[DistributionRecord::SEED_PREFIX, split_state.as_ref(), &index.to_le_bytes()],
+        &distribution_cpi::ID,
+    )
+    .0
+}
+
+/// Creator's revenue bucket for the setup period in a payment mint (system program = SOL)
+pub fn revenue_bucket_address(content: &Content, payment_token_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            RevenueBucket::SEED_PREFIX,
+            content.creator.pubkey().as_ref(),
+            payment_token_mint.as_ref(),
+            &content.revenue_period.to_le_bytes(),
+        ],
+        &distribution_cpi::ID,
+    )
+    .0
+}
+
+/// Takedown record for CONTENT_ID, checked by every sale
+pub fn creator_rebate_address(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CreatorRebate::SEED_PREFIX, creator.as_ref()], &payment_escrow::ID).0
+}
+
+pub fn platform_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[PlatformConfig::SEED_PREFIX], &payment_escrow::ID).0
+}
+
+pub fn content_freeze_address() -> Pubkey {
+    Pubkey::find_program_address(&[ContentFreeze::SEED_PREFIX, &CONTENT_ID], &payment_escrow::ID).0
+}
+
+/// Escrow program authority that signs access mints made without an escrow
+pub fn access_minter_address() -> Pubkey {
+    Pubkey::find_program_address(&[PlatformConfig::ACCESS_MINTER_SEED], &payment_escrow::ID).0
+}
+
+/// Denylist entry PDA for a wallet, checked on every purchase
+pub fn denylist_entry_address(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[DenylistEntry::SEED_PREFIX, wallet.as_ref()], &payment_escrow::ID).0
+}
+
+/// Buyer vault shared by the buyer's escrows in a payment mint (None = SOL)
+pub fn buyer_vault_address(buyer: &Pubkey, payment_token_mint: Option<Pubkey>) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            BuyerVault::SEED_PREFIX,
+            buyer.as_ref(),
+            BuyerVault::mint_seed(&payment_token_mint).as_ref(),
+        ],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+/// Buyer's pre-funded deposit in a payment mint (None = SOL)
+pub fn buyer_deposit_address(buyer: &Pubkey, payment_token_mint: Option<Pubkey>) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            BuyerDeposit::SEED_PREFIX,
+            buyer.as_ref(),
+            BuyerVault::mint_seed(&payment_token_mint).as_ref(),
+        ],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+pub fn listing_address(creator: &Pubkey, content_id: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[Listing::SEED_PREFIX, creator.as_ref(), content_id],
+        &payment_escrow::ID,
+    )
+    .0
+}
+
+/// List `content_id` for SOL at PRICE under the default license terms
+pub fn create_listing_ix(content: &Content, creator: &Pubkey, content_id: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::CreateListing {
+            creator: *creator,
+            listing: listing_address(creator, &content_id),
+            platform_config: content.platform_config,
+            payment_mint: None,
+            creator_bond: None,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::CreateListing {
+            content_id,
+            price: PRICE,
+            payment_token_mint: None,
+            price_change_policy: PriceChangePolicy::Grandfather,
+            license_terms_hash: [0; 32],
+        }
+        .data(),
+    }
+}
+
+pub fn initialize_escrow_ix(
+    content: &Content,
+    escrow_state: Pubkey,
+    payment_token_mint: Option<Pubkey>,
+    seed: u64,
+) -> Instruction {
+    initialize_escrow_with_vault_ata_ix(content, escrow_state, payment_token_mint, seed, None)
+}
+
+/// `initialize_escrow_ix` that also creates the buyer vault's ATA when given the SPL token accounts
+pub fn initialize_escrow_with_vault_ata_ix(
+    content: &Content,
+    escrow_state: Pubkey,
+    payment_token_mint: Option<Pubkey>,
+    seed: u64,
+    tokens: Option<&TokenAccounts>,
+) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::InitializeEscrow {
+            buyer: content.buyer.pubkey(),
+            payer: content.buyer.pubkey(),
+            creator: content.creator.pubkey(),
+            platform_config: content.platform_config,
+            content_freeze: content_freeze_address(),
+            listing: None,
+            escrow_state,
+            buyer_vault: buyer_vault_address(&content.buyer.pubkey(), payment_token_mint),
+            payment_mint: payment_token_mint,
+            vault_token_account: tokens.map(|tokens| tokens.buyer_vault),
+            token_program: tokens.map(|tokens| tokens.token_program),
+            associated_token_program: tokens.map(|_| spl_associated_token_account::ID),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::InitializeEscrow {
+            content_id: CONTENT_ID,
+            price: PRICE,
+            payment_token_mint,
+            seed,
+            memo: None,
+        }
+        .data(),
+    }
+}
+
+/// Token accounts used by an SPL purchase
+pub struct TokenAccounts {
+    pub token_program: Pubkey,
+    pub payment_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub buyer_vault: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub creator: Pubkey,
+    pub treasury: Pubkey,
+    pub collaborator: Pubkey,
+}
+
+/// Create a payment mint, fund the buyer and open ATAs for every party
+pub async fn setup_token_accounts(ctx: &mut ProgramTestContext, content: &Content) -> TokenAccounts {
+    setup_payment_mint(ctx, content, spl_token::ID, None).await
+}
+
+/// Same as `setup_token_accounts` with a Token-2022 mint charging a transfer fee
+pub async fn setup_token_2022_accounts(
+    ctx: &mut ProgramTestContext,
+    content: &Content,
+    transfer_fee_bps: u16,
+) -> TokenAccounts {
+    setup_payment_mint(ctx, content, spl_token_2022::ID, Some(transfer_fee_bps)).await
+}
+
+async fn setup_payment_mint(
+    ctx: &mut ProgramTestContext,
+    content: &Content,
+    token_program: Pubkey,
+    transfer_fee_bps: Option<u16>,
+) -> TokenAccounts {
+    let payment_mint = Keypair::new();
+    let buyer_vault = buyer_vault_address(&content.buyer.pubkey(), Some(payment_mint.pubkey()));
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+
+    let mint_len = match transfer_fee_bps {
+        Some(_) => ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap(),
+        None => spl_token::state::Mint::LEN,
+    };
+    let mut instructions = vec![system_instruction::create_account(
+        &payer,
+        &payment_mint.pubkey(),
+        rent.minimum_balance(mint_len),
+        mint_len as u64,
+        &token_program,
+    )];
+    if let Some(transfer_fee_bps) = transfer_fee_bps {
+        instructions.push(
+            transfer_fee::instruction::initialize_transfer_fee_config(
+                &token_program,
+                &payment_mint.pubkey(),
+                None,
+                None,
+                transfer_fee_bps,
+                u64::MAX,
+            )
+            .unwrap(),
+        );
+    }
+    instructions.push(
+        spl_token_2022::instruction::initialize_mint2(&token_program, &payment_mint.pubkey(), &payer, None, 6)
+            .unwrap(),
+    );
+
+    let owners = [
+        content.buyer.pubkey(),
+        buyer_vault,
+        content.distribution_vault,
+        content.creator.pubkey(),
+        content.treasury.pubkey(),
+        content.collaborator.pubkey(),
+    ];
+    for owner in owners.iter() {
+        instructions.push(
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &payer,
+                owner,
+                &payment_mint.pubkey(),
+                &token_program,
+            ),
+        );
+    }
+
+    let ata = |owner: &Pubkey| {
+        get_associated_token_address_with_program_id(owner, &payment_mint.pubkey(), &token_program)
+    };
+    let buyer = ata(&content.buyer.pubkey());
+    instructions.push(
+        spl_token_2022::instruction::mint_to(&token_program, &payment_mint.pubkey(), &buyer, &payer, &[], PRICE)
+            .unwrap(),
+    );
+
+    send(ctx, &instructions, &[&payment_mint]).await.unwrap();
+
+    TokenAccounts {
+        token_program,
+        payment_mint: payment_mint.pubkey(),
+        buyer,
+        buyer_vault: ata(&buyer_vault),
+        distribution_vault: ata(&content.distribution_vault),
+        creator: ata(&content.creator.pubkey()),
+        treasury: ata(&content.treasury.pubkey()),
+        collaborator: ata(&content.collaborator.pubkey()),
+    }
+}
+
+/// Create a Token-2022 payment mint with a permanent delegate, which escrows reject by default
+pub async fn create_permanent_delegate_mint(ctx: &mut ProgramTestContext) -> Pubkey {
+    let payment_mint = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::PermanentDelegate,
+    ])
+    .unwrap();
+
+    let instructions = [
+        system_instruction::create_account(
+            &payer,
+            &payment_mint.pubkey(),
+            rent.minimum_balance(mint_len),
+            mint_len as u64,
+            &spl_token_2022::ID,
+        ),
+        initialize_permanent_delegate(&spl_token_2022::ID, &payment_mint.pubkey(), &payer).unwrap(),
+        spl_token_2022::instruction::initialize_mint2(&spl_token_2022::ID, &payment_mint.pubkey(), &payer, None, 6)
+            .unwrap(),
+    ];
+    send(ctx, &instructions, &[&payment_mint]).await.unwrap();
+
+    payment_mint.pubkey()
+}
+
+pub fn set_extension_mint_whitelisted_ix(
+    content: &Content,
+    admin: Pubkey,
+    mint: Pubkey,
+    whitelisted: bool,
+) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetExtensionMintWhitelisted {
+            admin,
+            platform_config: content.platform_config,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetExtensionMintWhitelisted { mint, whitelisted }.data(),
+    }
+}
+
+/// `distribution_index` is the split's distribution count before this purchase
+pub fn buy_and_mint_ix(
+    content: &Content,
+    escrow_state: Pubkey,
+    tokens: Option<&TokenAccounts>,
+    distribution_index: u64,
+) -> Instruction {
+    buy_and_mint_with_receipt_ix(content, escrow_state, tokens, distribution_index, None)
+}
+
+/// `buy_and_mint_ix` that also mints a receipt NFT to `receipt_mint` when given
+pub fn buy_and_mint_with_receipt_ix(
+    content: &Content,
+    escrow_state: Pubkey,
+    tokens: Option<&TokenAccounts>,
+    distribution_index: u64,
+    receipt_mint: Option<Pubkey>,
+) -> Instruction {
+    let buyer_vault =
+        buyer_vault_address(&content.buyer.pubkey(), tokens.map(|tokens| tokens.payment_mint));
+    let buyer_access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+
+    // SOL purchases pass the system program in place of every token account
+    let (payment_token_mint, token_accounts, collaborator) = match tokens {
+        Some(tokens) => (
+            tokens.payment_mint,
+            [tokens.buyer, tokens.buyer_vault, tokens.distribution_vault, tokens.creator, tokens.treasury],
+            tokens.collaborator,
+        ),
+        None => (
+            system_program::ID,
+            [system_program::ID; 5],
+            content.collaborator.pubkey(),
+        ),
+    };
+
+    let mut accounts = payment_escrow::accounts::BuyAndMint {
+        buyer: content.buyer.pubkey(),
+        payer: content.buyer.pubkey(),
+        escrow_state,
+        buyer_deposit: None,
+        deposit_token_account: None,
+        session_key: None,
+        content_freeze: content_freeze_address(),
+        denylist_entry: denylist_entry_address(&content.buyer.pubkey()),
+        listing: None,
+        gate_token_account: None,
+        creator_badge: None,
+        platform: None,
+        purchase_counter: None,
+        attempt_log: None,
+        platform_sale: None,
+        insurance_pool: None,
+        instructions_sysvar: None,
+        platform_config: content.platform_config,
+        buyer_loyalty: None,
+        referrer_stats: None,
+        buyer_vault,
+        buyer_token_account: token_accounts[0],
+        vault_token_account: token_accounts[1],
+        token_program: tokens.map_or(spl_token::ID, |tokens| tokens.token_program),
+        access_mint_program: access_mint_cpi::ID,
+        access_mint_state: content.access_mint_state,
+        access_mint: content.access_mint.pubkey(),
+        mint_authority: content.mint_authority,
+        buyer_access_token_account,
+        preview_grant: None,
+        access_token_program: spl_token::ID,
+        associated_token_program: spl_associated_token_account::ID,
+        distribution_program: distribution_cpi::ID,
+        split_state: content.split_state,
+        distribution_record: distribution_record_address(&content.split_state, distribution_index),
+        revenue_bucket: revenue_bucket_address(content, &payment_token_mint),
+        distribution_vault: content.distribution_vault,
+        distribution_vault_token_account: token_accounts[2],
+        creator: content.creator.pubkey(),
+        platform_treasury: content.treasury.pubkey(),
+        payment_token_mint,
+        creator_token_account: token_accounts[3],
+        platform_treasury_token_account: token_accounts[4],
+        receipt_mint,
+        receipt_token_account: receipt_mint.map(|mint| {
+            get_associated_token_address_with_program_id(
+                &content.buyer.pubkey(),
+                &mint,
+                &spl_token_2022::ID,
+            )
+        }),
+        receipt_token_program: receipt_mint.map(|_| spl_token_2022::ID),
+        creator_rebate: creator_rebate_address(&content.creator.pubkey()),
+        rebate_mint: None,
+        creator_rebate_token_account: None,
+        rebate_authority: None,
+        fee_waiver: None,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    // The buyer signs wallet-paid purchases; the account only requires it without a session key
+    accounts[0].is_signer = true;
+    accounts.push(AccountMeta::new(collaborator, false));
+
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts,
+        data: payment_escrow::instruction::BuyAndMint {
+            payment_amount: PRICE,
+            license_terms_hash: [0; 32],
+            allowlist_proof: vec![],
+            receipt_uri: receipt_mint.map(|_| RECEIPT_URI.to_string()),
+            direct_split: false,
+            referrer: None,
+        }
+        .data(),
+    }
+}
+
+/// Create, pay for and complete a SOL escrow in one instruction
+pub fn initialize_and_buy_ix(
+    content: &Content,
+    escrow_state: Pubkey,
+    seed: u64,
+    distribution_index: u64,
+) -> Instruction {
+    let buyer_access_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+
+    let mut accounts = payment_escrow::accounts::InitializeAndBuy {
+        buyer: content.buyer.pubkey(),
+        creator: content.creator.pubkey(),
+        platform_config: content.platform_config,
+        content_freeze: content_freeze_address(),
+        denylist_entry: denylist_entry_address(&content.buyer.pubkey()),
+        listing: None,
+        gate_token_account: None,
+        creator_badge: None,
+        platform: None,
+        purchase_counter: None,
+        buyer_loyalty: None,
+        instructions_sysvar: None,
+        escrow_state,
+        buyer_token_account: system_program::ID,
+        token_program: spl_token::ID,
+        access_mint_program: access_mint_cpi::ID,
+        access_mint_state: content.access_mint_state,
+        access_mint: content.access_mint.pubkey(),
+        mint_authority: content.mint_authority,
+        access_minter: access_minter_address(),
+        buyer_access_token_account,
+        access_token_program: spl_token::ID,
+        associated_token_program: spl_associated_token_account::ID,
+        distribution_program: distribution_cpi::ID,
+        split_state: content.split_state,
+        distribution_record: distribution_record_address(&content.split_state, distribution_index),
+        revenue_bucket: revenue_bucket_address(content, &system_program::ID),
+        distribution_vault: content.distribution_vault,
+        distribution_vault_token_account: system_program::ID,
+        platform_treasury: content.treasury.pubkey(),
+        payment_token_mint: system_program::ID,
+        creator_token_account: system_program::ID,
+        platform_treasury_token_account: system_program::ID,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(content.collaborator.pubkey(), false));
+
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts,
+        data: payment_escrow::instruction::InitializeAndBuy {
+            content_id: CONTENT_ID,
+            price: PRICE,
+            payment_token_mint: None,
+            seed,
+            license_terms_hash: [0; 32],
+            allowlist_proof: vec![],
+        }
+        .data(),
+    }
+}
+
+/// Cancel a SOL escrow, passing the system program for the token accounts
+pub fn cancel_escrow_ix(content: &Content, escrow_state: Pubkey) -> Instruction {
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::CancelEscrow {
+            buyer: content.buyer.pubkey(),
+            escrow_state,
+            rent_recipient: content.buyer.pubkey(),
+            buyer_vault: buyer_vault_address(&content.buyer.pubkey(), None),
+            buyer_token_account: system_program::ID,
+            vault_token_account: system_program::ID,
+            payment_token_mint: system_program::ID,
+            token_program: system_program::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::CancelEscrow {}.data(),
+    }
+}
+
+pub fn expected_shares() -> (u64, u64, u64) {
+    let platform = PRICE * PLATFORM_FEE_BPS as u64 / 10_000;
+    let collaborator = PRICE * COLLABORATOR_SHARE_BPS as u64 / 10_000;
+    (platform, collaborator, PRICE - platform - collaborator)
+}
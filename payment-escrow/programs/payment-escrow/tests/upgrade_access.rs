@@ -0,0 +1,276 @@
+//! Upgrading basic-tier access to premium
+//!
+//! The tests need the programs built with `anchor build` and run with
+//! `cargo test -- --ignored`.
+
+mod support;
+
+use access_mint_cpi::state::{AccessGrant, AccessMintState, AccessTier};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use distribution_cpi::state::DistributionRecord;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+use support::*;
+
+const PREMIUM_PRICE: u64 = 3 * LAMPORTS_PER_SOL;
+const PREMIUM_SEED: u64 = 2;
+
+struct Premium {
+    mint: Keypair,
+    access_mint_state: Pubkey,
+    mint_authority: Pubkey,
+}
+
+/// List CONTENT_ID with a premium tier and initialize the premium access mint
+async fn setup_premium(ctx: &mut ProgramTestContext, content: &Content) -> Premium {
+    fund(ctx, &content.collaborator.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let creator = content.creator.pubkey();
+    let mint = Keypair::new();
+    let (access_mint_state, _) = Pubkey::find_program_address(
+        &[AccessMintState::SEED_PREFIX, creator.as_ref(), &CONTENT_ID, &PREMIUM_SEED.to_le_bytes()],
+        &access_mint_cpi::ID,
+    );
+    let (mint_authority, _) = Pubkey::find_program_address(
+        &[AccessMintState::AUTHORITY_SEED_PREFIX, creator.as_ref(), &CONTENT_ID, &PREMIUM_SEED.to_le_bytes()],
+        &access_mint_cpi::ID,
+    );
+
+    let set_premium_price = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetPremiumPrice {
+            creator,
+            listing: listing_address(&creator, &CONTENT_ID),
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetPremiumPrice { premium_price: PREMIUM_PRICE }.data(),
+    };
+    let initialize_premium_mint = Instruction {
+        program_id: access_mint_cpi::ID,
+        accounts: access_mint_cpi::accounts::InitializeMint {
+            creator,
+            access_mint_state,
+            mint: mint.pubkey(),
+            mint_authority,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: solana_sdk::sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: access_mint_cpi::instruction::InitializeMint {
+            content_id: CONTENT_ID,
+            seed: PREMIUM_SEED,
+            tier: AccessTier::Premium,
+            decimals: 0,
+            units_per_purchase: 1,
+        }
+        .data(),
+    };
+    send(
+        ctx,
+        &[
+            create_listing_ix(content, &creator, CONTENT_ID),
+            set_premium_price,
+            initialize_premium_mint,
+        ],
+        &[&content.creator, &mint],
+    )
+    .await
+    .unwrap();
+
+    Premium {
+        mint,
+        access_mint_state,
+        mint_authority,
+    }
+}
+
+fn upgrade_access_ix(
+    content: &Content,
+    premium: &Premium,
+    holder: &Pubkey,
+    distribution_index: u64,
+) -> Instruction {
+    let basic_mint = content.access_mint.pubkey();
+    let mut accounts = payment_escrow::accounts::UpgradeAccess {
+        buyer: *holder,
+        platform_config: content.platform_config,
+        listing: listing_address(&content.creator.pubkey(), &CONTENT_ID),
+        platform: None,
+        content_freeze: content_freeze_address(),
+        buyer_token_account: system_program::ID,
+        token_program: system_program::ID,
+        access_mint_program: access_mint_cpi::ID,
+        basic_access_mint_state: content.access_mint_state,
+        basic_mint,
+        buyer_basic_token_account: get_associated_token_address(holder, &basic_mint),
+        buyer_access_grant: Pubkey::find_program_address(
+            &[AccessGrant::SEED_PREFIX, basic_mint.as_ref(), holder.as_ref()],
+            &access_mint_cpi::ID,
+        )
+        .0,
+        premium_access_mint_state: premium.access_mint_state,
+        premium_mint: premium.mint.pubkey(),
+        premium_mint_authority: premium.mint_authority,
+        access_minter: access_minter_address(),
+        buyer_premium_token_account: get_associated_token_address(holder, &premium.mint.pubkey()),
+        access_token_program: spl_token::ID,
+        associated_token_program: spl_associated_token_account::ID,
+        distribution_program: distribution_cpi::ID,
+        split_state: content.split_state,
+        distribution_record: distribution_record_address(&content.split_state, distribution_index),
+        revenue_bucket: revenue_bucket_address(content, &system_program::ID),
+        distribution_vault: content.distribution_vault,
+        distribution_vault_token_account: system_program::ID,
+        creator: content.creator.pubkey(),
+        platform_treasury: content.treasury.pubkey(),
+        payment_token_mint: system_program::ID,
+        creator_token_account: system_program::ID,
+        platform_treasury_token_account: system_program::ID,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(content.collaborator.pubkey(), false));
+
+    Instruction {
+        program_id: payment_escrow::ID,
+        accounts,
+        data: payment_escrow::instruction::UpgradeAccess {}.data(),
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn upgrade_burns_basic_and_mints_premium_for_the_price_difference() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let premium = setup_premium(&mut ctx, &content).await;
+
+    let seed = 60;
+    let escrow_state = escrow_address(&content.buyer.pubkey(), seed);
+    send(
+        &mut ctx,
+        &[
+            initialize_escrow_ix(&content, escrow_state, None, seed),
+            buy_and_mint_ix(&content, escrow_state, None, 0),
+        ],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    send(
+        &mut ctx,
+        &[upgrade_access_ix(&content, &premium, &content.buyer.pubkey(), 1)],
+        &[&content.buyer],
+    )
+    .await
+    .unwrap();
+
+    // The basic unit is burned and a premium one minted in its place
+    let basic_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &content.access_mint.pubkey());
+    let premium_token_account =
+        get_associated_token_address(&content.buyer.pubkey(), &premium.mint.pubkey());
+    assert_eq!(token_balance(&mut ctx, &basic_token_account).await, 0);
+    assert_eq!(token_balance(&mut ctx, &premium_token_account).await, 1);
+
+    // Only the difference over the basic price was charged and distributed
+    let record: DistributionRecord =
+        fetch(&mut ctx, &distribution_record_address(&content.split_state, 1)).await;
+    assert_eq!(record.total_amount, PREMIUM_PRICE - PRICE);
+    assert_eq!(lamports(&mut ctx, &content.distribution_vault).await, 0);
+}
+
+#[tokio::test]
+#[ignore = "requires programs built with `anchor build`"]
+async fn rented_access_cannot_be_upgraded() {
+    let mut ctx = start().await;
+    let content = setup_content(&mut ctx).await;
+    let premium = setup_premium(&mut ctx, &content).await;
+
+    let renter = Keypair::new();
+    fund(&mut ctx, &renter.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let basic_mint = content.access_mint.pubkey();
+    let rental_token_account = get_associated_token_address(&renter.pubkey(), &basic_mint);
+    let listing = listing_address(&content.creator.pubkey(), &CONTENT_ID);
+
+    // Free day-long rentals, minted through the escrow program's access minter
+    let set_rental_terms = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::SetRentalTerms {
+            creator: content.creator.pubkey(),
+            listing,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::SetRentalTerms {
+            rental_price: 0,
+            rental_duration: 24 * 60 * 60,
+        }
+        .data(),
+    };
+    send(&mut ctx, &[set_rental_terms], &[&content.creator]).await.unwrap();
+    let rent = Instruction {
+        program_id: payment_escrow::ID,
+        accounts: payment_escrow::accounts::RentAccess {
+            buyer: renter.pubkey(),
+            platform_config: content.platform_config,
+            listing,
+            platform: None,
+            content_freeze: content_freeze_address(),
+            buyer_token_account: system_program::ID,
+            token_program: system_program::ID,
+            access_mint_program: access_mint_cpi::ID,
+            access_mint_state: content.access_mint_state,
+            access_mint: basic_mint,
+            mint_authority: content.mint_authority,
+            access_minter: access_minter_address(),
+            buyer_access_token_account: rental_token_account,
+            access_grant: Pubkey::find_program_address(
+                &[AccessGrant::SEED_PREFIX, basic_mint.as_ref(), renter.pubkey().as_ref()],
+                &access_mint_cpi::ID,
+            )
+            .0,
+            access_token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            distribution_program: distribution_cpi::ID,
+            split_state: content.split_state,
+            distribution_record: distribution_record_address(&content.split_state, 0),
+            revenue_bucket: revenue_bucket_address(&content, &system_program::ID),
+            distribution_vault: content.distribution_vault,
+            distribution_vault_token_account: system_program::ID,
+            creator: content.creator.pubkey(),
+            platform_treasury: content.treasury.pubkey(),
+            payment_token_mint: system_program::ID,
+            creator_token_account: system_program::ID,
+            platform_treasury_token_account: system_program::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: payment_escrow::instruction::RentAccess {}.data(),
+    };
+    send(&mut ctx, &[rent], &[&renter]).await.unwrap();
+
+    // A rental would otherwise turn into permanent premium access for the price difference
+    let renter_before = lamports(&mut ctx, &renter.pubkey()).await;
+    let result = send(
+        &mut ctx,
+        &[upgrade_access_ix(&content, &premium, &renter.pubkey(), 0)],
+        &[&renter],
+    )
+    .await;
+    assert!(result.is_err());
+
+    assert_eq!(token_balance(&mut ctx, &rental_token_account).await, 1);
+    assert_eq!(lamports(&mut ctx, &renter.pubkey()).await, renter_before);
+    let premium_token_account = get_associated_token_address(&renter.pubkey(), &premium.mint.pubkey());
+    assert!(ctx.banks_client.get_account(premium_token_account).await.unwrap().is_none());
+}
@@ -7,6 +7,12 @@ import {
   Keypair,
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("Payment Escrow Program", () => {
@@ -18,6 +24,27 @@ describe("Payment Escrow Program", () => {
   let buyer: Keypair;
   let creator: Keypair;
 
+  const [platformConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    program.programId
+  );
+
+  // Program data account recording the upgrade authority, the only wallet allowed to initialize the config
+  const [programDataPda] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
+  // Buyer vault shared by all of the buyer's SOL escrows
+  const [buyerVaultPda, buyerVaultBump] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("buyer_vault"),
+      provider.wallet.publicKey.toBuffer(),
+      SystemProgram.programId.toBuffer(),
+    ],
+    program.programId
+  );
+
   const contentId = Array.from({ length: 32 }, (_, i) => i + 1);
   const price = new anchor.BN(1 * LAMPORTS_PER_SOL);
   
@@ -35,6 +62,22 @@ describe("Payment Escrow Program", () => {
     );
     await provider.connection.confirmTransaction(airdropSig);
 
+    // Initialize platform config (may already exist on a shared cluster)
+    try {
+      await program.methods
+        .initializePlatformConfig(false)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          program: program.programId,
+          programData: programDataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    } catch (error) {
+      console.log("Platform config already initialized");
+    }
+
     console.log("Test accounts initialized");
     console.log("Program ID:", program.programId.toString());
   });
@@ -56,12 +99,18 @@ describe("Payment Escrow Program", () => {
         program.programId
       );
 
+      const configBefore = await program.account.platformConfig.fetch(platformConfigPda);
+
       const tx = await program.methods
-        .initializeEscrow(contentId, price, null, seed)
+        .initializeEscrow(contentId, price, null, seed, "order-1001")
         .accountsPartial({
           buyer: buyer.publicKey,
+          payer: buyer.publicKey,
           creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: null,
           escrowState: escrowPda,
+          buyerVault: buyerVaultPda,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -78,6 +127,13 @@ describe("Payment Escrow Program", () => {
       expect(escrowState.paymentAmount.toNumber()).to.equal(0);
       expect(escrowState.paymentTokenMint).to.be.null;
       expect(escrowState.seed.toString()).to.equal(seed.toString());
+      expect(escrowState.vaultBump).to.equal(buyerVaultBump);
+      expect(escrowState.rentRecipient.toString()).to.equal(buyer.publicKey.toString());
+      expect(escrowState.memo).to.equal("order-1001");
+
+      // The EscrowInitialized event took the next platform-wide sequence number
+      const configAfter = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(configAfter.eventSequence.toNumber()).to.equal(configBefore.eventSequence.toNumber() + 1);
 
       console.log("Escrow created on-chain");
       console.log("Price:", escrowState.price.toNumber() / LAMPORTS_PER_SOL, "SOL");
@@ -98,11 +154,15 @@ describe("Payment Escrow Program", () => {
       );
 
       await program.methods
-        .initializeEscrow(contentId, price, null, seed2)
+        .initializeEscrow(contentId, price, null, seed2, null)
         .accountsPartial({
           buyer: buyer.publicKey,
+          payer: buyer.publicKey,
           creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: null,
           escrowState: escrowPda2,
+          buyerVault: buyerVaultPda,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -118,6 +178,12 @@ describe("Payment Escrow Program", () => {
       expect(escrow1.buyer.toString()).to.equal(escrow2.buyer.toString());
       expect(escrow1.creator.toString()).to.equal(escrow2.creator.toString());
 
+      // Both escrows pay through the same buyer vault
+      const buyerVault = await program.account.buyerVault.fetch(buyerVaultPda);
+      expect(buyerVault.buyer.toString()).to.equal(buyer.publicKey.toString());
+      expect(buyerVault.paymentTokenMint).to.be.null;
+      expect(buyerVault.heldAmount.toNumber()).to.equal(0);
+
       console.log("Multiple escrows for same buyer/content supported");
       console.log("Escrow 1 seed:", escrow1.seed.toString());
       console.log("Escrow 2 seed:", escrow2.seed.toString());
@@ -138,18 +204,17 @@ describe("Payment Escrow Program", () => {
         program.programId
       );
 
-      const [vaultPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), escrowPda3.toBuffer()],
-        program.programId
-      );
-
       // Initialize escrow
       await program.methods
-        .initializeEscrow(contentId, price, null, seed3)
+        .initializeEscrow(contentId, price, null, seed3, null)
         .accountsPartial({
           buyer: buyer.publicKey,
+          payer: buyer.publicKey,
           creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: null,
           escrowState: escrowPda3,
+          buyerVault: buyerVaultPda,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -162,10 +227,12 @@ describe("Payment Escrow Program", () => {
         .cancelEscrow()
         .accountsPartial({
           buyer: buyer.publicKey,
+          rentRecipient: buyer.publicKey,
           escrowState: escrowPda3,
-          vault: vaultPda,
+          buyerVault: buyerVaultPda,
           buyerTokenAccount: buyer.publicKey,  // Placeholder for SOL
-          vaultTokenAccount: vaultPda,         // Placeholder for SOL
+          vaultTokenAccount: buyerVaultPda,    // Placeholder for SOL
+          paymentTokenMint: SystemProgram.programId,
           tokenProgram: SystemProgram.programId,
           systemProgram: SystemProgram.programId,
         })
@@ -201,11 +268,15 @@ describe("Payment Escrow Program", () => {
 
       // Initialize
       await program.methods
-        .initializeEscrow(contentId, price, null, seed4)
+        .initializeEscrow(contentId, price, null, seed4, null)
         .accountsPartial({
           buyer: buyer.publicKey,
+          payer: buyer.publicKey,
           creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: null,
           escrowState: escrowPda,
+          buyerVault: buyerVaultPda,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -227,4 +298,1923 @@ describe("Payment Escrow Program", () => {
       console.log("Payment amount:", escrowState.paymentAmount.toNumber());
     });
   });
+
+  describe("Creator Co-signing", () => {
+    const listedContentId = Array.from({ length: 32 }, (_, i) => 32 - i);
+    const licenseTermsHash = Array.from({ length: 32 }, () => 5);
+
+    before(async () => {
+      await program.methods
+        .updatePlatformConfig(true)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .updatePlatformConfig(false)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+    });
+
+    it("Should reject escrow without creator signature or listing", async () => {
+      const seed = getUniqueSeed();
+
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeEscrow(listedContentId, price, null, seed, null)
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            payer: buyer.publicKey,
+            creator: creator.publicKey,
+            platformConfig: platformConfigPda,
+            listing: null,
+            escrowState: escrowPda,
+            buyerVault: buyerVaultPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Escrow should require creator signature");
+      } catch (error: any) {
+        expect(error.toString()).to.include("CreatorSignatureRequired");
+      }
+    });
+
+    it("Should initialize escrow against a creator-signed listing", async () => {
+      const seed = getUniqueSeed();
+
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createListing(listedContentId, price, null, { grandfather: {} }, licenseTermsHash)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeEscrow(listedContentId, price, null, seed, null)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: listingPda,
+          escrowState: escrowPda,
+          buyerVault: buyerVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const escrowState = await program.account.escrowState.fetch(escrowPda);
+      expect(escrowState.creator.toString()).to.equal(creator.publicKey.toString());
+
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(listing.licenseTermsHash).to.deep.equal(licenseTermsHash);
+      expect(listing.paymentDecimals).to.equal(9);
+      console.log("Escrow initialized against listing");
+    });
+
+    it("Should reject escrow whose price differs from the listing", async () => {
+      const seed = getUniqueSeed();
+
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeEscrow(listedContentId, price.muln(3), null, seed, null)
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            payer: buyer.publicKey,
+            creator: creator.publicKey,
+            platformConfig: platformConfigPda,
+            listing: listingPda,
+            escrowState: escrowPda,
+            buyerVault: buyerVaultPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Escrow terms should match the listing");
+      } catch (error: any) {
+        expect(error.toString()).to.include("ListingMismatch");
+      }
+    });
+
+    it("Should create an empty attempt log for a listing", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [attemptLogPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("attempt_log"), listingPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeAttemptLog()
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+          attemptLog: attemptLogPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const attemptLog = await program.account.attemptLog.fetch(attemptLogPda);
+      expect(attemptLog.listing.toString()).to.equal(listingPda.toString());
+      expect(attemptLog.totalAttempts.toNumber()).to.equal(0);
+      expect(attemptLog.entries).to.have.length(0);
+    });
+
+    it("Should bump listing price version on price update", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      const before = await program.account.listing.fetch(listingPda);
+
+      await program.methods
+        .updateListingPrice(price.muln(2))
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const after = await program.account.listing.fetch(listingPda);
+      expect(after.price.toString()).to.equal(price.muln(2).toString());
+      expect(after.priceVersion).to.equal(before.priceVersion + 1);
+      console.log("Listing price version:", after.priceVersion);
+    });
+
+    it("Should reject listing prices below the platform floor", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const floor = new anchor.BN(0.001 * LAMPORTS_PER_SOL);
+
+      await program.methods
+        .setMinPrice(null, floor)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.minPriceLamports.toString()).to.equal(floor.toString());
+
+      try {
+        await program.methods
+          .updateListingPrice(floor.subn(1))
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown PriceBelowFloor error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("PriceBelowFloor");
+      }
+
+      // Remove the floor for the remaining tests
+      await program.methods
+        .setMinPrice(null, new anchor.BN(0))
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+    });
+
+    it("Should set the loyalty rate within bounds", async () => {
+      try {
+        await program.methods
+          .setLoyaltyRate(10001)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidLoyaltyRate error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidLoyaltyRate");
+      }
+
+      await program.methods
+        .setLoyaltyRate(100)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+
+      const config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.loyaltyRateBps).to.equal(100);
+    });
+
+    it("Should set the payment tolerance within bounds", async () => {
+      try {
+        await program.methods
+          .setPaymentTolerance(101)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidPaymentTolerance error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidPaymentTolerance");
+      }
+
+      await program.methods
+        .setPaymentTolerance(10)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+
+      let config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.paymentToleranceBps).to.equal(10);
+
+      // Restore exact payments for the remaining tests
+      await program.methods
+        .setPaymentTolerance(0)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+        })
+        .rpc();
+
+      config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.paymentToleranceBps).to.equal(0);
+    });
+
+    it("Should update the listing license terms", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      const newTermsHash = Array.from({ length: 32 }, () => 9);
+
+      await program.methods
+        .setLicenseTerms(newTermsHash)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(listing.licenseTermsHash).to.deep.equal(newTermsHash);
+    });
+
+    it("Should schedule a sale window for a listing", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .scheduleSale(new anchor.BN(2_000), new anchor.BN(1_000))
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Sale ending before it starts should be rejected");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidSaleSchedule");
+      }
+
+      const saleStart = Math.floor(Date.now() / 1000) + 3600;
+
+      await program.methods
+        .scheduleSale(new anchor.BN(saleStart), new anchor.BN(saleStart + 86400))
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.saleStartTs.toNumber()).to.equal(saleStart);
+      expect(listing.saleEndTs.toNumber()).to.equal(saleStart + 86400);
+
+      // Reopen the sale for the remaining tests
+      await program.methods
+        .scheduleSale(new anchor.BN(0), new anchor.BN(0))
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.saleStartTs.toNumber()).to.equal(0);
+    });
+
+    it("Should set presale and public sale phases for a listing", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      const now = Math.floor(Date.now() / 1000);
+      const presale = {
+        price: new anchor.BN(0.05 * LAMPORTS_PER_SOL),
+        supply: 100,
+        sold: 0,
+        allowlistRoot: Array.from({ length: 32 }, () => 1),
+        startTs: new anchor.BN(now - 60),
+        endTs: new anchor.BN(now + 3600),
+      };
+      const publicSale = {
+        ...presale,
+        price: new anchor.BN(0.08 * LAMPORTS_PER_SOL),
+        supply: 0,
+        allowlistRoot: Array(32).fill(0),
+        startTs: new anchor.BN(now + 3600),
+        endTs: new anchor.BN(now + 86400),
+      };
+
+      try {
+        await program.methods
+          .setSalePhases([publicSale, presale])
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Out of order phases should be rejected");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidSalePhases");
+      }
+
+      await program.methods
+        .setSalePhases([presale, publicSale])
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.phases.length).to.equal(2);
+      expect(listing.phases[0].price.toNumber()).to.equal(presale.price.toNumber());
+      expect(listing.phases[1].supply).to.equal(0);
+
+      // Clear the phases for the remaining tests
+      await program.methods
+        .setSalePhases([])
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.phases.length).to.equal(0);
+    });
+
+    it("Should gate a listing on another access token", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const seasonPassMint = Keypair.generate().publicKey;
+
+      await program.methods
+        .setGateMint(seasonPassMint)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.gateMint.toString()).to.equal(seasonPassMint.toString());
+
+      // Remove the gate for the remaining tests
+      await program.methods
+        .setGateMint(null)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.gateMint).to.be.null;
+    });
+
+    it("Should set a per-wallet purchase cooldown for a listing", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .setPurchaseCooldown(new anchor.BN(60))
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.purchaseCooldown.toNumber()).to.equal(60);
+
+      try {
+        await program.methods
+          .setPurchaseCooldown(new anchor.BN(-1))
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown InvalidPurchaseCooldown error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidPurchaseCooldown");
+      }
+
+      // Remove the throttle for the remaining tests
+      await program.methods
+        .setPurchaseCooldown(new anchor.BN(0))
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.purchaseCooldown.toNumber()).to.equal(0);
+    });
+
+    it("Should set the resale royalty within the 25% cap", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .setResaleRoyalty(1000)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(listing.resaleRoyaltyBps).to.equal(1000);
+
+      try {
+        await program.methods
+          .setResaleRoyalty(2501)
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown InvalidResaleRoyalty error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidResaleRoyalty");
+      }
+    });
+
+    it("Should configure a platform sale and opt a listing in", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [platformSalePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_sale")],
+        program.programId
+      );
+      const now = Math.floor(Date.now() / 1000);
+
+      try {
+        await program.methods
+          .configurePlatformSale(2000, new anchor.BN(now), new anchor.BN(now - 1), true)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+            platformSale: platformSalePda,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidSaleSchedule error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidSaleSchedule");
+      }
+
+      await program.methods
+        .configurePlatformSale(2000, new anchor.BN(now), new anchor.BN(now + 3600), true)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          platformSale: platformSalePda,
+        })
+        .rpc();
+
+      const before = await provider.connection.getBalance(platformSalePda);
+      await program.methods
+        .fundPlatformSale(new anchor.BN(LAMPORTS_PER_SOL / 10))
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          platformSale: platformSalePda,
+        })
+        .rpc();
+      const after = await provider.connection.getBalance(platformSalePda);
+      expect(after - before).to.equal(LAMPORTS_PER_SOL / 10);
+
+      const platformSale = await program.account.platformSale.fetch(platformSalePda);
+      expect(platformSale.discountBps).to.equal(2000);
+      expect(platformSale.subsidized).to.be.true;
+
+      await program.methods
+        .setPlatformSaleOptIn(true)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(listing.platformSaleOptIn).to.be.true;
+    });
+
+    it("Should toggle strict mode for a listing", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      for (const strictMode of [true, false]) {
+        await program.methods
+          .setStrictMode(strictMode)
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+
+        const listing = await program.account.listing.fetch(listingPda);
+        expect(listing.strictMode).to.equal(strictMode);
+      }
+    });
+
+    it("Should tag a listing with a region code", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      for (const region of [840, 0]) {
+        await program.methods
+          .setListingRegion(region)
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+
+        const listing = await program.account.listing.fetch(listingPda);
+        expect(listing.region).to.equal(region);
+      }
+    });
+
+    it("Should cap a listing's cancellation fee at the platform maximum", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const setMaxFee = (maxFeeBps: number) =>
+        program.methods
+          .setMaxCancellationFee(maxFeeBps)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+          })
+          .rpc();
+      const setFee = (feeBps: number) =>
+        program.methods
+          .setCancellationFee(feeBps)
+          .accountsPartial({
+            creator: creator.publicKey,
+            platformConfig: platformConfigPda,
+            listing: listingPda,
+          })
+          .signers([creator])
+          .rpc();
+
+      await setMaxFee(500);
+
+      try {
+        await setFee(1000);
+        expect.fail("Should have thrown CancellationFeeTooHigh error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("CancellationFeeTooHigh");
+      }
+
+      await setFee(300);
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.cancellationFeeBps).to.equal(300);
+
+      // Restore full refunds for the remaining tests
+      await setFee(0);
+      await setMaxFee(0);
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.cancellationFeeBps).to.equal(0);
+    });
+
+    it("Should open a priority queue and escrow registered intents", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [queueStatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("queue_state"), listingPda.toBuffer()],
+        program.programId
+      );
+      const [queueEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("queue_entry"), queueStatePda.toBuffer(), buyer.publicKey.toBuffer()],
+        program.programId
+      );
+      const registerEndTs = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .initializeQueue(2, registerEndTs)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+          queueState: queueStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .registerIntent()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          queueState: queueStatePda,
+          queueEntry: queueEntryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const queue = await program.account.queueState.fetch(queueStatePda);
+      expect(queue.entrants).to.equal(1);
+      expect(queue.status).to.deep.equal({ registering: {} });
+
+      const entryLamports = await provider.connection.getBalance(queueEntryPda);
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        program.account.queueEntry.size
+      );
+      expect(entryLamports).to.equal(rent + queue.price.toNumber());
+
+      try {
+        await program.methods
+          .commitDraw()
+          .accountsPartial({
+            creator: creator.publicKey,
+            queueState: queueStatePda,
+            randomnessAccount: SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Draw should not be committed while registration is open");
+      } catch (err) {
+        expect(err.toString()).to.include("QueueRegistrationOpen");
+      }
+    });
+
+    it("Should hold paid access until it is claimed or expires", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [pendingAccessPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_access"), buyer.publicKey.toBuffer(), listingPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .payForAccess()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          listing: listingPda,
+          pendingAccess: pendingAccessPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const pendingAccess = await program.account.pendingAccess.fetch(pendingAccessPda);
+      expect(pendingAccess.buyer.toString()).to.equal(buyer.publicKey.toString());
+      expect(pendingAccess.expiryTs.toNumber()).to.be.greaterThan(pendingAccess.paidTs.toNumber());
+
+      try {
+        await program.methods
+          .refundPendingAccess()
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            pendingAccess: pendingAccessPda,
+          })
+          .rpc();
+        expect.fail("Pending access should not be refundable before it expires");
+      } catch (err) {
+        expect(err.toString()).to.include("PendingAccessNotExpired");
+      }
+    });
+
+    it("Should hold a billing deposit until the agreement is cancelled", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [billingAgreementPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("billing_agreement"), buyer.publicKey.toBuffer(), listingPda.toBuffer()],
+        program.programId
+      );
+      const rentalPrice = new anchor.BN(LAMPORTS_PER_SOL / 100);
+      const period = new anchor.BN(30 * 24 * 60 * 60);
+      const deposit = rentalPrice.muln(2);
+
+      await program.methods
+        .setRentalTerms(rentalPrice, period)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .createBillingAgreement(deposit)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          listing: listingPda,
+          billingAgreement: billingAgreementPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .fundBillingAgreement(rentalPrice)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          billingAgreement: billingAgreementPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const agreement = await program.account.billingAgreement.fetch(billingAgreementPda);
+      expect(agreement.amount.toString()).to.equal(rentalPrice.toString());
+      expect(agreement.period.toString()).to.equal(period.toString());
+      expect(agreement.deposit.toString()).to.equal(deposit.add(rentalPrice).toString());
+
+      await program.methods
+        .cancelBillingAgreement()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          billingAgreement: billingAgreementPda,
+        })
+        .rpc();
+
+      const closed = await provider.connection.getAccountInfo(billingAgreementPda);
+      expect(closed).to.be.null;
+    });
+
+    it("Should open a group buy capped at the listing price", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const seed = getUniqueSeed();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [campaignPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [contributionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("contribution"), campaignPda.toBuffer(), buyer.publicKey.toBuffer()],
+        program.programId
+      );
+      const [campaignVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("campaign_vault"), campaignPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeGroupBuy(deadline, seed, new anchor.BN(0))
+        .accountsPartial({
+          organizer: buyer.publicKey,
+          listing: listingPda,
+          campaign: campaignPda,
+          vault: campaignVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const campaign = await program.account.campaignState.fetch(campaignPda);
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(campaign.goal.toString()).to.equal(listing.price.toString());
+      expect(campaign.listing.toString()).to.equal(listingPda.toString());
+
+      try {
+        await program.methods
+          .contribute(campaign.goal.addn(1))
+          .accountsPartial({
+            contributor: buyer.publicKey,
+            campaign: campaignPda,
+            contribution: contributionPda,
+            vault: campaignVaultPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Group buy contributions should not exceed the price");
+      } catch (err) {
+        expect(err.toString()).to.include("GroupBuyOverfunded");
+      }
+    });
+
+    it("Should only accept ratings backed by a completed purchase", async () => {
+      const seed = getUniqueSeed();
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [ratingPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("rating"), listingPda.toBuffer(), buyer.publicKey.toBuffer()],
+        program.programId
+      );
+      const listing = await program.account.listing.fetch(listingPda);
+
+      await program.methods
+        .initializeEscrow(listedContentId, listing.price, null, seed, null)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: listingPda,
+          escrowState: escrowPda,
+          buyerVault: buyerVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .rateContent(5)
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            listing: listingPda,
+            escrowState: escrowPda,
+            ratingState: ratingPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Unpaid escrows should not be able to rate");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidEscrowStatus");
+      }
+
+      expect(listing.ratingCount).to.equal(0);
+    });
+
+    it("Should issue and revoke a creator badge", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+      const [badgeMintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_badge_mint")],
+        program.programId
+      );
+      const [creatorBadgePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_badge"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const badgeTokenAccount = getAssociatedTokenAddressSync(
+        badgeMintPda,
+        creator.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await program.methods
+        .initializeBadgeMint()
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          badgeMint: badgeMintPda,
+        })
+        .rpc();
+
+      await program.methods
+        .issueBadge()
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          creator: creator.publicKey,
+          creatorBadge: creatorBadgePda,
+          badgeMint: badgeMintPda,
+          creatorBadgeTokenAccount: badgeTokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      const badge = await program.account.creatorBadge.fetch(creatorBadgePda);
+      expect(badge.creator.toString()).to.equal(creator.publicKey.toString());
+      let badgeAccount = await getAccount(
+        provider.connection,
+        badgeTokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(badgeAccount.amount.toString()).to.equal("1");
+
+      await program.methods
+        .setRequireCreatorBadge(true)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.requireCreatorBadge).to.be.true;
+
+      await program.methods
+        .revokeBadge()
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          creator: creator.publicKey,
+          creatorBadge: creatorBadgePda,
+          badgeMint: badgeMintPda,
+          creatorBadgeTokenAccount: badgeTokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(creatorBadgePda)).to.be
+        .null;
+      badgeAccount = await getAccount(
+        provider.connection,
+        badgeTokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(badgeAccount.amount.toString()).to.equal("0");
+
+      // Lift the requirement for the remaining tests
+      await program.methods
+        .setRequireCreatorBadge(false)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.requireCreatorBadge).to.be.false;
+    });
+
+    it("Should commit content metadata URI for a listing", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      const [contentMetadataPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("content_metadata"), listingPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .commitContentMetadata("ar://metadata", Buffer.from("not the preimage"))
+          .accountsPartial({
+            creator: creator.publicKey,
+            listing: listingPda,
+            contentMetadata: contentMetadataPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Mismatched preimage should be rejected");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidContentId");
+      }
+
+      await program.methods
+        .commitContentMetadata("ar://metadata", null)
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+          contentMetadata: contentMetadataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const metadata = await program.account.contentMetadata.fetch(contentMetadataPda);
+      expect(metadata.uri).to.equal("ar://metadata");
+      expect(metadata.verified).to.be.false;
+      console.log("Content metadata committed:", metadata.uri);
+    });
+
+    it("Should register a platform and sell a listing through it", async () => {
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(listedContentId),
+        ],
+        program.programId
+      );
+
+      const [platformPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const treasury = Keypair.generate();
+
+      try {
+        await program.methods
+          .registerPlatform(1001)
+          .accountsPartial({
+            authority: creator.publicKey,
+            treasury: treasury.publicKey,
+            platform: platformPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown InvalidPlatformFee error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidPlatformFee");
+      }
+
+      await program.methods
+        .registerPlatform(250)
+        .accountsPartial({
+          authority: creator.publicKey,
+          treasury: treasury.publicKey,
+          platform: platformPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const platform = await program.account.platform.fetch(platformPda);
+      expect(platform.treasury.toString()).to.equal(treasury.publicKey.toString());
+      expect(platform.feeBps).to.equal(250);
+
+      await program.methods
+        .setListingPlatform()
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+          platform: platformPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      let listing = await program.account.listing.fetch(listingPda);
+      expect(listing.platform.toString()).to.equal(platformPda.toString());
+
+      // Detach the platform for the remaining tests
+      await program.methods
+        .setListingPlatform()
+        .accountsPartial({
+          creator: creator.publicKey,
+          listing: listingPda,
+          platform: null,
+        })
+        .signers([creator])
+        .rpc();
+
+      listing = await program.account.listing.fetch(listingPda);
+      expect(listing.platform).to.be.null;
+    });
+  });
+
+  describe("Creator Bond", () => {
+    it("Should stake a creator bond and hold withdrawals for the cooldown", async () => {
+      const [creatorBondPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_bond"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const stake = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+      await program.methods
+        .stakeBond(stake)
+        .accountsPartial({
+          creator: creator.publicKey,
+          creatorBond: creatorBondPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .requestUnstake(stake)
+        .accountsPartial({
+          creator: creator.publicKey,
+          creatorBond: creatorBondPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      try {
+        await program.methods
+          .unstakeBond()
+          .accountsPartial({
+            creator: creator.publicKey,
+            creatorBond: creatorBondPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown UnstakeCooldownActive error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("UnstakeCooldownActive");
+      }
+
+      // Disputes are recorded per completed purchase and slashed to its buyer,
+      // covered by the program tests in programs/payment-escrow/tests/creator_bond.rs
+      const bond = await program.account.creatorBond.fetch(creatorBondPda);
+      expect(bond.staked.toString()).to.equal(stake.toString());
+      expect(bond.unstakeAmount.toString()).to.equal(stake.toString());
+    });
+  });
+
+  describe("Sweep Surplus", () => {
+    it("Should return lamports sent to a buyer vault outside the escrow flow to the buyer", async () => {
+      const seed = getUniqueSeed();
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeEscrow(contentId, price, null, seed, null)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          listing: null,
+          escrowState: escrowPda,
+          buyerVault: buyerVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      // A mistaken transfer straight into the vault
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: buyer.publicKey,
+            toPubkey: buyerVaultPda,
+            lamports: LAMPORTS_PER_SOL / 20,
+          })
+        )
+      );
+
+      const sweepAccounts = (authority: PublicKey) => ({
+        authority,
+        buyer: buyer.publicKey,
+        platformConfig: platformConfigPda,
+        buyerVault: buyerVaultPda,
+        vaultTokenAccount: null,
+        recipientTokenAccount: null,
+        paymentMint: null,
+        tokenProgram: null,
+      });
+      const sweep = () => program.methods.sweepSurplus().accountsPartial(sweepAccounts(buyer.publicKey)).rpc();
+
+      // A creator the buyer has paid can't take the buyer's surplus
+      try {
+        await program.methods
+          .sweepSurplus()
+          .accountsPartial(sweepAccounts(creator.publicKey))
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await sweep();
+
+      // Only the rent reserve and escrowed payments remain
+      const vault = await program.account.buyerVault.fetch(buyerVaultPda);
+      const vaultInfo = await provider.connection.getAccountInfo(buyerVaultPda);
+      const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(
+        vaultInfo!.data.length
+      );
+      expect(vaultInfo!.lamports).to.equal(rentExempt + vault.heldAmount.toNumber());
+
+      try {
+        await sweep();
+        expect.fail("Should have thrown NoSurplus error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("NoSurplus");
+      }
+    });
+  });
+
+  describe("Cross-chain Payments", () => {
+    it("Should register a foreign emitter and reject Solana as a foreign chain", async () => {
+      const chain = 2; // Ethereum
+      const emitterAddress = Array.from({ length: 32 }, (_, i) => (i < 12 ? 0 : i));
+      const [foreignEmitterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("foreign_emitter"), new anchor.BN(chain).toArrayLike(Buffer, "le", 2)],
+        program.programId
+      );
+
+      await program.methods
+        .registerForeignEmitter(chain, emitterAddress)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          foreignEmitter: foreignEmitterPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const emitter = await program.account.foreignEmitter.fetch(foreignEmitterPda);
+      expect(emitter.chain).to.equal(chain);
+      expect(emitter.address).to.deep.equal(emitterAddress);
+
+      const [solanaEmitterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("foreign_emitter"), new anchor.BN(1).toArrayLike(Buffer, "le", 2)],
+        program.programId
+      );
+      try {
+        await program.methods
+          .registerForeignEmitter(1, emitterAddress)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+            foreignEmitter: solanaEmitterPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidEmitter error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidEmitter");
+      }
+    });
+  });
+
+  describe("Fiat Settlement", () => {
+    it("Should set and clear the settlement authority", async () => {
+      const settlementAuthority = Keypair.generate();
+      const setAuthority = (authority: PublicKey) =>
+        program.methods
+          .setSettlementAuthority(authority)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+          })
+          .rpc();
+
+      await setAuthority(settlementAuthority.publicKey);
+      let config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.settlementAuthority.toString()).to.equal(
+        settlementAuthority.publicKey.toString()
+      );
+
+      // The default pubkey disables fiat settlement
+      await setAuthority(PublicKey.default);
+      config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.settlementAuthority.toString()).to.equal(PublicKey.default.toString());
+    });
+  });
+
+  describe("Offers", () => {
+    it("Should escrow an offer and refund it on withdrawal", async () => {
+      const seed = getUniqueSeed();
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+      const [offerPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("offer"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [contentFreezePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("content_freeze"), Buffer.from(contentId)],
+        program.programId
+      );
+
+      await program.methods
+        .makeOffer(creator.publicKey, contentId, amount, seed)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          offer: offerPda,
+          contentFreeze: contentFreezePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const offer = await program.account.offer.fetch(offerPda);
+      expect(offer.amount.toString()).to.equal(amount.toString());
+      expect(offer.creator.toString()).to.equal(creator.publicKey.toString());
+
+      const offerLamports = await provider.connection.getBalance(offerPda);
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        program.account.offer.size
+      );
+      expect(offerLamports).to.equal(rent + amount.toNumber());
+
+      await program.methods
+        .withdrawOffer()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          offer: offerPda,
+        })
+        .rpc();
+
+      const closed = await provider.connection.getAccountInfo(offerPda);
+      expect(closed).to.be.null;
+    });
+  });
+
+  describe("Commissions", () => {
+    it("Should walk a milestone through submit, dispute and approval", async () => {
+      const seed = getUniqueSeed();
+      const amounts = [
+        new anchor.BN(LAMPORTS_PER_SOL / 20),
+        new anchor.BN(LAMPORTS_PER_SOL / 10),
+      ];
+      const [milestoneStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("milestone_state"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeCommission(creator.publicKey, contentId, seed, amounts)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          milestoneState: milestoneStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const submit = () =>
+        program.methods
+          .submitMilestone()
+          .accountsPartial({
+            creator: creator.publicKey,
+            milestoneState: milestoneStatePda,
+          })
+          .signers([creator])
+          .rpc();
+
+      await submit();
+      await program.methods
+        .disputeMilestone()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          milestoneState: milestoneStatePda,
+        })
+        .rpc();
+
+      let state = await program.account.milestoneState.fetch(milestoneStatePda);
+      expect(state.milestones[0].status).to.deep.equal({ disputed: {} });
+
+      // Approval requires a fresh submission
+      try {
+        await program.methods
+          .approveMilestone()
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            milestoneState: milestoneStatePda,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidMilestoneStatus error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidMilestoneStatus");
+      }
+
+      await submit();
+      await program.methods
+        .approveMilestone()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          milestoneState: milestoneStatePda,
+        })
+        .rpc();
+
+      state = await program.account.milestoneState.fetch(milestoneStatePda);
+      expect(state.milestones[0].status).to.deep.equal({ approved: {} });
+      expect(state.current).to.equal(0);
+    });
+
+    it("Should refund a commission once the admin upholds the buyer's dispute", async () => {
+      const seed = getUniqueSeed();
+      const amounts = [new anchor.BN(LAMPORTS_PER_SOL / 20)];
+      const [milestoneStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("milestone_state"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initializeCommission(creator.publicKey, contentId, seed, amounts)
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          milestoneState: milestoneStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .submitMilestone()
+        .accountsPartial({
+          creator: creator.publicKey,
+          milestoneState: milestoneStatePda,
+        })
+        .signers([creator])
+        .rpc();
+      await program.methods
+        .disputeMilestone()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          milestoneState: milestoneStatePda,
+        })
+        .rpc();
+
+      // Without the admin the milestone isn't overdue yet
+      try {
+        await program.methods
+          .refundCommission()
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            admin: null,
+            milestoneState: milestoneStatePda,
+          })
+          .rpc();
+        expect.fail("Should have thrown CommissionNotRefundable error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("CommissionNotRefundable");
+      }
+
+      // The test wallet is both the buyer and the platform admin
+      await program.methods
+        .refundCommission()
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          admin: buyer.publicKey,
+          milestoneState: milestoneStatePda,
+        })
+        .rpc();
+
+      const closed = await provider.connection.getAccountInfo(milestoneStatePda);
+      expect(closed).to.be.null;
+    });
+  });
+
+  describe("Fee Rebates", () => {
+    const [rebateAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("rebate_authority")],
+      program.programId
+    );
+
+    it("Should only accept a rebate mint controlled by the rebate authority", async () => {
+      const foreignMint = await createMint(
+        provider.connection,
+        buyer,
+        buyer.publicKey,
+        null,
+        6
+      );
+
+      try {
+        await program.methods
+          .setRebateConfig(5000)
+          .accountsPartial({
+            admin: buyer.publicKey,
+            platformConfig: platformConfigPda,
+            rebateMint: foreignMint,
+            rebateAuthority: rebateAuthorityPda,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidRebateConfig error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("InvalidRebateConfig");
+      }
+
+      const rebateMint = await createMint(
+        provider.connection,
+        buyer,
+        rebateAuthorityPda,
+        null,
+        6
+      );
+
+      await program.methods
+        .setRebateConfig(5000)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          rebateMint,
+          rebateAuthority: rebateAuthorityPda,
+        })
+        .rpc();
+
+      const config = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(config.rebateMint.toString()).to.equal(rebateMint.toString());
+      expect(config.rebateBps).to.equal(5000);
+    });
+
+    it("Should let a creator opt in and out of rebates", async () => {
+      const [creatorRebatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_rebate"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .optInRebates()
+        .accountsPartial({
+          creator: creator.publicKey,
+          creatorRebate: creatorRebatePda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const creatorRebate = await program.account.creatorRebate.fetch(creatorRebatePda);
+      expect(creatorRebate.creator.toString()).to.equal(creator.publicKey.toString());
+      expect(creatorRebate.totalRebated.toNumber()).to.equal(0);
+
+      await program.methods
+        .optOutRebates()
+        .accountsPartial({
+          creator: creator.publicKey,
+          creatorRebate: creatorRebatePda,
+        })
+        .signers([creator])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(creatorRebatePda)).to.be.null;
+    });
+  });
+
+  describe("Mystery Boxes", () => {
+    it("Should create a mystery box and reject a non-Switchboard randomness account", async () => {
+      const boxId = Array.from(Keypair.generate().publicKey.toBytes());
+      const items = [
+        Array.from(Keypair.generate().publicKey.toBytes()),
+        Array.from(Keypair.generate().publicKey.toBytes()),
+      ];
+      const price = new anchor.BN(LAMPORTS_PER_SOL / 10);
+      const [mysteryBoxPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("mystery_box"), creator.publicKey.toBuffer(), Buffer.from(boxId)],
+        program.programId
+      );
+
+      await program.methods
+        .createMysteryBox(boxId, price, items)
+        .accountsPartial({
+          creator: creator.publicKey,
+          mysteryBox: mysteryBoxPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const mysteryBox = await program.account.mysteryBox.fetch(mysteryBoxPda);
+      expect(mysteryBox.items.length).to.equal(2);
+      expect(mysteryBox.price.toString()).to.equal(price.toString());
+
+      const seed = getUniqueSeed();
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          buyer.publicKey.toBuffer(),
+          Buffer.from(boxId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .commitMysteryBox(seed)
+          .accountsPartial({
+            buyer: buyer.publicKey,
+            mysteryBox: mysteryBoxPda,
+            escrowState: escrowPda,
+            randomnessAccount: mysteryBoxPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Purchase should require a Switchboard randomness account");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRandomnessAccount");
+      }
+    });
+  });
+
+  describe("Insurance Pool", () => {
+    it("Should configure, fund and withdraw from the insurance pool", async () => {
+      const [insurancePoolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("insurance_pool")],
+        program.programId
+      );
+      const maxPayout = new anchor.BN(LAMPORTS_PER_SOL / 2);
+      const topUp = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+      await program.methods
+        .configureInsurancePool(1000, maxPayout)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          insurancePool: insurancePoolPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .fundInsurancePool(topUp)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          insurancePool: insurancePoolPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const pool = await program.account.insurancePool.fetch(insurancePoolPda);
+      expect(pool.contributionBps).to.equal(1000);
+      expect(pool.maxPayout.toString()).to.equal(maxPayout.toString());
+
+      const before = await provider.connection.getBalance(insurancePoolPda);
+      await program.methods
+        .withdrawInsurancePool(topUp)
+        .accountsPartial({
+          admin: buyer.publicKey,
+          platformConfig: platformConfigPda,
+          insurancePool: insurancePoolPda,
+        })
+        .rpc();
+      const after = await provider.connection.getBalance(insurancePoolPda);
+      expect(before - after).to.equal(topUp.toNumber());
+    });
+  });
+
+  describe("Crowdfunding Campaign", () => {
+    it("Should accept contributions towards the goal", async () => {
+      const seed = getUniqueSeed();
+      const goal = new anchor.BN(2 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [campaignPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign"),
+          creator.publicKey.toBuffer(),
+          Buffer.from(contentId),
+          seed.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [contributionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("contribution"), campaignPda.toBuffer(), buyer.publicKey.toBuffer()],
+        program.programId
+      );
+      const [campaignVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("campaign_vault"), campaignPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeCampaign(contentId, goal, deadline, seed)
+        .accountsPartial({
+          creator: creator.publicKey,
+          campaign: campaignPda,
+          vault: campaignVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const contribution = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+      await program.methods
+        .contribute(contribution)
+        .accountsPartial({
+          contributor: buyer.publicKey,
+          campaign: campaignPda,
+          contribution: contributionPda,
+          vault: campaignVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const campaign = await program.account.campaignState.fetch(campaignPda);
+      expect(campaign.totalRaised.toString()).to.equal(contribution.toString());
+      expect(campaign.contributorCount).to.equal(1);
+      expect(campaign.status).to.deep.equal({ active: {} });
+
+      const record = await program.account.contribution.fetch(contributionPda);
+      expect(record.amount.toString()).to.equal(contribution.toString());
+
+      console.log("Campaign raised:", campaign.totalRaised.toNumber() / LAMPORTS_PER_SOL, "SOL");
+    });
+  });
 });